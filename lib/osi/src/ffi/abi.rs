@@ -36,6 +36,12 @@
 //! alignment of 8. Instead, [`crate::ffi::Integer`] can be used to model the
 //! exact ABI of the foreign system.
 
+// Big-endian integer with the given native type and alignment.
+type Be<Native, Alignment> = crate::ffi::Integer<
+    crate::ffi::BigEndian<Native>,
+    Alignment,
+>;
+
 // Little-endian integer with the given native type and alignment.
 type Le<Native, Alignment> = crate::ffi::Integer<
     crate::ffi::LittleEndian<Native>,
@@ -127,6 +133,159 @@ pub mod aarch64_sysv {
     pub use super::shared::*;
 }
 
+/// # ARM EABI
+///
+/// This ABI represents the 32-bit little-endian EABI of ARM systems. It is
+/// used by Linux. Unlike the 32-bit ABI of x86, 64-bit integers and floats
+/// are aligned to 8 bytes.
+pub mod arm_eabi {
+    use crate::align;
+
+    pub type I8 = super::Le<i8, align::AlignAs<1>>;
+    pub type I16 = super::Le<i16, align::AlignAs<2>>;
+    pub type I32 = super::Le<i32, align::AlignAs<4>>;
+    pub type I64 = super::Le<i64, align::AlignAs<8>>;
+    pub type I128 = super::Le<i128, align::AlignAs<8>>;
+    pub type Isize = super::Le<i32, align::AlignAs<4>>;
+
+    pub type U8 = super::Le<u8, align::AlignAs<1>>;
+    pub type U16 = super::Le<u16, align::AlignAs<2>>;
+    pub type U32 = super::Le<u32, align::AlignAs<4>>;
+    pub type U64 = super::Le<u64, align::AlignAs<8>>;
+    pub type U128 = super::Le<u128, align::AlignAs<8>>;
+    pub type Usize = super::Le<u32, align::AlignAs<4>>;
+
+    pub type F32 = super::Le<f32, align::AlignAs<4>>;
+    pub type F64 = super::Le<f64, align::AlignAs<8>>;
+
+    pub type Addr = super::Le<core::num::NonZeroU32, align::AlignAs<4>>;
+    pub type Ptr<Target> = crate::ffi::Pointer<Addr, Target>;
+
+    pub use super::shared::*;
+}
+
+/// # LP64D loongarch64 ABI
+///
+/// This ABI represents the 64-bit LP64D ABI of loongarch64 systems, with
+/// 64-bit floating-point registers. It is used by Linux.
+pub mod loongarch64_lp64d {
+    use crate::align;
+
+    pub type I8 = super::Le<i8, align::AlignAs<1>>;
+    pub type I16 = super::Le<i16, align::AlignAs<2>>;
+    pub type I32 = super::Le<i32, align::AlignAs<4>>;
+    pub type I64 = super::Le<i64, align::AlignAs<8>>;
+    pub type I128 = super::Le<i128, align::AlignAs<16>>;
+    pub type Isize = super::Le<i64, align::AlignAs<8>>;
+
+    pub type U8 = super::Le<u8, align::AlignAs<1>>;
+    pub type U16 = super::Le<u16, align::AlignAs<2>>;
+    pub type U32 = super::Le<u32, align::AlignAs<4>>;
+    pub type U64 = super::Le<u64, align::AlignAs<8>>;
+    pub type U128 = super::Le<u128, align::AlignAs<16>>;
+    pub type Usize = super::Le<u64, align::AlignAs<8>>;
+
+    pub type F32 = super::Le<f32, align::AlignAs<4>>;
+    pub type F64 = super::Le<f64, align::AlignAs<8>>;
+
+    pub type Addr = super::Le<core::num::NonZeroU64, align::AlignAs<8>>;
+    pub type Ptr<Target> = crate::ffi::Pointer<Addr, Target>;
+
+    pub use super::shared::*;
+}
+
+/// # System-V ppc64 ABI
+///
+/// This ABI represents the 64-bit big-endian ABI of System-V for PowerPC
+/// systems (ELFv1). It is used by Linux.
+pub mod ppc64_sysv {
+    use crate::align;
+
+    pub type I8 = super::Be<i8, align::AlignAs<1>>;
+    pub type I16 = super::Be<i16, align::AlignAs<2>>;
+    pub type I32 = super::Be<i32, align::AlignAs<4>>;
+    pub type I64 = super::Be<i64, align::AlignAs<8>>;
+    pub type I128 = super::Be<i128, align::AlignAs<16>>;
+    pub type Isize = super::Be<i64, align::AlignAs<8>>;
+
+    pub type U8 = super::Be<u8, align::AlignAs<1>>;
+    pub type U16 = super::Be<u16, align::AlignAs<2>>;
+    pub type U32 = super::Be<u32, align::AlignAs<4>>;
+    pub type U64 = super::Be<u64, align::AlignAs<8>>;
+    pub type U128 = super::Be<u128, align::AlignAs<16>>;
+    pub type Usize = super::Be<u64, align::AlignAs<8>>;
+
+    pub type F32 = super::Be<f32, align::AlignAs<4>>;
+    pub type F64 = super::Be<f64, align::AlignAs<8>>;
+
+    pub type Addr = super::Be<core::num::NonZeroU64, align::AlignAs<8>>;
+    pub type Ptr<Target> = crate::ffi::Pointer<Addr, Target>;
+
+    pub use super::shared::*;
+}
+
+/// # LP64D riscv64 ABI
+///
+/// This ABI represents the 64-bit LP64D ABI of riscv64 systems, with 64-bit
+/// floating-point registers. It is used by Linux and most other UNIX
+/// compatible systems.
+pub mod riscv64_lp64d {
+    use crate::align;
+
+    pub type I8 = super::Le<i8, align::AlignAs<1>>;
+    pub type I16 = super::Le<i16, align::AlignAs<2>>;
+    pub type I32 = super::Le<i32, align::AlignAs<4>>;
+    pub type I64 = super::Le<i64, align::AlignAs<8>>;
+    pub type I128 = super::Le<i128, align::AlignAs<16>>;
+    pub type Isize = super::Le<i64, align::AlignAs<8>>;
+
+    pub type U8 = super::Le<u8, align::AlignAs<1>>;
+    pub type U16 = super::Le<u16, align::AlignAs<2>>;
+    pub type U32 = super::Le<u32, align::AlignAs<4>>;
+    pub type U64 = super::Le<u64, align::AlignAs<8>>;
+    pub type U128 = super::Le<u128, align::AlignAs<16>>;
+    pub type Usize = super::Le<u64, align::AlignAs<8>>;
+
+    pub type F32 = super::Le<f32, align::AlignAs<4>>;
+    pub type F64 = super::Le<f64, align::AlignAs<8>>;
+
+    pub type Addr = super::Le<core::num::NonZeroU64, align::AlignAs<8>>;
+    pub type Ptr<Target> = crate::ffi::Pointer<Addr, Target>;
+
+    pub use super::shared::*;
+}
+
+/// # System-V s390x ABI
+///
+/// This ABI represents the 64-bit big-endian ABI of System-V for
+/// z/Architecture systems. It is used by Linux. Unlike most other 64-bit
+/// ABIs, 128-bit integers are only aligned to 8 bytes.
+pub mod s390x_sysv {
+    use crate::align;
+
+    pub type I8 = super::Be<i8, align::AlignAs<1>>;
+    pub type I16 = super::Be<i16, align::AlignAs<2>>;
+    pub type I32 = super::Be<i32, align::AlignAs<4>>;
+    pub type I64 = super::Be<i64, align::AlignAs<8>>;
+    pub type I128 = super::Be<i128, align::AlignAs<8>>;
+    pub type Isize = super::Be<i64, align::AlignAs<8>>;
+
+    pub type U8 = super::Be<u8, align::AlignAs<1>>;
+    pub type U16 = super::Be<u16, align::AlignAs<2>>;
+    pub type U32 = super::Be<u32, align::AlignAs<4>>;
+    pub type U64 = super::Be<u64, align::AlignAs<8>>;
+    pub type U128 = super::Be<u128, align::AlignAs<8>>;
+    pub type Usize = super::Be<u64, align::AlignAs<8>>;
+
+    pub type F32 = super::Be<f32, align::AlignAs<4>>;
+    pub type F64 = super::Be<f64, align::AlignAs<8>>;
+
+    pub type Addr = super::Be<core::num::NonZeroU64, align::AlignAs<8>>;
+    pub type Ptr<Target> = crate::ffi::Pointer<Addr, Target>;
+
+    pub use super::shared::*;
+}
+
 /// # System-V x86 ABI
 ///
 /// This ABI represents the 32-bit ABI of System-V for x86 systems. It is used
@@ -187,12 +346,75 @@ pub mod x86_64_sysv {
     pub use super::shared::*;
 }
 
+/// # System-V x32 ABI
+///
+/// This ABI represents the 32-bit x32 ABI of System-V for x86-64 systems.
+/// It uses the x86-64 instruction set and alignments, but 32-bit pointers
+/// and `long`. It is used by Linux.
+pub mod x86_64_x32 {
+    use crate::align;
+
+    pub type I8 = super::Le<i8, align::AlignAs<1>>;
+    pub type I16 = super::Le<i16, align::AlignAs<2>>;
+    pub type I32 = super::Le<i32, align::AlignAs<4>>;
+    pub type I64 = super::Le<i64, align::AlignAs<8>>;
+    pub type I128 = super::Le<i128, align::AlignAs<16>>;
+    pub type Isize = super::Le<i32, align::AlignAs<4>>;
+
+    pub type U8 = super::Le<u8, align::AlignAs<1>>;
+    pub type U16 = super::Le<u16, align::AlignAs<2>>;
+    pub type U32 = super::Le<u32, align::AlignAs<4>>;
+    pub type U64 = super::Le<u64, align::AlignAs<8>>;
+    pub type U128 = super::Le<u128, align::AlignAs<16>>;
+    pub type Usize = super::Le<u32, align::AlignAs<4>>;
+
+    pub type F32 = super::Le<f32, align::AlignAs<4>>;
+    pub type F64 = super::Le<f64, align::AlignAs<8>>;
+
+    pub type Addr = super::Le<core::num::NonZeroU32, align::AlignAs<4>>;
+    pub type Ptr<Target> = crate::ffi::Pointer<Addr, Target>;
+
+    pub use super::shared::*;
+}
+
 #[cfg(all(
     target_arch = "aarch64",
     target_family = "unix",
 ))]
 pub use aarch64_sysv as target;
 
+#[cfg(all(
+    target_arch = "arm",
+    target_endian = "little",
+    target_family = "unix",
+))]
+pub use arm_eabi as target;
+
+#[cfg(all(
+    target_arch = "loongarch64",
+    target_family = "unix",
+))]
+pub use loongarch64_lp64d as target;
+
+#[cfg(all(
+    target_arch = "powerpc64",
+    target_endian = "big",
+    target_family = "unix",
+))]
+pub use ppc64_sysv as target;
+
+#[cfg(all(
+    target_arch = "riscv64",
+    target_family = "unix",
+))]
+pub use riscv64_lp64d as target;
+
+#[cfg(all(
+    target_arch = "s390x",
+    target_family = "unix",
+))]
+pub use s390x_sysv as target;
+
 #[cfg(all(
     target_arch = "x86",
     target_family = "unix",
@@ -202,9 +424,17 @@ pub use x86_sysv as target;
 #[cfg(all(
     target_arch = "x86_64",
     target_family = "unix",
+    target_pointer_width = "64",
 ))]
 pub use x86_64_sysv as target;
 
+#[cfg(all(
+    target_arch = "x86_64",
+    target_family = "unix",
+    target_pointer_width = "32",
+))]
+pub use x86_64_x32 as target;
+
 #[cfg(all(
     target_arch = "x86",
     target_env = "msvc",
@@ -218,3 +448,53 @@ pub use x86_win as target;
     target_family = "windows",
 ))]
 pub use x86_64_win as target;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify that `num()` yields the byte order of the respective ABI,
+    // regardless of the byte order of the host.
+    #[test]
+    fn num_byte_order() {
+        let v: ppc64_sysv::U32 = ppc64_sysv::num(0x01020304);
+        assert_eq!(crate::ffi::to_raw::<_, u32>(v).to_ne_bytes(), [1, 2, 3, 4]);
+        assert_eq!(crate::ffi::to_native::<_, u32>(v), 0x01020304);
+
+        let v: s390x_sysv::U64 = s390x_sysv::num(0x0102030405060708);
+        assert_eq!(crate::ffi::to_raw::<_, u64>(v).to_ne_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(crate::ffi::to_native::<_, u64>(v), 0x0102030405060708);
+
+        let v: x86_64_sysv::U32 = x86_64_sysv::num(0x01020304);
+        assert_eq!(crate::ffi::to_raw::<_, u32>(v).to_ne_bytes(), [4, 3, 2, 1]);
+        assert_eq!(crate::ffi::to_native::<_, u32>(v), 0x01020304);
+    }
+
+    // Verify the layout of the 32-bit ABIs, which differ in the alignment of
+    // 64-bit integers.
+    #[test]
+    fn ilp32_layout() {
+        assert_eq!(core::mem::align_of::<arm_eabi::U64>(), 8);
+        assert_eq!(core::mem::align_of::<arm_eabi::F64>(), 8);
+        assert_eq!(core::mem::size_of::<arm_eabi::Usize>(), 4);
+        assert_eq!(core::mem::align_of::<x86_sysv::U64>(), 4);
+        assert_eq!(core::mem::size_of::<x86_sysv::Usize>(), 4);
+        assert_eq!(core::mem::align_of::<x86_64_x32::U64>(), 8);
+        assert_eq!(core::mem::align_of::<x86_64_x32::U128>(), 16);
+        assert_eq!(core::mem::size_of::<x86_64_x32::Usize>(), 4);
+        assert_eq!(core::mem::size_of::<x86_64_x32::Addr>(), 4);
+    }
+
+    // Verify the layout of the big-endian ABIs, which differ in the alignment
+    // of 128-bit integers.
+    #[test]
+    fn big_endian_layout() {
+        assert_eq!(core::mem::size_of::<ppc64_sysv::U64>(), 8);
+        assert_eq!(core::mem::align_of::<ppc64_sysv::U64>(), 8);
+        assert_eq!(core::mem::align_of::<ppc64_sysv::U128>(), 16);
+        assert_eq!(core::mem::size_of::<s390x_sysv::U64>(), 8);
+        assert_eq!(core::mem::align_of::<s390x_sysv::U64>(), 8);
+        assert_eq!(core::mem::align_of::<s390x_sysv::U128>(), 8);
+        assert_eq!(core::mem::size_of::<s390x_sysv::U128>(), 16);
+    }
+}
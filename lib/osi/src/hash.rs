@@ -4,6 +4,7 @@
 //! support. A common trait is provided to allow uniform access regardless of
 //! the hashing function used.
 
+pub mod crc32;
 pub mod sha256;
 
 /// A hash engine can be used to stream data into a hashing function and
@@ -0,0 +1,138 @@
+//! # CRC-32 Checksum
+//!
+//! This is an implementation of the CRC-32 checksum as used by ISO-HDLC,
+//! IEEE-802.3, zlib, and PNG. It uses the reflected polynomial `0xedb88320`,
+//! starts with all bits set, and inverts the final value.
+//!
+//! The checksum value is produced as 4 bytes in big-endian order, matching
+//! its common hexadecimal representation.
+
+/// Size of the fixed-size hash result the CRC-32 engine produces. The size is
+/// given in bytes.
+const CRC32_HASH: usize = 4;
+
+/// Reflected CRC-32 polynomial as defined by IEEE-802.3.
+const CRC32_POLYNOMIAL: u32 = 0xedb88320;
+
+/// Lookup table of the CRC-32 remainder of every possible byte value.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+/// CRC-32 engine type that implements a streaming capable checksum function
+/// compatible with CRC-32 (ISO-HDLC).
+#[derive(Clone, Debug)]
+pub struct Engine {
+    crc: u32,
+    total: u64,
+}
+
+/// Compute the lookup table of the CRC-32 engine for byte-wise processing.
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut v = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            v = if v & 1 == 1 { (v >> 1) ^ CRC32_POLYNOMIAL } else { v >> 1 };
+            bit += 1;
+        }
+        table[i] = v;
+        i += 1;
+    }
+
+    table
+}
+
+/// Update the CRC-32 remainder `crc` with the given bytes.
+fn crc32_update(mut crc: u32, data: &[u8]) -> u32 {
+    for b in data {
+        crc = CRC32_TABLE[((crc ^ *b as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    crc
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Self {
+            crc: !0,
+            total: 0,
+        }
+    }
+}
+
+impl crate::hash::Engine for Engine {
+    type Hash = crate::hash::Hash::<CRC32_HASH>;
+
+    fn is_reset(&self) -> bool {
+        self.total == 0
+    }
+
+    fn reset(&mut self) {
+        self.crc = !0;
+        self.total = 0;
+    }
+
+    fn push(&mut self, data: &[u8]) {
+        self.crc = crc32_update(self.crc, data);
+        self.total = self.total
+            .checked_add(u64::try_from(data.len()).unwrap()).unwrap();
+    }
+
+    fn finalize(&mut self) -> Self::Hash {
+        let r = crate::hash::Hash((!self.crc).to_be_bytes());
+
+        // Reset the engine.
+        self.crc = !0;
+        self.total = 0;
+
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test the CRC-32 engine against a couple of reference vectors.
+    #[test]
+    fn reference_vectors() {
+        assert_eq!(
+            crate::hash::hash::<Engine>(&[]).to_hex(),
+            "00000000",
+        );
+
+        assert_eq!(
+            crate::hash::hash::<Engine>(b"123456789").to_hex(),
+            "cbf43926",
+        );
+
+        assert_eq!(
+            crate::hash::hash::<Engine>(
+                b"The quick brown fox jumps over the lazy dog",
+            ).to_hex(),
+            "414fa339",
+        );
+    }
+
+    // Verify splitting of the input and zero-padding do not affect the
+    // output.
+    #[test]
+    fn split() {
+        let e: &mut dyn crate::hash::Engine<Hash = _> = &mut <Engine as Default>::default();
+
+        e.push(b"1234");
+        assert!(!e.is_reset());
+        e.push(b"");
+        e.push(b"56789");
+        assert_eq!(e.finalize().to_hex(), "cbf43926");
+        assert!(e.is_reset());
+
+        e.push(b"x");
+        e.push_zero(300);
+        let r = e.finalize();
+        let mut data = [0u8; 301];
+        data[0] = b'x';
+        assert_eq!(r.0, crate::hash::hash::<Engine>(&data).0);
+    }
+}
@@ -421,6 +421,8 @@ mod common {
 
     pub const SHF_AMD64_LARGE: u32 = 0x10000000; // from: oracle
 
+    pub const PN_XNUM: u16 = 0xffff;
+
     pub const PT_NULL: u32 = 0;
     pub const PT_LOAD: u32 = 1;
     pub const PT_DYNAMIC: u32 = 2;
@@ -648,6 +650,239 @@ mod common {
     pub const DF_P1_LAZYLOAD: u32 = 0x00000001;
     pub const DF_P1_GROUPPERM: u32 = 0x00000002;
 
+    pub const VER_DEF_NONE: u16 = 0;
+    pub const VER_DEF_CURRENT: u16 = 1;
+
+    pub const VER_NEED_NONE: u16 = 0;
+    pub const VER_NEED_CURRENT: u16 = 1;
+
+    pub const VER_FLG_BASE: u16 = 0x0001;
+    pub const VER_FLG_WEAK: u16 = 0x0002;
+    pub const VER_FLG_INFO: u16 = 0x0004; // from: solaris
+
+    pub const VER_NDX_LOCAL: u16 = 0;
+    pub const VER_NDX_GLOBAL: u16 = 1;
+    pub const VER_NDX_LORESERVE: u16 = 0xff00;
+    pub const VER_NDX_ELIMINATE: u16 = 0xff01;
+
+    pub const VERSYM_VERSION: u16 = 0x7fff; // from: llvm
+    pub const VERSYM_HIDDEN: u16 = 0x8000; // from: llvm
+
+    pub const NT_GNU_ABI_TAG: u32 = 1; // from: glibc
+    pub const NT_GNU_HWCAP: u32 = 2; // from: glibc
+    pub const NT_GNU_BUILD_ID: u32 = 3; // from: glibc
+    pub const NT_GNU_GOLD_VERSION: u32 = 4; // from: glibc
+    pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5; // from: glibc
+    pub const NT_GO_BUILDID: u32 = 4; // from: go
+    pub const NT_FDO_PACKAGING_METADATA: u32 = 0xcafe1a7e; // from: systemd
+
+    pub const ELF_NOTE_OS_LINUX: u32 = 0; // from: glibc
+    pub const ELF_NOTE_OS_GNU: u32 = 1; // from: glibc
+    pub const ELF_NOTE_OS_SOLARIS2: u32 = 2; // from: glibc
+    pub const ELF_NOTE_OS_FREEBSD: u32 = 3; // from: glibc
+
+    pub const GNU_PROPERTY_STACK_SIZE: u32 = 1; // from: glibc
+    pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2; // from: glibc
+    pub const GNU_PROPERTY_LOPROC: u32 = 0xc0000000; // from: glibc
+    pub const GNU_PROPERTY_HIPROC: u32 = 0xdfffffff; // from: glibc
+    pub const GNU_PROPERTY_LOUSER: u32 = 0xe0000000; // from: glibc
+    pub const GNU_PROPERTY_HIUSER: u32 = 0xffffffff; // from: glibc
+
+    pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002; // from: glibc
+    pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 0x00000001; // from: glibc
+    pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 0x00000002; // from: glibc
+
+    pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000; // from: glibc
+    pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 0x00000001; // from: glibc
+    pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 0x00000002; // from: glibc
+
+    pub const R_386_NONE: u32 = 0;
+    pub const R_386_32: u32 = 1;
+    pub const R_386_PC32: u32 = 2;
+    pub const R_386_GOT32: u32 = 3;
+    pub const R_386_PLT32: u32 = 4;
+    pub const R_386_COPY: u32 = 5;
+    pub const R_386_GLOB_DAT: u32 = 6;
+    pub const R_386_JMP_SLOT: u32 = 7;
+    pub const R_386_RELATIVE: u32 = 8;
+    pub const R_386_GOTOFF: u32 = 9;
+    pub const R_386_GOTPC: u32 = 10;
+    pub const R_386_32PLT: u32 = 11;
+    pub const R_386_TLS_TPOFF: u32 = 14;
+    pub const R_386_TLS_IE: u32 = 15;
+    pub const R_386_TLS_GOTIE: u32 = 16;
+    pub const R_386_TLS_LE: u32 = 17;
+    pub const R_386_TLS_GD: u32 = 18;
+    pub const R_386_TLS_LDM: u32 = 19;
+    pub const R_386_16: u32 = 20;
+    pub const R_386_PC16: u32 = 21;
+    pub const R_386_8: u32 = 22;
+    pub const R_386_PC8: u32 = 23;
+    pub const R_386_TLS_GD_32: u32 = 24;
+    pub const R_386_TLS_GD_PUSH: u32 = 25;
+    pub const R_386_TLS_GD_CALL: u32 = 26;
+    pub const R_386_TLS_GD_POP: u32 = 27;
+    pub const R_386_TLS_LDM_32: u32 = 28;
+    pub const R_386_TLS_LDM_PUSH: u32 = 29;
+    pub const R_386_TLS_LDM_CALL: u32 = 30;
+    pub const R_386_TLS_LDM_POP: u32 = 31;
+    pub const R_386_TLS_LDO_32: u32 = 32;
+    pub const R_386_TLS_IE_32: u32 = 33;
+    pub const R_386_TLS_LE_32: u32 = 34;
+    pub const R_386_TLS_DTPMOD32: u32 = 35;
+    pub const R_386_TLS_DTPOFF32: u32 = 36;
+    pub const R_386_TLS_TPOFF32: u32 = 37;
+    pub const R_386_SIZE32: u32 = 38;
+    pub const R_386_TLS_GOTDESC: u32 = 39;
+    pub const R_386_TLS_DESC_CALL: u32 = 40;
+    pub const R_386_TLS_DESC: u32 = 41;
+    pub const R_386_IRELATIVE: u32 = 42;
+    pub const R_386_GOT32X: u32 = 43;
+
+    pub const R_X86_64_NONE: u32 = 0;
+    pub const R_X86_64_64: u32 = 1;
+    pub const R_X86_64_PC32: u32 = 2;
+    pub const R_X86_64_GOT32: u32 = 3;
+    pub const R_X86_64_PLT32: u32 = 4;
+    pub const R_X86_64_COPY: u32 = 5;
+    pub const R_X86_64_GLOB_DAT: u32 = 6;
+    pub const R_X86_64_JUMP_SLOT: u32 = 7;
+    pub const R_X86_64_RELATIVE: u32 = 8;
+    pub const R_X86_64_GOTPCREL: u32 = 9;
+    pub const R_X86_64_32: u32 = 10;
+    pub const R_X86_64_32S: u32 = 11;
+    pub const R_X86_64_16: u32 = 12;
+    pub const R_X86_64_PC16: u32 = 13;
+    pub const R_X86_64_8: u32 = 14;
+    pub const R_X86_64_PC8: u32 = 15;
+    pub const R_X86_64_DTPMOD64: u32 = 16;
+    pub const R_X86_64_DTPOFF64: u32 = 17;
+    pub const R_X86_64_TPOFF64: u32 = 18;
+    pub const R_X86_64_TLSGD: u32 = 19;
+    pub const R_X86_64_TLSLD: u32 = 20;
+    pub const R_X86_64_DTPOFF32: u32 = 21;
+    pub const R_X86_64_GOTTPOFF: u32 = 22;
+    pub const R_X86_64_TPOFF32: u32 = 23;
+    pub const R_X86_64_PC64: u32 = 24;
+    pub const R_X86_64_GOTOFF64: u32 = 25;
+    pub const R_X86_64_GOTPC32: u32 = 26;
+    pub const R_X86_64_GOT64: u32 = 27;
+    pub const R_X86_64_GOTPCREL64: u32 = 28;
+    pub const R_X86_64_GOTPC64: u32 = 29;
+    pub const R_X86_64_GOTPLT64: u32 = 30;
+    pub const R_X86_64_PLTOFF64: u32 = 31;
+    pub const R_X86_64_SIZE32: u32 = 32;
+    pub const R_X86_64_SIZE64: u32 = 33;
+    pub const R_X86_64_GOTPC32_TLSDESC: u32 = 34;
+    pub const R_X86_64_TLSDESC_CALL: u32 = 35;
+    pub const R_X86_64_TLSDESC: u32 = 36;
+    pub const R_X86_64_IRELATIVE: u32 = 37;
+    pub const R_X86_64_RELATIVE64: u32 = 38;
+    pub const R_X86_64_GOTPCRELX: u32 = 41;
+    pub const R_X86_64_REX_GOTPCRELX: u32 = 42;
+
+    pub const R_AARCH64_NONE: u32 = 0;
+    pub const R_AARCH64_ABS64: u32 = 257;
+    pub const R_AARCH64_ABS32: u32 = 258;
+    pub const R_AARCH64_ABS16: u32 = 259;
+    pub const R_AARCH64_PREL64: u32 = 260;
+    pub const R_AARCH64_PREL32: u32 = 261;
+    pub const R_AARCH64_PREL16: u32 = 262;
+    pub const R_AARCH64_ADR_PREL_LO21: u32 = 274;
+    pub const R_AARCH64_ADR_PREL_PG_HI21: u32 = 275;
+    pub const R_AARCH64_ADR_PREL_PG_HI21_NC: u32 = 276;
+    pub const R_AARCH64_ADD_ABS_LO12_NC: u32 = 277;
+    pub const R_AARCH64_LDST8_ABS_LO12_NC: u32 = 278;
+    pub const R_AARCH64_TSTBR14: u32 = 279;
+    pub const R_AARCH64_CONDBR19: u32 = 280;
+    pub const R_AARCH64_JUMP26: u32 = 282;
+    pub const R_AARCH64_CALL26: u32 = 283;
+    pub const R_AARCH64_LDST16_ABS_LO12_NC: u32 = 284;
+    pub const R_AARCH64_LDST32_ABS_LO12_NC: u32 = 285;
+    pub const R_AARCH64_LDST64_ABS_LO12_NC: u32 = 286;
+    pub const R_AARCH64_LDST128_ABS_LO12_NC: u32 = 299;
+    pub const R_AARCH64_COPY: u32 = 1024;
+    pub const R_AARCH64_GLOB_DAT: u32 = 1025;
+    pub const R_AARCH64_JUMP_SLOT: u32 = 1026;
+    pub const R_AARCH64_RELATIVE: u32 = 1027;
+    pub const R_AARCH64_TLS_DTPMOD: u32 = 1028;
+    pub const R_AARCH64_TLS_DTPREL: u32 = 1029;
+    pub const R_AARCH64_TLS_TPREL: u32 = 1030;
+    pub const R_AARCH64_TLSDESC: u32 = 1031;
+    pub const R_AARCH64_IRELATIVE: u32 = 1032;
+
+    pub const R_ARM_NONE: u32 = 0;
+    pub const R_ARM_ABS32: u32 = 2;
+    pub const R_ARM_REL32: u32 = 3;
+    pub const R_ARM_TLS_DESC: u32 = 13;
+    pub const R_ARM_TLS_DTPMOD32: u32 = 17;
+    pub const R_ARM_TLS_DTPOFF32: u32 = 18;
+    pub const R_ARM_TLS_TPOFF32: u32 = 19;
+    pub const R_ARM_COPY: u32 = 20;
+    pub const R_ARM_GLOB_DAT: u32 = 21;
+    pub const R_ARM_JUMP_SLOT: u32 = 22;
+    pub const R_ARM_RELATIVE: u32 = 23;
+    pub const R_ARM_IRELATIVE: u32 = 160;
+
+    pub const R_LARCH_NONE: u32 = 0;
+    pub const R_LARCH_32: u32 = 1;
+    pub const R_LARCH_64: u32 = 2;
+    pub const R_LARCH_RELATIVE: u32 = 3;
+    pub const R_LARCH_COPY: u32 = 4;
+    pub const R_LARCH_JUMP_SLOT: u32 = 5;
+    pub const R_LARCH_TLS_DTPMOD32: u32 = 6;
+    pub const R_LARCH_TLS_DTPMOD64: u32 = 7;
+    pub const R_LARCH_TLS_DTPREL32: u32 = 8;
+    pub const R_LARCH_TLS_DTPREL64: u32 = 9;
+    pub const R_LARCH_TLS_TPREL32: u32 = 10;
+    pub const R_LARCH_TLS_TPREL64: u32 = 11;
+    pub const R_LARCH_IRELATIVE: u32 = 12;
+    pub const R_LARCH_TLS_DESC32: u32 = 13;
+    pub const R_LARCH_TLS_DESC64: u32 = 14;
+
+    pub const R_PPC64_NONE: u32 = 0;
+    pub const R_PPC64_ADDR32: u32 = 1;
+    pub const R_PPC64_COPY: u32 = 19;
+    pub const R_PPC64_GLOB_DAT: u32 = 20;
+    pub const R_PPC64_JMP_SLOT: u32 = 21;
+    pub const R_PPC64_RELATIVE: u32 = 22;
+    pub const R_PPC64_ADDR64: u32 = 38;
+    pub const R_PPC64_DTPMOD64: u32 = 68;
+    pub const R_PPC64_TPREL64: u32 = 73;
+    pub const R_PPC64_DTPREL64: u32 = 78;
+    pub const R_PPC64_IRELATIVE: u32 = 248;
+
+    pub const R_RISCV_NONE: u32 = 0;
+    pub const R_RISCV_32: u32 = 1;
+    pub const R_RISCV_64: u32 = 2;
+    pub const R_RISCV_RELATIVE: u32 = 3;
+    pub const R_RISCV_COPY: u32 = 4;
+    pub const R_RISCV_JUMP_SLOT: u32 = 5;
+    pub const R_RISCV_TLS_DTPMOD32: u32 = 6;
+    pub const R_RISCV_TLS_DTPMOD64: u32 = 7;
+    pub const R_RISCV_TLS_DTPREL32: u32 = 8;
+    pub const R_RISCV_TLS_DTPREL64: u32 = 9;
+    pub const R_RISCV_TLS_TPREL32: u32 = 10;
+    pub const R_RISCV_TLS_TPREL64: u32 = 11;
+    pub const R_RISCV_TLSDESC: u32 = 12;
+    pub const R_RISCV_IRELATIVE: u32 = 58;
+
+    pub const R_390_NONE: u32 = 0;
+    pub const R_390_8: u32 = 1;
+    pub const R_390_12: u32 = 2;
+    pub const R_390_16: u32 = 3;
+    pub const R_390_32: u32 = 4;
+    pub const R_390_PC32: u32 = 5;
+    pub const R_390_COPY: u32 = 9;
+    pub const R_390_GLOB_DAT: u32 = 10;
+    pub const R_390_JMP_SLOT: u32 = 11;
+    pub const R_390_RELATIVE: u32 = 12;
+    pub const R_390_64: u32 = 22;
+    pub const R_390_TLS_DTPMOD: u32 = 54;
+    pub const R_390_TLS_DTPOFF: u32 = 55;
+    pub const R_390_TLS_TPOFF: u32 = 56;
+    pub const R_390_IRELATIVE: u32 = 61;
+
     /// Identification Table
     ///
     /// The first 16 bytes of the ELF header contain the identification table.
@@ -665,6 +900,90 @@ mod common {
         pub i_abiversion: u8,
         pub i_pad: [u8; 7],
     }
+
+    /// Version Definition
+    ///
+    /// The version definition section (`DT_VERDEF`) is a linked list of
+    /// version definitions. Each entry is followed by `vd_cnt` auxiliary
+    /// entries, located via the byte offsets `vd_aux` and `vda_next`. The
+    /// next definition is located via the byte offset `vd_next`, relative
+    /// to the start of the current entry.
+    ///
+    /// The structure is identical on 32-bit and 64-bit machines.
+    #[derive(Clone, Copy, Debug)]
+    #[repr(C)]
+    pub struct Verdef {
+        pub vd_version: u16,
+        pub vd_flags: u16,
+        pub vd_ndx: u16,
+        pub vd_cnt: u16,
+        pub vd_hash: u32,
+        pub vd_aux: u32,
+        pub vd_next: u32,
+    }
+
+    /// Version Definition Auxiliary Entry
+    ///
+    /// The first auxiliary entry of a version definition carries the name of
+    /// the version, any further entries name its predecessors.
+    #[derive(Clone, Copy, Debug)]
+    #[repr(C)]
+    pub struct Verdaux {
+        pub vda_name: u32,
+        pub vda_next: u32,
+    }
+
+    /// Version Requirement
+    ///
+    /// The version requirement section (`DT_VERNEED`) is a linked list of
+    /// files a version is required from. Each entry is followed by `vn_cnt`
+    /// auxiliary entries, located via the byte offsets `vn_aux` and
+    /// `vna_next`. The next requirement is located via the byte offset
+    /// `vn_next`, relative to the start of the current entry.
+    ///
+    /// The structure is identical on 32-bit and 64-bit machines.
+    #[derive(Clone, Copy, Debug)]
+    #[repr(C)]
+    pub struct Verneed {
+        pub vn_version: u16,
+        pub vn_cnt: u16,
+        pub vn_file: u32,
+        pub vn_aux: u32,
+        pub vn_next: u32,
+    }
+
+    /// Version Requirement Auxiliary Entry
+    ///
+    /// Each auxiliary entry names a version required from the file of the
+    /// version requirement. `vna_other` is the version index used in the
+    /// symbol version table to refer to this version.
+    #[derive(Clone, Copy, Debug)]
+    #[repr(C)]
+    pub struct Vernaux {
+        pub vna_hash: u32,
+        pub vna_flags: u16,
+        pub vna_other: u16,
+        pub vna_name: u32,
+        pub vna_next: u32,
+    }
+
+    /// Note Header
+    ///
+    /// Note sections (`SHT_NOTE`) and segments (`PT_NOTE`) contain a sequence
+    /// of notes. Each note header is followed by the owner name of
+    /// `n_namesz` bytes including its terminating NUL, and the descriptor of
+    /// `n_descsz` bytes. Name and descriptor are padded to the alignment of
+    /// the section or segment, which is 4 bytes for most notes, but 8 bytes
+    /// for some notes of 64-bit objects (e.g., `NT_GNU_PROPERTY_TYPE_0`).
+    ///
+    /// The structure is identical on 32-bit and 64-bit machines.
+    #[derive(Clone, Copy, Debug)]
+    #[repr(C)]
+    pub struct Nhdr {
+        pub n_namesz: u32,
+        pub n_descsz: u32,
+        pub n_type: u32,
+    }
 }
 
 macro_rules! impl_elf {
@@ -903,6 +1222,16 @@ mod test {
     fn typeinfo() {
         assert_eq!(align_of::<common::Ident>(), 1);
         assert_eq!(size_of::<common::Ident>(), 16);
+        assert_eq!(align_of::<common::Verdef>(), 4);
+        assert_eq!(size_of::<common::Verdef>(), 20);
+        assert_eq!(align_of::<common::Verdaux>(), 4);
+        assert_eq!(size_of::<common::Verdaux>(), 8);
+        assert_eq!(align_of::<common::Verneed>(), 4);
+        assert_eq!(size_of::<common::Verneed>(), 16);
+        assert_eq!(align_of::<common::Vernaux>(), 4);
+        assert_eq!(size_of::<common::Vernaux>(), 16);
+        assert_eq!(align_of::<common::Nhdr>(), 4);
+        assert_eq!(size_of::<common::Nhdr>(), 12);
 
         assert_eq!(align_of::<elf32::Ident>(), 1);
         assert_eq!(size_of::<elf32::Ident>(), 16);
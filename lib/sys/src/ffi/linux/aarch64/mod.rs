@@ -7,6 +7,18 @@
 
 use super::abi;
 
+pub mod user;
+
+// Platform properties and values that differ between platforms, as required
+// by `common`.
+mod arch {
+    use super::abi;
+
+    pub const BIG_ENDIAN: bool = false;
+
+    pub const EDEADLOCK: abi::U16 = abi::num(35);
+}
+
 #[path = "../common/mod.rs"]
 mod common;
 
@@ -0,0 +1,38 @@
+//! # User Register Sets
+//!
+//! The register sets of a task as exposed via `ptrace(2)` and as stored in
+//! the notes of core-dumps. The layouts follow `<sys/user.h>` of the C
+//! library, which matches the layouts used by the kernel (`user_pt_regs` and
+//! `user_fpsimd_state`).
+
+use super::abi;
+
+/// General purpose registers (`NT_PRSTATUS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRegsStruct {
+    pub regs: [abi::U64; 31],
+    pub sp: abi::U64,
+    pub pc: abi::U64,
+    pub pstate: abi::U64,
+}
+
+/// Floating point and SIMD registers (`NT_PRFPREG`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserFpsimdStruct {
+    pub vregs: [abi::U128; 32],
+    pub fpsr: abi::U32,
+    pub fpcr: abi::U32,
+    pub reserved: [abi::U32; 2],
+}
+
+pub type UserFpregsStruct = UserFpsimdStruct;
+
+pub type ElfGreg = abi::U64;
+pub const ELF_NGREG: usize = 34;
+pub type ElfGregset = [ElfGreg; ELF_NGREG];
+pub type ElfFpregset = UserFpsimdStruct;
+
+pub type PrUid = abi::U32;
+pub type PrGid = abi::U32;
@@ -0,0 +1,39 @@
+//! # User Register Sets
+//!
+//! The register sets of a task as exposed via `ptrace(2)` and as stored in
+//! the notes of core-dumps. The layouts follow `<sys/user.h>` of the C
+//! library (`user_regs` and `user_fpregs`), which matches the layouts used by
+//! the kernel.
+
+use super::abi;
+
+/// General purpose registers (`NT_PRSTATUS`).
+///
+/// This holds `r0` to `r15`, followed by `cpsr` and `orig_r0`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRegsStruct {
+    pub uregs: [abi::U32; 18],
+}
+
+/// Floating point registers of the legacy FPA (`NT_PRFPREG`).
+///
+/// Each register is stored as a 96-bit C bit-field, which is exposed as raw
+/// 32-bit words.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserFpregsStruct {
+    pub fpregs: [[abi::U32; 3]; 8],
+    pub fpsr: abi::U32,
+    pub fpcr: abi::U32,
+    pub ftype: [abi::U8; 8],
+    pub init_flag: abi::U32,
+}
+
+pub type ElfGreg = abi::U32;
+pub const ELF_NGREG: usize = 18;
+pub type ElfGregset = [ElfGreg; ELF_NGREG];
+pub type ElfFpregset = UserFpregsStruct;
+
+pub type PrUid = abi::U16;
+pub type PrGid = abi::U16;
@@ -0,0 +1,405 @@
+//! # Extended Berkeley Packet Filter
+//!
+//! The `bpf(2)` syscall multiplexes all operations on eBPF maps, programs,
+//! links, and BTF objects. The first argument selects the operation
+//! (`BPF_*` commands), the second argument points to a `BpfAttr` union, and
+//! the third argument is the size of the union as known to the caller. The
+//! kernel requires all bytes beyond the members it knows to be zero, so the
+//! size of the member used by the command can be passed, rather than the size
+//! of the entire union.
+//!
+//! ## Alignment
+//!
+//! The kernel headers use `__aligned_u64` for all pointers and 64-bit
+//! values, and align the entire union to 8 bytes. This differs from the
+//! platform alignment of 64-bit integers on some 32-bit platforms. All
+//! structures here are thus explicitly aligned to 8 bytes, and carry explicit
+//! padding where a 64-bit member would otherwise be misplaced. Pointers are
+//! passed as `abi::U64` on all platforms.
+
+use super::abi;
+
+pub const BPF_MAP_CREATE: abi::I32 = abi::num(0);
+pub const BPF_MAP_LOOKUP_ELEM: abi::I32 = abi::num(1);
+pub const BPF_MAP_UPDATE_ELEM: abi::I32 = abi::num(2);
+pub const BPF_MAP_DELETE_ELEM: abi::I32 = abi::num(3);
+pub const BPF_MAP_GET_NEXT_KEY: abi::I32 = abi::num(4);
+pub const BPF_PROG_LOAD: abi::I32 = abi::num(5);
+pub const BPF_OBJ_PIN: abi::I32 = abi::num(6);
+pub const BPF_OBJ_GET: abi::I32 = abi::num(7);
+pub const BPF_PROG_ATTACH: abi::I32 = abi::num(8);
+pub const BPF_PROG_DETACH: abi::I32 = abi::num(9);
+pub const BPF_PROG_TEST_RUN: abi::I32 = abi::num(10);
+pub const BPF_PROG_RUN: abi::I32 = abi::num(10);
+pub const BPF_PROG_GET_NEXT_ID: abi::I32 = abi::num(11);
+pub const BPF_MAP_GET_NEXT_ID: abi::I32 = abi::num(12);
+pub const BPF_PROG_GET_FD_BY_ID: abi::I32 = abi::num(13);
+pub const BPF_MAP_GET_FD_BY_ID: abi::I32 = abi::num(14);
+pub const BPF_OBJ_GET_INFO_BY_FD: abi::I32 = abi::num(15);
+pub const BPF_PROG_QUERY: abi::I32 = abi::num(16);
+pub const BPF_RAW_TRACEPOINT_OPEN: abi::I32 = abi::num(17);
+pub const BPF_BTF_LOAD: abi::I32 = abi::num(18);
+pub const BPF_BTF_GET_FD_BY_ID: abi::I32 = abi::num(19);
+pub const BPF_TASK_FD_QUERY: abi::I32 = abi::num(20);
+pub const BPF_MAP_LOOKUP_AND_DELETE_ELEM: abi::I32 = abi::num(21);
+pub const BPF_MAP_FREEZE: abi::I32 = abi::num(22);
+pub const BPF_BTF_GET_NEXT_ID: abi::I32 = abi::num(23);
+pub const BPF_MAP_LOOKUP_BATCH: abi::I32 = abi::num(24);
+pub const BPF_MAP_LOOKUP_AND_DELETE_BATCH: abi::I32 = abi::num(25);
+pub const BPF_MAP_UPDATE_BATCH: abi::I32 = abi::num(26);
+pub const BPF_MAP_DELETE_BATCH: abi::I32 = abi::num(27);
+pub const BPF_LINK_CREATE: abi::I32 = abi::num(28);
+pub const BPF_LINK_UPDATE: abi::I32 = abi::num(29);
+pub const BPF_LINK_GET_FD_BY_ID: abi::I32 = abi::num(30);
+pub const BPF_LINK_GET_NEXT_ID: abi::I32 = abi::num(31);
+pub const BPF_ENABLE_STATS: abi::I32 = abi::num(32);
+pub const BPF_ITER_CREATE: abi::I32 = abi::num(33);
+pub const BPF_LINK_DETACH: abi::I32 = abi::num(34);
+pub const BPF_PROG_BIND_MAP: abi::I32 = abi::num(35);
+pub const BPF_TOKEN_CREATE: abi::I32 = abi::num(36);
+
+pub const BPF_MAP_TYPE_UNSPEC: abi::U32 = abi::num(0);
+pub const BPF_MAP_TYPE_HASH: abi::U32 = abi::num(1);
+pub const BPF_MAP_TYPE_ARRAY: abi::U32 = abi::num(2);
+pub const BPF_MAP_TYPE_PROG_ARRAY: abi::U32 = abi::num(3);
+pub const BPF_MAP_TYPE_PERF_EVENT_ARRAY: abi::U32 = abi::num(4);
+pub const BPF_MAP_TYPE_PERCPU_HASH: abi::U32 = abi::num(5);
+pub const BPF_MAP_TYPE_PERCPU_ARRAY: abi::U32 = abi::num(6);
+pub const BPF_MAP_TYPE_STACK_TRACE: abi::U32 = abi::num(7);
+pub const BPF_MAP_TYPE_CGROUP_ARRAY: abi::U32 = abi::num(8);
+pub const BPF_MAP_TYPE_LRU_HASH: abi::U32 = abi::num(9);
+pub const BPF_MAP_TYPE_LRU_PERCPU_HASH: abi::U32 = abi::num(10);
+pub const BPF_MAP_TYPE_LPM_TRIE: abi::U32 = abi::num(11);
+pub const BPF_MAP_TYPE_ARRAY_OF_MAPS: abi::U32 = abi::num(12);
+pub const BPF_MAP_TYPE_HASH_OF_MAPS: abi::U32 = abi::num(13);
+pub const BPF_MAP_TYPE_DEVMAP: abi::U32 = abi::num(14);
+pub const BPF_MAP_TYPE_SOCKMAP: abi::U32 = abi::num(15);
+pub const BPF_MAP_TYPE_CPUMAP: abi::U32 = abi::num(16);
+pub const BPF_MAP_TYPE_XSKMAP: abi::U32 = abi::num(17);
+pub const BPF_MAP_TYPE_SOCKHASH: abi::U32 = abi::num(18);
+pub const BPF_MAP_TYPE_CGROUP_STORAGE: abi::U32 = abi::num(19);
+pub const BPF_MAP_TYPE_REUSEPORT_SOCKARRAY: abi::U32 = abi::num(20);
+pub const BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE: abi::U32 = abi::num(21);
+pub const BPF_MAP_TYPE_QUEUE: abi::U32 = abi::num(22);
+pub const BPF_MAP_TYPE_STACK: abi::U32 = abi::num(23);
+pub const BPF_MAP_TYPE_SK_STORAGE: abi::U32 = abi::num(24);
+pub const BPF_MAP_TYPE_DEVMAP_HASH: abi::U32 = abi::num(25);
+pub const BPF_MAP_TYPE_STRUCT_OPS: abi::U32 = abi::num(26);
+pub const BPF_MAP_TYPE_RINGBUF: abi::U32 = abi::num(27);
+pub const BPF_MAP_TYPE_INODE_STORAGE: abi::U32 = abi::num(28);
+pub const BPF_MAP_TYPE_TASK_STORAGE: abi::U32 = abi::num(29);
+pub const BPF_MAP_TYPE_BLOOM_FILTER: abi::U32 = abi::num(30);
+pub const BPF_MAP_TYPE_USER_RINGBUF: abi::U32 = abi::num(31);
+pub const BPF_MAP_TYPE_CGRP_STORAGE: abi::U32 = abi::num(32);
+pub const BPF_MAP_TYPE_ARENA: abi::U32 = abi::num(33);
+
+pub const BPF_PROG_TYPE_UNSPEC: abi::U32 = abi::num(0);
+pub const BPF_PROG_TYPE_SOCKET_FILTER: abi::U32 = abi::num(1);
+pub const BPF_PROG_TYPE_KPROBE: abi::U32 = abi::num(2);
+pub const BPF_PROG_TYPE_SCHED_CLS: abi::U32 = abi::num(3);
+pub const BPF_PROG_TYPE_SCHED_ACT: abi::U32 = abi::num(4);
+pub const BPF_PROG_TYPE_TRACEPOINT: abi::U32 = abi::num(5);
+pub const BPF_PROG_TYPE_XDP: abi::U32 = abi::num(6);
+pub const BPF_PROG_TYPE_PERF_EVENT: abi::U32 = abi::num(7);
+pub const BPF_PROG_TYPE_CGROUP_SKB: abi::U32 = abi::num(8);
+pub const BPF_PROG_TYPE_CGROUP_SOCK: abi::U32 = abi::num(9);
+pub const BPF_PROG_TYPE_LWT_IN: abi::U32 = abi::num(10);
+pub const BPF_PROG_TYPE_LWT_OUT: abi::U32 = abi::num(11);
+pub const BPF_PROG_TYPE_LWT_XMIT: abi::U32 = abi::num(12);
+pub const BPF_PROG_TYPE_SOCK_OPS: abi::U32 = abi::num(13);
+pub const BPF_PROG_TYPE_SK_SKB: abi::U32 = abi::num(14);
+pub const BPF_PROG_TYPE_CGROUP_DEVICE: abi::U32 = abi::num(15);
+pub const BPF_PROG_TYPE_SK_MSG: abi::U32 = abi::num(16);
+pub const BPF_PROG_TYPE_RAW_TRACEPOINT: abi::U32 = abi::num(17);
+pub const BPF_PROG_TYPE_CGROUP_SOCK_ADDR: abi::U32 = abi::num(18);
+pub const BPF_PROG_TYPE_LWT_SEG6LOCAL: abi::U32 = abi::num(19);
+pub const BPF_PROG_TYPE_LIRC_MODE2: abi::U32 = abi::num(20);
+pub const BPF_PROG_TYPE_SK_REUSEPORT: abi::U32 = abi::num(21);
+pub const BPF_PROG_TYPE_FLOW_DISSECTOR: abi::U32 = abi::num(22);
+pub const BPF_PROG_TYPE_CGROUP_SYSCTL: abi::U32 = abi::num(23);
+pub const BPF_PROG_TYPE_RAW_TRACEPOINT_WRITABLE: abi::U32 = abi::num(24);
+pub const BPF_PROG_TYPE_CGROUP_SOCKOPT: abi::U32 = abi::num(25);
+pub const BPF_PROG_TYPE_TRACING: abi::U32 = abi::num(26);
+pub const BPF_PROG_TYPE_STRUCT_OPS: abi::U32 = abi::num(27);
+pub const BPF_PROG_TYPE_EXT: abi::U32 = abi::num(28);
+pub const BPF_PROG_TYPE_LSM: abi::U32 = abi::num(29);
+pub const BPF_PROG_TYPE_SK_LOOKUP: abi::U32 = abi::num(30);
+pub const BPF_PROG_TYPE_SYSCALL: abi::U32 = abi::num(31);
+pub const BPF_PROG_TYPE_NETFILTER: abi::U32 = abi::num(32);
+
+pub const BPF_ANY: abi::U64 = abi::num(0);
+pub const BPF_NOEXIST: abi::U64 = abi::num(1);
+pub const BPF_EXIST: abi::U64 = abi::num(2);
+pub const BPF_F_LOCK: abi::U64 = abi::num(4);
+
+pub const BPF_F_NO_PREALLOC: abi::U32 = abi::num(1 << 0);
+pub const BPF_F_NO_COMMON_LRU: abi::U32 = abi::num(1 << 1);
+pub const BPF_F_NUMA_NODE: abi::U32 = abi::num(1 << 2);
+pub const BPF_F_RDONLY: abi::U32 = abi::num(1 << 3);
+pub const BPF_F_WRONLY: abi::U32 = abi::num(1 << 4);
+pub const BPF_F_STACK_BUILD_ID: abi::U32 = abi::num(1 << 5);
+pub const BPF_F_ZERO_SEED: abi::U32 = abi::num(1 << 6);
+pub const BPF_F_RDONLY_PROG: abi::U32 = abi::num(1 << 7);
+pub const BPF_F_WRONLY_PROG: abi::U32 = abi::num(1 << 8);
+pub const BPF_F_CLONE: abi::U32 = abi::num(1 << 9);
+pub const BPF_F_MMAPABLE: abi::U32 = abi::num(1 << 10);
+pub const BPF_F_PRESERVE_ELEMS: abi::U32 = abi::num(1 << 11);
+pub const BPF_F_INNER_MAP: abi::U32 = abi::num(1 << 12);
+pub const BPF_F_LINK: abi::U32 = abi::num(1 << 13);
+pub const BPF_F_PATH_FD: abi::U32 = abi::num(1 << 14);
+
+pub const BPF_F_STRICT_ALIGNMENT: abi::U32 = abi::num(1 << 0);
+pub const BPF_F_ANY_ALIGNMENT: abi::U32 = abi::num(1 << 1);
+pub const BPF_F_TEST_RND_HI32: abi::U32 = abi::num(1 << 2);
+pub const BPF_F_TEST_STATE_FREQ: abi::U32 = abi::num(1 << 3);
+pub const BPF_F_SLEEPABLE: abi::U32 = abi::num(1 << 4);
+pub const BPF_F_XDP_HAS_FRAGS: abi::U32 = abi::num(1 << 5);
+pub const BPF_F_XDP_DEV_BOUND_ONLY: abi::U32 = abi::num(1 << 6);
+
+pub const BPF_F_ALLOW_OVERRIDE: abi::U32 = abi::num(1 << 0);
+pub const BPF_F_ALLOW_MULTI: abi::U32 = abi::num(1 << 1);
+pub const BPF_F_REPLACE: abi::U32 = abi::num(1 << 2);
+
+pub const BPF_STATS_RUN_TIME: abi::U32 = abi::num(0);
+
+/// Maximum length of object names, including the terminating zero.
+pub const BPF_OBJ_NAME_LEN: usize = 16;
+
+/// Attributes of `BPF_MAP_CREATE`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrMapCreate {
+    pub map_type: abi::U32,
+    pub key_size: abi::U32,
+    pub value_size: abi::U32,
+    pub max_entries: abi::U32,
+    pub map_flags: abi::U32,
+    pub inner_map_fd: abi::U32,
+    pub numa_node: abi::U32,
+    pub map_name: [abi::U8; BPF_OBJ_NAME_LEN],
+    pub map_ifindex: abi::U32,
+    pub btf_fd: abi::U32,
+    pub btf_key_type_id: abi::U32,
+    pub btf_value_type_id: abi::U32,
+    pub btf_vmlinux_value_type_id: abi::U32,
+    pub map_extra: abi::U64,
+    pub value_type_btf_obj_fd: abi::I32,
+    pub map_token_fd: abi::I32,
+}
+
+/// Attributes of `BPF_MAP_*_ELEM`, `BPF_MAP_GET_NEXT_KEY`, and
+/// `BPF_MAP_FREEZE`.
+///
+/// `value` is also known as `next_key` for `BPF_MAP_GET_NEXT_KEY`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrMapElem {
+    pub map_fd: abi::U32,
+    pub pad0: abi::U32,
+    pub key: abi::U64,
+    pub value: abi::U64,
+    pub flags: abi::U64,
+}
+
+/// Attributes of `BPF_MAP_*_BATCH`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrMapBatch {
+    pub in_batch: abi::U64,
+    pub out_batch: abi::U64,
+    pub keys: abi::U64,
+    pub values: abi::U64,
+    pub count: abi::U32,
+    pub map_fd: abi::U32,
+    pub elem_flags: abi::U64,
+    pub flags: abi::U64,
+}
+
+/// Attributes of `BPF_PROG_LOAD`.
+///
+/// `attach_prog_fd` is also known as `attach_btf_obj_fd`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrProgLoad {
+    pub prog_type: abi::U32,
+    pub insn_cnt: abi::U32,
+    pub insns: abi::U64,
+    pub license: abi::U64,
+    pub log_level: abi::U32,
+    pub log_size: abi::U32,
+    pub log_buf: abi::U64,
+    pub kern_version: abi::U32,
+    pub prog_flags: abi::U32,
+    pub prog_name: [abi::U8; BPF_OBJ_NAME_LEN],
+    pub prog_ifindex: abi::U32,
+    pub expected_attach_type: abi::U32,
+    pub prog_btf_fd: abi::U32,
+    pub func_info_rec_size: abi::U32,
+    pub func_info: abi::U64,
+    pub func_info_cnt: abi::U32,
+    pub line_info_rec_size: abi::U32,
+    pub line_info: abi::U64,
+    pub line_info_cnt: abi::U32,
+    pub attach_btf_id: abi::U32,
+    pub attach_prog_fd: abi::U32,
+    pub core_relo_cnt: abi::U32,
+    pub fd_array: abi::U64,
+    pub core_relos: abi::U64,
+    pub core_relo_rec_size: abi::U32,
+    pub log_true_size: abi::U32,
+    pub prog_token_fd: abi::I32,
+    pub fd_array_cnt: abi::U32,
+}
+
+/// Attributes of `BPF_OBJ_PIN` and `BPF_OBJ_GET`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrObj {
+    pub pathname: abi::U64,
+    pub bpf_fd: abi::U32,
+    pub file_flags: abi::U32,
+    pub path_fd: abi::I32,
+}
+
+/// Attributes of `BPF_PROG_ATTACH` and `BPF_PROG_DETACH`.
+///
+/// `target_fd` is also known as `target_ifindex`, and `relative_fd` is also
+/// known as `relative_id`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrProgAttach {
+    pub target_fd: abi::U32,
+    pub attach_bpf_fd: abi::U32,
+    pub attach_type: abi::U32,
+    pub attach_flags: abi::U32,
+    pub replace_bpf_fd: abi::U32,
+    pub relative_fd: abi::U32,
+    pub expected_revision: abi::U64,
+}
+
+/// Attributes of `BPF_PROG_TEST_RUN`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrTestRun {
+    pub prog_fd: abi::U32,
+    pub retval: abi::U32,
+    pub data_size_in: abi::U32,
+    pub data_size_out: abi::U32,
+    pub data_in: abi::U64,
+    pub data_out: abi::U64,
+    pub repeat: abi::U32,
+    pub duration: abi::U32,
+    pub ctx_size_in: abi::U32,
+    pub ctx_size_out: abi::U32,
+    pub ctx_in: abi::U64,
+    pub ctx_out: abi::U64,
+    pub flags: abi::U32,
+    pub cpu: abi::U32,
+    pub batch_size: abi::U32,
+}
+
+/// Attributes of `BPF_*_GET_NEXT_ID` and `BPF_*_GET_FD_BY_ID`.
+///
+/// `start_id` is also known as `prog_id`, `map_id`, `btf_id`, or `link_id`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrGetId {
+    pub start_id: abi::U32,
+    pub next_id: abi::U32,
+    pub open_flags: abi::U32,
+    pub fd_by_id_token_fd: abi::I32,
+}
+
+/// Attributes of `BPF_OBJ_GET_INFO_BY_FD`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrInfo {
+    pub bpf_fd: abi::U32,
+    pub info_len: abi::U32,
+    pub info: abi::U64,
+}
+
+/// Attributes of `BPF_RAW_TRACEPOINT_OPEN`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrRawTracepoint {
+    pub name: abi::U64,
+    pub prog_fd: abi::U32,
+    pub pad0: abi::U32,
+    pub cookie: abi::U64,
+}
+
+/// Attributes of `BPF_BTF_LOAD`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrBtfLoad {
+    pub btf: abi::U64,
+    pub btf_log_buf: abi::U64,
+    pub btf_size: abi::U32,
+    pub btf_log_size: abi::U32,
+    pub btf_log_level: abi::U32,
+    pub btf_log_true_size: abi::U32,
+    pub btf_flags: abi::U32,
+    pub btf_token_fd: abi::I32,
+}
+
+/// Attributes of `BPF_ENABLE_STATS`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrEnableStats {
+    pub type_: abi::U32,
+}
+
+/// Attributes of `BPF_ITER_CREATE`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrIterCreate {
+    pub link_fd: abi::U32,
+    pub flags: abi::U32,
+}
+
+/// Attributes of `BPF_LINK_DETACH`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrLinkDetach {
+    pub link_fd: abi::U32,
+}
+
+/// Attributes of `BPF_PROG_BIND_MAP`.
+#[repr(C, align(8))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BpfAttrProgBindMap {
+    pub prog_fd: abi::U32,
+    pub map_fd: abi::U32,
+    pub flags: abi::U32,
+}
+
+/// Attributes of `bpf(2)`
+///
+/// This union combines the attributes of all supported commands. The member
+/// to use is selected by the command. Unlike the kernel headers, this union
+/// does not embed anonymous structures, but uses one named member per group
+/// of commands.
+///
+/// The union is at least as large as its largest member, but smaller than the
+/// union of recent kernels, since not all commands are supported. Callers
+/// should pass the size of the member they use.
+#[repr(C, align(8))]
+#[derive(Clone, Copy)]
+pub union BpfAttr {
+    pub map_create: BpfAttrMapCreate,
+    pub map_elem: BpfAttrMapElem,
+    pub map_batch: BpfAttrMapBatch,
+    pub prog_load: BpfAttrProgLoad,
+    pub obj: BpfAttrObj,
+    pub prog_attach: BpfAttrProgAttach,
+    pub test_run: BpfAttrTestRun,
+    pub get_id: BpfAttrGetId,
+    pub info: BpfAttrInfo,
+    pub raw_tracepoint: BpfAttrRawTracepoint,
+    pub btf_load: BpfAttrBtfLoad,
+    pub enable_stats: BpfAttrEnableStats,
+    pub iter_create: BpfAttrIterCreate,
+    pub link_detach: BpfAttrLinkDetach,
+    pub prog_bind_map: BpfAttrProgBindMap,
+}
@@ -0,0 +1,94 @@
+//! # Capabilities
+//!
+//! Linux splits the privileges traditionally associated with the superuser
+//! into distinct units, called capabilities. Each capability is identified by
+//! a small integer, which is used as bit-index into the capability sets of a
+//! task. The kernel currently defines less than 64 capabilities, and the
+//! user-space interfaces use 64-bit masks to represent capability sets. For
+//! this reason, we use `u8` as underlying data-type for capability numbers.
+//!
+//! The `capget(2)` and `capset(2)` syscalls take a header that selects the
+//! interface version, and an array of data structures that carry the
+//! effective, permitted, and inheritable sets. With version 2 and later, two
+//! data structures are used, where the first carries the lower 32 bits of each
+//! set, and the second carries the upper 32 bits. Version 1 only supports a
+//! single data structure and is deprecated.
+
+use super::abi;
+
+pub const CAP_CHOWN: abi::U8 = abi::num(0);
+pub const CAP_DAC_OVERRIDE: abi::U8 = abi::num(1);
+pub const CAP_DAC_READ_SEARCH: abi::U8 = abi::num(2);
+pub const CAP_FOWNER: abi::U8 = abi::num(3);
+pub const CAP_FSETID: abi::U8 = abi::num(4);
+pub const CAP_KILL: abi::U8 = abi::num(5);
+pub const CAP_SETGID: abi::U8 = abi::num(6);
+pub const CAP_SETUID: abi::U8 = abi::num(7);
+pub const CAP_SETPCAP: abi::U8 = abi::num(8);
+pub const CAP_LINUX_IMMUTABLE: abi::U8 = abi::num(9);
+pub const CAP_NET_BIND_SERVICE: abi::U8 = abi::num(10);
+pub const CAP_NET_BROADCAST: abi::U8 = abi::num(11);
+pub const CAP_NET_ADMIN: abi::U8 = abi::num(12);
+pub const CAP_NET_RAW: abi::U8 = abi::num(13);
+pub const CAP_IPC_LOCK: abi::U8 = abi::num(14);
+pub const CAP_IPC_OWNER: abi::U8 = abi::num(15);
+pub const CAP_SYS_MODULE: abi::U8 = abi::num(16);
+pub const CAP_SYS_RAWIO: abi::U8 = abi::num(17);
+pub const CAP_SYS_CHROOT: abi::U8 = abi::num(18);
+pub const CAP_SYS_PTRACE: abi::U8 = abi::num(19);
+pub const CAP_SYS_PACCT: abi::U8 = abi::num(20);
+pub const CAP_SYS_ADMIN: abi::U8 = abi::num(21);
+pub const CAP_SYS_BOOT: abi::U8 = abi::num(22);
+pub const CAP_SYS_NICE: abi::U8 = abi::num(23);
+pub const CAP_SYS_RESOURCE: abi::U8 = abi::num(24);
+pub const CAP_SYS_TIME: abi::U8 = abi::num(25);
+pub const CAP_SYS_TTY_CONFIG: abi::U8 = abi::num(26);
+pub const CAP_MKNOD: abi::U8 = abi::num(27);
+pub const CAP_LEASE: abi::U8 = abi::num(28);
+pub const CAP_AUDIT_WRITE: abi::U8 = abi::num(29);
+pub const CAP_AUDIT_CONTROL: abi::U8 = abi::num(30);
+pub const CAP_SETFCAP: abi::U8 = abi::num(31);
+pub const CAP_MAC_OVERRIDE: abi::U8 = abi::num(32);
+pub const CAP_MAC_ADMIN: abi::U8 = abi::num(33);
+pub const CAP_SYSLOG: abi::U8 = abi::num(34);
+pub const CAP_WAKE_ALARM: abi::U8 = abi::num(35);
+pub const CAP_BLOCK_SUSPEND: abi::U8 = abi::num(36);
+pub const CAP_AUDIT_READ: abi::U8 = abi::num(37);
+pub const CAP_PERFMON: abi::U8 = abi::num(38);
+pub const CAP_BPF: abi::U8 = abi::num(39);
+pub const CAP_CHECKPOINT_RESTORE: abi::U8 = abi::num(40);
+
+pub const CAP_LAST_CAP: abi::U8 = abi::num(40); // CAP_CHECKPOINT_RESTORE
+
+pub const _LINUX_CAPABILITY_VERSION_1: abi::U32 = abi::num(0x19980330);
+pub const _LINUX_CAPABILITY_U32S_1: abi::U32 = abi::num(1);
+pub const _LINUX_CAPABILITY_VERSION_2: abi::U32 = abi::num(0x20071026);
+pub const _LINUX_CAPABILITY_U32S_2: abi::U32 = abi::num(2);
+pub const _LINUX_CAPABILITY_VERSION_3: abi::U32 = abi::num(0x20080522);
+pub const _LINUX_CAPABILITY_U32S_3: abi::U32 = abi::num(2);
+
+/// Header passed to `capget(2)` and `capset(2)`.
+///
+/// `version` selects the interface version, and `pid` the target task (`0`
+/// refers to the calling thread). If the kernel does not support the
+/// requested version, it writes its preferred version into `version` and
+/// fails with `EINVAL`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CapUserHeader {
+    pub version: abi::U32,
+    pub pid: abi::I32,
+}
+
+/// Capability sets passed to `capget(2)` and `capset(2)`.
+///
+/// With `_LINUX_CAPABILITY_VERSION_3`, an array of
+/// `_LINUX_CAPABILITY_U32S_3` elements is used, with the first element
+/// carrying the lower 32 bits of each set.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CapUserData {
+    pub effective: abi::U32,
+    pub permitted: abi::U32,
+    pub inheritable: abi::U32,
+}
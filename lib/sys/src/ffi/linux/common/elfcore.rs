@@ -0,0 +1,147 @@
+//! # Core-Dump Notes
+//!
+//! Core-dumps of Linux tasks are ELF files of type `ET_CORE`. Apart from the
+//! memory contents, they carry a `PT_NOTE` segment with information about the
+//! dumped process. Each note is identified by its owner name (`CORE` or
+//! `LINUX` for the notes defined here) and its note type (`NT_*`).
+//!
+//! The layout of the register sets embedded in the notes depends on the
+//! platform and is provided by the `user` module of each platform.
+
+use super::{abi, user};
+
+pub const NT_PRSTATUS: abi::U32 = abi::num(1);
+pub const NT_PRFPREG: abi::U32 = abi::num(2);
+pub const NT_PRPSINFO: abi::U32 = abi::num(3);
+pub const NT_TASKSTRUCT: abi::U32 = abi::num(4);
+pub const NT_AUXV: abi::U32 = abi::num(6);
+pub const NT_SIGINFO: abi::U32 = abi::num(0x53494749); // "SIGI"
+pub const NT_FILE: abi::U32 = abi::num(0x46494c45); // "FILE"
+pub const NT_PRXFPREG: abi::U32 = abi::num(0x46e62b7f);
+
+pub const NT_PPC_VMX: abi::U32 = abi::num(0x100);
+pub const NT_PPC_SPE: abi::U32 = abi::num(0x101);
+pub const NT_PPC_VSX: abi::U32 = abi::num(0x102);
+pub const NT_PPC_TAR: abi::U32 = abi::num(0x103);
+pub const NT_PPC_PPR: abi::U32 = abi::num(0x104);
+pub const NT_PPC_DSCR: abi::U32 = abi::num(0x105);
+pub const NT_PPC_EBB: abi::U32 = abi::num(0x106);
+pub const NT_PPC_PMU: abi::U32 = abi::num(0x107);
+
+pub const NT_386_TLS: abi::U32 = abi::num(0x200);
+pub const NT_386_IOPERM: abi::U32 = abi::num(0x201);
+pub const NT_X86_XSTATE: abi::U32 = abi::num(0x202);
+pub const NT_X86_SHSTK: abi::U32 = abi::num(0x204);
+pub const NT_X86_XSAVE_LAYOUT: abi::U32 = abi::num(0x205);
+
+pub const NT_S390_HIGH_GPRS: abi::U32 = abi::num(0x300);
+pub const NT_S390_TIMER: abi::U32 = abi::num(0x301);
+pub const NT_S390_TODCMP: abi::U32 = abi::num(0x302);
+pub const NT_S390_TODPREG: abi::U32 = abi::num(0x303);
+pub const NT_S390_CTRS: abi::U32 = abi::num(0x304);
+pub const NT_S390_PREFIX: abi::U32 = abi::num(0x305);
+pub const NT_S390_LAST_BREAK: abi::U32 = abi::num(0x306);
+pub const NT_S390_SYSTEM_CALL: abi::U32 = abi::num(0x307);
+pub const NT_S390_TDB: abi::U32 = abi::num(0x308);
+pub const NT_S390_VXRS_LOW: abi::U32 = abi::num(0x309);
+pub const NT_S390_VXRS_HIGH: abi::U32 = abi::num(0x30a);
+pub const NT_S390_GS_CB: abi::U32 = abi::num(0x30b);
+pub const NT_S390_GS_BC: abi::U32 = abi::num(0x30c);
+pub const NT_S390_RI_CB: abi::U32 = abi::num(0x30d);
+pub const NT_S390_PV_CPU_DATA: abi::U32 = abi::num(0x30e);
+
+pub const NT_ARM_VFP: abi::U32 = abi::num(0x400);
+pub const NT_ARM_TLS: abi::U32 = abi::num(0x401);
+pub const NT_ARM_HW_BREAK: abi::U32 = abi::num(0x402);
+pub const NT_ARM_HW_WATCH: abi::U32 = abi::num(0x403);
+pub const NT_ARM_SYSTEM_CALL: abi::U32 = abi::num(0x404);
+pub const NT_ARM_SVE: abi::U32 = abi::num(0x405);
+pub const NT_ARM_PAC_MASK: abi::U32 = abi::num(0x406);
+pub const NT_ARM_PACA_KEYS: abi::U32 = abi::num(0x407);
+pub const NT_ARM_PACG_KEYS: abi::U32 = abi::num(0x408);
+pub const NT_ARM_TAGGED_ADDR_CTRL: abi::U32 = abi::num(0x409);
+pub const NT_ARM_PAC_ENABLED_KEYS: abi::U32 = abi::num(0x40a);
+pub const NT_ARM_SSVE: abi::U32 = abi::num(0x40b);
+pub const NT_ARM_ZA: abi::U32 = abi::num(0x40c);
+pub const NT_ARM_ZT: abi::U32 = abi::num(0x40d);
+
+pub const NT_VMCOREDD: abi::U32 = abi::num(0x700);
+
+pub const NT_RISCV_CSR: abi::U32 = abi::num(0x900);
+pub const NT_RISCV_VECTOR: abi::U32 = abi::num(0x901);
+
+pub const NT_LOONGARCH_CPUCFG: abi::U32 = abi::num(0xa00);
+pub const NT_LOONGARCH_CSR: abi::U32 = abi::num(0xa01);
+pub const NT_LOONGARCH_LSX: abi::U32 = abi::num(0xa02);
+pub const NT_LOONGARCH_LASX: abi::U32 = abi::num(0xa03);
+pub const NT_LOONGARCH_LBT: abi::U32 = abi::num(0xa04);
+pub const NT_LOONGARCH_HW_BREAK: abi::U32 = abi::num(0xa05);
+pub const NT_LOONGARCH_HW_WATCH: abi::U32 = abi::num(0xa06);
+
+/// Maximum length of the argument string in `ElfPrpsinfo`.
+pub const ELF_PRARGSZ: usize = 80;
+
+/// Time value as used by `ElfPrstatusCommon` (`__kernel_old_timeval`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OldTimeval {
+    pub tv_sec: abi::Isize,
+    pub tv_usec: abi::Isize,
+}
+
+/// Signal information embedded in `ElfPrstatusCommon`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ElfSiginfo {
+    pub si_signo: abi::I32,
+    pub si_code: abi::I32,
+    pub si_errno: abi::I32,
+}
+
+/// Platform independent part of `ElfPrstatus`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ElfPrstatusCommon {
+    pub pr_info: ElfSiginfo,
+    pub pr_cursig: abi::I16,
+    pub pr_sigpend: abi::Usize,
+    pub pr_sighold: abi::Usize,
+    pub pr_pid: abi::I32,
+    pub pr_ppid: abi::I32,
+    pub pr_pgrp: abi::I32,
+    pub pr_sid: abi::I32,
+    pub pr_utime: OldTimeval,
+    pub pr_stime: OldTimeval,
+    pub pr_cutime: OldTimeval,
+    pub pr_cstime: OldTimeval,
+}
+
+/// Content of `NT_PRSTATUS` notes. One such note is emitted for each thread
+/// of the dumped process.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ElfPrstatus {
+    pub common: ElfPrstatusCommon,
+    pub pr_reg: user::ElfGregset,
+    pub pr_fpvalid: abi::I32,
+}
+
+/// Content of `NT_PRPSINFO` notes. A single such note is emitted for the
+/// dumped process.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ElfPrpsinfo {
+    pub pr_state: abi::I8,
+    pub pr_sname: abi::U8,
+    pub pr_zomb: abi::I8,
+    pub pr_nice: abi::I8,
+    pub pr_flag: abi::Usize,
+    pub pr_uid: user::PrUid,
+    pub pr_gid: user::PrGid,
+    pub pr_pid: abi::I32,
+    pub pr_ppid: abi::I32,
+    pub pr_pgrp: abi::I32,
+    pub pr_sid: abi::I32,
+    pub pr_fname: [abi::U8; 16],
+    pub pr_psargs: [abi::U8; ELF_PRARGSZ],
+}
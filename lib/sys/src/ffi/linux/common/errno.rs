@@ -18,7 +18,7 @@
 //! those are reserved for kernel-internal pages, anyway. This means, even
 //! syscalls that return pointers will use this scheme.
 
-use super::abi;
+use super::{abi, arch};
 
 // Base error codes
 pub const EPERM: abi::U16 = abi::num(1);
@@ -80,7 +80,7 @@ pub const EXFULL: abi::U16 = abi::num(54);
 pub const ENOANO: abi::U16 = abi::num(55);
 pub const EBADRQC: abi::U16 = abi::num(56);
 pub const EBADSLT: abi::U16 = abi::num(57);
-// 58 is unused (EDEADLOCK on UNIX).
+// 58 is unused (EDEADLOCK on UNIX and powerpc).
 pub const EBFONT: abi::U16 = abi::num(59);
 pub const ENOSTR: abi::U16 = abi::num(60);
 pub const ENODATA: abi::U16 = abi::num(61);
@@ -159,4 +159,4 @@ pub const EHWPOISON: abi::U16 = abi::num(133);
 
 // Aliases to the canonical linux codes.
 pub const EWOULDBLOCK: abi::U16 = abi::num(11); // EAGAIN
-pub const EDEADLOCK: abi::U16 = abi::num(35); // EDEADLCK
+pub const EDEADLOCK: abi::U16 = arch::EDEADLOCK; // EDEADLCK, except on powerpc
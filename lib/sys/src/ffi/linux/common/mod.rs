@@ -14,11 +14,26 @@
 //
 //  * `super::abi`: This must be an ABI module with the same symbols as
 //    defined by `osi::ffi::abi`.
+//  * `super::arch`: This must be a module with platform properties and
+//    values that differ between platforms. That is, `BIG_ENDIAN` (whether
+//    the platform is big-endian, which affects the allocation of C
+//    bit-fields) and `EDEADLOCK` must be provided.
+//  * `super::user`: This must be a module with the platform specific
+//    register sets as used by `ptrace(2)` and core-dumps. That is,
+//    `UserRegsStruct`, `ElfGregset`, `PrUid`, and `PrGid` must be provided.
 //
 // Sub-modules of this module never reference the including module directly,
 // but only ever use the symbols exported here. Hence, this module re-exports
 // all required symbols for internal use only.
 
 use super::abi as abi;
+use super::arch as arch;
+use super::user as user;
 
+pub mod bpf;
+pub mod capability;
+pub mod elfcore;
 pub mod errno;
+pub mod perf_event;
+pub mod prctl;
+pub mod securebits;
@@ -0,0 +1,268 @@
+//! # Performance Events
+//!
+//! The `perf_event_open(2)` syscall creates a file-descriptor for a single
+//! performance counter, described by `PerfEventAttr`. Counters are either
+//! hardware counters of the PMU, or software counters maintained by the
+//! kernel. The current value of a counter can be retrieved via `read(2)`, and
+//! samples are delivered through a ring-buffer that is mapped via `mmap(2)`
+//! and starts with a `PerfEventMmapPage`.
+//!
+//! `PerfEventAttr` is versioned via its `size` field. Newer kernels accept all
+//! older sizes (`PERF_ATTR_SIZE_VER*`), and older kernels accept larger sizes
+//! as long as all unknown trailing bytes are zero.
+//!
+//! ## Bit-Fields
+//!
+//! The kernel headers use C bit-fields for the boolean options of
+//! `PerfEventAttr` and the capabilities of `PerfEventMmapPage`. These are
+//! exposed as a single integer field, and the individual bits are provided as
+//! `PERF_ATTR_FLAG_*` and `PERF_MMAP_CAP_*` masks. C bit-fields are allocated
+//! starting at the least significant bit on little-endian platforms, but at
+//! the most significant bit on big-endian platforms. The masks account for
+//! this, so they can be applied to the logical value of the fields on all
+//! platforms.
+
+use super::{abi, arch};
+
+// Create the mask of a bit-field with `width` bits, preceded by `offset` bits
+// of other bit-fields in the same 64-bit storage unit.
+const fn bits(offset: u32, width: u32) -> abi::U64 {
+    let mask = !0u64 >> (64 - width);
+    if arch::BIG_ENDIAN {
+        abi::num(mask << (64 - offset - width))
+    } else {
+        abi::num(mask << offset)
+    }
+}
+
+pub const PERF_TYPE_HARDWARE: abi::U32 = abi::num(0);
+pub const PERF_TYPE_SOFTWARE: abi::U32 = abi::num(1);
+pub const PERF_TYPE_TRACEPOINT: abi::U32 = abi::num(2);
+pub const PERF_TYPE_HW_CACHE: abi::U32 = abi::num(3);
+pub const PERF_TYPE_RAW: abi::U32 = abi::num(4);
+pub const PERF_TYPE_BREAKPOINT: abi::U32 = abi::num(5);
+pub const PERF_TYPE_MAX: abi::U32 = abi::num(6);
+
+pub const PERF_COUNT_HW_CPU_CYCLES: abi::U64 = abi::num(0);
+pub const PERF_COUNT_HW_INSTRUCTIONS: abi::U64 = abi::num(1);
+pub const PERF_COUNT_HW_CACHE_REFERENCES: abi::U64 = abi::num(2);
+pub const PERF_COUNT_HW_CACHE_MISSES: abi::U64 = abi::num(3);
+pub const PERF_COUNT_HW_BRANCH_INSTRUCTIONS: abi::U64 = abi::num(4);
+pub const PERF_COUNT_HW_BRANCH_MISSES: abi::U64 = abi::num(5);
+pub const PERF_COUNT_HW_BUS_CYCLES: abi::U64 = abi::num(6);
+pub const PERF_COUNT_HW_STALLED_CYCLES_FRONTEND: abi::U64 = abi::num(7);
+pub const PERF_COUNT_HW_STALLED_CYCLES_BACKEND: abi::U64 = abi::num(8);
+pub const PERF_COUNT_HW_REF_CPU_CYCLES: abi::U64 = abi::num(9);
+pub const PERF_COUNT_HW_MAX: abi::U64 = abi::num(10);
+
+pub const PERF_COUNT_HW_CACHE_L1D: abi::U64 = abi::num(0);
+pub const PERF_COUNT_HW_CACHE_L1I: abi::U64 = abi::num(1);
+pub const PERF_COUNT_HW_CACHE_LL: abi::U64 = abi::num(2);
+pub const PERF_COUNT_HW_CACHE_DTLB: abi::U64 = abi::num(3);
+pub const PERF_COUNT_HW_CACHE_ITLB: abi::U64 = abi::num(4);
+pub const PERF_COUNT_HW_CACHE_BPU: abi::U64 = abi::num(5);
+pub const PERF_COUNT_HW_CACHE_NODE: abi::U64 = abi::num(6);
+pub const PERF_COUNT_HW_CACHE_MAX: abi::U64 = abi::num(7);
+
+pub const PERF_COUNT_HW_CACHE_OP_READ: abi::U64 = abi::num(0);
+pub const PERF_COUNT_HW_CACHE_OP_WRITE: abi::U64 = abi::num(1);
+pub const PERF_COUNT_HW_CACHE_OP_PREFETCH: abi::U64 = abi::num(2);
+pub const PERF_COUNT_HW_CACHE_OP_MAX: abi::U64 = abi::num(3);
+
+pub const PERF_COUNT_HW_CACHE_RESULT_ACCESS: abi::U64 = abi::num(0);
+pub const PERF_COUNT_HW_CACHE_RESULT_MISS: abi::U64 = abi::num(1);
+pub const PERF_COUNT_HW_CACHE_RESULT_MAX: abi::U64 = abi::num(2);
+
+pub const PERF_COUNT_SW_CPU_CLOCK: abi::U64 = abi::num(0);
+pub const PERF_COUNT_SW_TASK_CLOCK: abi::U64 = abi::num(1);
+pub const PERF_COUNT_SW_PAGE_FAULTS: abi::U64 = abi::num(2);
+pub const PERF_COUNT_SW_CONTEXT_SWITCHES: abi::U64 = abi::num(3);
+pub const PERF_COUNT_SW_CPU_MIGRATIONS: abi::U64 = abi::num(4);
+pub const PERF_COUNT_SW_PAGE_FAULTS_MIN: abi::U64 = abi::num(5);
+pub const PERF_COUNT_SW_PAGE_FAULTS_MAJ: abi::U64 = abi::num(6);
+pub const PERF_COUNT_SW_ALIGNMENT_FAULTS: abi::U64 = abi::num(7);
+pub const PERF_COUNT_SW_EMULATION_FAULTS: abi::U64 = abi::num(8);
+pub const PERF_COUNT_SW_DUMMY: abi::U64 = abi::num(9);
+pub const PERF_COUNT_SW_BPF_OUTPUT: abi::U64 = abi::num(10);
+pub const PERF_COUNT_SW_CGROUP_SWITCHES: abi::U64 = abi::num(11);
+pub const PERF_COUNT_SW_MAX: abi::U64 = abi::num(12);
+
+pub const PERF_SAMPLE_IP: abi::U64 = abi::num(1 << 0);
+pub const PERF_SAMPLE_TID: abi::U64 = abi::num(1 << 1);
+pub const PERF_SAMPLE_TIME: abi::U64 = abi::num(1 << 2);
+pub const PERF_SAMPLE_ADDR: abi::U64 = abi::num(1 << 3);
+pub const PERF_SAMPLE_READ: abi::U64 = abi::num(1 << 4);
+pub const PERF_SAMPLE_CALLCHAIN: abi::U64 = abi::num(1 << 5);
+pub const PERF_SAMPLE_ID: abi::U64 = abi::num(1 << 6);
+pub const PERF_SAMPLE_CPU: abi::U64 = abi::num(1 << 7);
+pub const PERF_SAMPLE_PERIOD: abi::U64 = abi::num(1 << 8);
+pub const PERF_SAMPLE_STREAM_ID: abi::U64 = abi::num(1 << 9);
+pub const PERF_SAMPLE_RAW: abi::U64 = abi::num(1 << 10);
+pub const PERF_SAMPLE_BRANCH_STACK: abi::U64 = abi::num(1 << 11);
+pub const PERF_SAMPLE_REGS_USER: abi::U64 = abi::num(1 << 12);
+pub const PERF_SAMPLE_STACK_USER: abi::U64 = abi::num(1 << 13);
+pub const PERF_SAMPLE_WEIGHT: abi::U64 = abi::num(1 << 14);
+pub const PERF_SAMPLE_DATA_SRC: abi::U64 = abi::num(1 << 15);
+pub const PERF_SAMPLE_IDENTIFIER: abi::U64 = abi::num(1 << 16);
+pub const PERF_SAMPLE_TRANSACTION: abi::U64 = abi::num(1 << 17);
+pub const PERF_SAMPLE_REGS_INTR: abi::U64 = abi::num(1 << 18);
+pub const PERF_SAMPLE_PHYS_ADDR: abi::U64 = abi::num(1 << 19);
+pub const PERF_SAMPLE_AUX: abi::U64 = abi::num(1 << 20);
+pub const PERF_SAMPLE_CGROUP: abi::U64 = abi::num(1 << 21);
+pub const PERF_SAMPLE_DATA_PAGE_SIZE: abi::U64 = abi::num(1 << 22);
+pub const PERF_SAMPLE_CODE_PAGE_SIZE: abi::U64 = abi::num(1 << 23);
+pub const PERF_SAMPLE_WEIGHT_STRUCT: abi::U64 = abi::num(1 << 24);
+
+pub const PERF_FORMAT_TOTAL_TIME_ENABLED: abi::U64 = abi::num(1 << 0);
+pub const PERF_FORMAT_TOTAL_TIME_RUNNING: abi::U64 = abi::num(1 << 1);
+pub const PERF_FORMAT_ID: abi::U64 = abi::num(1 << 2);
+pub const PERF_FORMAT_GROUP: abi::U64 = abi::num(1 << 3);
+pub const PERF_FORMAT_LOST: abi::U64 = abi::num(1 << 4);
+
+pub const PERF_FLAG_FD_NO_GROUP: abi::Usize = abi::num(1 << 0);
+pub const PERF_FLAG_FD_OUTPUT: abi::Usize = abi::num(1 << 1);
+pub const PERF_FLAG_PID_CGROUP: abi::Usize = abi::num(1 << 2);
+pub const PERF_FLAG_FD_CLOEXEC: abi::Usize = abi::num(1 << 3);
+
+pub const PERF_ATTR_SIZE_VER0: abi::U32 = abi::num(64);
+pub const PERF_ATTR_SIZE_VER1: abi::U32 = abi::num(72);
+pub const PERF_ATTR_SIZE_VER2: abi::U32 = abi::num(80);
+pub const PERF_ATTR_SIZE_VER3: abi::U32 = abi::num(96);
+pub const PERF_ATTR_SIZE_VER4: abi::U32 = abi::num(104);
+pub const PERF_ATTR_SIZE_VER5: abi::U32 = abi::num(112);
+pub const PERF_ATTR_SIZE_VER6: abi::U32 = abi::num(120);
+pub const PERF_ATTR_SIZE_VER7: abi::U32 = abi::num(128);
+pub const PERF_ATTR_SIZE_VER8: abi::U32 = abi::num(136);
+
+pub const PERF_ATTR_FLAG_DISABLED: abi::U64 = bits(0, 1);
+pub const PERF_ATTR_FLAG_INHERIT: abi::U64 = bits(1, 1);
+pub const PERF_ATTR_FLAG_PINNED: abi::U64 = bits(2, 1);
+pub const PERF_ATTR_FLAG_EXCLUSIVE: abi::U64 = bits(3, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_USER: abi::U64 = bits(4, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_KERNEL: abi::U64 = bits(5, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_HV: abi::U64 = bits(6, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_IDLE: abi::U64 = bits(7, 1);
+pub const PERF_ATTR_FLAG_MMAP: abi::U64 = bits(8, 1);
+pub const PERF_ATTR_FLAG_COMM: abi::U64 = bits(9, 1);
+pub const PERF_ATTR_FLAG_FREQ: abi::U64 = bits(10, 1);
+pub const PERF_ATTR_FLAG_INHERIT_STAT: abi::U64 = bits(11, 1);
+pub const PERF_ATTR_FLAG_ENABLE_ON_EXEC: abi::U64 = bits(12, 1);
+pub const PERF_ATTR_FLAG_TASK: abi::U64 = bits(13, 1);
+pub const PERF_ATTR_FLAG_WATERMARK: abi::U64 = bits(14, 1);
+pub const PERF_ATTR_FLAG_PRECISE_IP: abi::U64 = bits(15, 2);
+pub const PERF_ATTR_FLAG_MMAP_DATA: abi::U64 = bits(17, 1);
+pub const PERF_ATTR_FLAG_SAMPLE_ID_ALL: abi::U64 = bits(18, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_HOST: abi::U64 = bits(19, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_GUEST: abi::U64 = bits(20, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_KERNEL: abi::U64 = bits(21, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_USER: abi::U64 = bits(22, 1);
+pub const PERF_ATTR_FLAG_MMAP2: abi::U64 = bits(23, 1);
+pub const PERF_ATTR_FLAG_COMM_EXEC: abi::U64 = bits(24, 1);
+pub const PERF_ATTR_FLAG_USE_CLOCKID: abi::U64 = bits(25, 1);
+pub const PERF_ATTR_FLAG_CONTEXT_SWITCH: abi::U64 = bits(26, 1);
+pub const PERF_ATTR_FLAG_WRITE_BACKWARD: abi::U64 = bits(27, 1);
+pub const PERF_ATTR_FLAG_NAMESPACES: abi::U64 = bits(28, 1);
+pub const PERF_ATTR_FLAG_KSYMBOL: abi::U64 = bits(29, 1);
+pub const PERF_ATTR_FLAG_BPF_EVENT: abi::U64 = bits(30, 1);
+pub const PERF_ATTR_FLAG_AUX_OUTPUT: abi::U64 = bits(31, 1);
+pub const PERF_ATTR_FLAG_CGROUP: abi::U64 = bits(32, 1);
+pub const PERF_ATTR_FLAG_TEXT_POKE: abi::U64 = bits(33, 1);
+pub const PERF_ATTR_FLAG_BUILD_ID: abi::U64 = bits(34, 1);
+pub const PERF_ATTR_FLAG_INHERIT_THREAD: abi::U64 = bits(35, 1);
+pub const PERF_ATTR_FLAG_REMOVE_ON_EXEC: abi::U64 = bits(36, 1);
+pub const PERF_ATTR_FLAG_SIGTRAP: abi::U64 = bits(37, 1);
+
+pub const PERF_MMAP_CAP_BIT0: abi::U64 = bits(0, 1);
+pub const PERF_MMAP_CAP_BIT0_IS_DEPRECATED: abi::U64 = bits(1, 1);
+pub const PERF_MMAP_CAP_USER_RDPMC: abi::U64 = bits(2, 1);
+pub const PERF_MMAP_CAP_USER_TIME: abi::U64 = bits(3, 1);
+pub const PERF_MMAP_CAP_USER_TIME_ZERO: abi::U64 = bits(4, 1);
+pub const PERF_MMAP_CAP_USER_TIME_SHORT: abi::U64 = bits(5, 1);
+
+/// Event Attributes
+///
+/// This describes the counter to be created by `perf_event_open(2)`. The
+/// `size` field must be set to the size of the structure as known to the
+/// caller (usually `PERF_ATTR_SIZE_VER8`).
+///
+/// # Inlined Unions
+///
+/// The kernel headers use anonymous unions for several fields, which merely
+/// provide alternative names for the same integer. This structure uses a
+/// single name for each of them:
+///
+///  * `sample_period` is also known as `sample_freq` (if
+///    `PERF_ATTR_FLAG_FREQ` is set).
+///  * `wakeup_events` is also known as `wakeup_watermark` (if
+///    `PERF_ATTR_FLAG_WATERMARK` is set).
+///  * `config1` is also known as `bp_addr`, `kprobe_func`, or `uprobe_path`.
+///  * `config2` is also known as `bp_len`, `kprobe_addr`, or `probe_offset`.
+///
+/// The bit-fields are combined into `flags` (see `PERF_ATTR_FLAG_*`), and the
+/// AUX action bit-fields into `aux_action`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PerfEventAttr {
+    pub type_: abi::U32,
+    pub size: abi::U32,
+    pub config: abi::U64,
+    pub sample_period: abi::U64,
+    pub sample_type: abi::U64,
+    pub read_format: abi::U64,
+    pub flags: abi::U64,
+    pub wakeup_events: abi::U32,
+    pub bp_type: abi::U32,
+    pub config1: abi::U64,
+    pub config2: abi::U64,
+    pub branch_sample_type: abi::U64,
+    pub sample_regs_user: abi::U64,
+    pub sample_stack_user: abi::U32,
+    pub clockid: abi::I32,
+    pub sample_regs_intr: abi::U64,
+    pub aux_watermark: abi::U32,
+    pub sample_max_stack: abi::U16,
+    pub reserved_2: abi::U16,
+    pub aux_sample_size: abi::U32,
+    pub aux_action: abi::U32,
+    pub sig_data: abi::U64,
+    pub config3: abi::U64,
+}
+
+/// Ring-Buffer Control Page
+///
+/// This is the first page of a ring-buffer mapped from a perf event
+/// file-descriptor. It carries the self-monitoring information of the counter
+/// and the head and tail positions of the data and AUX buffers following it.
+///
+/// `data_head` is written by the kernel and must be read with acquire
+/// semantics, `data_tail` is written by user-space with release semantics.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PerfEventMmapPage {
+    pub version: abi::U32,
+    pub compat_version: abi::U32,
+    pub lock: abi::U32,
+    pub index: abi::U32,
+    pub offset: abi::I64,
+    pub time_enabled: abi::U64,
+    pub time_running: abi::U64,
+    pub capabilities: abi::U64,
+    pub pmc_width: abi::U16,
+    pub time_shift: abi::U16,
+    pub time_mult: abi::U32,
+    pub time_offset: abi::U64,
+    pub time_zero: abi::U64,
+    pub size: abi::U32,
+    pub reserved_1: abi::U32,
+    pub time_cycles: abi::U64,
+    pub time_mask: abi::U64,
+    pub reserved: [abi::U8; 116 * 8],
+    pub data_head: abi::U64,
+    pub data_tail: abi::U64,
+    pub data_offset: abi::U64,
+    pub data_size: abi::U64,
+    pub aux_head: abi::U64,
+    pub aux_tail: abi::U64,
+    pub aux_offset: abi::U64,
+    pub aux_size: abi::U64,
+}
@@ -0,0 +1,195 @@
+//! # Process Control
+//!
+//! The `prctl(2)` syscall multiplexes a wide range of operations on the
+//! calling thread or process. The first argument selects the operation
+//! (`PR_*` options), and the remaining arguments are interpreted according to
+//! the selected operation. Options and their arguments are passed as `int` or
+//! `unsigned long`, so we use `i32` as underlying data-type for all options and
+//! flags, except for values that only make sense as `unsigned long`.
+
+use super::abi;
+
+pub const PR_SET_PDEATHSIG: abi::I32 = abi::num(1);
+pub const PR_GET_PDEATHSIG: abi::I32 = abi::num(2);
+
+pub const PR_GET_DUMPABLE: abi::I32 = abi::num(3);
+pub const PR_SET_DUMPABLE: abi::I32 = abi::num(4);
+
+pub const PR_GET_UNALIGN: abi::I32 = abi::num(5);
+pub const PR_SET_UNALIGN: abi::I32 = abi::num(6);
+pub const PR_UNALIGN_NOPRINT: abi::I32 = abi::num(1);
+pub const PR_UNALIGN_SIGBUS: abi::I32 = abi::num(2);
+
+pub const PR_GET_KEEPCAPS: abi::I32 = abi::num(7);
+pub const PR_SET_KEEPCAPS: abi::I32 = abi::num(8);
+
+pub const PR_GET_FPEMU: abi::I32 = abi::num(9);
+pub const PR_SET_FPEMU: abi::I32 = abi::num(10);
+pub const PR_FPEMU_NOPRINT: abi::I32 = abi::num(1);
+pub const PR_FPEMU_SIGFPE: abi::I32 = abi::num(2);
+
+pub const PR_GET_FPEXC: abi::I32 = abi::num(11);
+pub const PR_SET_FPEXC: abi::I32 = abi::num(12);
+pub const PR_FP_EXC_SW_ENABLE: abi::I32 = abi::num(0x80);
+pub const PR_FP_EXC_DIV: abi::I32 = abi::num(0x010000);
+pub const PR_FP_EXC_OVF: abi::I32 = abi::num(0x020000);
+pub const PR_FP_EXC_UND: abi::I32 = abi::num(0x040000);
+pub const PR_FP_EXC_RES: abi::I32 = abi::num(0x080000);
+pub const PR_FP_EXC_INV: abi::I32 = abi::num(0x100000);
+pub const PR_FP_EXC_DISABLED: abi::I32 = abi::num(0);
+pub const PR_FP_EXC_NONRECOV: abi::I32 = abi::num(1);
+pub const PR_FP_EXC_ASYNC: abi::I32 = abi::num(2);
+pub const PR_FP_EXC_PRECISE: abi::I32 = abi::num(3);
+
+pub const PR_GET_TIMING: abi::I32 = abi::num(13);
+pub const PR_SET_TIMING: abi::I32 = abi::num(14);
+pub const PR_TIMING_STATISTICAL: abi::I32 = abi::num(0);
+pub const PR_TIMING_TIMESTAMP: abi::I32 = abi::num(1);
+
+pub const PR_SET_NAME: abi::I32 = abi::num(15);
+pub const PR_GET_NAME: abi::I32 = abi::num(16);
+
+pub const PR_GET_ENDIAN: abi::I32 = abi::num(19);
+pub const PR_SET_ENDIAN: abi::I32 = abi::num(20);
+pub const PR_ENDIAN_BIG: abi::I32 = abi::num(0);
+pub const PR_ENDIAN_LITTLE: abi::I32 = abi::num(1);
+pub const PR_ENDIAN_PPC_LITTLE: abi::I32 = abi::num(2);
+
+pub const PR_GET_SECCOMP: abi::I32 = abi::num(21);
+pub const PR_SET_SECCOMP: abi::I32 = abi::num(22);
+
+pub const PR_CAPBSET_READ: abi::I32 = abi::num(23);
+pub const PR_CAPBSET_DROP: abi::I32 = abi::num(24);
+
+pub const PR_GET_TSC: abi::I32 = abi::num(25);
+pub const PR_SET_TSC: abi::I32 = abi::num(26);
+pub const PR_TSC_ENABLE: abi::I32 = abi::num(1);
+pub const PR_TSC_SIGSEGV: abi::I32 = abi::num(2);
+
+pub const PR_GET_SECUREBITS: abi::I32 = abi::num(27);
+pub const PR_SET_SECUREBITS: abi::I32 = abi::num(28);
+
+pub const PR_SET_TIMERSLACK: abi::I32 = abi::num(29);
+pub const PR_GET_TIMERSLACK: abi::I32 = abi::num(30);
+
+pub const PR_TASK_PERF_EVENTS_DISABLE: abi::I32 = abi::num(31);
+pub const PR_TASK_PERF_EVENTS_ENABLE: abi::I32 = abi::num(32);
+
+pub const PR_MCE_KILL: abi::I32 = abi::num(33);
+pub const PR_MCE_KILL_CLEAR: abi::I32 = abi::num(0);
+pub const PR_MCE_KILL_SET: abi::I32 = abi::num(1);
+pub const PR_MCE_KILL_LATE: abi::I32 = abi::num(0);
+pub const PR_MCE_KILL_EARLY: abi::I32 = abi::num(1);
+pub const PR_MCE_KILL_DEFAULT: abi::I32 = abi::num(2);
+pub const PR_MCE_KILL_GET: abi::I32 = abi::num(34);
+
+pub const PR_SET_MM: abi::I32 = abi::num(35);
+pub const PR_SET_MM_START_CODE: abi::I32 = abi::num(1);
+pub const PR_SET_MM_END_CODE: abi::I32 = abi::num(2);
+pub const PR_SET_MM_START_DATA: abi::I32 = abi::num(3);
+pub const PR_SET_MM_END_DATA: abi::I32 = abi::num(4);
+pub const PR_SET_MM_START_STACK: abi::I32 = abi::num(5);
+pub const PR_SET_MM_START_BRK: abi::I32 = abi::num(6);
+pub const PR_SET_MM_BRK: abi::I32 = abi::num(7);
+pub const PR_SET_MM_ARG_START: abi::I32 = abi::num(8);
+pub const PR_SET_MM_ARG_END: abi::I32 = abi::num(9);
+pub const PR_SET_MM_ENV_START: abi::I32 = abi::num(10);
+pub const PR_SET_MM_ENV_END: abi::I32 = abi::num(11);
+pub const PR_SET_MM_AUXV: abi::I32 = abi::num(12);
+pub const PR_SET_MM_EXE_FILE: abi::I32 = abi::num(13);
+pub const PR_SET_MM_MAP: abi::I32 = abi::num(14);
+pub const PR_SET_MM_MAP_SIZE: abi::I32 = abi::num(15);
+
+pub const PR_SET_PTRACER: abi::I32 = abi::num(0x59616d61);
+pub const PR_SET_PTRACER_ANY: abi::Usize = abi::num(!0); // (unsigned long)-1
+
+pub const PR_SET_CHILD_SUBREAPER: abi::I32 = abi::num(36);
+pub const PR_GET_CHILD_SUBREAPER: abi::I32 = abi::num(37);
+
+pub const PR_SET_NO_NEW_PRIVS: abi::I32 = abi::num(38);
+pub const PR_GET_NO_NEW_PRIVS: abi::I32 = abi::num(39);
+
+pub const PR_GET_TID_ADDRESS: abi::I32 = abi::num(40);
+
+pub const PR_SET_THP_DISABLE: abi::I32 = abi::num(41);
+pub const PR_GET_THP_DISABLE: abi::I32 = abi::num(42);
+
+pub const PR_MPX_ENABLE_MANAGEMENT: abi::I32 = abi::num(43);
+pub const PR_MPX_DISABLE_MANAGEMENT: abi::I32 = abi::num(44);
+
+pub const PR_SET_FP_MODE: abi::I32 = abi::num(45);
+pub const PR_GET_FP_MODE: abi::I32 = abi::num(46);
+pub const PR_FP_MODE_FR: abi::I32 = abi::num(0x1);
+pub const PR_FP_MODE_FRE: abi::I32 = abi::num(0x2);
+
+pub const PR_CAP_AMBIENT: abi::I32 = abi::num(47);
+pub const PR_CAP_AMBIENT_IS_SET: abi::I32 = abi::num(1);
+pub const PR_CAP_AMBIENT_RAISE: abi::I32 = abi::num(2);
+pub const PR_CAP_AMBIENT_LOWER: abi::I32 = abi::num(3);
+pub const PR_CAP_AMBIENT_CLEAR_ALL: abi::I32 = abi::num(4);
+
+pub const PR_SVE_SET_VL: abi::I32 = abi::num(50);
+pub const PR_SVE_SET_VL_ONEXEC: abi::I32 = abi::num(0x40000);
+pub const PR_SVE_GET_VL: abi::I32 = abi::num(51);
+pub const PR_SVE_VL_LEN_MASK: abi::I32 = abi::num(0xffff);
+pub const PR_SVE_VL_INHERIT: abi::I32 = abi::num(0x20000);
+
+pub const PR_GET_SPECULATION_CTRL: abi::I32 = abi::num(52);
+pub const PR_SET_SPECULATION_CTRL: abi::I32 = abi::num(53);
+pub const PR_SPEC_STORE_BYPASS: abi::I32 = abi::num(0);
+pub const PR_SPEC_INDIRECT_BRANCH: abi::I32 = abi::num(1);
+pub const PR_SPEC_L1D_FLUSH: abi::I32 = abi::num(2);
+pub const PR_SPEC_NOT_AFFECTED: abi::I32 = abi::num(0);
+pub const PR_SPEC_PRCTL: abi::I32 = abi::num(0x1);
+pub const PR_SPEC_ENABLE: abi::I32 = abi::num(0x2);
+pub const PR_SPEC_DISABLE: abi::I32 = abi::num(0x4);
+pub const PR_SPEC_FORCE_DISABLE: abi::I32 = abi::num(0x8);
+pub const PR_SPEC_DISABLE_NOEXEC: abi::I32 = abi::num(0x10);
+
+pub const PR_PAC_RESET_KEYS: abi::I32 = abi::num(54);
+pub const PR_PAC_APIAKEY: abi::I32 = abi::num(0x1);
+pub const PR_PAC_APIBKEY: abi::I32 = abi::num(0x2);
+pub const PR_PAC_APDAKEY: abi::I32 = abi::num(0x4);
+pub const PR_PAC_APDBKEY: abi::I32 = abi::num(0x8);
+pub const PR_PAC_APGAKEY: abi::I32 = abi::num(0x10);
+
+pub const PR_SET_TAGGED_ADDR_CTRL: abi::I32 = abi::num(55);
+pub const PR_GET_TAGGED_ADDR_CTRL: abi::I32 = abi::num(56);
+pub const PR_TAGGED_ADDR_ENABLE: abi::I32 = abi::num(0x1);
+
+pub const PR_SET_IO_FLUSHER: abi::I32 = abi::num(57);
+pub const PR_GET_IO_FLUSHER: abi::I32 = abi::num(58);
+
+pub const PR_SET_SYSCALL_USER_DISPATCH: abi::I32 = abi::num(59);
+pub const PR_SYS_DISPATCH_OFF: abi::I32 = abi::num(0);
+pub const PR_SYS_DISPATCH_ON: abi::I32 = abi::num(1);
+
+pub const PR_PAC_SET_ENABLED_KEYS: abi::I32 = abi::num(60);
+pub const PR_PAC_GET_ENABLED_KEYS: abi::I32 = abi::num(61);
+
+pub const PR_SCHED_CORE: abi::I32 = abi::num(62);
+pub const PR_SCHED_CORE_GET: abi::I32 = abi::num(0);
+pub const PR_SCHED_CORE_CREATE: abi::I32 = abi::num(1);
+pub const PR_SCHED_CORE_SHARE_TO: abi::I32 = abi::num(2);
+pub const PR_SCHED_CORE_SHARE_FROM: abi::I32 = abi::num(3);
+pub const PR_SCHED_CORE_MAX: abi::I32 = abi::num(4);
+pub const PR_SCHED_CORE_SCOPE_THREAD: abi::I32 = abi::num(0);
+pub const PR_SCHED_CORE_SCOPE_THREAD_GROUP: abi::I32 = abi::num(1);
+pub const PR_SCHED_CORE_SCOPE_PROCESS_GROUP: abi::I32 = abi::num(2);
+
+pub const PR_SME_SET_VL: abi::I32 = abi::num(63);
+pub const PR_SME_SET_VL_ONEXEC: abi::I32 = abi::num(0x40000);
+pub const PR_SME_GET_VL: abi::I32 = abi::num(64);
+pub const PR_SME_VL_LEN_MASK: abi::I32 = abi::num(0xffff);
+pub const PR_SME_VL_INHERIT: abi::I32 = abi::num(0x20000);
+
+pub const PR_SET_MDWE: abi::I32 = abi::num(65);
+pub const PR_GET_MDWE: abi::I32 = abi::num(66);
+pub const PR_MDWE_REFUSE_EXEC_GAIN: abi::I32 = abi::num(0x1);
+pub const PR_MDWE_NO_INHERIT: abi::I32 = abi::num(0x2);
+
+pub const PR_SET_MEMORY_MERGE: abi::I32 = abi::num(67);
+pub const PR_GET_MEMORY_MERGE: abi::I32 = abi::num(68);
+
+pub const PR_SET_VMA: abi::I32 = abi::num(0x53564d41);
+pub const PR_SET_VMA_ANON_NAME: abi::I32 = abi::num(0);
@@ -0,0 +1,42 @@
+//! # Secure Bits
+//!
+//! The secure bits control how the kernel grants capabilities to tasks that
+//! change their user IDs, or execute programs as root. They are read and
+//! written via `PR_GET_SECUREBITS` and `PR_SET_SECUREBITS` of `prctl(2)`.
+//!
+//! Each bit `SECURE_*` has a companion lock bit, which is the next higher bit.
+//! Once a lock bit is set, the related bit can no longer be changed. The
+//! `SECBIT_*` constants provide the corresponding masks.
+
+use super::abi;
+
+pub const SECURE_NOROOT: abi::I32 = abi::num(0);
+pub const SECURE_NOROOT_LOCKED: abi::I32 = abi::num(1);
+pub const SECURE_NO_SETUID_FIXUP: abi::I32 = abi::num(2);
+pub const SECURE_NO_SETUID_FIXUP_LOCKED: abi::I32 = abi::num(3);
+pub const SECURE_KEEP_CAPS: abi::I32 = abi::num(4);
+pub const SECURE_KEEP_CAPS_LOCKED: abi::I32 = abi::num(5);
+pub const SECURE_NO_CAP_AMBIENT_RAISE: abi::I32 = abi::num(6);
+pub const SECURE_NO_CAP_AMBIENT_RAISE_LOCKED: abi::I32 = abi::num(7);
+pub const SECURE_EXEC_RESTRICT_FILE: abi::I32 = abi::num(8);
+pub const SECURE_EXEC_RESTRICT_FILE_LOCKED: abi::I32 = abi::num(9);
+pub const SECURE_EXEC_DENY_INTERACTIVE: abi::I32 = abi::num(10);
+pub const SECURE_EXEC_DENY_INTERACTIVE_LOCKED: abi::I32 = abi::num(11);
+
+pub const SECBIT_NOROOT: abi::I32 = abi::num(0x001);
+pub const SECBIT_NOROOT_LOCKED: abi::I32 = abi::num(0x002);
+pub const SECBIT_NO_SETUID_FIXUP: abi::I32 = abi::num(0x004);
+pub const SECBIT_NO_SETUID_FIXUP_LOCKED: abi::I32 = abi::num(0x008);
+pub const SECBIT_KEEP_CAPS: abi::I32 = abi::num(0x010);
+pub const SECBIT_KEEP_CAPS_LOCKED: abi::I32 = abi::num(0x020);
+pub const SECBIT_NO_CAP_AMBIENT_RAISE: abi::I32 = abi::num(0x040);
+pub const SECBIT_NO_CAP_AMBIENT_RAISE_LOCKED: abi::I32 = abi::num(0x080);
+pub const SECBIT_EXEC_RESTRICT_FILE: abi::I32 = abi::num(0x100);
+pub const SECBIT_EXEC_RESTRICT_FILE_LOCKED: abi::I32 = abi::num(0x200);
+pub const SECBIT_EXEC_DENY_INTERACTIVE: abi::I32 = abi::num(0x400);
+pub const SECBIT_EXEC_DENY_INTERACTIVE_LOCKED: abi::I32 = abi::num(0x800);
+
+pub const SECUREBITS_DEFAULT: abi::I32 = abi::num(0x000);
+pub const SECURE_ALL_BITS: abi::I32 = abi::num(0x555);
+pub const SECURE_ALL_LOCKS: abi::I32 = abi::num(0xaaa);
+pub const SECURE_ALL_UNPRIVILEGED: abi::I32 = abi::num(0x500);
@@ -0,0 +1,146 @@
+use super::abi;
+
+pub const BPF_MAP_CREATE: abi::I32 = 0;
+pub const BPF_MAP_LOOKUP_ELEM: abi::I32 = 1;
+pub const BPF_MAP_UPDATE_ELEM: abi::I32 = 2;
+pub const BPF_MAP_DELETE_ELEM: abi::I32 = 3;
+pub const BPF_MAP_GET_NEXT_KEY: abi::I32 = 4;
+pub const BPF_PROG_LOAD: abi::I32 = 5;
+pub const BPF_OBJ_PIN: abi::I32 = 6;
+pub const BPF_OBJ_GET: abi::I32 = 7;
+pub const BPF_PROG_ATTACH: abi::I32 = 8;
+pub const BPF_PROG_DETACH: abi::I32 = 9;
+pub const BPF_PROG_TEST_RUN: abi::I32 = 10;
+pub const BPF_PROG_RUN: abi::I32 = 10;
+pub const BPF_PROG_GET_NEXT_ID: abi::I32 = 11;
+pub const BPF_MAP_GET_NEXT_ID: abi::I32 = 12;
+pub const BPF_PROG_GET_FD_BY_ID: abi::I32 = 13;
+pub const BPF_MAP_GET_FD_BY_ID: abi::I32 = 14;
+pub const BPF_OBJ_GET_INFO_BY_FD: abi::I32 = 15;
+pub const BPF_PROG_QUERY: abi::I32 = 16;
+pub const BPF_RAW_TRACEPOINT_OPEN: abi::I32 = 17;
+pub const BPF_BTF_LOAD: abi::I32 = 18;
+pub const BPF_BTF_GET_FD_BY_ID: abi::I32 = 19;
+pub const BPF_TASK_FD_QUERY: abi::I32 = 20;
+pub const BPF_MAP_LOOKUP_AND_DELETE_ELEM: abi::I32 = 21;
+pub const BPF_MAP_FREEZE: abi::I32 = 22;
+pub const BPF_BTF_GET_NEXT_ID: abi::I32 = 23;
+pub const BPF_MAP_LOOKUP_BATCH: abi::I32 = 24;
+pub const BPF_MAP_LOOKUP_AND_DELETE_BATCH: abi::I32 = 25;
+pub const BPF_MAP_UPDATE_BATCH: abi::I32 = 26;
+pub const BPF_MAP_DELETE_BATCH: abi::I32 = 27;
+pub const BPF_LINK_CREATE: abi::I32 = 28;
+pub const BPF_LINK_UPDATE: abi::I32 = 29;
+pub const BPF_LINK_GET_FD_BY_ID: abi::I32 = 30;
+pub const BPF_LINK_GET_NEXT_ID: abi::I32 = 31;
+pub const BPF_ENABLE_STATS: abi::I32 = 32;
+pub const BPF_ITER_CREATE: abi::I32 = 33;
+pub const BPF_LINK_DETACH: abi::I32 = 34;
+pub const BPF_PROG_BIND_MAP: abi::I32 = 35;
+pub const BPF_TOKEN_CREATE: abi::I32 = 36;
+
+pub const BPF_MAP_TYPE_UNSPEC: abi::U32 = 0;
+pub const BPF_MAP_TYPE_HASH: abi::U32 = 1;
+pub const BPF_MAP_TYPE_ARRAY: abi::U32 = 2;
+pub const BPF_MAP_TYPE_PROG_ARRAY: abi::U32 = 3;
+pub const BPF_MAP_TYPE_PERF_EVENT_ARRAY: abi::U32 = 4;
+pub const BPF_MAP_TYPE_PERCPU_HASH: abi::U32 = 5;
+pub const BPF_MAP_TYPE_PERCPU_ARRAY: abi::U32 = 6;
+pub const BPF_MAP_TYPE_STACK_TRACE: abi::U32 = 7;
+pub const BPF_MAP_TYPE_CGROUP_ARRAY: abi::U32 = 8;
+pub const BPF_MAP_TYPE_LRU_HASH: abi::U32 = 9;
+pub const BPF_MAP_TYPE_LRU_PERCPU_HASH: abi::U32 = 10;
+pub const BPF_MAP_TYPE_LPM_TRIE: abi::U32 = 11;
+pub const BPF_MAP_TYPE_ARRAY_OF_MAPS: abi::U32 = 12;
+pub const BPF_MAP_TYPE_HASH_OF_MAPS: abi::U32 = 13;
+pub const BPF_MAP_TYPE_DEVMAP: abi::U32 = 14;
+pub const BPF_MAP_TYPE_SOCKMAP: abi::U32 = 15;
+pub const BPF_MAP_TYPE_CPUMAP: abi::U32 = 16;
+pub const BPF_MAP_TYPE_XSKMAP: abi::U32 = 17;
+pub const BPF_MAP_TYPE_SOCKHASH: abi::U32 = 18;
+pub const BPF_MAP_TYPE_CGROUP_STORAGE: abi::U32 = 19;
+pub const BPF_MAP_TYPE_REUSEPORT_SOCKARRAY: abi::U32 = 20;
+pub const BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE: abi::U32 = 21;
+pub const BPF_MAP_TYPE_QUEUE: abi::U32 = 22;
+pub const BPF_MAP_TYPE_STACK: abi::U32 = 23;
+pub const BPF_MAP_TYPE_SK_STORAGE: abi::U32 = 24;
+pub const BPF_MAP_TYPE_DEVMAP_HASH: abi::U32 = 25;
+pub const BPF_MAP_TYPE_STRUCT_OPS: abi::U32 = 26;
+pub const BPF_MAP_TYPE_RINGBUF: abi::U32 = 27;
+pub const BPF_MAP_TYPE_INODE_STORAGE: abi::U32 = 28;
+pub const BPF_MAP_TYPE_TASK_STORAGE: abi::U32 = 29;
+pub const BPF_MAP_TYPE_BLOOM_FILTER: abi::U32 = 30;
+pub const BPF_MAP_TYPE_USER_RINGBUF: abi::U32 = 31;
+pub const BPF_MAP_TYPE_CGRP_STORAGE: abi::U32 = 32;
+pub const BPF_MAP_TYPE_ARENA: abi::U32 = 33;
+
+pub const BPF_PROG_TYPE_UNSPEC: abi::U32 = 0;
+pub const BPF_PROG_TYPE_SOCKET_FILTER: abi::U32 = 1;
+pub const BPF_PROG_TYPE_KPROBE: abi::U32 = 2;
+pub const BPF_PROG_TYPE_SCHED_CLS: abi::U32 = 3;
+pub const BPF_PROG_TYPE_SCHED_ACT: abi::U32 = 4;
+pub const BPF_PROG_TYPE_TRACEPOINT: abi::U32 = 5;
+pub const BPF_PROG_TYPE_XDP: abi::U32 = 6;
+pub const BPF_PROG_TYPE_PERF_EVENT: abi::U32 = 7;
+pub const BPF_PROG_TYPE_CGROUP_SKB: abi::U32 = 8;
+pub const BPF_PROG_TYPE_CGROUP_SOCK: abi::U32 = 9;
+pub const BPF_PROG_TYPE_LWT_IN: abi::U32 = 10;
+pub const BPF_PROG_TYPE_LWT_OUT: abi::U32 = 11;
+pub const BPF_PROG_TYPE_LWT_XMIT: abi::U32 = 12;
+pub const BPF_PROG_TYPE_SOCK_OPS: abi::U32 = 13;
+pub const BPF_PROG_TYPE_SK_SKB: abi::U32 = 14;
+pub const BPF_PROG_TYPE_CGROUP_DEVICE: abi::U32 = 15;
+pub const BPF_PROG_TYPE_SK_MSG: abi::U32 = 16;
+pub const BPF_PROG_TYPE_RAW_TRACEPOINT: abi::U32 = 17;
+pub const BPF_PROG_TYPE_CGROUP_SOCK_ADDR: abi::U32 = 18;
+pub const BPF_PROG_TYPE_LWT_SEG6LOCAL: abi::U32 = 19;
+pub const BPF_PROG_TYPE_LIRC_MODE2: abi::U32 = 20;
+pub const BPF_PROG_TYPE_SK_REUSEPORT: abi::U32 = 21;
+pub const BPF_PROG_TYPE_FLOW_DISSECTOR: abi::U32 = 22;
+pub const BPF_PROG_TYPE_CGROUP_SYSCTL: abi::U32 = 23;
+pub const BPF_PROG_TYPE_RAW_TRACEPOINT_WRITABLE: abi::U32 = 24;
+pub const BPF_PROG_TYPE_CGROUP_SOCKOPT: abi::U32 = 25;
+pub const BPF_PROG_TYPE_TRACING: abi::U32 = 26;
+pub const BPF_PROG_TYPE_STRUCT_OPS: abi::U32 = 27;
+pub const BPF_PROG_TYPE_EXT: abi::U32 = 28;
+pub const BPF_PROG_TYPE_LSM: abi::U32 = 29;
+pub const BPF_PROG_TYPE_SK_LOOKUP: abi::U32 = 30;
+pub const BPF_PROG_TYPE_SYSCALL: abi::U32 = 31;
+pub const BPF_PROG_TYPE_NETFILTER: abi::U32 = 32;
+
+pub const BPF_ANY: abi::U64 = 0;
+pub const BPF_NOEXIST: abi::U64 = 1;
+pub const BPF_EXIST: abi::U64 = 2;
+pub const BPF_F_LOCK: abi::U64 = 4;
+
+pub const BPF_F_NO_PREALLOC: abi::U32 = 1 << 0;
+pub const BPF_F_NO_COMMON_LRU: abi::U32 = 1 << 1;
+pub const BPF_F_NUMA_NODE: abi::U32 = 1 << 2;
+pub const BPF_F_RDONLY: abi::U32 = 1 << 3;
+pub const BPF_F_WRONLY: abi::U32 = 1 << 4;
+pub const BPF_F_STACK_BUILD_ID: abi::U32 = 1 << 5;
+pub const BPF_F_ZERO_SEED: abi::U32 = 1 << 6;
+pub const BPF_F_RDONLY_PROG: abi::U32 = 1 << 7;
+pub const BPF_F_WRONLY_PROG: abi::U32 = 1 << 8;
+pub const BPF_F_CLONE: abi::U32 = 1 << 9;
+pub const BPF_F_MMAPABLE: abi::U32 = 1 << 10;
+pub const BPF_F_PRESERVE_ELEMS: abi::U32 = 1 << 11;
+pub const BPF_F_INNER_MAP: abi::U32 = 1 << 12;
+pub const BPF_F_LINK: abi::U32 = 1 << 13;
+pub const BPF_F_PATH_FD: abi::U32 = 1 << 14;
+
+pub const BPF_F_STRICT_ALIGNMENT: abi::U32 = 1 << 0;
+pub const BPF_F_ANY_ALIGNMENT: abi::U32 = 1 << 1;
+pub const BPF_F_TEST_RND_HI32: abi::U32 = 1 << 2;
+pub const BPF_F_TEST_STATE_FREQ: abi::U32 = 1 << 3;
+pub const BPF_F_SLEEPABLE: abi::U32 = 1 << 4;
+pub const BPF_F_XDP_HAS_FRAGS: abi::U32 = 1 << 5;
+pub const BPF_F_XDP_DEV_BOUND_ONLY: abi::U32 = 1 << 6;
+
+pub const BPF_F_ALLOW_OVERRIDE: abi::U32 = 1 << 0;
+pub const BPF_F_ALLOW_MULTI: abi::U32 = 1 << 1;
+pub const BPF_F_REPLACE: abi::U32 = 1 << 2;
+
+pub const BPF_STATS_RUN_TIME: abi::U32 = 0;
+
+pub const BPF_OBJ_NAME_LEN: usize = 16;
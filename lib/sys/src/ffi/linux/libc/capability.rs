@@ -0,0 +1,67 @@
+use super::abi;
+
+pub const CAP_CHOWN: abi::U8 = 0;
+pub const CAP_DAC_OVERRIDE: abi::U8 = 1;
+pub const CAP_DAC_READ_SEARCH: abi::U8 = 2;
+pub const CAP_FOWNER: abi::U8 = 3;
+pub const CAP_FSETID: abi::U8 = 4;
+pub const CAP_KILL: abi::U8 = 5;
+pub const CAP_SETGID: abi::U8 = 6;
+pub const CAP_SETUID: abi::U8 = 7;
+pub const CAP_SETPCAP: abi::U8 = 8;
+pub const CAP_LINUX_IMMUTABLE: abi::U8 = 9;
+pub const CAP_NET_BIND_SERVICE: abi::U8 = 10;
+pub const CAP_NET_BROADCAST: abi::U8 = 11;
+pub const CAP_NET_ADMIN: abi::U8 = 12;
+pub const CAP_NET_RAW: abi::U8 = 13;
+pub const CAP_IPC_LOCK: abi::U8 = 14;
+pub const CAP_IPC_OWNER: abi::U8 = 15;
+pub const CAP_SYS_MODULE: abi::U8 = 16;
+pub const CAP_SYS_RAWIO: abi::U8 = 17;
+pub const CAP_SYS_CHROOT: abi::U8 = 18;
+pub const CAP_SYS_PTRACE: abi::U8 = 19;
+pub const CAP_SYS_PACCT: abi::U8 = 20;
+pub const CAP_SYS_ADMIN: abi::U8 = 21;
+pub const CAP_SYS_BOOT: abi::U8 = 22;
+pub const CAP_SYS_NICE: abi::U8 = 23;
+pub const CAP_SYS_RESOURCE: abi::U8 = 24;
+pub const CAP_SYS_TIME: abi::U8 = 25;
+pub const CAP_SYS_TTY_CONFIG: abi::U8 = 26;
+pub const CAP_MKNOD: abi::U8 = 27;
+pub const CAP_LEASE: abi::U8 = 28;
+pub const CAP_AUDIT_WRITE: abi::U8 = 29;
+pub const CAP_AUDIT_CONTROL: abi::U8 = 30;
+pub const CAP_SETFCAP: abi::U8 = 31;
+pub const CAP_MAC_OVERRIDE: abi::U8 = 32;
+pub const CAP_MAC_ADMIN: abi::U8 = 33;
+pub const CAP_SYSLOG: abi::U8 = 34;
+pub const CAP_WAKE_ALARM: abi::U8 = 35;
+pub const CAP_BLOCK_SUSPEND: abi::U8 = 36;
+pub const CAP_AUDIT_READ: abi::U8 = 37;
+pub const CAP_PERFMON: abi::U8 = 38;
+pub const CAP_BPF: abi::U8 = 39;
+pub const CAP_CHECKPOINT_RESTORE: abi::U8 = 40;
+
+pub const CAP_LAST_CAP: abi::U8 = 40; // CAP_CHECKPOINT_RESTORE
+
+pub const _LINUX_CAPABILITY_VERSION_1: abi::U32 = 0x19980330;
+pub const _LINUX_CAPABILITY_U32S_1: abi::U32 = 1;
+pub const _LINUX_CAPABILITY_VERSION_2: abi::U32 = 0x20071026;
+pub const _LINUX_CAPABILITY_U32S_2: abi::U32 = 2;
+pub const _LINUX_CAPABILITY_VERSION_3: abi::U32 = 0x20080522;
+pub const _LINUX_CAPABILITY_U32S_3: abi::U32 = 2;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CapUserHeader {
+    pub version: abi::U32,
+    pub pid: abi::I32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CapUserData {
+    pub effective: abi::U32,
+    pub permitted: abi::U32,
+    pub inheritable: abi::U32,
+}
@@ -41,4 +41,10 @@ pub mod abi {
     pub use osi::ffi::abi::shared::*;
 }
 
+pub mod bpf;
+pub mod capability;
+pub mod elfcore;
 pub mod errno;
+pub mod perf_event;
+pub mod prctl;
+pub mod securebits;
@@ -0,0 +1,154 @@
+use super::abi;
+
+const fn bits(offset: u32, width: u32) -> abi::U64 {
+    let mask = !0u64 >> (64 - width);
+    if cfg!(target_endian = "big") {
+        mask << (64 - offset - width)
+    } else {
+        mask << offset
+    }
+}
+
+pub const PERF_TYPE_HARDWARE: abi::U32 = 0;
+pub const PERF_TYPE_SOFTWARE: abi::U32 = 1;
+pub const PERF_TYPE_TRACEPOINT: abi::U32 = 2;
+pub const PERF_TYPE_HW_CACHE: abi::U32 = 3;
+pub const PERF_TYPE_RAW: abi::U32 = 4;
+pub const PERF_TYPE_BREAKPOINT: abi::U32 = 5;
+pub const PERF_TYPE_MAX: abi::U32 = 6;
+
+pub const PERF_COUNT_HW_CPU_CYCLES: abi::U64 = 0;
+pub const PERF_COUNT_HW_INSTRUCTIONS: abi::U64 = 1;
+pub const PERF_COUNT_HW_CACHE_REFERENCES: abi::U64 = 2;
+pub const PERF_COUNT_HW_CACHE_MISSES: abi::U64 = 3;
+pub const PERF_COUNT_HW_BRANCH_INSTRUCTIONS: abi::U64 = 4;
+pub const PERF_COUNT_HW_BRANCH_MISSES: abi::U64 = 5;
+pub const PERF_COUNT_HW_BUS_CYCLES: abi::U64 = 6;
+pub const PERF_COUNT_HW_STALLED_CYCLES_FRONTEND: abi::U64 = 7;
+pub const PERF_COUNT_HW_STALLED_CYCLES_BACKEND: abi::U64 = 8;
+pub const PERF_COUNT_HW_REF_CPU_CYCLES: abi::U64 = 9;
+pub const PERF_COUNT_HW_MAX: abi::U64 = 10;
+
+pub const PERF_COUNT_HW_CACHE_L1D: abi::U64 = 0;
+pub const PERF_COUNT_HW_CACHE_L1I: abi::U64 = 1;
+pub const PERF_COUNT_HW_CACHE_LL: abi::U64 = 2;
+pub const PERF_COUNT_HW_CACHE_DTLB: abi::U64 = 3;
+pub const PERF_COUNT_HW_CACHE_ITLB: abi::U64 = 4;
+pub const PERF_COUNT_HW_CACHE_BPU: abi::U64 = 5;
+pub const PERF_COUNT_HW_CACHE_NODE: abi::U64 = 6;
+pub const PERF_COUNT_HW_CACHE_MAX: abi::U64 = 7;
+
+pub const PERF_COUNT_HW_CACHE_OP_READ: abi::U64 = 0;
+pub const PERF_COUNT_HW_CACHE_OP_WRITE: abi::U64 = 1;
+pub const PERF_COUNT_HW_CACHE_OP_PREFETCH: abi::U64 = 2;
+pub const PERF_COUNT_HW_CACHE_OP_MAX: abi::U64 = 3;
+
+pub const PERF_COUNT_HW_CACHE_RESULT_ACCESS: abi::U64 = 0;
+pub const PERF_COUNT_HW_CACHE_RESULT_MISS: abi::U64 = 1;
+pub const PERF_COUNT_HW_CACHE_RESULT_MAX: abi::U64 = 2;
+
+pub const PERF_COUNT_SW_CPU_CLOCK: abi::U64 = 0;
+pub const PERF_COUNT_SW_TASK_CLOCK: abi::U64 = 1;
+pub const PERF_COUNT_SW_PAGE_FAULTS: abi::U64 = 2;
+pub const PERF_COUNT_SW_CONTEXT_SWITCHES: abi::U64 = 3;
+pub const PERF_COUNT_SW_CPU_MIGRATIONS: abi::U64 = 4;
+pub const PERF_COUNT_SW_PAGE_FAULTS_MIN: abi::U64 = 5;
+pub const PERF_COUNT_SW_PAGE_FAULTS_MAJ: abi::U64 = 6;
+pub const PERF_COUNT_SW_ALIGNMENT_FAULTS: abi::U64 = 7;
+pub const PERF_COUNT_SW_EMULATION_FAULTS: abi::U64 = 8;
+pub const PERF_COUNT_SW_DUMMY: abi::U64 = 9;
+pub const PERF_COUNT_SW_BPF_OUTPUT: abi::U64 = 10;
+pub const PERF_COUNT_SW_CGROUP_SWITCHES: abi::U64 = 11;
+pub const PERF_COUNT_SW_MAX: abi::U64 = 12;
+
+pub const PERF_SAMPLE_IP: abi::U64 = 1 << 0;
+pub const PERF_SAMPLE_TID: abi::U64 = 1 << 1;
+pub const PERF_SAMPLE_TIME: abi::U64 = 1 << 2;
+pub const PERF_SAMPLE_ADDR: abi::U64 = 1 << 3;
+pub const PERF_SAMPLE_READ: abi::U64 = 1 << 4;
+pub const PERF_SAMPLE_CALLCHAIN: abi::U64 = 1 << 5;
+pub const PERF_SAMPLE_ID: abi::U64 = 1 << 6;
+pub const PERF_SAMPLE_CPU: abi::U64 = 1 << 7;
+pub const PERF_SAMPLE_PERIOD: abi::U64 = 1 << 8;
+pub const PERF_SAMPLE_STREAM_ID: abi::U64 = 1 << 9;
+pub const PERF_SAMPLE_RAW: abi::U64 = 1 << 10;
+pub const PERF_SAMPLE_BRANCH_STACK: abi::U64 = 1 << 11;
+pub const PERF_SAMPLE_REGS_USER: abi::U64 = 1 << 12;
+pub const PERF_SAMPLE_STACK_USER: abi::U64 = 1 << 13;
+pub const PERF_SAMPLE_WEIGHT: abi::U64 = 1 << 14;
+pub const PERF_SAMPLE_DATA_SRC: abi::U64 = 1 << 15;
+pub const PERF_SAMPLE_IDENTIFIER: abi::U64 = 1 << 16;
+pub const PERF_SAMPLE_TRANSACTION: abi::U64 = 1 << 17;
+pub const PERF_SAMPLE_REGS_INTR: abi::U64 = 1 << 18;
+pub const PERF_SAMPLE_PHYS_ADDR: abi::U64 = 1 << 19;
+pub const PERF_SAMPLE_AUX: abi::U64 = 1 << 20;
+pub const PERF_SAMPLE_CGROUP: abi::U64 = 1 << 21;
+pub const PERF_SAMPLE_DATA_PAGE_SIZE: abi::U64 = 1 << 22;
+pub const PERF_SAMPLE_CODE_PAGE_SIZE: abi::U64 = 1 << 23;
+pub const PERF_SAMPLE_WEIGHT_STRUCT: abi::U64 = 1 << 24;
+
+pub const PERF_FORMAT_TOTAL_TIME_ENABLED: abi::U64 = 1 << 0;
+pub const PERF_FORMAT_TOTAL_TIME_RUNNING: abi::U64 = 1 << 1;
+pub const PERF_FORMAT_ID: abi::U64 = 1 << 2;
+pub const PERF_FORMAT_GROUP: abi::U64 = 1 << 3;
+pub const PERF_FORMAT_LOST: abi::U64 = 1 << 4;
+
+pub const PERF_FLAG_FD_NO_GROUP: abi::Usize = 1 << 0;
+pub const PERF_FLAG_FD_OUTPUT: abi::Usize = 1 << 1;
+pub const PERF_FLAG_PID_CGROUP: abi::Usize = 1 << 2;
+pub const PERF_FLAG_FD_CLOEXEC: abi::Usize = 1 << 3;
+
+pub const PERF_ATTR_SIZE_VER0: abi::U32 = 64;
+pub const PERF_ATTR_SIZE_VER1: abi::U32 = 72;
+pub const PERF_ATTR_SIZE_VER2: abi::U32 = 80;
+pub const PERF_ATTR_SIZE_VER3: abi::U32 = 96;
+pub const PERF_ATTR_SIZE_VER4: abi::U32 = 104;
+pub const PERF_ATTR_SIZE_VER5: abi::U32 = 112;
+pub const PERF_ATTR_SIZE_VER6: abi::U32 = 120;
+pub const PERF_ATTR_SIZE_VER7: abi::U32 = 128;
+pub const PERF_ATTR_SIZE_VER8: abi::U32 = 136;
+
+pub const PERF_ATTR_FLAG_DISABLED: abi::U64 = bits(0, 1);
+pub const PERF_ATTR_FLAG_INHERIT: abi::U64 = bits(1, 1);
+pub const PERF_ATTR_FLAG_PINNED: abi::U64 = bits(2, 1);
+pub const PERF_ATTR_FLAG_EXCLUSIVE: abi::U64 = bits(3, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_USER: abi::U64 = bits(4, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_KERNEL: abi::U64 = bits(5, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_HV: abi::U64 = bits(6, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_IDLE: abi::U64 = bits(7, 1);
+pub const PERF_ATTR_FLAG_MMAP: abi::U64 = bits(8, 1);
+pub const PERF_ATTR_FLAG_COMM: abi::U64 = bits(9, 1);
+pub const PERF_ATTR_FLAG_FREQ: abi::U64 = bits(10, 1);
+pub const PERF_ATTR_FLAG_INHERIT_STAT: abi::U64 = bits(11, 1);
+pub const PERF_ATTR_FLAG_ENABLE_ON_EXEC: abi::U64 = bits(12, 1);
+pub const PERF_ATTR_FLAG_TASK: abi::U64 = bits(13, 1);
+pub const PERF_ATTR_FLAG_WATERMARK: abi::U64 = bits(14, 1);
+pub const PERF_ATTR_FLAG_PRECISE_IP: abi::U64 = bits(15, 2);
+pub const PERF_ATTR_FLAG_MMAP_DATA: abi::U64 = bits(17, 1);
+pub const PERF_ATTR_FLAG_SAMPLE_ID_ALL: abi::U64 = bits(18, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_HOST: abi::U64 = bits(19, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_GUEST: abi::U64 = bits(20, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_KERNEL: abi::U64 = bits(21, 1);
+pub const PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_USER: abi::U64 = bits(22, 1);
+pub const PERF_ATTR_FLAG_MMAP2: abi::U64 = bits(23, 1);
+pub const PERF_ATTR_FLAG_COMM_EXEC: abi::U64 = bits(24, 1);
+pub const PERF_ATTR_FLAG_USE_CLOCKID: abi::U64 = bits(25, 1);
+pub const PERF_ATTR_FLAG_CONTEXT_SWITCH: abi::U64 = bits(26, 1);
+pub const PERF_ATTR_FLAG_WRITE_BACKWARD: abi::U64 = bits(27, 1);
+pub const PERF_ATTR_FLAG_NAMESPACES: abi::U64 = bits(28, 1);
+pub const PERF_ATTR_FLAG_KSYMBOL: abi::U64 = bits(29, 1);
+pub const PERF_ATTR_FLAG_BPF_EVENT: abi::U64 = bits(30, 1);
+pub const PERF_ATTR_FLAG_AUX_OUTPUT: abi::U64 = bits(31, 1);
+pub const PERF_ATTR_FLAG_CGROUP: abi::U64 = bits(32, 1);
+pub const PERF_ATTR_FLAG_TEXT_POKE: abi::U64 = bits(33, 1);
+pub const PERF_ATTR_FLAG_BUILD_ID: abi::U64 = bits(34, 1);
+pub const PERF_ATTR_FLAG_INHERIT_THREAD: abi::U64 = bits(35, 1);
+pub const PERF_ATTR_FLAG_REMOVE_ON_EXEC: abi::U64 = bits(36, 1);
+pub const PERF_ATTR_FLAG_SIGTRAP: abi::U64 = bits(37, 1);
+
+pub const PERF_MMAP_CAP_BIT0: abi::U64 = bits(0, 1);
+pub const PERF_MMAP_CAP_BIT0_IS_DEPRECATED: abi::U64 = bits(1, 1);
+pub const PERF_MMAP_CAP_USER_RDPMC: abi::U64 = bits(2, 1);
+pub const PERF_MMAP_CAP_USER_TIME: abi::U64 = bits(3, 1);
+pub const PERF_MMAP_CAP_USER_TIME_ZERO: abi::U64 = bits(4, 1);
+pub const PERF_MMAP_CAP_USER_TIME_SHORT: abi::U64 = bits(5, 1);
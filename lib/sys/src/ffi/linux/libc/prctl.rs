@@ -0,0 +1,186 @@
+use super::abi;
+
+pub const PR_SET_PDEATHSIG: abi::I32 = libc::PR_SET_PDEATHSIG as _;
+pub const PR_GET_PDEATHSIG: abi::I32 = libc::PR_GET_PDEATHSIG as _;
+
+pub const PR_GET_DUMPABLE: abi::I32 = libc::PR_GET_DUMPABLE as _;
+pub const PR_SET_DUMPABLE: abi::I32 = libc::PR_SET_DUMPABLE as _;
+
+pub const PR_GET_UNALIGN: abi::I32 = libc::PR_GET_UNALIGN as _;
+pub const PR_SET_UNALIGN: abi::I32 = libc::PR_SET_UNALIGN as _;
+pub const PR_UNALIGN_NOPRINT: abi::I32 = libc::PR_UNALIGN_NOPRINT as _;
+pub const PR_UNALIGN_SIGBUS: abi::I32 = libc::PR_UNALIGN_SIGBUS as _;
+
+pub const PR_GET_KEEPCAPS: abi::I32 = libc::PR_GET_KEEPCAPS as _;
+pub const PR_SET_KEEPCAPS: abi::I32 = libc::PR_SET_KEEPCAPS as _;
+
+pub const PR_GET_FPEMU: abi::I32 = libc::PR_GET_FPEMU as _;
+pub const PR_SET_FPEMU: abi::I32 = libc::PR_SET_FPEMU as _;
+pub const PR_FPEMU_NOPRINT: abi::I32 = libc::PR_FPEMU_NOPRINT as _;
+pub const PR_FPEMU_SIGFPE: abi::I32 = libc::PR_FPEMU_SIGFPE as _;
+
+pub const PR_GET_FPEXC: abi::I32 = libc::PR_GET_FPEXC as _;
+pub const PR_SET_FPEXC: abi::I32 = libc::PR_SET_FPEXC as _;
+pub const PR_FP_EXC_SW_ENABLE: abi::I32 = libc::PR_FP_EXC_SW_ENABLE as _;
+pub const PR_FP_EXC_DIV: abi::I32 = libc::PR_FP_EXC_DIV as _;
+pub const PR_FP_EXC_OVF: abi::I32 = libc::PR_FP_EXC_OVF as _;
+pub const PR_FP_EXC_UND: abi::I32 = libc::PR_FP_EXC_UND as _;
+pub const PR_FP_EXC_RES: abi::I32 = libc::PR_FP_EXC_RES as _;
+pub const PR_FP_EXC_INV: abi::I32 = libc::PR_FP_EXC_INV as _;
+pub const PR_FP_EXC_DISABLED: abi::I32 = libc::PR_FP_EXC_DISABLED as _;
+pub const PR_FP_EXC_NONRECOV: abi::I32 = libc::PR_FP_EXC_NONRECOV as _;
+pub const PR_FP_EXC_ASYNC: abi::I32 = libc::PR_FP_EXC_ASYNC as _;
+pub const PR_FP_EXC_PRECISE: abi::I32 = libc::PR_FP_EXC_PRECISE as _;
+
+pub const PR_GET_TIMING: abi::I32 = libc::PR_GET_TIMING as _;
+pub const PR_SET_TIMING: abi::I32 = libc::PR_SET_TIMING as _;
+pub const PR_TIMING_STATISTICAL: abi::I32 = libc::PR_TIMING_STATISTICAL as _;
+pub const PR_TIMING_TIMESTAMP: abi::I32 = libc::PR_TIMING_TIMESTAMP as _;
+
+pub const PR_SET_NAME: abi::I32 = libc::PR_SET_NAME as _;
+pub const PR_GET_NAME: abi::I32 = libc::PR_GET_NAME as _;
+
+pub const PR_GET_ENDIAN: abi::I32 = libc::PR_GET_ENDIAN as _;
+pub const PR_SET_ENDIAN: abi::I32 = libc::PR_SET_ENDIAN as _;
+pub const PR_ENDIAN_BIG: abi::I32 = libc::PR_ENDIAN_BIG as _;
+pub const PR_ENDIAN_LITTLE: abi::I32 = libc::PR_ENDIAN_LITTLE as _;
+pub const PR_ENDIAN_PPC_LITTLE: abi::I32 = libc::PR_ENDIAN_PPC_LITTLE as _;
+
+pub const PR_GET_SECCOMP: abi::I32 = libc::PR_GET_SECCOMP as _;
+pub const PR_SET_SECCOMP: abi::I32 = libc::PR_SET_SECCOMP as _;
+
+pub const PR_CAPBSET_READ: abi::I32 = libc::PR_CAPBSET_READ as _;
+pub const PR_CAPBSET_DROP: abi::I32 = libc::PR_CAPBSET_DROP as _;
+
+pub const PR_GET_TSC: abi::I32 = libc::PR_GET_TSC as _;
+pub const PR_SET_TSC: abi::I32 = libc::PR_SET_TSC as _;
+pub const PR_TSC_ENABLE: abi::I32 = libc::PR_TSC_ENABLE as _;
+pub const PR_TSC_SIGSEGV: abi::I32 = libc::PR_TSC_SIGSEGV as _;
+
+pub const PR_GET_SECUREBITS: abi::I32 = libc::PR_GET_SECUREBITS as _;
+pub const PR_SET_SECUREBITS: abi::I32 = libc::PR_SET_SECUREBITS as _;
+
+pub const PR_SET_TIMERSLACK: abi::I32 = libc::PR_SET_TIMERSLACK as _;
+pub const PR_GET_TIMERSLACK: abi::I32 = libc::PR_GET_TIMERSLACK as _;
+
+pub const PR_TASK_PERF_EVENTS_DISABLE: abi::I32 = libc::PR_TASK_PERF_EVENTS_DISABLE as _;
+pub const PR_TASK_PERF_EVENTS_ENABLE: abi::I32 = libc::PR_TASK_PERF_EVENTS_ENABLE as _;
+
+pub const PR_MCE_KILL: abi::I32 = libc::PR_MCE_KILL as _;
+pub const PR_MCE_KILL_CLEAR: abi::I32 = libc::PR_MCE_KILL_CLEAR as _;
+pub const PR_MCE_KILL_SET: abi::I32 = libc::PR_MCE_KILL_SET as _;
+pub const PR_MCE_KILL_LATE: abi::I32 = libc::PR_MCE_KILL_LATE as _;
+pub const PR_MCE_KILL_EARLY: abi::I32 = libc::PR_MCE_KILL_EARLY as _;
+pub const PR_MCE_KILL_DEFAULT: abi::I32 = libc::PR_MCE_KILL_DEFAULT as _;
+pub const PR_MCE_KILL_GET: abi::I32 = libc::PR_MCE_KILL_GET as _;
+
+pub const PR_SET_MM: abi::I32 = libc::PR_SET_MM as _;
+pub const PR_SET_MM_START_CODE: abi::I32 = libc::PR_SET_MM_START_CODE as _;
+pub const PR_SET_MM_END_CODE: abi::I32 = libc::PR_SET_MM_END_CODE as _;
+pub const PR_SET_MM_START_DATA: abi::I32 = libc::PR_SET_MM_START_DATA as _;
+pub const PR_SET_MM_END_DATA: abi::I32 = libc::PR_SET_MM_END_DATA as _;
+pub const PR_SET_MM_START_STACK: abi::I32 = libc::PR_SET_MM_START_STACK as _;
+pub const PR_SET_MM_START_BRK: abi::I32 = libc::PR_SET_MM_START_BRK as _;
+pub const PR_SET_MM_BRK: abi::I32 = libc::PR_SET_MM_BRK as _;
+pub const PR_SET_MM_ARG_START: abi::I32 = libc::PR_SET_MM_ARG_START as _;
+pub const PR_SET_MM_ARG_END: abi::I32 = libc::PR_SET_MM_ARG_END as _;
+pub const PR_SET_MM_ENV_START: abi::I32 = libc::PR_SET_MM_ENV_START as _;
+pub const PR_SET_MM_ENV_END: abi::I32 = libc::PR_SET_MM_ENV_END as _;
+pub const PR_SET_MM_AUXV: abi::I32 = libc::PR_SET_MM_AUXV as _;
+pub const PR_SET_MM_EXE_FILE: abi::I32 = libc::PR_SET_MM_EXE_FILE as _;
+pub const PR_SET_MM_MAP: abi::I32 = libc::PR_SET_MM_MAP as _;
+pub const PR_SET_MM_MAP_SIZE: abi::I32 = libc::PR_SET_MM_MAP_SIZE as _;
+
+pub const PR_SET_PTRACER: abi::I32 = libc::PR_SET_PTRACER as _;
+pub const PR_SET_PTRACER_ANY: abi::Usize = libc::PR_SET_PTRACER_ANY as _;
+
+pub const PR_SET_CHILD_SUBREAPER: abi::I32 = libc::PR_SET_CHILD_SUBREAPER as _;
+pub const PR_GET_CHILD_SUBREAPER: abi::I32 = libc::PR_GET_CHILD_SUBREAPER as _;
+
+pub const PR_SET_NO_NEW_PRIVS: abi::I32 = libc::PR_SET_NO_NEW_PRIVS as _;
+pub const PR_GET_NO_NEW_PRIVS: abi::I32 = libc::PR_GET_NO_NEW_PRIVS as _;
+
+pub const PR_GET_TID_ADDRESS: abi::I32 = libc::PR_GET_TID_ADDRESS as _;
+
+pub const PR_SET_THP_DISABLE: abi::I32 = libc::PR_SET_THP_DISABLE as _;
+pub const PR_GET_THP_DISABLE: abi::I32 = libc::PR_GET_THP_DISABLE as _;
+
+pub const PR_MPX_ENABLE_MANAGEMENT: abi::I32 = libc::PR_MPX_ENABLE_MANAGEMENT as _;
+pub const PR_MPX_DISABLE_MANAGEMENT: abi::I32 = libc::PR_MPX_DISABLE_MANAGEMENT as _;
+
+pub const PR_SET_FP_MODE: abi::I32 = libc::PR_SET_FP_MODE as _;
+pub const PR_GET_FP_MODE: abi::I32 = libc::PR_GET_FP_MODE as _;
+pub const PR_FP_MODE_FR: abi::I32 = libc::PR_FP_MODE_FR as _;
+pub const PR_FP_MODE_FRE: abi::I32 = libc::PR_FP_MODE_FRE as _;
+
+pub const PR_CAP_AMBIENT: abi::I32 = libc::PR_CAP_AMBIENT as _;
+pub const PR_CAP_AMBIENT_IS_SET: abi::I32 = libc::PR_CAP_AMBIENT_IS_SET as _;
+pub const PR_CAP_AMBIENT_RAISE: abi::I32 = libc::PR_CAP_AMBIENT_RAISE as _;
+pub const PR_CAP_AMBIENT_LOWER: abi::I32 = libc::PR_CAP_AMBIENT_LOWER as _;
+pub const PR_CAP_AMBIENT_CLEAR_ALL: abi::I32 = libc::PR_CAP_AMBIENT_CLEAR_ALL as _;
+
+pub const PR_SVE_SET_VL: abi::I32 = 50;
+pub const PR_SVE_SET_VL_ONEXEC: abi::I32 = 0x40000;
+pub const PR_SVE_GET_VL: abi::I32 = 51;
+pub const PR_SVE_VL_LEN_MASK: abi::I32 = 0xffff;
+pub const PR_SVE_VL_INHERIT: abi::I32 = 0x20000;
+
+pub const PR_GET_SPECULATION_CTRL: abi::I32 = 52;
+pub const PR_SET_SPECULATION_CTRL: abi::I32 = 53;
+pub const PR_SPEC_STORE_BYPASS: abi::I32 = 0;
+pub const PR_SPEC_INDIRECT_BRANCH: abi::I32 = 1;
+pub const PR_SPEC_L1D_FLUSH: abi::I32 = 2;
+pub const PR_SPEC_NOT_AFFECTED: abi::I32 = 0;
+pub const PR_SPEC_PRCTL: abi::I32 = 0x1;
+pub const PR_SPEC_ENABLE: abi::I32 = 0x2;
+pub const PR_SPEC_DISABLE: abi::I32 = 0x4;
+pub const PR_SPEC_FORCE_DISABLE: abi::I32 = 0x8;
+pub const PR_SPEC_DISABLE_NOEXEC: abi::I32 = 0x10;
+
+pub const PR_PAC_RESET_KEYS: abi::I32 = 54;
+pub const PR_PAC_APIAKEY: abi::I32 = 0x1;
+pub const PR_PAC_APIBKEY: abi::I32 = 0x2;
+pub const PR_PAC_APDAKEY: abi::I32 = 0x4;
+pub const PR_PAC_APDBKEY: abi::I32 = 0x8;
+pub const PR_PAC_APGAKEY: abi::I32 = 0x10;
+
+pub const PR_SET_TAGGED_ADDR_CTRL: abi::I32 = 55;
+pub const PR_GET_TAGGED_ADDR_CTRL: abi::I32 = 56;
+pub const PR_TAGGED_ADDR_ENABLE: abi::I32 = 0x1;
+
+pub const PR_SET_IO_FLUSHER: abi::I32 = 57;
+pub const PR_GET_IO_FLUSHER: abi::I32 = 58;
+
+pub const PR_SET_SYSCALL_USER_DISPATCH: abi::I32 = 59;
+pub const PR_SYS_DISPATCH_OFF: abi::I32 = 0;
+pub const PR_SYS_DISPATCH_ON: abi::I32 = 1;
+
+pub const PR_PAC_SET_ENABLED_KEYS: abi::I32 = 60;
+pub const PR_PAC_GET_ENABLED_KEYS: abi::I32 = 61;
+
+pub const PR_SCHED_CORE: abi::I32 = libc::PR_SCHED_CORE as _;
+pub const PR_SCHED_CORE_GET: abi::I32 = libc::PR_SCHED_CORE_GET as _;
+pub const PR_SCHED_CORE_CREATE: abi::I32 = libc::PR_SCHED_CORE_CREATE as _;
+pub const PR_SCHED_CORE_SHARE_TO: abi::I32 = libc::PR_SCHED_CORE_SHARE_TO as _;
+pub const PR_SCHED_CORE_SHARE_FROM: abi::I32 = libc::PR_SCHED_CORE_SHARE_FROM as _;
+pub const PR_SCHED_CORE_MAX: abi::I32 = libc::PR_SCHED_CORE_MAX as _;
+pub const PR_SCHED_CORE_SCOPE_THREAD: abi::I32 = libc::PR_SCHED_CORE_SCOPE_THREAD as _;
+pub const PR_SCHED_CORE_SCOPE_THREAD_GROUP: abi::I32 = libc::PR_SCHED_CORE_SCOPE_THREAD_GROUP as _;
+pub const PR_SCHED_CORE_SCOPE_PROCESS_GROUP: abi::I32 = libc::PR_SCHED_CORE_SCOPE_PROCESS_GROUP as _;
+
+pub const PR_SME_SET_VL: abi::I32 = 63;
+pub const PR_SME_SET_VL_ONEXEC: abi::I32 = 0x40000;
+pub const PR_SME_GET_VL: abi::I32 = 64;
+pub const PR_SME_VL_LEN_MASK: abi::I32 = 0xffff;
+pub const PR_SME_VL_INHERIT: abi::I32 = 0x20000;
+
+pub const PR_SET_MDWE: abi::I32 = libc::PR_SET_MDWE as _;
+pub const PR_GET_MDWE: abi::I32 = libc::PR_GET_MDWE as _;
+pub const PR_MDWE_REFUSE_EXEC_GAIN: abi::I32 = libc::PR_MDWE_REFUSE_EXEC_GAIN as _;
+pub const PR_MDWE_NO_INHERIT: abi::I32 = libc::PR_MDWE_NO_INHERIT as _;
+
+pub const PR_SET_MEMORY_MERGE: abi::I32 = libc::PR_SET_MEMORY_MERGE as _;
+pub const PR_GET_MEMORY_MERGE: abi::I32 = libc::PR_GET_MEMORY_MERGE as _;
+
+pub const PR_SET_VMA: abi::I32 = libc::PR_SET_VMA as _;
+pub const PR_SET_VMA_ANON_NAME: abi::I32 = libc::PR_SET_VMA_ANON_NAME as _;
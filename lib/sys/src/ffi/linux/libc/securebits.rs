@@ -0,0 +1,32 @@
+use super::abi;
+
+pub const SECURE_NOROOT: abi::I32 = 0;
+pub const SECURE_NOROOT_LOCKED: abi::I32 = 1;
+pub const SECURE_NO_SETUID_FIXUP: abi::I32 = 2;
+pub const SECURE_NO_SETUID_FIXUP_LOCKED: abi::I32 = 3;
+pub const SECURE_KEEP_CAPS: abi::I32 = 4;
+pub const SECURE_KEEP_CAPS_LOCKED: abi::I32 = 5;
+pub const SECURE_NO_CAP_AMBIENT_RAISE: abi::I32 = 6;
+pub const SECURE_NO_CAP_AMBIENT_RAISE_LOCKED: abi::I32 = 7;
+pub const SECURE_EXEC_RESTRICT_FILE: abi::I32 = 8;
+pub const SECURE_EXEC_RESTRICT_FILE_LOCKED: abi::I32 = 9;
+pub const SECURE_EXEC_DENY_INTERACTIVE: abi::I32 = 10;
+pub const SECURE_EXEC_DENY_INTERACTIVE_LOCKED: abi::I32 = 11;
+
+pub const SECBIT_NOROOT: abi::I32 = libc::SECBIT_NOROOT as _;
+pub const SECBIT_NOROOT_LOCKED: abi::I32 = libc::SECBIT_NOROOT_LOCKED as _;
+pub const SECBIT_NO_SETUID_FIXUP: abi::I32 = libc::SECBIT_NO_SETUID_FIXUP as _;
+pub const SECBIT_NO_SETUID_FIXUP_LOCKED: abi::I32 = libc::SECBIT_NO_SETUID_FIXUP_LOCKED as _;
+pub const SECBIT_KEEP_CAPS: abi::I32 = libc::SECBIT_KEEP_CAPS as _;
+pub const SECBIT_KEEP_CAPS_LOCKED: abi::I32 = libc::SECBIT_KEEP_CAPS_LOCKED as _;
+pub const SECBIT_NO_CAP_AMBIENT_RAISE: abi::I32 = libc::SECBIT_NO_CAP_AMBIENT_RAISE as _;
+pub const SECBIT_NO_CAP_AMBIENT_RAISE_LOCKED: abi::I32 = libc::SECBIT_NO_CAP_AMBIENT_RAISE_LOCKED as _;
+pub const SECBIT_EXEC_RESTRICT_FILE: abi::I32 = libc::SECBIT_EXEC_RESTRICT_FILE as _;
+pub const SECBIT_EXEC_RESTRICT_FILE_LOCKED: abi::I32 = libc::SECBIT_EXEC_RESTRICT_FILE_LOCKED as _;
+pub const SECBIT_EXEC_DENY_INTERACTIVE: abi::I32 = libc::SECBIT_EXEC_DENY_INTERACTIVE as _;
+pub const SECBIT_EXEC_DENY_INTERACTIVE_LOCKED: abi::I32 = libc::SECBIT_EXEC_DENY_INTERACTIVE_LOCKED as _;
+
+pub const SECUREBITS_DEFAULT: abi::I32 = libc::SECUREBITS_DEFAULT as _;
+pub const SECURE_ALL_BITS: abi::I32 = libc::SECURE_ALL_BITS as _;
+pub const SECURE_ALL_LOCKS: abi::I32 = libc::SECURE_ALL_LOCKS as _;
+pub const SECURE_ALL_UNPRIVILEGED: abi::I32 = libc::SECURE_ALL_UNPRIVILEGED as _;
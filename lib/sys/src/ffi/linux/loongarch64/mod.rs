@@ -0,0 +1,25 @@
+// Platform Module for loongarch64
+//
+// This module is included multiple times by `../mod.rs`, using outer
+// comments for documentation. It is also the responsibility of the caller
+// to define the ABI to use. This module simply re-uses it via
+// `use super::abi`.
+
+use super::abi;
+
+pub mod user;
+
+// Platform properties and values that differ between platforms, as required
+// by `common`.
+mod arch {
+    use super::abi;
+
+    pub const BIG_ENDIAN: bool = false;
+
+    pub const EDEADLOCK: abi::U16 = abi::num(35);
+}
+
+#[path = "../common/mod.rs"]
+mod common;
+
+pub use common::*;
@@ -0,0 +1,36 @@
+//! # User Register Sets
+//!
+//! The register sets of a task as exposed via `ptrace(2)` and as stored in
+//! the notes of core-dumps. The layouts follow `<sys/user.h>` of the C
+//! library, which matches the layouts used by the kernel (`user_pt_regs` and
+//! `user_fp_state`).
+
+use super::abi;
+
+/// General purpose registers (`NT_PRSTATUS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRegsStruct {
+    pub regs: [abi::U64; 32],
+    pub orig_a0: abi::U64,
+    pub csr_era: abi::U64,
+    pub csr_badv: abi::U64,
+    pub reserved: [abi::U64; 10],
+}
+
+/// Floating point registers (`NT_PRFPREG`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserFpregsStruct {
+    pub fpr: [abi::U64; 32],
+    pub fcc: abi::U64,
+    pub fcsr: abi::U32,
+}
+
+pub type ElfGreg = abi::U64;
+pub const ELF_NGREG: usize = 45;
+pub type ElfGregset = [ElfGreg; ELF_NGREG];
+pub type ElfFpregset = UserFpregsStruct;
+
+pub type PrUid = abi::U32;
+pub type PrGid = abi::U32;
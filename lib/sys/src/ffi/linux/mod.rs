@@ -132,6 +132,41 @@ impl_platform!(
     osi::ffi::abi::aarch64_sysv,
 );
 
+impl_platform!(
+    arm,
+    cfg(all(target_arch = "arm", target_endian = "little")),
+    path = "../arm/mod.rs",
+    osi::ffi::abi::arm_eabi,
+);
+
+impl_platform!(
+    loongarch64,
+    cfg(target_arch = "loongarch64"),
+    path = "../loongarch64/mod.rs",
+    osi::ffi::abi::loongarch64_lp64d,
+);
+
+impl_platform!(
+    ppc64,
+    cfg(all(target_arch = "powerpc64", target_endian = "big")),
+    path = "../ppc64/mod.rs",
+    osi::ffi::abi::ppc64_sysv,
+);
+
+impl_platform!(
+    riscv64,
+    cfg(target_arch = "riscv64"),
+    path = "../riscv64/mod.rs",
+    osi::ffi::abi::riscv64_lp64d,
+);
+
+impl_platform!(
+    s390x,
+    cfg(target_arch = "s390x"),
+    path = "../s390x/mod.rs",
+    osi::ffi::abi::s390x_sysv,
+);
+
 impl_platform!(
     x86,
     cfg(target_arch = "x86"),
@@ -141,7 +176,14 @@ impl_platform!(
 
 impl_platform!(
     x86_64,
-    cfg(target_arch = "x86_64"),
+    cfg(all(target_arch = "x86_64", target_pointer_width = "64")),
     path = "../x86_64/mod.rs",
     osi::ffi::abi::x86_64_sysv,
 );
+
+impl_platform!(
+    x32,
+    cfg(all(target_arch = "x86_64", target_pointer_width = "32")),
+    path = "../x32/mod.rs",
+    osi::ffi::abi::x86_64_x32,
+);
@@ -0,0 +1,40 @@
+//! # User Register Sets
+//!
+//! The register sets of a task as exposed via `ptrace(2)` and as stored in
+//! the notes of core-dumps. The layouts follow `<asm/ptrace.h>` and
+//! `<asm/elf.h>` of the kernel (`pt_regs` and `elf_gregset_t`).
+
+use super::abi;
+
+/// General purpose registers (`NT_PRSTATUS`).
+///
+/// The core-dump notes store these in `ElfGregset`, which is larger than this
+/// structure. The trailing registers are unused.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRegsStruct {
+    pub gpr: [abi::U64; 32],
+    pub nip: abi::U64,
+    pub msr: abi::U64,
+    pub orig_gpr3: abi::U64,
+    pub ctr: abi::U64,
+    pub link: abi::U64,
+    pub xer: abi::U64,
+    pub ccr: abi::U64,
+    pub softe: abi::U64,
+    pub trap: abi::U64,
+    pub dar: abi::U64,
+    pub dsisr: abi::U64,
+    pub result: abi::U64,
+}
+
+pub type ElfGreg = abi::U64;
+pub const ELF_NGREG: usize = 48;
+pub type ElfGregset = [ElfGreg; ELF_NGREG];
+
+/// Number of floating point registers in `ElfFpregset`, including `fpscr`.
+pub const ELF_NFPREG: usize = 33;
+pub type ElfFpregset = [abi::F64; ELF_NFPREG];
+
+pub type PrUid = abi::U32;
+pub type PrGid = abi::U32;
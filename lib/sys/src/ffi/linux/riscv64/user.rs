@@ -0,0 +1,64 @@
+//! # User Register Sets
+//!
+//! The register sets of a task as exposed via `ptrace(2)` and as stored in
+//! the notes of core-dumps. The layouts follow `<sys/user.h>` of the C
+//! library, which matches the layouts used by the kernel (`user_regs_struct`
+//! and `__riscv_d_ext_state`).
+
+use super::abi;
+
+/// General purpose registers (`NT_PRSTATUS`).
+///
+/// The zero register is not stored. Its slot is used for `pc` instead.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRegsStruct {
+    pub pc: abi::U64,
+    pub ra: abi::U64,
+    pub sp: abi::U64,
+    pub gp: abi::U64,
+    pub tp: abi::U64,
+    pub t0: abi::U64,
+    pub t1: abi::U64,
+    pub t2: abi::U64,
+    pub s0: abi::U64,
+    pub s1: abi::U64,
+    pub a0: abi::U64,
+    pub a1: abi::U64,
+    pub a2: abi::U64,
+    pub a3: abi::U64,
+    pub a4: abi::U64,
+    pub a5: abi::U64,
+    pub a6: abi::U64,
+    pub a7: abi::U64,
+    pub s2: abi::U64,
+    pub s3: abi::U64,
+    pub s4: abi::U64,
+    pub s5: abi::U64,
+    pub s6: abi::U64,
+    pub s7: abi::U64,
+    pub s8: abi::U64,
+    pub s9: abi::U64,
+    pub s10: abi::U64,
+    pub s11: abi::U64,
+    pub t3: abi::U64,
+    pub t4: abi::U64,
+    pub t5: abi::U64,
+    pub t6: abi::U64,
+}
+
+/// Floating point registers of the D extension (`NT_PRFPREG`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserFpregsStruct {
+    pub f: [abi::U64; 32],
+    pub fcsr: abi::U32,
+}
+
+pub type ElfGreg = abi::U64;
+pub const ELF_NGREG: usize = 32;
+pub type ElfGregset = [ElfGreg; ELF_NGREG];
+pub type ElfFpregset = UserFpregsStruct;
+
+pub type PrUid = abi::U32;
+pub type PrGid = abi::U32;
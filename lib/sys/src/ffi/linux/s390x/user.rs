@@ -0,0 +1,43 @@
+//! # User Register Sets
+//!
+//! The register sets of a task as stored in the notes of core-dumps. The
+//! layouts follow `<asm/ptrace.h>` of the kernel (`s390_regs` and
+//! `s390_fp_regs`). Note that the `ptrace(2)` user area embeds these, but is
+//! considerably larger.
+
+use super::abi;
+
+/// Program status word.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Psw {
+    pub mask: abi::U64,
+    pub addr: abi::U64,
+}
+
+/// General purpose registers (`NT_PRSTATUS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRegsStruct {
+    pub psw: Psw,
+    pub gprs: [abi::U64; 16],
+    pub acrs: [abi::U32; 16],
+    pub orig_gpr2: abi::U64,
+}
+
+/// Floating point registers (`NT_PRFPREG`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserFpregsStruct {
+    pub fpc: abi::U32,
+    pub pad: abi::U32,
+    pub fprs: [abi::U64; 16],
+}
+
+pub type ElfGreg = abi::U64;
+pub const ELF_NGREG: usize = 27;
+pub type ElfGregset = [ElfGreg; ELF_NGREG];
+pub type ElfFpregset = UserFpregsStruct;
+
+pub type PrUid = abi::U32;
+pub type PrGid = abi::U32;
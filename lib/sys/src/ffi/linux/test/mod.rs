@@ -12,9 +12,147 @@ use crate::ffi::linux::*;
 #[test]
 fn platform_availability() {
     assert_eq!(core::mem::size_of::<aarch64::abi::U16>(), 2);
+    assert_eq!(core::mem::size_of::<arm::abi::U16>(), 2);
+    assert_eq!(core::mem::size_of::<loongarch64::abi::U16>(), 2);
+    assert_eq!(core::mem::size_of::<ppc64::abi::U16>(), 2);
+    assert_eq!(core::mem::size_of::<riscv64::abi::U16>(), 2);
+    assert_eq!(core::mem::size_of::<s390x::abi::U16>(), 2);
     assert_eq!(core::mem::size_of::<x86::abi::U16>(), 2);
     assert_eq!(core::mem::size_of::<x86_64::abi::U16>(), 2);
+    assert_eq!(core::mem::size_of::<x32::abi::U16>(), 2);
 
     #[cfg(feature = "libc")]
     assert_eq!(core::mem::size_of::<libc::abi::U16>(), 2);
 }
+
+// Verify the layout of the capability structures, which is identical on all
+// platforms.
+#[test]
+fn capability_layout() {
+    assert_eq!(core::mem::size_of::<aarch64::capability::CapUserHeader>(), 8);
+    assert_eq!(core::mem::size_of::<aarch64::capability::CapUserData>(), 12);
+    assert_eq!(core::mem::size_of::<x86::capability::CapUserHeader>(), 8);
+    assert_eq!(core::mem::size_of::<x86::capability::CapUserData>(), 12);
+    assert_eq!(core::mem::size_of::<x86_64::capability::CapUserHeader>(), 8);
+    assert_eq!(core::mem::size_of::<x86_64::capability::CapUserData>(), 12);
+}
+
+// Verify the layout of the register sets and core-dump notes of all
+// platforms. The sizes are taken from the kernel and C library headers.
+#[test]
+fn elfcore_layout() {
+    assert_eq!(core::mem::size_of::<aarch64::user::UserRegsStruct>(), 272);
+    assert_eq!(core::mem::size_of::<aarch64::user::UserFpsimdStruct>(), 528);
+    assert_eq!(core::mem::size_of::<aarch64::elfcore::ElfPrstatus>(), 392);
+    assert_eq!(core::mem::size_of::<aarch64::elfcore::ElfPrpsinfo>(), 136);
+
+    assert_eq!(core::mem::size_of::<arm::user::UserRegsStruct>(), 72);
+    assert_eq!(core::mem::size_of::<arm::user::UserFpregsStruct>(), 116);
+    assert_eq!(core::mem::size_of::<arm::elfcore::ElfPrstatus>(), 148);
+    assert_eq!(core::mem::align_of::<arm::elfcore::ElfPrstatus>(), 4);
+    assert_eq!(core::mem::size_of::<arm::elfcore::ElfPrpsinfo>(), 124);
+
+    assert_eq!(core::mem::size_of::<loongarch64::user::UserRegsStruct>(), 360);
+    assert_eq!(core::mem::size_of::<loongarch64::user::UserFpregsStruct>(), 272);
+    assert_eq!(core::mem::size_of::<loongarch64::elfcore::ElfPrstatus>(), 480);
+    assert_eq!(core::mem::size_of::<loongarch64::elfcore::ElfPrpsinfo>(), 136);
+
+    assert_eq!(core::mem::size_of::<ppc64::user::UserRegsStruct>(), 352);
+    assert_eq!(core::mem::size_of::<ppc64::user::ElfGregset>(), 384);
+    assert_eq!(core::mem::size_of::<ppc64::user::ElfFpregset>(), 264);
+    assert_eq!(core::mem::size_of::<ppc64::elfcore::ElfPrstatus>(), 504);
+    assert_eq!(core::mem::size_of::<ppc64::elfcore::ElfPrpsinfo>(), 136);
+
+    assert_eq!(core::mem::size_of::<riscv64::user::UserRegsStruct>(), 256);
+    assert_eq!(core::mem::size_of::<riscv64::user::UserFpregsStruct>(), 264);
+    assert_eq!(core::mem::size_of::<riscv64::elfcore::ElfPrstatus>(), 376);
+    assert_eq!(core::mem::size_of::<riscv64::elfcore::ElfPrpsinfo>(), 136);
+
+    assert_eq!(core::mem::size_of::<s390x::user::UserRegsStruct>(), 216);
+    assert_eq!(core::mem::size_of::<s390x::user::ElfGregset>(), 216);
+    assert_eq!(core::mem::size_of::<s390x::user::UserFpregsStruct>(), 136);
+    assert_eq!(core::mem::size_of::<s390x::elfcore::ElfPrstatus>(), 336);
+    assert_eq!(core::mem::size_of::<s390x::elfcore::ElfPrpsinfo>(), 136);
+
+    assert_eq!(core::mem::size_of::<x86::user::UserRegsStruct>(), 68);
+    assert_eq!(core::mem::size_of::<x86::user::UserFpregsStruct>(), 108);
+    assert_eq!(core::mem::size_of::<x86::user::UserFpxregsStruct>(), 512);
+    assert_eq!(core::mem::size_of::<x86::elfcore::ElfPrstatus>(), 144);
+    assert_eq!(core::mem::align_of::<x86::elfcore::ElfPrstatus>(), 4);
+    assert_eq!(core::mem::size_of::<x86::elfcore::ElfPrpsinfo>(), 124);
+
+    assert_eq!(core::mem::size_of::<x86_64::user::UserRegsStruct>(), 216);
+    assert_eq!(core::mem::size_of::<x86_64::user::UserFpregsStruct>(), 512);
+    assert_eq!(core::mem::size_of::<x86_64::elfcore::ElfPrstatus>(), 336);
+    assert_eq!(core::mem::align_of::<x86_64::elfcore::ElfPrstatus>(), 8);
+    assert_eq!(core::mem::size_of::<x86_64::elfcore::ElfPrpsinfo>(), 136);
+
+    assert_eq!(core::mem::size_of::<x32::user::UserRegsStruct>(), 216);
+    assert_eq!(core::mem::size_of::<x32::user::UserFpregsStruct>(), 512);
+    assert_eq!(core::mem::size_of::<x32::elfcore::ElfPrstatus>(), 296);
+    assert_eq!(core::mem::offset_of!(x32::elfcore::ElfPrstatus, pr_reg), 72);
+    assert_eq!(core::mem::size_of::<x32::elfcore::ElfPrpsinfo>(), 124);
+}
+
+// Verify the layout of the performance event structures, which is identical
+// on all platforms.
+#[test]
+fn perf_event_layout() {
+    assert_eq!(core::mem::size_of::<aarch64::perf_event::PerfEventAttr>(), 136);
+    assert_eq!(core::mem::size_of::<aarch64::perf_event::PerfEventMmapPage>(), 1088);
+    assert_eq!(core::mem::size_of::<x86::perf_event::PerfEventAttr>(), 136);
+    assert_eq!(core::mem::size_of::<x86::perf_event::PerfEventMmapPage>(), 1088);
+    assert_eq!(core::mem::size_of::<x86_64::perf_event::PerfEventAttr>(), 136);
+    assert_eq!(core::mem::size_of::<x86_64::perf_event::PerfEventMmapPage>(), 1088);
+}
+
+// Verify the layout of the eBPF attributes. Thanks to the explicit alignment,
+// they are identical on all platforms, including 32-bit x86.
+#[test]
+fn bpf_layout() {
+    assert_eq!(core::mem::offset_of!(x86::bpf::BpfAttrMapElem, key), 8);
+    assert_eq!(core::mem::offset_of!(x86::bpf::BpfAttrProgLoad, fd_array), 120);
+    assert_eq!(core::mem::offset_of!(x86::bpf::BpfAttrRawTracepoint, cookie), 16);
+
+    assert_eq!(core::mem::size_of::<aarch64::bpf::BpfAttrMapCreate>(), 80);
+    assert_eq!(core::mem::size_of::<aarch64::bpf::BpfAttrProgLoad>(), 152);
+    assert_eq!(core::mem::size_of::<aarch64::bpf::BpfAttr>(), 152);
+    assert_eq!(core::mem::size_of::<x86::bpf::BpfAttrMapElem>(), 32);
+    assert_eq!(core::mem::size_of::<x86::bpf::BpfAttrMapBatch>(), 56);
+    assert_eq!(core::mem::size_of::<x86::bpf::BpfAttrMapCreate>(), 80);
+    assert_eq!(core::mem::size_of::<x86::bpf::BpfAttrProgLoad>(), 152);
+    assert_eq!(core::mem::size_of::<x86::bpf::BpfAttrTestRun>(), 80);
+    assert_eq!(core::mem::size_of::<x86::bpf::BpfAttr>(), 152);
+    assert_eq!(core::mem::align_of::<x86::bpf::BpfAttr>(), 8);
+    assert_eq!(core::mem::size_of::<x86_64::bpf::BpfAttrObj>(), 24);
+    assert_eq!(core::mem::size_of::<x86_64::bpf::BpfAttrProgAttach>(), 32);
+    assert_eq!(core::mem::size_of::<x86_64::bpf::BpfAttrBtfLoad>(), 40);
+    assert_eq!(core::mem::size_of::<x86_64::bpf::BpfAttr>(), 152);
+}
+
+// Verify that the big-endian platforms store values in big-endian byte order,
+// regardless of the byte order of the host, and that the platform specific
+// deviations are applied.
+#[test]
+fn big_endian_values() {
+    let v = osi::ffi::to_raw::<_, u16>(s390x::errno::EHWPOISON);
+    assert_eq!(v.to_ne_bytes(), [0, 133]);
+    let v = osi::ffi::to_raw::<_, u32>(ppc64::elfcore::NT_SIGINFO);
+    assert_eq!(&v.to_ne_bytes(), b"SIGI");
+    let v = osi::ffi::to_raw::<_, u32>(x86_64::elfcore::NT_SIGINFO);
+    assert_eq!(&v.to_ne_bytes(), b"IGIS");
+
+    assert_eq!(ppc64::errno::EDEADLOCK.to_native(), 58);
+    assert_eq!(s390x::errno::EDEADLOCK.to_native(), 35);
+    assert_eq!(x86_64::errno::EDEADLOCK.to_native(), 35);
+
+    // C bit-fields are allocated from the most significant bit on big-endian
+    // platforms, hence `disabled` is the first bit in memory in both cases.
+    let v = ppc64::perf_event::PERF_ATTR_FLAG_DISABLED;
+    assert_eq!(v.to_native(), 1u64 << 63);
+    assert_eq!(osi::ffi::to_raw::<_, u64>(v).to_ne_bytes(), [0x80, 0, 0, 0, 0, 0, 0, 0]);
+    let v = x86_64::perf_event::PERF_ATTR_FLAG_DISABLED;
+    assert_eq!(v.to_native(), 1u64);
+    assert_eq!(osi::ffi::to_raw::<_, u64>(v).to_ne_bytes(), [1, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(s390x::perf_event::PERF_ATTR_FLAG_PRECISE_IP.to_native(), 3u64 << 47);
+}
@@ -10,6 +10,11 @@
         feature = "libc",
         any(
             target_arch = "aarch64",
+            all(target_arch = "arm", target_endian = "little"),
+            target_arch = "loongarch64",
+            all(target_arch = "powerpc64", target_endian = "big"),
+            target_arch = "riscv64",
+            target_arch = "s390x",
             target_arch = "x86",
             target_arch = "x86_64",
         ),
@@ -226,3 +231,590 @@ fn target_errno() {
         assert!(eq3_def_const(&target::errno::EDEADLOCK, &native::errno::EDEADLOCK, &libc::errno::EDEADLOCK));
     }
 }
+
+// Compare capability definitions of target, native and libc.
+#[test]
+fn target_capability() {
+    assert!(eq3_def_type::<target::capability::CapUserHeader, native::capability::CapUserHeader, libc::capability::CapUserHeader>());
+    assert!(eq3_def_type::<target::capability::CapUserData, native::capability::CapUserData, libc::capability::CapUserData>());
+
+    unsafe {
+        assert!(eq3_def_const(&target::capability::CAP_CHOWN, &native::capability::CAP_CHOWN, &libc::capability::CAP_CHOWN));
+        assert!(eq3_def_const(&target::capability::CAP_DAC_OVERRIDE, &native::capability::CAP_DAC_OVERRIDE, &libc::capability::CAP_DAC_OVERRIDE));
+        assert!(eq3_def_const(&target::capability::CAP_DAC_READ_SEARCH, &native::capability::CAP_DAC_READ_SEARCH, &libc::capability::CAP_DAC_READ_SEARCH));
+        assert!(eq3_def_const(&target::capability::CAP_FOWNER, &native::capability::CAP_FOWNER, &libc::capability::CAP_FOWNER));
+        assert!(eq3_def_const(&target::capability::CAP_FSETID, &native::capability::CAP_FSETID, &libc::capability::CAP_FSETID));
+        assert!(eq3_def_const(&target::capability::CAP_KILL, &native::capability::CAP_KILL, &libc::capability::CAP_KILL));
+        assert!(eq3_def_const(&target::capability::CAP_SETGID, &native::capability::CAP_SETGID, &libc::capability::CAP_SETGID));
+        assert!(eq3_def_const(&target::capability::CAP_SETUID, &native::capability::CAP_SETUID, &libc::capability::CAP_SETUID));
+        assert!(eq3_def_const(&target::capability::CAP_SETPCAP, &native::capability::CAP_SETPCAP, &libc::capability::CAP_SETPCAP));
+        assert!(eq3_def_const(&target::capability::CAP_LINUX_IMMUTABLE, &native::capability::CAP_LINUX_IMMUTABLE, &libc::capability::CAP_LINUX_IMMUTABLE));
+        assert!(eq3_def_const(&target::capability::CAP_NET_BIND_SERVICE, &native::capability::CAP_NET_BIND_SERVICE, &libc::capability::CAP_NET_BIND_SERVICE));
+        assert!(eq3_def_const(&target::capability::CAP_NET_BROADCAST, &native::capability::CAP_NET_BROADCAST, &libc::capability::CAP_NET_BROADCAST));
+        assert!(eq3_def_const(&target::capability::CAP_NET_ADMIN, &native::capability::CAP_NET_ADMIN, &libc::capability::CAP_NET_ADMIN));
+        assert!(eq3_def_const(&target::capability::CAP_NET_RAW, &native::capability::CAP_NET_RAW, &libc::capability::CAP_NET_RAW));
+        assert!(eq3_def_const(&target::capability::CAP_IPC_LOCK, &native::capability::CAP_IPC_LOCK, &libc::capability::CAP_IPC_LOCK));
+        assert!(eq3_def_const(&target::capability::CAP_IPC_OWNER, &native::capability::CAP_IPC_OWNER, &libc::capability::CAP_IPC_OWNER));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_MODULE, &native::capability::CAP_SYS_MODULE, &libc::capability::CAP_SYS_MODULE));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_RAWIO, &native::capability::CAP_SYS_RAWIO, &libc::capability::CAP_SYS_RAWIO));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_CHROOT, &native::capability::CAP_SYS_CHROOT, &libc::capability::CAP_SYS_CHROOT));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_PTRACE, &native::capability::CAP_SYS_PTRACE, &libc::capability::CAP_SYS_PTRACE));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_PACCT, &native::capability::CAP_SYS_PACCT, &libc::capability::CAP_SYS_PACCT));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_ADMIN, &native::capability::CAP_SYS_ADMIN, &libc::capability::CAP_SYS_ADMIN));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_BOOT, &native::capability::CAP_SYS_BOOT, &libc::capability::CAP_SYS_BOOT));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_NICE, &native::capability::CAP_SYS_NICE, &libc::capability::CAP_SYS_NICE));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_RESOURCE, &native::capability::CAP_SYS_RESOURCE, &libc::capability::CAP_SYS_RESOURCE));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_TIME, &native::capability::CAP_SYS_TIME, &libc::capability::CAP_SYS_TIME));
+        assert!(eq3_def_const(&target::capability::CAP_SYS_TTY_CONFIG, &native::capability::CAP_SYS_TTY_CONFIG, &libc::capability::CAP_SYS_TTY_CONFIG));
+        assert!(eq3_def_const(&target::capability::CAP_MKNOD, &native::capability::CAP_MKNOD, &libc::capability::CAP_MKNOD));
+        assert!(eq3_def_const(&target::capability::CAP_LEASE, &native::capability::CAP_LEASE, &libc::capability::CAP_LEASE));
+        assert!(eq3_def_const(&target::capability::CAP_AUDIT_WRITE, &native::capability::CAP_AUDIT_WRITE, &libc::capability::CAP_AUDIT_WRITE));
+        assert!(eq3_def_const(&target::capability::CAP_AUDIT_CONTROL, &native::capability::CAP_AUDIT_CONTROL, &libc::capability::CAP_AUDIT_CONTROL));
+        assert!(eq3_def_const(&target::capability::CAP_SETFCAP, &native::capability::CAP_SETFCAP, &libc::capability::CAP_SETFCAP));
+        assert!(eq3_def_const(&target::capability::CAP_MAC_OVERRIDE, &native::capability::CAP_MAC_OVERRIDE, &libc::capability::CAP_MAC_OVERRIDE));
+        assert!(eq3_def_const(&target::capability::CAP_MAC_ADMIN, &native::capability::CAP_MAC_ADMIN, &libc::capability::CAP_MAC_ADMIN));
+        assert!(eq3_def_const(&target::capability::CAP_SYSLOG, &native::capability::CAP_SYSLOG, &libc::capability::CAP_SYSLOG));
+        assert!(eq3_def_const(&target::capability::CAP_WAKE_ALARM, &native::capability::CAP_WAKE_ALARM, &libc::capability::CAP_WAKE_ALARM));
+        assert!(eq3_def_const(&target::capability::CAP_BLOCK_SUSPEND, &native::capability::CAP_BLOCK_SUSPEND, &libc::capability::CAP_BLOCK_SUSPEND));
+        assert!(eq3_def_const(&target::capability::CAP_AUDIT_READ, &native::capability::CAP_AUDIT_READ, &libc::capability::CAP_AUDIT_READ));
+        assert!(eq3_def_const(&target::capability::CAP_PERFMON, &native::capability::CAP_PERFMON, &libc::capability::CAP_PERFMON));
+        assert!(eq3_def_const(&target::capability::CAP_BPF, &native::capability::CAP_BPF, &libc::capability::CAP_BPF));
+        assert!(eq3_def_const(&target::capability::CAP_CHECKPOINT_RESTORE, &native::capability::CAP_CHECKPOINT_RESTORE, &libc::capability::CAP_CHECKPOINT_RESTORE));
+        assert!(eq3_def_const(&target::capability::CAP_LAST_CAP, &native::capability::CAP_LAST_CAP, &libc::capability::CAP_LAST_CAP));
+        assert!(eq3_def_const(&target::capability::_LINUX_CAPABILITY_VERSION_1, &native::capability::_LINUX_CAPABILITY_VERSION_1, &libc::capability::_LINUX_CAPABILITY_VERSION_1));
+        assert!(eq3_def_const(&target::capability::_LINUX_CAPABILITY_U32S_1, &native::capability::_LINUX_CAPABILITY_U32S_1, &libc::capability::_LINUX_CAPABILITY_U32S_1));
+        assert!(eq3_def_const(&target::capability::_LINUX_CAPABILITY_VERSION_2, &native::capability::_LINUX_CAPABILITY_VERSION_2, &libc::capability::_LINUX_CAPABILITY_VERSION_2));
+        assert!(eq3_def_const(&target::capability::_LINUX_CAPABILITY_U32S_2, &native::capability::_LINUX_CAPABILITY_U32S_2, &libc::capability::_LINUX_CAPABILITY_U32S_2));
+        assert!(eq3_def_const(&target::capability::_LINUX_CAPABILITY_VERSION_3, &native::capability::_LINUX_CAPABILITY_VERSION_3, &libc::capability::_LINUX_CAPABILITY_VERSION_3));
+        assert!(eq3_def_const(&target::capability::_LINUX_CAPABILITY_U32S_3, &native::capability::_LINUX_CAPABILITY_U32S_3, &libc::capability::_LINUX_CAPABILITY_U32S_3));
+    }
+}
+
+// Compare prctl options of target, native and libc.
+#[test]
+fn target_prctl() {
+    unsafe {
+        assert!(eq3_def_const(&target::prctl::PR_SET_PDEATHSIG, &native::prctl::PR_SET_PDEATHSIG, &libc::prctl::PR_SET_PDEATHSIG));
+        assert!(eq3_def_const(&target::prctl::PR_GET_PDEATHSIG, &native::prctl::PR_GET_PDEATHSIG, &libc::prctl::PR_GET_PDEATHSIG));
+        assert!(eq3_def_const(&target::prctl::PR_GET_DUMPABLE, &native::prctl::PR_GET_DUMPABLE, &libc::prctl::PR_GET_DUMPABLE));
+        assert!(eq3_def_const(&target::prctl::PR_SET_DUMPABLE, &native::prctl::PR_SET_DUMPABLE, &libc::prctl::PR_SET_DUMPABLE));
+        assert!(eq3_def_const(&target::prctl::PR_GET_UNALIGN, &native::prctl::PR_GET_UNALIGN, &libc::prctl::PR_GET_UNALIGN));
+        assert!(eq3_def_const(&target::prctl::PR_SET_UNALIGN, &native::prctl::PR_SET_UNALIGN, &libc::prctl::PR_SET_UNALIGN));
+        assert!(eq3_def_const(&target::prctl::PR_UNALIGN_NOPRINT, &native::prctl::PR_UNALIGN_NOPRINT, &libc::prctl::PR_UNALIGN_NOPRINT));
+        assert!(eq3_def_const(&target::prctl::PR_UNALIGN_SIGBUS, &native::prctl::PR_UNALIGN_SIGBUS, &libc::prctl::PR_UNALIGN_SIGBUS));
+        assert!(eq3_def_const(&target::prctl::PR_GET_KEEPCAPS, &native::prctl::PR_GET_KEEPCAPS, &libc::prctl::PR_GET_KEEPCAPS));
+        assert!(eq3_def_const(&target::prctl::PR_SET_KEEPCAPS, &native::prctl::PR_SET_KEEPCAPS, &libc::prctl::PR_SET_KEEPCAPS));
+        assert!(eq3_def_const(&target::prctl::PR_GET_FPEMU, &native::prctl::PR_GET_FPEMU, &libc::prctl::PR_GET_FPEMU));
+        assert!(eq3_def_const(&target::prctl::PR_SET_FPEMU, &native::prctl::PR_SET_FPEMU, &libc::prctl::PR_SET_FPEMU));
+        assert!(eq3_def_const(&target::prctl::PR_FPEMU_NOPRINT, &native::prctl::PR_FPEMU_NOPRINT, &libc::prctl::PR_FPEMU_NOPRINT));
+        assert!(eq3_def_const(&target::prctl::PR_FPEMU_SIGFPE, &native::prctl::PR_FPEMU_SIGFPE, &libc::prctl::PR_FPEMU_SIGFPE));
+        assert!(eq3_def_const(&target::prctl::PR_GET_FPEXC, &native::prctl::PR_GET_FPEXC, &libc::prctl::PR_GET_FPEXC));
+        assert!(eq3_def_const(&target::prctl::PR_SET_FPEXC, &native::prctl::PR_SET_FPEXC, &libc::prctl::PR_SET_FPEXC));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_SW_ENABLE, &native::prctl::PR_FP_EXC_SW_ENABLE, &libc::prctl::PR_FP_EXC_SW_ENABLE));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_DIV, &native::prctl::PR_FP_EXC_DIV, &libc::prctl::PR_FP_EXC_DIV));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_OVF, &native::prctl::PR_FP_EXC_OVF, &libc::prctl::PR_FP_EXC_OVF));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_UND, &native::prctl::PR_FP_EXC_UND, &libc::prctl::PR_FP_EXC_UND));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_RES, &native::prctl::PR_FP_EXC_RES, &libc::prctl::PR_FP_EXC_RES));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_INV, &native::prctl::PR_FP_EXC_INV, &libc::prctl::PR_FP_EXC_INV));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_DISABLED, &native::prctl::PR_FP_EXC_DISABLED, &libc::prctl::PR_FP_EXC_DISABLED));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_NONRECOV, &native::prctl::PR_FP_EXC_NONRECOV, &libc::prctl::PR_FP_EXC_NONRECOV));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_ASYNC, &native::prctl::PR_FP_EXC_ASYNC, &libc::prctl::PR_FP_EXC_ASYNC));
+        assert!(eq3_def_const(&target::prctl::PR_FP_EXC_PRECISE, &native::prctl::PR_FP_EXC_PRECISE, &libc::prctl::PR_FP_EXC_PRECISE));
+        assert!(eq3_def_const(&target::prctl::PR_GET_TIMING, &native::prctl::PR_GET_TIMING, &libc::prctl::PR_GET_TIMING));
+        assert!(eq3_def_const(&target::prctl::PR_SET_TIMING, &native::prctl::PR_SET_TIMING, &libc::prctl::PR_SET_TIMING));
+        assert!(eq3_def_const(&target::prctl::PR_TIMING_STATISTICAL, &native::prctl::PR_TIMING_STATISTICAL, &libc::prctl::PR_TIMING_STATISTICAL));
+        assert!(eq3_def_const(&target::prctl::PR_TIMING_TIMESTAMP, &native::prctl::PR_TIMING_TIMESTAMP, &libc::prctl::PR_TIMING_TIMESTAMP));
+        assert!(eq3_def_const(&target::prctl::PR_SET_NAME, &native::prctl::PR_SET_NAME, &libc::prctl::PR_SET_NAME));
+        assert!(eq3_def_const(&target::prctl::PR_GET_NAME, &native::prctl::PR_GET_NAME, &libc::prctl::PR_GET_NAME));
+        assert!(eq3_def_const(&target::prctl::PR_GET_ENDIAN, &native::prctl::PR_GET_ENDIAN, &libc::prctl::PR_GET_ENDIAN));
+        assert!(eq3_def_const(&target::prctl::PR_SET_ENDIAN, &native::prctl::PR_SET_ENDIAN, &libc::prctl::PR_SET_ENDIAN));
+        assert!(eq3_def_const(&target::prctl::PR_ENDIAN_BIG, &native::prctl::PR_ENDIAN_BIG, &libc::prctl::PR_ENDIAN_BIG));
+        assert!(eq3_def_const(&target::prctl::PR_ENDIAN_LITTLE, &native::prctl::PR_ENDIAN_LITTLE, &libc::prctl::PR_ENDIAN_LITTLE));
+        assert!(eq3_def_const(&target::prctl::PR_ENDIAN_PPC_LITTLE, &native::prctl::PR_ENDIAN_PPC_LITTLE, &libc::prctl::PR_ENDIAN_PPC_LITTLE));
+        assert!(eq3_def_const(&target::prctl::PR_GET_SECCOMP, &native::prctl::PR_GET_SECCOMP, &libc::prctl::PR_GET_SECCOMP));
+        assert!(eq3_def_const(&target::prctl::PR_SET_SECCOMP, &native::prctl::PR_SET_SECCOMP, &libc::prctl::PR_SET_SECCOMP));
+        assert!(eq3_def_const(&target::prctl::PR_CAPBSET_READ, &native::prctl::PR_CAPBSET_READ, &libc::prctl::PR_CAPBSET_READ));
+        assert!(eq3_def_const(&target::prctl::PR_CAPBSET_DROP, &native::prctl::PR_CAPBSET_DROP, &libc::prctl::PR_CAPBSET_DROP));
+        assert!(eq3_def_const(&target::prctl::PR_GET_TSC, &native::prctl::PR_GET_TSC, &libc::prctl::PR_GET_TSC));
+        assert!(eq3_def_const(&target::prctl::PR_SET_TSC, &native::prctl::PR_SET_TSC, &libc::prctl::PR_SET_TSC));
+        assert!(eq3_def_const(&target::prctl::PR_TSC_ENABLE, &native::prctl::PR_TSC_ENABLE, &libc::prctl::PR_TSC_ENABLE));
+        assert!(eq3_def_const(&target::prctl::PR_TSC_SIGSEGV, &native::prctl::PR_TSC_SIGSEGV, &libc::prctl::PR_TSC_SIGSEGV));
+        assert!(eq3_def_const(&target::prctl::PR_GET_SECUREBITS, &native::prctl::PR_GET_SECUREBITS, &libc::prctl::PR_GET_SECUREBITS));
+        assert!(eq3_def_const(&target::prctl::PR_SET_SECUREBITS, &native::prctl::PR_SET_SECUREBITS, &libc::prctl::PR_SET_SECUREBITS));
+        assert!(eq3_def_const(&target::prctl::PR_SET_TIMERSLACK, &native::prctl::PR_SET_TIMERSLACK, &libc::prctl::PR_SET_TIMERSLACK));
+        assert!(eq3_def_const(&target::prctl::PR_GET_TIMERSLACK, &native::prctl::PR_GET_TIMERSLACK, &libc::prctl::PR_GET_TIMERSLACK));
+        assert!(eq3_def_const(&target::prctl::PR_TASK_PERF_EVENTS_DISABLE, &native::prctl::PR_TASK_PERF_EVENTS_DISABLE, &libc::prctl::PR_TASK_PERF_EVENTS_DISABLE));
+        assert!(eq3_def_const(&target::prctl::PR_TASK_PERF_EVENTS_ENABLE, &native::prctl::PR_TASK_PERF_EVENTS_ENABLE, &libc::prctl::PR_TASK_PERF_EVENTS_ENABLE));
+        assert!(eq3_def_const(&target::prctl::PR_MCE_KILL, &native::prctl::PR_MCE_KILL, &libc::prctl::PR_MCE_KILL));
+        assert!(eq3_def_const(&target::prctl::PR_MCE_KILL_CLEAR, &native::prctl::PR_MCE_KILL_CLEAR, &libc::prctl::PR_MCE_KILL_CLEAR));
+        assert!(eq3_def_const(&target::prctl::PR_MCE_KILL_SET, &native::prctl::PR_MCE_KILL_SET, &libc::prctl::PR_MCE_KILL_SET));
+        assert!(eq3_def_const(&target::prctl::PR_MCE_KILL_LATE, &native::prctl::PR_MCE_KILL_LATE, &libc::prctl::PR_MCE_KILL_LATE));
+        assert!(eq3_def_const(&target::prctl::PR_MCE_KILL_EARLY, &native::prctl::PR_MCE_KILL_EARLY, &libc::prctl::PR_MCE_KILL_EARLY));
+        assert!(eq3_def_const(&target::prctl::PR_MCE_KILL_DEFAULT, &native::prctl::PR_MCE_KILL_DEFAULT, &libc::prctl::PR_MCE_KILL_DEFAULT));
+        assert!(eq3_def_const(&target::prctl::PR_MCE_KILL_GET, &native::prctl::PR_MCE_KILL_GET, &libc::prctl::PR_MCE_KILL_GET));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM, &native::prctl::PR_SET_MM, &libc::prctl::PR_SET_MM));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_START_CODE, &native::prctl::PR_SET_MM_START_CODE, &libc::prctl::PR_SET_MM_START_CODE));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_END_CODE, &native::prctl::PR_SET_MM_END_CODE, &libc::prctl::PR_SET_MM_END_CODE));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_START_DATA, &native::prctl::PR_SET_MM_START_DATA, &libc::prctl::PR_SET_MM_START_DATA));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_END_DATA, &native::prctl::PR_SET_MM_END_DATA, &libc::prctl::PR_SET_MM_END_DATA));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_START_STACK, &native::prctl::PR_SET_MM_START_STACK, &libc::prctl::PR_SET_MM_START_STACK));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_START_BRK, &native::prctl::PR_SET_MM_START_BRK, &libc::prctl::PR_SET_MM_START_BRK));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_BRK, &native::prctl::PR_SET_MM_BRK, &libc::prctl::PR_SET_MM_BRK));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_ARG_START, &native::prctl::PR_SET_MM_ARG_START, &libc::prctl::PR_SET_MM_ARG_START));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_ARG_END, &native::prctl::PR_SET_MM_ARG_END, &libc::prctl::PR_SET_MM_ARG_END));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_ENV_START, &native::prctl::PR_SET_MM_ENV_START, &libc::prctl::PR_SET_MM_ENV_START));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_ENV_END, &native::prctl::PR_SET_MM_ENV_END, &libc::prctl::PR_SET_MM_ENV_END));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_AUXV, &native::prctl::PR_SET_MM_AUXV, &libc::prctl::PR_SET_MM_AUXV));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_EXE_FILE, &native::prctl::PR_SET_MM_EXE_FILE, &libc::prctl::PR_SET_MM_EXE_FILE));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_MAP, &native::prctl::PR_SET_MM_MAP, &libc::prctl::PR_SET_MM_MAP));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MM_MAP_SIZE, &native::prctl::PR_SET_MM_MAP_SIZE, &libc::prctl::PR_SET_MM_MAP_SIZE));
+        assert!(eq3_def_const(&target::prctl::PR_SET_PTRACER, &native::prctl::PR_SET_PTRACER, &libc::prctl::PR_SET_PTRACER));
+        assert!(eq3_def_const(&target::prctl::PR_SET_PTRACER_ANY, &native::prctl::PR_SET_PTRACER_ANY, &libc::prctl::PR_SET_PTRACER_ANY));
+        assert!(eq3_def_const(&target::prctl::PR_SET_CHILD_SUBREAPER, &native::prctl::PR_SET_CHILD_SUBREAPER, &libc::prctl::PR_SET_CHILD_SUBREAPER));
+        assert!(eq3_def_const(&target::prctl::PR_GET_CHILD_SUBREAPER, &native::prctl::PR_GET_CHILD_SUBREAPER, &libc::prctl::PR_GET_CHILD_SUBREAPER));
+        assert!(eq3_def_const(&target::prctl::PR_SET_NO_NEW_PRIVS, &native::prctl::PR_SET_NO_NEW_PRIVS, &libc::prctl::PR_SET_NO_NEW_PRIVS));
+        assert!(eq3_def_const(&target::prctl::PR_GET_NO_NEW_PRIVS, &native::prctl::PR_GET_NO_NEW_PRIVS, &libc::prctl::PR_GET_NO_NEW_PRIVS));
+        assert!(eq3_def_const(&target::prctl::PR_GET_TID_ADDRESS, &native::prctl::PR_GET_TID_ADDRESS, &libc::prctl::PR_GET_TID_ADDRESS));
+        assert!(eq3_def_const(&target::prctl::PR_SET_THP_DISABLE, &native::prctl::PR_SET_THP_DISABLE, &libc::prctl::PR_SET_THP_DISABLE));
+        assert!(eq3_def_const(&target::prctl::PR_GET_THP_DISABLE, &native::prctl::PR_GET_THP_DISABLE, &libc::prctl::PR_GET_THP_DISABLE));
+        assert!(eq3_def_const(&target::prctl::PR_MPX_ENABLE_MANAGEMENT, &native::prctl::PR_MPX_ENABLE_MANAGEMENT, &libc::prctl::PR_MPX_ENABLE_MANAGEMENT));
+        assert!(eq3_def_const(&target::prctl::PR_MPX_DISABLE_MANAGEMENT, &native::prctl::PR_MPX_DISABLE_MANAGEMENT, &libc::prctl::PR_MPX_DISABLE_MANAGEMENT));
+        assert!(eq3_def_const(&target::prctl::PR_SET_FP_MODE, &native::prctl::PR_SET_FP_MODE, &libc::prctl::PR_SET_FP_MODE));
+        assert!(eq3_def_const(&target::prctl::PR_GET_FP_MODE, &native::prctl::PR_GET_FP_MODE, &libc::prctl::PR_GET_FP_MODE));
+        assert!(eq3_def_const(&target::prctl::PR_FP_MODE_FR, &native::prctl::PR_FP_MODE_FR, &libc::prctl::PR_FP_MODE_FR));
+        assert!(eq3_def_const(&target::prctl::PR_FP_MODE_FRE, &native::prctl::PR_FP_MODE_FRE, &libc::prctl::PR_FP_MODE_FRE));
+        assert!(eq3_def_const(&target::prctl::PR_CAP_AMBIENT, &native::prctl::PR_CAP_AMBIENT, &libc::prctl::PR_CAP_AMBIENT));
+        assert!(eq3_def_const(&target::prctl::PR_CAP_AMBIENT_IS_SET, &native::prctl::PR_CAP_AMBIENT_IS_SET, &libc::prctl::PR_CAP_AMBIENT_IS_SET));
+        assert!(eq3_def_const(&target::prctl::PR_CAP_AMBIENT_RAISE, &native::prctl::PR_CAP_AMBIENT_RAISE, &libc::prctl::PR_CAP_AMBIENT_RAISE));
+        assert!(eq3_def_const(&target::prctl::PR_CAP_AMBIENT_LOWER, &native::prctl::PR_CAP_AMBIENT_LOWER, &libc::prctl::PR_CAP_AMBIENT_LOWER));
+        assert!(eq3_def_const(&target::prctl::PR_CAP_AMBIENT_CLEAR_ALL, &native::prctl::PR_CAP_AMBIENT_CLEAR_ALL, &libc::prctl::PR_CAP_AMBIENT_CLEAR_ALL));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE, &native::prctl::PR_SCHED_CORE, &libc::prctl::PR_SCHED_CORE));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE_GET, &native::prctl::PR_SCHED_CORE_GET, &libc::prctl::PR_SCHED_CORE_GET));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE_CREATE, &native::prctl::PR_SCHED_CORE_CREATE, &libc::prctl::PR_SCHED_CORE_CREATE));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE_SHARE_TO, &native::prctl::PR_SCHED_CORE_SHARE_TO, &libc::prctl::PR_SCHED_CORE_SHARE_TO));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE_SHARE_FROM, &native::prctl::PR_SCHED_CORE_SHARE_FROM, &libc::prctl::PR_SCHED_CORE_SHARE_FROM));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE_MAX, &native::prctl::PR_SCHED_CORE_MAX, &libc::prctl::PR_SCHED_CORE_MAX));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE_SCOPE_THREAD, &native::prctl::PR_SCHED_CORE_SCOPE_THREAD, &libc::prctl::PR_SCHED_CORE_SCOPE_THREAD));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE_SCOPE_THREAD_GROUP, &native::prctl::PR_SCHED_CORE_SCOPE_THREAD_GROUP, &libc::prctl::PR_SCHED_CORE_SCOPE_THREAD_GROUP));
+        assert!(eq3_def_const(&target::prctl::PR_SCHED_CORE_SCOPE_PROCESS_GROUP, &native::prctl::PR_SCHED_CORE_SCOPE_PROCESS_GROUP, &libc::prctl::PR_SCHED_CORE_SCOPE_PROCESS_GROUP));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MDWE, &native::prctl::PR_SET_MDWE, &libc::prctl::PR_SET_MDWE));
+        assert!(eq3_def_const(&target::prctl::PR_GET_MDWE, &native::prctl::PR_GET_MDWE, &libc::prctl::PR_GET_MDWE));
+        assert!(eq3_def_const(&target::prctl::PR_MDWE_REFUSE_EXEC_GAIN, &native::prctl::PR_MDWE_REFUSE_EXEC_GAIN, &libc::prctl::PR_MDWE_REFUSE_EXEC_GAIN));
+        assert!(eq3_def_const(&target::prctl::PR_MDWE_NO_INHERIT, &native::prctl::PR_MDWE_NO_INHERIT, &libc::prctl::PR_MDWE_NO_INHERIT));
+        assert!(eq3_def_const(&target::prctl::PR_SET_MEMORY_MERGE, &native::prctl::PR_SET_MEMORY_MERGE, &libc::prctl::PR_SET_MEMORY_MERGE));
+        assert!(eq3_def_const(&target::prctl::PR_GET_MEMORY_MERGE, &native::prctl::PR_GET_MEMORY_MERGE, &libc::prctl::PR_GET_MEMORY_MERGE));
+        assert!(eq3_def_const(&target::prctl::PR_SET_VMA, &native::prctl::PR_SET_VMA, &libc::prctl::PR_SET_VMA));
+        assert!(eq3_def_const(&target::prctl::PR_SET_VMA_ANON_NAME, &native::prctl::PR_SET_VMA_ANON_NAME, &libc::prctl::PR_SET_VMA_ANON_NAME));
+    }
+}
+
+// Compare secure bits of target, native and libc.
+#[test]
+fn target_securebits() {
+    unsafe {
+        assert!(eq3_def_const(&target::securebits::SECURE_NOROOT, &native::securebits::SECURE_NOROOT, &libc::securebits::SECURE_NOROOT));
+        assert!(eq3_def_const(&target::securebits::SECURE_NOROOT_LOCKED, &native::securebits::SECURE_NOROOT_LOCKED, &libc::securebits::SECURE_NOROOT_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECURE_NO_SETUID_FIXUP, &native::securebits::SECURE_NO_SETUID_FIXUP, &libc::securebits::SECURE_NO_SETUID_FIXUP));
+        assert!(eq3_def_const(&target::securebits::SECURE_NO_SETUID_FIXUP_LOCKED, &native::securebits::SECURE_NO_SETUID_FIXUP_LOCKED, &libc::securebits::SECURE_NO_SETUID_FIXUP_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECURE_KEEP_CAPS, &native::securebits::SECURE_KEEP_CAPS, &libc::securebits::SECURE_KEEP_CAPS));
+        assert!(eq3_def_const(&target::securebits::SECURE_KEEP_CAPS_LOCKED, &native::securebits::SECURE_KEEP_CAPS_LOCKED, &libc::securebits::SECURE_KEEP_CAPS_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECURE_NO_CAP_AMBIENT_RAISE, &native::securebits::SECURE_NO_CAP_AMBIENT_RAISE, &libc::securebits::SECURE_NO_CAP_AMBIENT_RAISE));
+        assert!(eq3_def_const(&target::securebits::SECURE_NO_CAP_AMBIENT_RAISE_LOCKED, &native::securebits::SECURE_NO_CAP_AMBIENT_RAISE_LOCKED, &libc::securebits::SECURE_NO_CAP_AMBIENT_RAISE_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECURE_EXEC_RESTRICT_FILE, &native::securebits::SECURE_EXEC_RESTRICT_FILE, &libc::securebits::SECURE_EXEC_RESTRICT_FILE));
+        assert!(eq3_def_const(&target::securebits::SECURE_EXEC_RESTRICT_FILE_LOCKED, &native::securebits::SECURE_EXEC_RESTRICT_FILE_LOCKED, &libc::securebits::SECURE_EXEC_RESTRICT_FILE_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECURE_EXEC_DENY_INTERACTIVE, &native::securebits::SECURE_EXEC_DENY_INTERACTIVE, &libc::securebits::SECURE_EXEC_DENY_INTERACTIVE));
+        assert!(eq3_def_const(&target::securebits::SECURE_EXEC_DENY_INTERACTIVE_LOCKED, &native::securebits::SECURE_EXEC_DENY_INTERACTIVE_LOCKED, &libc::securebits::SECURE_EXEC_DENY_INTERACTIVE_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECBIT_NOROOT, &native::securebits::SECBIT_NOROOT, &libc::securebits::SECBIT_NOROOT));
+        assert!(eq3_def_const(&target::securebits::SECBIT_NOROOT_LOCKED, &native::securebits::SECBIT_NOROOT_LOCKED, &libc::securebits::SECBIT_NOROOT_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECBIT_NO_SETUID_FIXUP, &native::securebits::SECBIT_NO_SETUID_FIXUP, &libc::securebits::SECBIT_NO_SETUID_FIXUP));
+        assert!(eq3_def_const(&target::securebits::SECBIT_NO_SETUID_FIXUP_LOCKED, &native::securebits::SECBIT_NO_SETUID_FIXUP_LOCKED, &libc::securebits::SECBIT_NO_SETUID_FIXUP_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECBIT_KEEP_CAPS, &native::securebits::SECBIT_KEEP_CAPS, &libc::securebits::SECBIT_KEEP_CAPS));
+        assert!(eq3_def_const(&target::securebits::SECBIT_KEEP_CAPS_LOCKED, &native::securebits::SECBIT_KEEP_CAPS_LOCKED, &libc::securebits::SECBIT_KEEP_CAPS_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECBIT_NO_CAP_AMBIENT_RAISE, &native::securebits::SECBIT_NO_CAP_AMBIENT_RAISE, &libc::securebits::SECBIT_NO_CAP_AMBIENT_RAISE));
+        assert!(eq3_def_const(&target::securebits::SECBIT_NO_CAP_AMBIENT_RAISE_LOCKED, &native::securebits::SECBIT_NO_CAP_AMBIENT_RAISE_LOCKED, &libc::securebits::SECBIT_NO_CAP_AMBIENT_RAISE_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECBIT_EXEC_RESTRICT_FILE, &native::securebits::SECBIT_EXEC_RESTRICT_FILE, &libc::securebits::SECBIT_EXEC_RESTRICT_FILE));
+        assert!(eq3_def_const(&target::securebits::SECBIT_EXEC_RESTRICT_FILE_LOCKED, &native::securebits::SECBIT_EXEC_RESTRICT_FILE_LOCKED, &libc::securebits::SECBIT_EXEC_RESTRICT_FILE_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECBIT_EXEC_DENY_INTERACTIVE, &native::securebits::SECBIT_EXEC_DENY_INTERACTIVE, &libc::securebits::SECBIT_EXEC_DENY_INTERACTIVE));
+        assert!(eq3_def_const(&target::securebits::SECBIT_EXEC_DENY_INTERACTIVE_LOCKED, &native::securebits::SECBIT_EXEC_DENY_INTERACTIVE_LOCKED, &libc::securebits::SECBIT_EXEC_DENY_INTERACTIVE_LOCKED));
+        assert!(eq3_def_const(&target::securebits::SECUREBITS_DEFAULT, &native::securebits::SECUREBITS_DEFAULT, &libc::securebits::SECUREBITS_DEFAULT));
+        assert!(eq3_def_const(&target::securebits::SECURE_ALL_BITS, &native::securebits::SECURE_ALL_BITS, &libc::securebits::SECURE_ALL_BITS));
+        assert!(eq3_def_const(&target::securebits::SECURE_ALL_LOCKS, &native::securebits::SECURE_ALL_LOCKS, &libc::securebits::SECURE_ALL_LOCKS));
+        assert!(eq3_def_const(&target::securebits::SECURE_ALL_UNPRIVILEGED, &native::securebits::SECURE_ALL_UNPRIVILEGED, &libc::securebits::SECURE_ALL_UNPRIVILEGED));
+    }
+}
+
+// Compare core-dump note types of target, native and libc.
+#[test]
+fn target_elfcore() {
+    unsafe {
+        assert!(eq3_def_const(&target::elfcore::NT_PRSTATUS, &native::elfcore::NT_PRSTATUS, &libc::elfcore::NT_PRSTATUS));
+        assert!(eq3_def_const(&target::elfcore::NT_PRFPREG, &native::elfcore::NT_PRFPREG, &libc::elfcore::NT_PRFPREG));
+        assert!(eq3_def_const(&target::elfcore::NT_PRPSINFO, &native::elfcore::NT_PRPSINFO, &libc::elfcore::NT_PRPSINFO));
+        assert!(eq3_def_const(&target::elfcore::NT_TASKSTRUCT, &native::elfcore::NT_TASKSTRUCT, &libc::elfcore::NT_TASKSTRUCT));
+        assert!(eq3_def_const(&target::elfcore::NT_AUXV, &native::elfcore::NT_AUXV, &libc::elfcore::NT_AUXV));
+        assert!(eq3_def_const(&target::elfcore::NT_SIGINFO, &native::elfcore::NT_SIGINFO, &libc::elfcore::NT_SIGINFO));
+        assert!(eq3_def_const(&target::elfcore::NT_FILE, &native::elfcore::NT_FILE, &libc::elfcore::NT_FILE));
+        assert!(eq3_def_const(&target::elfcore::NT_PRXFPREG, &native::elfcore::NT_PRXFPREG, &libc::elfcore::NT_PRXFPREG));
+        assert!(eq3_def_const(&target::elfcore::NT_PPC_VMX, &native::elfcore::NT_PPC_VMX, &libc::elfcore::NT_PPC_VMX));
+        assert!(eq3_def_const(&target::elfcore::NT_PPC_SPE, &native::elfcore::NT_PPC_SPE, &libc::elfcore::NT_PPC_SPE));
+        assert!(eq3_def_const(&target::elfcore::NT_PPC_VSX, &native::elfcore::NT_PPC_VSX, &libc::elfcore::NT_PPC_VSX));
+        assert!(eq3_def_const(&target::elfcore::NT_PPC_TAR, &native::elfcore::NT_PPC_TAR, &libc::elfcore::NT_PPC_TAR));
+        assert!(eq3_def_const(&target::elfcore::NT_PPC_PPR, &native::elfcore::NT_PPC_PPR, &libc::elfcore::NT_PPC_PPR));
+        assert!(eq3_def_const(&target::elfcore::NT_PPC_DSCR, &native::elfcore::NT_PPC_DSCR, &libc::elfcore::NT_PPC_DSCR));
+        assert!(eq3_def_const(&target::elfcore::NT_PPC_EBB, &native::elfcore::NT_PPC_EBB, &libc::elfcore::NT_PPC_EBB));
+        assert!(eq3_def_const(&target::elfcore::NT_PPC_PMU, &native::elfcore::NT_PPC_PMU, &libc::elfcore::NT_PPC_PMU));
+        assert!(eq3_def_const(&target::elfcore::NT_386_TLS, &native::elfcore::NT_386_TLS, &libc::elfcore::NT_386_TLS));
+        assert!(eq3_def_const(&target::elfcore::NT_386_IOPERM, &native::elfcore::NT_386_IOPERM, &libc::elfcore::NT_386_IOPERM));
+        assert!(eq3_def_const(&target::elfcore::NT_X86_XSTATE, &native::elfcore::NT_X86_XSTATE, &libc::elfcore::NT_X86_XSTATE));
+        assert!(eq3_def_const(&target::elfcore::NT_X86_SHSTK, &native::elfcore::NT_X86_SHSTK, &libc::elfcore::NT_X86_SHSTK));
+        assert!(eq3_def_const(&target::elfcore::NT_X86_XSAVE_LAYOUT, &native::elfcore::NT_X86_XSAVE_LAYOUT, &libc::elfcore::NT_X86_XSAVE_LAYOUT));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_HIGH_GPRS, &native::elfcore::NT_S390_HIGH_GPRS, &libc::elfcore::NT_S390_HIGH_GPRS));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_TIMER, &native::elfcore::NT_S390_TIMER, &libc::elfcore::NT_S390_TIMER));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_TODCMP, &native::elfcore::NT_S390_TODCMP, &libc::elfcore::NT_S390_TODCMP));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_TODPREG, &native::elfcore::NT_S390_TODPREG, &libc::elfcore::NT_S390_TODPREG));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_CTRS, &native::elfcore::NT_S390_CTRS, &libc::elfcore::NT_S390_CTRS));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_PREFIX, &native::elfcore::NT_S390_PREFIX, &libc::elfcore::NT_S390_PREFIX));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_LAST_BREAK, &native::elfcore::NT_S390_LAST_BREAK, &libc::elfcore::NT_S390_LAST_BREAK));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_SYSTEM_CALL, &native::elfcore::NT_S390_SYSTEM_CALL, &libc::elfcore::NT_S390_SYSTEM_CALL));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_TDB, &native::elfcore::NT_S390_TDB, &libc::elfcore::NT_S390_TDB));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_VXRS_LOW, &native::elfcore::NT_S390_VXRS_LOW, &libc::elfcore::NT_S390_VXRS_LOW));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_VXRS_HIGH, &native::elfcore::NT_S390_VXRS_HIGH, &libc::elfcore::NT_S390_VXRS_HIGH));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_GS_CB, &native::elfcore::NT_S390_GS_CB, &libc::elfcore::NT_S390_GS_CB));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_GS_BC, &native::elfcore::NT_S390_GS_BC, &libc::elfcore::NT_S390_GS_BC));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_RI_CB, &native::elfcore::NT_S390_RI_CB, &libc::elfcore::NT_S390_RI_CB));
+        assert!(eq3_def_const(&target::elfcore::NT_S390_PV_CPU_DATA, &native::elfcore::NT_S390_PV_CPU_DATA, &libc::elfcore::NT_S390_PV_CPU_DATA));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_VFP, &native::elfcore::NT_ARM_VFP, &libc::elfcore::NT_ARM_VFP));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_TLS, &native::elfcore::NT_ARM_TLS, &libc::elfcore::NT_ARM_TLS));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_HW_BREAK, &native::elfcore::NT_ARM_HW_BREAK, &libc::elfcore::NT_ARM_HW_BREAK));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_HW_WATCH, &native::elfcore::NT_ARM_HW_WATCH, &libc::elfcore::NT_ARM_HW_WATCH));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_SYSTEM_CALL, &native::elfcore::NT_ARM_SYSTEM_CALL, &libc::elfcore::NT_ARM_SYSTEM_CALL));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_SVE, &native::elfcore::NT_ARM_SVE, &libc::elfcore::NT_ARM_SVE));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_PAC_MASK, &native::elfcore::NT_ARM_PAC_MASK, &libc::elfcore::NT_ARM_PAC_MASK));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_PACA_KEYS, &native::elfcore::NT_ARM_PACA_KEYS, &libc::elfcore::NT_ARM_PACA_KEYS));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_PACG_KEYS, &native::elfcore::NT_ARM_PACG_KEYS, &libc::elfcore::NT_ARM_PACG_KEYS));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_TAGGED_ADDR_CTRL, &native::elfcore::NT_ARM_TAGGED_ADDR_CTRL, &libc::elfcore::NT_ARM_TAGGED_ADDR_CTRL));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_PAC_ENABLED_KEYS, &native::elfcore::NT_ARM_PAC_ENABLED_KEYS, &libc::elfcore::NT_ARM_PAC_ENABLED_KEYS));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_SSVE, &native::elfcore::NT_ARM_SSVE, &libc::elfcore::NT_ARM_SSVE));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_ZA, &native::elfcore::NT_ARM_ZA, &libc::elfcore::NT_ARM_ZA));
+        assert!(eq3_def_const(&target::elfcore::NT_ARM_ZT, &native::elfcore::NT_ARM_ZT, &libc::elfcore::NT_ARM_ZT));
+        assert!(eq3_def_const(&target::elfcore::NT_VMCOREDD, &native::elfcore::NT_VMCOREDD, &libc::elfcore::NT_VMCOREDD));
+        assert!(eq3_def_const(&target::elfcore::NT_RISCV_CSR, &native::elfcore::NT_RISCV_CSR, &libc::elfcore::NT_RISCV_CSR));
+        assert!(eq3_def_const(&target::elfcore::NT_RISCV_VECTOR, &native::elfcore::NT_RISCV_VECTOR, &libc::elfcore::NT_RISCV_VECTOR));
+        assert!(eq3_def_const(&target::elfcore::NT_LOONGARCH_CPUCFG, &native::elfcore::NT_LOONGARCH_CPUCFG, &libc::elfcore::NT_LOONGARCH_CPUCFG));
+        assert!(eq3_def_const(&target::elfcore::NT_LOONGARCH_CSR, &native::elfcore::NT_LOONGARCH_CSR, &libc::elfcore::NT_LOONGARCH_CSR));
+        assert!(eq3_def_const(&target::elfcore::NT_LOONGARCH_LSX, &native::elfcore::NT_LOONGARCH_LSX, &libc::elfcore::NT_LOONGARCH_LSX));
+        assert!(eq3_def_const(&target::elfcore::NT_LOONGARCH_LASX, &native::elfcore::NT_LOONGARCH_LASX, &libc::elfcore::NT_LOONGARCH_LASX));
+        assert!(eq3_def_const(&target::elfcore::NT_LOONGARCH_LBT, &native::elfcore::NT_LOONGARCH_LBT, &libc::elfcore::NT_LOONGARCH_LBT));
+        assert!(eq3_def_const(&target::elfcore::NT_LOONGARCH_HW_BREAK, &native::elfcore::NT_LOONGARCH_HW_BREAK, &libc::elfcore::NT_LOONGARCH_HW_BREAK));
+        assert!(eq3_def_const(&target::elfcore::NT_LOONGARCH_HW_WATCH, &native::elfcore::NT_LOONGARCH_HW_WATCH, &libc::elfcore::NT_LOONGARCH_HW_WATCH));
+    }
+}
+
+// Compare the register sets of target and native with the definitions of the
+// C library. Only the GNU C library exposes them via `libc`. On powerpc and
+// s390x, it only exposes the larger `ptrace(2)` user area, hence those are
+// skipped.
+#[cfg(all(
+    target_env = "gnu",
+    not(any(target_arch = "powerpc64", target_arch = "s390x")),
+))]
+#[test]
+fn target_user() {
+    #[cfg(target_arch = "arm")]
+    use ::libc::user_regs as user_regs_struct;
+    #[cfg(not(target_arch = "arm"))]
+    use ::libc::user_regs_struct;
+
+    assert!(eq3_def_type::<target::user::UserRegsStruct, native::user::UserRegsStruct, user_regs_struct>());
+    assert!(eq3_def_type::<target::user::ElfGregset, native::user::ElfGregset, user_regs_struct>());
+
+    #[cfg(target_arch = "aarch64")]
+    assert!(eq3_def_type::<target::user::UserFpsimdStruct, native::user::UserFpsimdStruct, ::libc::user_fpsimd_struct>());
+    #[cfg(target_arch = "loongarch64")]
+    assert!(eq3_def_type::<target::user::UserFpregsStruct, native::user::UserFpregsStruct, ::libc::user_fp_struct>());
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    assert!(eq3_def_type::<target::user::UserFpregsStruct, native::user::UserFpregsStruct, ::libc::user_fpregs_struct>());
+    #[cfg(target_arch = "x86")]
+    assert!(eq3_def_type::<target::user::UserFpxregsStruct, native::user::UserFpxregsStruct, ::libc::user_fpxregs_struct>());
+}
+
+// Compare performance event definitions of target, native and libc.
+#[test]
+fn target_perf_event() {
+    unsafe {
+        assert!(eq3_def_const(&target::perf_event::PERF_TYPE_HARDWARE, &native::perf_event::PERF_TYPE_HARDWARE, &libc::perf_event::PERF_TYPE_HARDWARE));
+        assert!(eq3_def_const(&target::perf_event::PERF_TYPE_SOFTWARE, &native::perf_event::PERF_TYPE_SOFTWARE, &libc::perf_event::PERF_TYPE_SOFTWARE));
+        assert!(eq3_def_const(&target::perf_event::PERF_TYPE_TRACEPOINT, &native::perf_event::PERF_TYPE_TRACEPOINT, &libc::perf_event::PERF_TYPE_TRACEPOINT));
+        assert!(eq3_def_const(&target::perf_event::PERF_TYPE_HW_CACHE, &native::perf_event::PERF_TYPE_HW_CACHE, &libc::perf_event::PERF_TYPE_HW_CACHE));
+        assert!(eq3_def_const(&target::perf_event::PERF_TYPE_RAW, &native::perf_event::PERF_TYPE_RAW, &libc::perf_event::PERF_TYPE_RAW));
+        assert!(eq3_def_const(&target::perf_event::PERF_TYPE_BREAKPOINT, &native::perf_event::PERF_TYPE_BREAKPOINT, &libc::perf_event::PERF_TYPE_BREAKPOINT));
+        assert!(eq3_def_const(&target::perf_event::PERF_TYPE_MAX, &native::perf_event::PERF_TYPE_MAX, &libc::perf_event::PERF_TYPE_MAX));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CPU_CYCLES, &native::perf_event::PERF_COUNT_HW_CPU_CYCLES, &libc::perf_event::PERF_COUNT_HW_CPU_CYCLES));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_INSTRUCTIONS, &native::perf_event::PERF_COUNT_HW_INSTRUCTIONS, &libc::perf_event::PERF_COUNT_HW_INSTRUCTIONS));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_REFERENCES, &native::perf_event::PERF_COUNT_HW_CACHE_REFERENCES, &libc::perf_event::PERF_COUNT_HW_CACHE_REFERENCES));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_MISSES, &native::perf_event::PERF_COUNT_HW_CACHE_MISSES, &libc::perf_event::PERF_COUNT_HW_CACHE_MISSES));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_BRANCH_INSTRUCTIONS, &native::perf_event::PERF_COUNT_HW_BRANCH_INSTRUCTIONS, &libc::perf_event::PERF_COUNT_HW_BRANCH_INSTRUCTIONS));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_BRANCH_MISSES, &native::perf_event::PERF_COUNT_HW_BRANCH_MISSES, &libc::perf_event::PERF_COUNT_HW_BRANCH_MISSES));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_BUS_CYCLES, &native::perf_event::PERF_COUNT_HW_BUS_CYCLES, &libc::perf_event::PERF_COUNT_HW_BUS_CYCLES));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_STALLED_CYCLES_FRONTEND, &native::perf_event::PERF_COUNT_HW_STALLED_CYCLES_FRONTEND, &libc::perf_event::PERF_COUNT_HW_STALLED_CYCLES_FRONTEND));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_STALLED_CYCLES_BACKEND, &native::perf_event::PERF_COUNT_HW_STALLED_CYCLES_BACKEND, &libc::perf_event::PERF_COUNT_HW_STALLED_CYCLES_BACKEND));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_REF_CPU_CYCLES, &native::perf_event::PERF_COUNT_HW_REF_CPU_CYCLES, &libc::perf_event::PERF_COUNT_HW_REF_CPU_CYCLES));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_MAX, &native::perf_event::PERF_COUNT_HW_MAX, &libc::perf_event::PERF_COUNT_HW_MAX));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_L1D, &native::perf_event::PERF_COUNT_HW_CACHE_L1D, &libc::perf_event::PERF_COUNT_HW_CACHE_L1D));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_L1I, &native::perf_event::PERF_COUNT_HW_CACHE_L1I, &libc::perf_event::PERF_COUNT_HW_CACHE_L1I));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_LL, &native::perf_event::PERF_COUNT_HW_CACHE_LL, &libc::perf_event::PERF_COUNT_HW_CACHE_LL));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_DTLB, &native::perf_event::PERF_COUNT_HW_CACHE_DTLB, &libc::perf_event::PERF_COUNT_HW_CACHE_DTLB));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_ITLB, &native::perf_event::PERF_COUNT_HW_CACHE_ITLB, &libc::perf_event::PERF_COUNT_HW_CACHE_ITLB));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_BPU, &native::perf_event::PERF_COUNT_HW_CACHE_BPU, &libc::perf_event::PERF_COUNT_HW_CACHE_BPU));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_NODE, &native::perf_event::PERF_COUNT_HW_CACHE_NODE, &libc::perf_event::PERF_COUNT_HW_CACHE_NODE));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_MAX, &native::perf_event::PERF_COUNT_HW_CACHE_MAX, &libc::perf_event::PERF_COUNT_HW_CACHE_MAX));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_OP_READ, &native::perf_event::PERF_COUNT_HW_CACHE_OP_READ, &libc::perf_event::PERF_COUNT_HW_CACHE_OP_READ));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_OP_WRITE, &native::perf_event::PERF_COUNT_HW_CACHE_OP_WRITE, &libc::perf_event::PERF_COUNT_HW_CACHE_OP_WRITE));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_OP_PREFETCH, &native::perf_event::PERF_COUNT_HW_CACHE_OP_PREFETCH, &libc::perf_event::PERF_COUNT_HW_CACHE_OP_PREFETCH));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_OP_MAX, &native::perf_event::PERF_COUNT_HW_CACHE_OP_MAX, &libc::perf_event::PERF_COUNT_HW_CACHE_OP_MAX));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_RESULT_ACCESS, &native::perf_event::PERF_COUNT_HW_CACHE_RESULT_ACCESS, &libc::perf_event::PERF_COUNT_HW_CACHE_RESULT_ACCESS));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_RESULT_MISS, &native::perf_event::PERF_COUNT_HW_CACHE_RESULT_MISS, &libc::perf_event::PERF_COUNT_HW_CACHE_RESULT_MISS));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_HW_CACHE_RESULT_MAX, &native::perf_event::PERF_COUNT_HW_CACHE_RESULT_MAX, &libc::perf_event::PERF_COUNT_HW_CACHE_RESULT_MAX));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_CPU_CLOCK, &native::perf_event::PERF_COUNT_SW_CPU_CLOCK, &libc::perf_event::PERF_COUNT_SW_CPU_CLOCK));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_TASK_CLOCK, &native::perf_event::PERF_COUNT_SW_TASK_CLOCK, &libc::perf_event::PERF_COUNT_SW_TASK_CLOCK));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_PAGE_FAULTS, &native::perf_event::PERF_COUNT_SW_PAGE_FAULTS, &libc::perf_event::PERF_COUNT_SW_PAGE_FAULTS));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_CONTEXT_SWITCHES, &native::perf_event::PERF_COUNT_SW_CONTEXT_SWITCHES, &libc::perf_event::PERF_COUNT_SW_CONTEXT_SWITCHES));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_CPU_MIGRATIONS, &native::perf_event::PERF_COUNT_SW_CPU_MIGRATIONS, &libc::perf_event::PERF_COUNT_SW_CPU_MIGRATIONS));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_PAGE_FAULTS_MIN, &native::perf_event::PERF_COUNT_SW_PAGE_FAULTS_MIN, &libc::perf_event::PERF_COUNT_SW_PAGE_FAULTS_MIN));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_PAGE_FAULTS_MAJ, &native::perf_event::PERF_COUNT_SW_PAGE_FAULTS_MAJ, &libc::perf_event::PERF_COUNT_SW_PAGE_FAULTS_MAJ));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_ALIGNMENT_FAULTS, &native::perf_event::PERF_COUNT_SW_ALIGNMENT_FAULTS, &libc::perf_event::PERF_COUNT_SW_ALIGNMENT_FAULTS));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_EMULATION_FAULTS, &native::perf_event::PERF_COUNT_SW_EMULATION_FAULTS, &libc::perf_event::PERF_COUNT_SW_EMULATION_FAULTS));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_DUMMY, &native::perf_event::PERF_COUNT_SW_DUMMY, &libc::perf_event::PERF_COUNT_SW_DUMMY));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_BPF_OUTPUT, &native::perf_event::PERF_COUNT_SW_BPF_OUTPUT, &libc::perf_event::PERF_COUNT_SW_BPF_OUTPUT));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_CGROUP_SWITCHES, &native::perf_event::PERF_COUNT_SW_CGROUP_SWITCHES, &libc::perf_event::PERF_COUNT_SW_CGROUP_SWITCHES));
+        assert!(eq3_def_const(&target::perf_event::PERF_COUNT_SW_MAX, &native::perf_event::PERF_COUNT_SW_MAX, &libc::perf_event::PERF_COUNT_SW_MAX));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_IP, &native::perf_event::PERF_SAMPLE_IP, &libc::perf_event::PERF_SAMPLE_IP));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_TID, &native::perf_event::PERF_SAMPLE_TID, &libc::perf_event::PERF_SAMPLE_TID));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_TIME, &native::perf_event::PERF_SAMPLE_TIME, &libc::perf_event::PERF_SAMPLE_TIME));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_ADDR, &native::perf_event::PERF_SAMPLE_ADDR, &libc::perf_event::PERF_SAMPLE_ADDR));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_READ, &native::perf_event::PERF_SAMPLE_READ, &libc::perf_event::PERF_SAMPLE_READ));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_CALLCHAIN, &native::perf_event::PERF_SAMPLE_CALLCHAIN, &libc::perf_event::PERF_SAMPLE_CALLCHAIN));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_ID, &native::perf_event::PERF_SAMPLE_ID, &libc::perf_event::PERF_SAMPLE_ID));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_CPU, &native::perf_event::PERF_SAMPLE_CPU, &libc::perf_event::PERF_SAMPLE_CPU));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_PERIOD, &native::perf_event::PERF_SAMPLE_PERIOD, &libc::perf_event::PERF_SAMPLE_PERIOD));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_STREAM_ID, &native::perf_event::PERF_SAMPLE_STREAM_ID, &libc::perf_event::PERF_SAMPLE_STREAM_ID));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_RAW, &native::perf_event::PERF_SAMPLE_RAW, &libc::perf_event::PERF_SAMPLE_RAW));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_BRANCH_STACK, &native::perf_event::PERF_SAMPLE_BRANCH_STACK, &libc::perf_event::PERF_SAMPLE_BRANCH_STACK));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_REGS_USER, &native::perf_event::PERF_SAMPLE_REGS_USER, &libc::perf_event::PERF_SAMPLE_REGS_USER));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_STACK_USER, &native::perf_event::PERF_SAMPLE_STACK_USER, &libc::perf_event::PERF_SAMPLE_STACK_USER));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_WEIGHT, &native::perf_event::PERF_SAMPLE_WEIGHT, &libc::perf_event::PERF_SAMPLE_WEIGHT));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_DATA_SRC, &native::perf_event::PERF_SAMPLE_DATA_SRC, &libc::perf_event::PERF_SAMPLE_DATA_SRC));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_IDENTIFIER, &native::perf_event::PERF_SAMPLE_IDENTIFIER, &libc::perf_event::PERF_SAMPLE_IDENTIFIER));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_TRANSACTION, &native::perf_event::PERF_SAMPLE_TRANSACTION, &libc::perf_event::PERF_SAMPLE_TRANSACTION));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_REGS_INTR, &native::perf_event::PERF_SAMPLE_REGS_INTR, &libc::perf_event::PERF_SAMPLE_REGS_INTR));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_PHYS_ADDR, &native::perf_event::PERF_SAMPLE_PHYS_ADDR, &libc::perf_event::PERF_SAMPLE_PHYS_ADDR));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_AUX, &native::perf_event::PERF_SAMPLE_AUX, &libc::perf_event::PERF_SAMPLE_AUX));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_CGROUP, &native::perf_event::PERF_SAMPLE_CGROUP, &libc::perf_event::PERF_SAMPLE_CGROUP));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_DATA_PAGE_SIZE, &native::perf_event::PERF_SAMPLE_DATA_PAGE_SIZE, &libc::perf_event::PERF_SAMPLE_DATA_PAGE_SIZE));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_CODE_PAGE_SIZE, &native::perf_event::PERF_SAMPLE_CODE_PAGE_SIZE, &libc::perf_event::PERF_SAMPLE_CODE_PAGE_SIZE));
+        assert!(eq3_def_const(&target::perf_event::PERF_SAMPLE_WEIGHT_STRUCT, &native::perf_event::PERF_SAMPLE_WEIGHT_STRUCT, &libc::perf_event::PERF_SAMPLE_WEIGHT_STRUCT));
+        assert!(eq3_def_const(&target::perf_event::PERF_FORMAT_TOTAL_TIME_ENABLED, &native::perf_event::PERF_FORMAT_TOTAL_TIME_ENABLED, &libc::perf_event::PERF_FORMAT_TOTAL_TIME_ENABLED));
+        assert!(eq3_def_const(&target::perf_event::PERF_FORMAT_TOTAL_TIME_RUNNING, &native::perf_event::PERF_FORMAT_TOTAL_TIME_RUNNING, &libc::perf_event::PERF_FORMAT_TOTAL_TIME_RUNNING));
+        assert!(eq3_def_const(&target::perf_event::PERF_FORMAT_ID, &native::perf_event::PERF_FORMAT_ID, &libc::perf_event::PERF_FORMAT_ID));
+        assert!(eq3_def_const(&target::perf_event::PERF_FORMAT_GROUP, &native::perf_event::PERF_FORMAT_GROUP, &libc::perf_event::PERF_FORMAT_GROUP));
+        assert!(eq3_def_const(&target::perf_event::PERF_FORMAT_LOST, &native::perf_event::PERF_FORMAT_LOST, &libc::perf_event::PERF_FORMAT_LOST));
+        assert!(eq3_def_const(&target::perf_event::PERF_FLAG_FD_NO_GROUP, &native::perf_event::PERF_FLAG_FD_NO_GROUP, &libc::perf_event::PERF_FLAG_FD_NO_GROUP));
+        assert!(eq3_def_const(&target::perf_event::PERF_FLAG_FD_OUTPUT, &native::perf_event::PERF_FLAG_FD_OUTPUT, &libc::perf_event::PERF_FLAG_FD_OUTPUT));
+        assert!(eq3_def_const(&target::perf_event::PERF_FLAG_PID_CGROUP, &native::perf_event::PERF_FLAG_PID_CGROUP, &libc::perf_event::PERF_FLAG_PID_CGROUP));
+        assert!(eq3_def_const(&target::perf_event::PERF_FLAG_FD_CLOEXEC, &native::perf_event::PERF_FLAG_FD_CLOEXEC, &libc::perf_event::PERF_FLAG_FD_CLOEXEC));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER0, &native::perf_event::PERF_ATTR_SIZE_VER0, &libc::perf_event::PERF_ATTR_SIZE_VER0));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER1, &native::perf_event::PERF_ATTR_SIZE_VER1, &libc::perf_event::PERF_ATTR_SIZE_VER1));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER2, &native::perf_event::PERF_ATTR_SIZE_VER2, &libc::perf_event::PERF_ATTR_SIZE_VER2));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER3, &native::perf_event::PERF_ATTR_SIZE_VER3, &libc::perf_event::PERF_ATTR_SIZE_VER3));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER4, &native::perf_event::PERF_ATTR_SIZE_VER4, &libc::perf_event::PERF_ATTR_SIZE_VER4));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER5, &native::perf_event::PERF_ATTR_SIZE_VER5, &libc::perf_event::PERF_ATTR_SIZE_VER5));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER6, &native::perf_event::PERF_ATTR_SIZE_VER6, &libc::perf_event::PERF_ATTR_SIZE_VER6));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER7, &native::perf_event::PERF_ATTR_SIZE_VER7, &libc::perf_event::PERF_ATTR_SIZE_VER7));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_SIZE_VER8, &native::perf_event::PERF_ATTR_SIZE_VER8, &libc::perf_event::PERF_ATTR_SIZE_VER8));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_DISABLED, &native::perf_event::PERF_ATTR_FLAG_DISABLED, &libc::perf_event::PERF_ATTR_FLAG_DISABLED));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_INHERIT, &native::perf_event::PERF_ATTR_FLAG_INHERIT, &libc::perf_event::PERF_ATTR_FLAG_INHERIT));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_PINNED, &native::perf_event::PERF_ATTR_FLAG_PINNED, &libc::perf_event::PERF_ATTR_FLAG_PINNED));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUSIVE, &native::perf_event::PERF_ATTR_FLAG_EXCLUSIVE, &libc::perf_event::PERF_ATTR_FLAG_EXCLUSIVE));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUDE_USER, &native::perf_event::PERF_ATTR_FLAG_EXCLUDE_USER, &libc::perf_event::PERF_ATTR_FLAG_EXCLUDE_USER));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUDE_KERNEL, &native::perf_event::PERF_ATTR_FLAG_EXCLUDE_KERNEL, &libc::perf_event::PERF_ATTR_FLAG_EXCLUDE_KERNEL));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUDE_HV, &native::perf_event::PERF_ATTR_FLAG_EXCLUDE_HV, &libc::perf_event::PERF_ATTR_FLAG_EXCLUDE_HV));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUDE_IDLE, &native::perf_event::PERF_ATTR_FLAG_EXCLUDE_IDLE, &libc::perf_event::PERF_ATTR_FLAG_EXCLUDE_IDLE));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_MMAP, &native::perf_event::PERF_ATTR_FLAG_MMAP, &libc::perf_event::PERF_ATTR_FLAG_MMAP));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_COMM, &native::perf_event::PERF_ATTR_FLAG_COMM, &libc::perf_event::PERF_ATTR_FLAG_COMM));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_FREQ, &native::perf_event::PERF_ATTR_FLAG_FREQ, &libc::perf_event::PERF_ATTR_FLAG_FREQ));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_INHERIT_STAT, &native::perf_event::PERF_ATTR_FLAG_INHERIT_STAT, &libc::perf_event::PERF_ATTR_FLAG_INHERIT_STAT));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_ENABLE_ON_EXEC, &native::perf_event::PERF_ATTR_FLAG_ENABLE_ON_EXEC, &libc::perf_event::PERF_ATTR_FLAG_ENABLE_ON_EXEC));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_TASK, &native::perf_event::PERF_ATTR_FLAG_TASK, &libc::perf_event::PERF_ATTR_FLAG_TASK));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_WATERMARK, &native::perf_event::PERF_ATTR_FLAG_WATERMARK, &libc::perf_event::PERF_ATTR_FLAG_WATERMARK));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_PRECISE_IP, &native::perf_event::PERF_ATTR_FLAG_PRECISE_IP, &libc::perf_event::PERF_ATTR_FLAG_PRECISE_IP));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_MMAP_DATA, &native::perf_event::PERF_ATTR_FLAG_MMAP_DATA, &libc::perf_event::PERF_ATTR_FLAG_MMAP_DATA));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_SAMPLE_ID_ALL, &native::perf_event::PERF_ATTR_FLAG_SAMPLE_ID_ALL, &libc::perf_event::PERF_ATTR_FLAG_SAMPLE_ID_ALL));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUDE_HOST, &native::perf_event::PERF_ATTR_FLAG_EXCLUDE_HOST, &libc::perf_event::PERF_ATTR_FLAG_EXCLUDE_HOST));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUDE_GUEST, &native::perf_event::PERF_ATTR_FLAG_EXCLUDE_GUEST, &libc::perf_event::PERF_ATTR_FLAG_EXCLUDE_GUEST));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_KERNEL, &native::perf_event::PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_KERNEL, &libc::perf_event::PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_KERNEL));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_USER, &native::perf_event::PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_USER, &libc::perf_event::PERF_ATTR_FLAG_EXCLUDE_CALLCHAIN_USER));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_MMAP2, &native::perf_event::PERF_ATTR_FLAG_MMAP2, &libc::perf_event::PERF_ATTR_FLAG_MMAP2));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_COMM_EXEC, &native::perf_event::PERF_ATTR_FLAG_COMM_EXEC, &libc::perf_event::PERF_ATTR_FLAG_COMM_EXEC));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_USE_CLOCKID, &native::perf_event::PERF_ATTR_FLAG_USE_CLOCKID, &libc::perf_event::PERF_ATTR_FLAG_USE_CLOCKID));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_CONTEXT_SWITCH, &native::perf_event::PERF_ATTR_FLAG_CONTEXT_SWITCH, &libc::perf_event::PERF_ATTR_FLAG_CONTEXT_SWITCH));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_WRITE_BACKWARD, &native::perf_event::PERF_ATTR_FLAG_WRITE_BACKWARD, &libc::perf_event::PERF_ATTR_FLAG_WRITE_BACKWARD));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_NAMESPACES, &native::perf_event::PERF_ATTR_FLAG_NAMESPACES, &libc::perf_event::PERF_ATTR_FLAG_NAMESPACES));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_KSYMBOL, &native::perf_event::PERF_ATTR_FLAG_KSYMBOL, &libc::perf_event::PERF_ATTR_FLAG_KSYMBOL));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_BPF_EVENT, &native::perf_event::PERF_ATTR_FLAG_BPF_EVENT, &libc::perf_event::PERF_ATTR_FLAG_BPF_EVENT));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_AUX_OUTPUT, &native::perf_event::PERF_ATTR_FLAG_AUX_OUTPUT, &libc::perf_event::PERF_ATTR_FLAG_AUX_OUTPUT));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_CGROUP, &native::perf_event::PERF_ATTR_FLAG_CGROUP, &libc::perf_event::PERF_ATTR_FLAG_CGROUP));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_TEXT_POKE, &native::perf_event::PERF_ATTR_FLAG_TEXT_POKE, &libc::perf_event::PERF_ATTR_FLAG_TEXT_POKE));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_BUILD_ID, &native::perf_event::PERF_ATTR_FLAG_BUILD_ID, &libc::perf_event::PERF_ATTR_FLAG_BUILD_ID));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_INHERIT_THREAD, &native::perf_event::PERF_ATTR_FLAG_INHERIT_THREAD, &libc::perf_event::PERF_ATTR_FLAG_INHERIT_THREAD));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_REMOVE_ON_EXEC, &native::perf_event::PERF_ATTR_FLAG_REMOVE_ON_EXEC, &libc::perf_event::PERF_ATTR_FLAG_REMOVE_ON_EXEC));
+        assert!(eq3_def_const(&target::perf_event::PERF_ATTR_FLAG_SIGTRAP, &native::perf_event::PERF_ATTR_FLAG_SIGTRAP, &libc::perf_event::PERF_ATTR_FLAG_SIGTRAP));
+        assert!(eq3_def_const(&target::perf_event::PERF_MMAP_CAP_BIT0, &native::perf_event::PERF_MMAP_CAP_BIT0, &libc::perf_event::PERF_MMAP_CAP_BIT0));
+        assert!(eq3_def_const(&target::perf_event::PERF_MMAP_CAP_BIT0_IS_DEPRECATED, &native::perf_event::PERF_MMAP_CAP_BIT0_IS_DEPRECATED, &libc::perf_event::PERF_MMAP_CAP_BIT0_IS_DEPRECATED));
+        assert!(eq3_def_const(&target::perf_event::PERF_MMAP_CAP_USER_RDPMC, &native::perf_event::PERF_MMAP_CAP_USER_RDPMC, &libc::perf_event::PERF_MMAP_CAP_USER_RDPMC));
+        assert!(eq3_def_const(&target::perf_event::PERF_MMAP_CAP_USER_TIME, &native::perf_event::PERF_MMAP_CAP_USER_TIME, &libc::perf_event::PERF_MMAP_CAP_USER_TIME));
+        assert!(eq3_def_const(&target::perf_event::PERF_MMAP_CAP_USER_TIME_ZERO, &native::perf_event::PERF_MMAP_CAP_USER_TIME_ZERO, &libc::perf_event::PERF_MMAP_CAP_USER_TIME_ZERO));
+        assert!(eq3_def_const(&target::perf_event::PERF_MMAP_CAP_USER_TIME_SHORT, &native::perf_event::PERF_MMAP_CAP_USER_TIME_SHORT, &libc::perf_event::PERF_MMAP_CAP_USER_TIME_SHORT));
+    }
+}
+
+// Compare eBPF definitions of target, native and libc.
+#[test]
+fn target_bpf() {
+    unsafe {
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_CREATE, &native::bpf::BPF_MAP_CREATE, &libc::bpf::BPF_MAP_CREATE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_LOOKUP_ELEM, &native::bpf::BPF_MAP_LOOKUP_ELEM, &libc::bpf::BPF_MAP_LOOKUP_ELEM));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_UPDATE_ELEM, &native::bpf::BPF_MAP_UPDATE_ELEM, &libc::bpf::BPF_MAP_UPDATE_ELEM));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_DELETE_ELEM, &native::bpf::BPF_MAP_DELETE_ELEM, &libc::bpf::BPF_MAP_DELETE_ELEM));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_GET_NEXT_KEY, &native::bpf::BPF_MAP_GET_NEXT_KEY, &libc::bpf::BPF_MAP_GET_NEXT_KEY));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_LOAD, &native::bpf::BPF_PROG_LOAD, &libc::bpf::BPF_PROG_LOAD));
+        assert!(eq3_def_const(&target::bpf::BPF_OBJ_PIN, &native::bpf::BPF_OBJ_PIN, &libc::bpf::BPF_OBJ_PIN));
+        assert!(eq3_def_const(&target::bpf::BPF_OBJ_GET, &native::bpf::BPF_OBJ_GET, &libc::bpf::BPF_OBJ_GET));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_ATTACH, &native::bpf::BPF_PROG_ATTACH, &libc::bpf::BPF_PROG_ATTACH));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_DETACH, &native::bpf::BPF_PROG_DETACH, &libc::bpf::BPF_PROG_DETACH));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TEST_RUN, &native::bpf::BPF_PROG_TEST_RUN, &libc::bpf::BPF_PROG_TEST_RUN));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_RUN, &native::bpf::BPF_PROG_RUN, &libc::bpf::BPF_PROG_RUN));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_GET_NEXT_ID, &native::bpf::BPF_PROG_GET_NEXT_ID, &libc::bpf::BPF_PROG_GET_NEXT_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_GET_NEXT_ID, &native::bpf::BPF_MAP_GET_NEXT_ID, &libc::bpf::BPF_MAP_GET_NEXT_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_GET_FD_BY_ID, &native::bpf::BPF_PROG_GET_FD_BY_ID, &libc::bpf::BPF_PROG_GET_FD_BY_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_GET_FD_BY_ID, &native::bpf::BPF_MAP_GET_FD_BY_ID, &libc::bpf::BPF_MAP_GET_FD_BY_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_OBJ_GET_INFO_BY_FD, &native::bpf::BPF_OBJ_GET_INFO_BY_FD, &libc::bpf::BPF_OBJ_GET_INFO_BY_FD));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_QUERY, &native::bpf::BPF_PROG_QUERY, &libc::bpf::BPF_PROG_QUERY));
+        assert!(eq3_def_const(&target::bpf::BPF_RAW_TRACEPOINT_OPEN, &native::bpf::BPF_RAW_TRACEPOINT_OPEN, &libc::bpf::BPF_RAW_TRACEPOINT_OPEN));
+        assert!(eq3_def_const(&target::bpf::BPF_BTF_LOAD, &native::bpf::BPF_BTF_LOAD, &libc::bpf::BPF_BTF_LOAD));
+        assert!(eq3_def_const(&target::bpf::BPF_BTF_GET_FD_BY_ID, &native::bpf::BPF_BTF_GET_FD_BY_ID, &libc::bpf::BPF_BTF_GET_FD_BY_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_TASK_FD_QUERY, &native::bpf::BPF_TASK_FD_QUERY, &libc::bpf::BPF_TASK_FD_QUERY));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_LOOKUP_AND_DELETE_ELEM, &native::bpf::BPF_MAP_LOOKUP_AND_DELETE_ELEM, &libc::bpf::BPF_MAP_LOOKUP_AND_DELETE_ELEM));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_FREEZE, &native::bpf::BPF_MAP_FREEZE, &libc::bpf::BPF_MAP_FREEZE));
+        assert!(eq3_def_const(&target::bpf::BPF_BTF_GET_NEXT_ID, &native::bpf::BPF_BTF_GET_NEXT_ID, &libc::bpf::BPF_BTF_GET_NEXT_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_LOOKUP_BATCH, &native::bpf::BPF_MAP_LOOKUP_BATCH, &libc::bpf::BPF_MAP_LOOKUP_BATCH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_LOOKUP_AND_DELETE_BATCH, &native::bpf::BPF_MAP_LOOKUP_AND_DELETE_BATCH, &libc::bpf::BPF_MAP_LOOKUP_AND_DELETE_BATCH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_UPDATE_BATCH, &native::bpf::BPF_MAP_UPDATE_BATCH, &libc::bpf::BPF_MAP_UPDATE_BATCH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_DELETE_BATCH, &native::bpf::BPF_MAP_DELETE_BATCH, &libc::bpf::BPF_MAP_DELETE_BATCH));
+        assert!(eq3_def_const(&target::bpf::BPF_LINK_CREATE, &native::bpf::BPF_LINK_CREATE, &libc::bpf::BPF_LINK_CREATE));
+        assert!(eq3_def_const(&target::bpf::BPF_LINK_UPDATE, &native::bpf::BPF_LINK_UPDATE, &libc::bpf::BPF_LINK_UPDATE));
+        assert!(eq3_def_const(&target::bpf::BPF_LINK_GET_FD_BY_ID, &native::bpf::BPF_LINK_GET_FD_BY_ID, &libc::bpf::BPF_LINK_GET_FD_BY_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_LINK_GET_NEXT_ID, &native::bpf::BPF_LINK_GET_NEXT_ID, &libc::bpf::BPF_LINK_GET_NEXT_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_ENABLE_STATS, &native::bpf::BPF_ENABLE_STATS, &libc::bpf::BPF_ENABLE_STATS));
+        assert!(eq3_def_const(&target::bpf::BPF_ITER_CREATE, &native::bpf::BPF_ITER_CREATE, &libc::bpf::BPF_ITER_CREATE));
+        assert!(eq3_def_const(&target::bpf::BPF_LINK_DETACH, &native::bpf::BPF_LINK_DETACH, &libc::bpf::BPF_LINK_DETACH));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_BIND_MAP, &native::bpf::BPF_PROG_BIND_MAP, &libc::bpf::BPF_PROG_BIND_MAP));
+        assert!(eq3_def_const(&target::bpf::BPF_TOKEN_CREATE, &native::bpf::BPF_TOKEN_CREATE, &libc::bpf::BPF_TOKEN_CREATE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_UNSPEC, &native::bpf::BPF_MAP_TYPE_UNSPEC, &libc::bpf::BPF_MAP_TYPE_UNSPEC));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_HASH, &native::bpf::BPF_MAP_TYPE_HASH, &libc::bpf::BPF_MAP_TYPE_HASH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_ARRAY, &native::bpf::BPF_MAP_TYPE_ARRAY, &libc::bpf::BPF_MAP_TYPE_ARRAY));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_PROG_ARRAY, &native::bpf::BPF_MAP_TYPE_PROG_ARRAY, &libc::bpf::BPF_MAP_TYPE_PROG_ARRAY));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_PERF_EVENT_ARRAY, &native::bpf::BPF_MAP_TYPE_PERF_EVENT_ARRAY, &libc::bpf::BPF_MAP_TYPE_PERF_EVENT_ARRAY));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_PERCPU_HASH, &native::bpf::BPF_MAP_TYPE_PERCPU_HASH, &libc::bpf::BPF_MAP_TYPE_PERCPU_HASH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_PERCPU_ARRAY, &native::bpf::BPF_MAP_TYPE_PERCPU_ARRAY, &libc::bpf::BPF_MAP_TYPE_PERCPU_ARRAY));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_STACK_TRACE, &native::bpf::BPF_MAP_TYPE_STACK_TRACE, &libc::bpf::BPF_MAP_TYPE_STACK_TRACE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_CGROUP_ARRAY, &native::bpf::BPF_MAP_TYPE_CGROUP_ARRAY, &libc::bpf::BPF_MAP_TYPE_CGROUP_ARRAY));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_LRU_HASH, &native::bpf::BPF_MAP_TYPE_LRU_HASH, &libc::bpf::BPF_MAP_TYPE_LRU_HASH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_LRU_PERCPU_HASH, &native::bpf::BPF_MAP_TYPE_LRU_PERCPU_HASH, &libc::bpf::BPF_MAP_TYPE_LRU_PERCPU_HASH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_LPM_TRIE, &native::bpf::BPF_MAP_TYPE_LPM_TRIE, &libc::bpf::BPF_MAP_TYPE_LPM_TRIE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_ARRAY_OF_MAPS, &native::bpf::BPF_MAP_TYPE_ARRAY_OF_MAPS, &libc::bpf::BPF_MAP_TYPE_ARRAY_OF_MAPS));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_HASH_OF_MAPS, &native::bpf::BPF_MAP_TYPE_HASH_OF_MAPS, &libc::bpf::BPF_MAP_TYPE_HASH_OF_MAPS));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_DEVMAP, &native::bpf::BPF_MAP_TYPE_DEVMAP, &libc::bpf::BPF_MAP_TYPE_DEVMAP));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_SOCKMAP, &native::bpf::BPF_MAP_TYPE_SOCKMAP, &libc::bpf::BPF_MAP_TYPE_SOCKMAP));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_CPUMAP, &native::bpf::BPF_MAP_TYPE_CPUMAP, &libc::bpf::BPF_MAP_TYPE_CPUMAP));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_XSKMAP, &native::bpf::BPF_MAP_TYPE_XSKMAP, &libc::bpf::BPF_MAP_TYPE_XSKMAP));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_SOCKHASH, &native::bpf::BPF_MAP_TYPE_SOCKHASH, &libc::bpf::BPF_MAP_TYPE_SOCKHASH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_CGROUP_STORAGE, &native::bpf::BPF_MAP_TYPE_CGROUP_STORAGE, &libc::bpf::BPF_MAP_TYPE_CGROUP_STORAGE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_REUSEPORT_SOCKARRAY, &native::bpf::BPF_MAP_TYPE_REUSEPORT_SOCKARRAY, &libc::bpf::BPF_MAP_TYPE_REUSEPORT_SOCKARRAY));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE, &native::bpf::BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE, &libc::bpf::BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_QUEUE, &native::bpf::BPF_MAP_TYPE_QUEUE, &libc::bpf::BPF_MAP_TYPE_QUEUE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_STACK, &native::bpf::BPF_MAP_TYPE_STACK, &libc::bpf::BPF_MAP_TYPE_STACK));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_SK_STORAGE, &native::bpf::BPF_MAP_TYPE_SK_STORAGE, &libc::bpf::BPF_MAP_TYPE_SK_STORAGE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_DEVMAP_HASH, &native::bpf::BPF_MAP_TYPE_DEVMAP_HASH, &libc::bpf::BPF_MAP_TYPE_DEVMAP_HASH));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_STRUCT_OPS, &native::bpf::BPF_MAP_TYPE_STRUCT_OPS, &libc::bpf::BPF_MAP_TYPE_STRUCT_OPS));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_RINGBUF, &native::bpf::BPF_MAP_TYPE_RINGBUF, &libc::bpf::BPF_MAP_TYPE_RINGBUF));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_INODE_STORAGE, &native::bpf::BPF_MAP_TYPE_INODE_STORAGE, &libc::bpf::BPF_MAP_TYPE_INODE_STORAGE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_TASK_STORAGE, &native::bpf::BPF_MAP_TYPE_TASK_STORAGE, &libc::bpf::BPF_MAP_TYPE_TASK_STORAGE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_BLOOM_FILTER, &native::bpf::BPF_MAP_TYPE_BLOOM_FILTER, &libc::bpf::BPF_MAP_TYPE_BLOOM_FILTER));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_USER_RINGBUF, &native::bpf::BPF_MAP_TYPE_USER_RINGBUF, &libc::bpf::BPF_MAP_TYPE_USER_RINGBUF));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_CGRP_STORAGE, &native::bpf::BPF_MAP_TYPE_CGRP_STORAGE, &libc::bpf::BPF_MAP_TYPE_CGRP_STORAGE));
+        assert!(eq3_def_const(&target::bpf::BPF_MAP_TYPE_ARENA, &native::bpf::BPF_MAP_TYPE_ARENA, &libc::bpf::BPF_MAP_TYPE_ARENA));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_UNSPEC, &native::bpf::BPF_PROG_TYPE_UNSPEC, &libc::bpf::BPF_PROG_TYPE_UNSPEC));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SOCKET_FILTER, &native::bpf::BPF_PROG_TYPE_SOCKET_FILTER, &libc::bpf::BPF_PROG_TYPE_SOCKET_FILTER));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_KPROBE, &native::bpf::BPF_PROG_TYPE_KPROBE, &libc::bpf::BPF_PROG_TYPE_KPROBE));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SCHED_CLS, &native::bpf::BPF_PROG_TYPE_SCHED_CLS, &libc::bpf::BPF_PROG_TYPE_SCHED_CLS));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SCHED_ACT, &native::bpf::BPF_PROG_TYPE_SCHED_ACT, &libc::bpf::BPF_PROG_TYPE_SCHED_ACT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_TRACEPOINT, &native::bpf::BPF_PROG_TYPE_TRACEPOINT, &libc::bpf::BPF_PROG_TYPE_TRACEPOINT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_XDP, &native::bpf::BPF_PROG_TYPE_XDP, &libc::bpf::BPF_PROG_TYPE_XDP));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_PERF_EVENT, &native::bpf::BPF_PROG_TYPE_PERF_EVENT, &libc::bpf::BPF_PROG_TYPE_PERF_EVENT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_CGROUP_SKB, &native::bpf::BPF_PROG_TYPE_CGROUP_SKB, &libc::bpf::BPF_PROG_TYPE_CGROUP_SKB));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_CGROUP_SOCK, &native::bpf::BPF_PROG_TYPE_CGROUP_SOCK, &libc::bpf::BPF_PROG_TYPE_CGROUP_SOCK));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_LWT_IN, &native::bpf::BPF_PROG_TYPE_LWT_IN, &libc::bpf::BPF_PROG_TYPE_LWT_IN));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_LWT_OUT, &native::bpf::BPF_PROG_TYPE_LWT_OUT, &libc::bpf::BPF_PROG_TYPE_LWT_OUT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_LWT_XMIT, &native::bpf::BPF_PROG_TYPE_LWT_XMIT, &libc::bpf::BPF_PROG_TYPE_LWT_XMIT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SOCK_OPS, &native::bpf::BPF_PROG_TYPE_SOCK_OPS, &libc::bpf::BPF_PROG_TYPE_SOCK_OPS));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SK_SKB, &native::bpf::BPF_PROG_TYPE_SK_SKB, &libc::bpf::BPF_PROG_TYPE_SK_SKB));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_CGROUP_DEVICE, &native::bpf::BPF_PROG_TYPE_CGROUP_DEVICE, &libc::bpf::BPF_PROG_TYPE_CGROUP_DEVICE));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SK_MSG, &native::bpf::BPF_PROG_TYPE_SK_MSG, &libc::bpf::BPF_PROG_TYPE_SK_MSG));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_RAW_TRACEPOINT, &native::bpf::BPF_PROG_TYPE_RAW_TRACEPOINT, &libc::bpf::BPF_PROG_TYPE_RAW_TRACEPOINT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_CGROUP_SOCK_ADDR, &native::bpf::BPF_PROG_TYPE_CGROUP_SOCK_ADDR, &libc::bpf::BPF_PROG_TYPE_CGROUP_SOCK_ADDR));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_LWT_SEG6LOCAL, &native::bpf::BPF_PROG_TYPE_LWT_SEG6LOCAL, &libc::bpf::BPF_PROG_TYPE_LWT_SEG6LOCAL));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_LIRC_MODE2, &native::bpf::BPF_PROG_TYPE_LIRC_MODE2, &libc::bpf::BPF_PROG_TYPE_LIRC_MODE2));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SK_REUSEPORT, &native::bpf::BPF_PROG_TYPE_SK_REUSEPORT, &libc::bpf::BPF_PROG_TYPE_SK_REUSEPORT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_FLOW_DISSECTOR, &native::bpf::BPF_PROG_TYPE_FLOW_DISSECTOR, &libc::bpf::BPF_PROG_TYPE_FLOW_DISSECTOR));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_CGROUP_SYSCTL, &native::bpf::BPF_PROG_TYPE_CGROUP_SYSCTL, &libc::bpf::BPF_PROG_TYPE_CGROUP_SYSCTL));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_RAW_TRACEPOINT_WRITABLE, &native::bpf::BPF_PROG_TYPE_RAW_TRACEPOINT_WRITABLE, &libc::bpf::BPF_PROG_TYPE_RAW_TRACEPOINT_WRITABLE));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_CGROUP_SOCKOPT, &native::bpf::BPF_PROG_TYPE_CGROUP_SOCKOPT, &libc::bpf::BPF_PROG_TYPE_CGROUP_SOCKOPT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_TRACING, &native::bpf::BPF_PROG_TYPE_TRACING, &libc::bpf::BPF_PROG_TYPE_TRACING));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_STRUCT_OPS, &native::bpf::BPF_PROG_TYPE_STRUCT_OPS, &libc::bpf::BPF_PROG_TYPE_STRUCT_OPS));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_EXT, &native::bpf::BPF_PROG_TYPE_EXT, &libc::bpf::BPF_PROG_TYPE_EXT));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_LSM, &native::bpf::BPF_PROG_TYPE_LSM, &libc::bpf::BPF_PROG_TYPE_LSM));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SK_LOOKUP, &native::bpf::BPF_PROG_TYPE_SK_LOOKUP, &libc::bpf::BPF_PROG_TYPE_SK_LOOKUP));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_SYSCALL, &native::bpf::BPF_PROG_TYPE_SYSCALL, &libc::bpf::BPF_PROG_TYPE_SYSCALL));
+        assert!(eq3_def_const(&target::bpf::BPF_PROG_TYPE_NETFILTER, &native::bpf::BPF_PROG_TYPE_NETFILTER, &libc::bpf::BPF_PROG_TYPE_NETFILTER));
+        assert!(eq3_def_const(&target::bpf::BPF_ANY, &native::bpf::BPF_ANY, &libc::bpf::BPF_ANY));
+        assert!(eq3_def_const(&target::bpf::BPF_NOEXIST, &native::bpf::BPF_NOEXIST, &libc::bpf::BPF_NOEXIST));
+        assert!(eq3_def_const(&target::bpf::BPF_EXIST, &native::bpf::BPF_EXIST, &libc::bpf::BPF_EXIST));
+        assert!(eq3_def_const(&target::bpf::BPF_F_LOCK, &native::bpf::BPF_F_LOCK, &libc::bpf::BPF_F_LOCK));
+        assert!(eq3_def_const(&target::bpf::BPF_F_NO_PREALLOC, &native::bpf::BPF_F_NO_PREALLOC, &libc::bpf::BPF_F_NO_PREALLOC));
+        assert!(eq3_def_const(&target::bpf::BPF_F_NO_COMMON_LRU, &native::bpf::BPF_F_NO_COMMON_LRU, &libc::bpf::BPF_F_NO_COMMON_LRU));
+        assert!(eq3_def_const(&target::bpf::BPF_F_NUMA_NODE, &native::bpf::BPF_F_NUMA_NODE, &libc::bpf::BPF_F_NUMA_NODE));
+        assert!(eq3_def_const(&target::bpf::BPF_F_RDONLY, &native::bpf::BPF_F_RDONLY, &libc::bpf::BPF_F_RDONLY));
+        assert!(eq3_def_const(&target::bpf::BPF_F_WRONLY, &native::bpf::BPF_F_WRONLY, &libc::bpf::BPF_F_WRONLY));
+        assert!(eq3_def_const(&target::bpf::BPF_F_STACK_BUILD_ID, &native::bpf::BPF_F_STACK_BUILD_ID, &libc::bpf::BPF_F_STACK_BUILD_ID));
+        assert!(eq3_def_const(&target::bpf::BPF_F_ZERO_SEED, &native::bpf::BPF_F_ZERO_SEED, &libc::bpf::BPF_F_ZERO_SEED));
+        assert!(eq3_def_const(&target::bpf::BPF_F_RDONLY_PROG, &native::bpf::BPF_F_RDONLY_PROG, &libc::bpf::BPF_F_RDONLY_PROG));
+        assert!(eq3_def_const(&target::bpf::BPF_F_WRONLY_PROG, &native::bpf::BPF_F_WRONLY_PROG, &libc::bpf::BPF_F_WRONLY_PROG));
+        assert!(eq3_def_const(&target::bpf::BPF_F_CLONE, &native::bpf::BPF_F_CLONE, &libc::bpf::BPF_F_CLONE));
+        assert!(eq3_def_const(&target::bpf::BPF_F_MMAPABLE, &native::bpf::BPF_F_MMAPABLE, &libc::bpf::BPF_F_MMAPABLE));
+        assert!(eq3_def_const(&target::bpf::BPF_F_PRESERVE_ELEMS, &native::bpf::BPF_F_PRESERVE_ELEMS, &libc::bpf::BPF_F_PRESERVE_ELEMS));
+        assert!(eq3_def_const(&target::bpf::BPF_F_INNER_MAP, &native::bpf::BPF_F_INNER_MAP, &libc::bpf::BPF_F_INNER_MAP));
+        assert!(eq3_def_const(&target::bpf::BPF_F_LINK, &native::bpf::BPF_F_LINK, &libc::bpf::BPF_F_LINK));
+        assert!(eq3_def_const(&target::bpf::BPF_F_PATH_FD, &native::bpf::BPF_F_PATH_FD, &libc::bpf::BPF_F_PATH_FD));
+        assert!(eq3_def_const(&target::bpf::BPF_F_STRICT_ALIGNMENT, &native::bpf::BPF_F_STRICT_ALIGNMENT, &libc::bpf::BPF_F_STRICT_ALIGNMENT));
+        assert!(eq3_def_const(&target::bpf::BPF_F_ANY_ALIGNMENT, &native::bpf::BPF_F_ANY_ALIGNMENT, &libc::bpf::BPF_F_ANY_ALIGNMENT));
+        assert!(eq3_def_const(&target::bpf::BPF_F_TEST_RND_HI32, &native::bpf::BPF_F_TEST_RND_HI32, &libc::bpf::BPF_F_TEST_RND_HI32));
+        assert!(eq3_def_const(&target::bpf::BPF_F_TEST_STATE_FREQ, &native::bpf::BPF_F_TEST_STATE_FREQ, &libc::bpf::BPF_F_TEST_STATE_FREQ));
+        assert!(eq3_def_const(&target::bpf::BPF_F_SLEEPABLE, &native::bpf::BPF_F_SLEEPABLE, &libc::bpf::BPF_F_SLEEPABLE));
+        assert!(eq3_def_const(&target::bpf::BPF_F_XDP_HAS_FRAGS, &native::bpf::BPF_F_XDP_HAS_FRAGS, &libc::bpf::BPF_F_XDP_HAS_FRAGS));
+        assert!(eq3_def_const(&target::bpf::BPF_F_XDP_DEV_BOUND_ONLY, &native::bpf::BPF_F_XDP_DEV_BOUND_ONLY, &libc::bpf::BPF_F_XDP_DEV_BOUND_ONLY));
+        assert!(eq3_def_const(&target::bpf::BPF_F_ALLOW_OVERRIDE, &native::bpf::BPF_F_ALLOW_OVERRIDE, &libc::bpf::BPF_F_ALLOW_OVERRIDE));
+        assert!(eq3_def_const(&target::bpf::BPF_F_ALLOW_MULTI, &native::bpf::BPF_F_ALLOW_MULTI, &libc::bpf::BPF_F_ALLOW_MULTI));
+        assert!(eq3_def_const(&target::bpf::BPF_F_REPLACE, &native::bpf::BPF_F_REPLACE, &libc::bpf::BPF_F_REPLACE));
+        assert!(eq3_def_const(&target::bpf::BPF_STATS_RUN_TIME, &native::bpf::BPF_STATS_RUN_TIME, &libc::bpf::BPF_STATS_RUN_TIME));
+    }
+}
@@ -0,0 +1,70 @@
+//! # User Register Sets
+//!
+//! The register sets of a task as exposed via `ptrace(2)` and as stored in
+//! the notes of core-dumps. The layouts follow `<sys/user.h>` of the C
+//! library, which matches the layouts used by the kernel.
+//!
+//! x32 tasks use the 64-bit register sets of x86_64.
+
+use super::abi;
+
+/// General purpose registers (`NT_PRSTATUS`, `PTRACE_GETREGS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRegsStruct {
+    pub r15: abi::U64,
+    pub r14: abi::U64,
+    pub r13: abi::U64,
+    pub r12: abi::U64,
+    pub rbp: abi::U64,
+    pub rbx: abi::U64,
+    pub r11: abi::U64,
+    pub r10: abi::U64,
+    pub r9: abi::U64,
+    pub r8: abi::U64,
+    pub rax: abi::U64,
+    pub rcx: abi::U64,
+    pub rdx: abi::U64,
+    pub rsi: abi::U64,
+    pub rdi: abi::U64,
+    pub orig_rax: abi::U64,
+    pub rip: abi::U64,
+    pub cs: abi::U64,
+    pub eflags: abi::U64,
+    pub rsp: abi::U64,
+    pub ss: abi::U64,
+    pub fs_base: abi::U64,
+    pub gs_base: abi::U64,
+    pub ds: abi::U64,
+    pub es: abi::U64,
+    pub fs: abi::U64,
+    pub gs: abi::U64,
+}
+
+/// Floating point and SSE registers in `FXSAVE` layout (`NT_PRFPREG`,
+/// `PTRACE_GETFPREGS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct UserFpregsStruct {
+    pub cwd: abi::U16,
+    pub swd: abi::U16,
+    pub ftw: abi::U16,
+    pub fop: abi::U16,
+    pub rip: abi::U64,
+    pub rdp: abi::U64,
+    pub mxcsr: abi::U32,
+    pub mxcr_mask: abi::U32,
+    pub st_space: [abi::U32; 32],
+    pub xmm_space: [abi::U32; 64],
+    pub padding: [abi::U32; 24],
+}
+
+pub type ElfGreg = abi::U64;
+pub const ELF_NGREG: usize = 27;
+pub type ElfGregset = [ElfGreg; ELF_NGREG];
+pub type ElfFpregset = UserFpregsStruct;
+
+// Core-dumps of x32 tasks use the compat layout of the kernel, which uses
+// 16-bit IDs like x86.
+pub type PrUid = abi::U16;
+pub type PrGid = abi::U16;
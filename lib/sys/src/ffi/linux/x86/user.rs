@@ -0,0 +1,75 @@
+//! # User Register Sets
+//!
+//! The register sets of a task as exposed via `ptrace(2)` and as stored in
+//! the notes of core-dumps. The layouts follow `<sys/user.h>` of the C
+//! library, which matches the layouts used by the kernel.
+
+use super::abi;
+
+/// General purpose registers (`NT_PRSTATUS`, `PTRACE_GETREGS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserRegsStruct {
+    pub ebx: abi::I32,
+    pub ecx: abi::I32,
+    pub edx: abi::I32,
+    pub esi: abi::I32,
+    pub edi: abi::I32,
+    pub ebp: abi::I32,
+    pub eax: abi::I32,
+    pub xds: abi::I32,
+    pub xes: abi::I32,
+    pub xfs: abi::I32,
+    pub xgs: abi::I32,
+    pub orig_eax: abi::I32,
+    pub eip: abi::I32,
+    pub xcs: abi::I32,
+    pub eflags: abi::I32,
+    pub esp: abi::I32,
+    pub xss: abi::I32,
+}
+
+/// Legacy x87 floating point registers (`NT_PRFPREG`,
+/// `PTRACE_GETFPREGS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UserFpregsStruct {
+    pub cwd: abi::I32,
+    pub swd: abi::I32,
+    pub twd: abi::I32,
+    pub fip: abi::I32,
+    pub fcs: abi::I32,
+    pub foo: abi::I32,
+    pub fos: abi::I32,
+    pub st_space: [abi::I32; 20],
+}
+
+/// Extended floating point and SSE registers in `FXSAVE` layout
+/// (`NT_PRXFPREG`, `PTRACE_GETFPXREGS`).
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct UserFpxregsStruct {
+    pub cwd: abi::U16,
+    pub swd: abi::U16,
+    pub twd: abi::U16,
+    pub fop: abi::U16,
+    pub fip: abi::I32,
+    pub fcs: abi::I32,
+    pub foo: abi::I32,
+    pub fos: abi::I32,
+    pub mxcsr: abi::I32,
+    pub reserved: abi::I32,
+    pub st_space: [abi::I32; 32],
+    pub xmm_space: [abi::I32; 32],
+    pub padding: [abi::I32; 56],
+}
+
+pub type ElfGreg = abi::U32;
+pub const ELF_NGREG: usize = 17;
+pub type ElfGregset = [ElfGreg; ELF_NGREG];
+pub type ElfFpregset = UserFpregsStruct;
+pub type ElfFpxregset = UserFpxregsStruct;
+
+// `__kernel_uid_t` and `__kernel_gid_t` are 16-bit on x86.
+pub type PrUid = abi::U16;
+pub type PrGid = abi::U16;
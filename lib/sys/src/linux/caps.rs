@@ -0,0 +1,392 @@
+//! # Capabilities
+//!
+//! This module provides utilities to inspect and modify the capability sets of
+//! Linux tasks. Each task carries five capability sets: the effective,
+//! permitted, inheritable, ambient, and bounding set. Each set is represented
+//! as a bit-mask of capability numbers (see
+//! [`capability`](crate::ffi::linux::native::capability) for the numbers
+//! known to this crate).
+//!
+//! The kernel exposes the capability sets of a task via `capget(2)` and
+//! `capset(2)` (only the effective, permitted, and inheritable sets), as well
+//! as via the `Cap*:` lines in `/proc/<pid>/status` (all sets). This module
+//! can convert from and to both representations.
+
+use crate::ffi::linux::native::capability as ffi;
+
+/// Capability Set
+///
+/// This is a set of capabilities, represented as a 64-bit mask where bit `n`
+/// is set if capability `n` is part of the set. This matches the
+/// representation used by the kernel.
+#[derive(Clone, Copy, Debug, Default, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Set {
+    mask: u64,
+}
+
+/// Capability State
+///
+/// This represents the full capability state of a task, consisting of all five
+/// capability sets.
+#[derive(Clone, Copy, Debug, Default, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct State {
+    /// Capabilities that are used for permission checks.
+    pub effective: Set,
+    /// Limiting superset of the effective capabilities.
+    pub permitted: Set,
+    /// Capabilities preserved across `execve(2)`, if the executed file
+    /// carries them in its inheritable file capabilities.
+    pub inheritable: Set,
+    /// Capabilities preserved across `execve(2)` of unprivileged programs.
+    pub ambient: Set,
+    /// Limiting superset of the capabilities a task can gain via
+    /// `execve(2)`.
+    pub bounding: Set,
+}
+
+/// Errors of the `/proc/<pid>/status` parser.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// A capability line is missing from the input.
+    Missing,
+    /// A capability line is present multiple times.
+    Duplicate,
+    /// A capability line does not carry a valid hexadecimal mask.
+    Invalid,
+}
+
+impl Set {
+    /// Create a new empty capability set.
+    pub const fn new() -> Self {
+        Self::with_mask(0)
+    }
+
+    /// Create a new capability set from its bit-mask.
+    pub const fn with_mask(mask: u64) -> Self {
+        Self { mask }
+    }
+
+    /// Create a new capability set with all capabilities known to this crate
+    /// (i.e., capabilities `0` to `CAP_LAST_CAP`).
+    ///
+    /// Note that the running kernel might support more or less capabilities.
+    pub const fn full() -> Self {
+        Self::with_mask(u64::MAX >> (63 - ffi::CAP_LAST_CAP))
+    }
+
+    /// Create a new capability set from the halves used by `capget(2)` and
+    /// `capset(2)`.
+    pub const fn with_halves(lo: u32, hi: u32) -> Self {
+        Self::with_mask((lo as u64) | ((hi as u64) << 32))
+    }
+
+    /// Return the bit-mask of this capability set.
+    pub const fn mask(self) -> u64 {
+        self.mask
+    }
+
+    /// Return the lower and upper halves of this capability set, as used by
+    /// `capget(2)` and `capset(2)`.
+    pub const fn halves(self) -> (u32, u32) {
+        (self.mask as u32, (self.mask >> 32) as u32)
+    }
+
+    /// Parse a capability set from its hexadecimal representation, as used by
+    /// `/proc/<pid>/status`.
+    ///
+    /// Leading and trailing whitespace is ignored, but the string must
+    /// otherwise consist of 1 to 16 hexadecimal digits.
+    pub fn parse_hex(v: &[u8]) -> Option<Self> {
+        let v = v.trim_ascii();
+        if v.is_empty() || v.len() > 16 {
+            return None;
+        }
+
+        let mut mask = 0u64;
+        for c in v {
+            mask = (mask << 4) | ((*c as char).to_digit(16)? as u64);
+        }
+
+        Some(Self::with_mask(mask))
+    }
+
+    /// Check whether the capability set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.mask == 0
+    }
+
+    /// Check whether the capability set contains the given capability.
+    ///
+    /// Capability numbers that cannot be represented are never contained in
+    /// any set.
+    pub const fn contains(self, cap: u8) -> bool {
+        cap < 64 && (self.mask & (1 << cap)) != 0
+    }
+
+    /// Check whether this capability set is a subset of `other`.
+    pub const fn is_subset(self, other: Self) -> bool {
+        (self.mask & !other.mask) == 0
+    }
+
+    /// Add the given capability to the set. Returns `true` if it was not
+    /// part of the set before.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `cap` cannot be represented in a capability set.
+    pub fn insert(&mut self, cap: u8) -> bool {
+        assert!(cap < 64);
+        let r = !self.contains(cap);
+        self.mask |= 1 << cap;
+        r
+    }
+
+    /// Remove the given capability from the set. Returns `true` if it was
+    /// part of the set before.
+    pub fn remove(&mut self, cap: u8) -> bool {
+        let r = self.contains(cap);
+        if r {
+            self.mask &= !(1 << cap);
+        }
+        r
+    }
+
+    /// Return the union of both sets.
+    pub const fn union(self, other: Self) -> Self {
+        Self::with_mask(self.mask | other.mask)
+    }
+
+    /// Return the intersection of both sets.
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::with_mask(self.mask & other.mask)
+    }
+
+    /// Return all capabilities of this set, which are not part of `other`.
+    pub const fn difference(self, other: Self) -> Self {
+        Self::with_mask(self.mask & !other.mask)
+    }
+
+    /// Iterate all capabilities of this set in ascending order.
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        (0..64u8).filter(move |v| self.contains(*v))
+    }
+}
+
+impl core::iter::FromIterator<u8> for Set {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for cap in iter {
+            set.insert(cap);
+        }
+        set
+    }
+}
+
+impl State {
+    /// Create a new capability state with all sets empty.
+    pub const fn new() -> Self {
+        Self {
+            effective: Set::new(),
+            permitted: Set::new(),
+            inheritable: Set::new(),
+            ambient: Set::new(),
+            bounding: Set::new(),
+        }
+    }
+
+    /// Parse the capability state from the content of `/proc/<pid>/status`.
+    ///
+    /// All five `Cap*:` lines must be present exactly once. Any other line is
+    /// ignored.
+    pub fn parse_status(data: &[u8]) -> Result<Self, Error> {
+        let mut sets: [Option<Set>; 5] = [None; 5];
+
+        for line in data.split(|v| *v == b'\n') {
+            let idx = if let Some(v) = line.strip_prefix(b"CapInh:") {
+                (0, v)
+            } else if let Some(v) = line.strip_prefix(b"CapPrm:") {
+                (1, v)
+            } else if let Some(v) = line.strip_prefix(b"CapEff:") {
+                (2, v)
+            } else if let Some(v) = line.strip_prefix(b"CapBnd:") {
+                (3, v)
+            } else if let Some(v) = line.strip_prefix(b"CapAmb:") {
+                (4, v)
+            } else {
+                continue;
+            };
+
+            if sets[idx.0].is_some() {
+                return Err(Error::Duplicate);
+            }
+            sets[idx.0] = Some(Set::parse_hex(idx.1).ok_or(Error::Invalid)?);
+        }
+
+        match sets {
+            [Some(inh), Some(prm), Some(eff), Some(bnd), Some(amb)] => Ok(Self {
+                effective: eff,
+                permitted: prm,
+                inheritable: inh,
+                ambient: amb,
+                bounding: bnd,
+            }),
+            _ => Err(Error::Missing),
+        }
+    }
+
+    /// Create the capability data for `capget(2)` and `capset(2)` with
+    /// `_LINUX_CAPABILITY_VERSION_3`. This only carries the effective,
+    /// permitted, and inheritable sets.
+    pub const fn user_data(&self) -> [ffi::CapUserData; 2] {
+        let eff = self.effective.halves();
+        let prm = self.permitted.halves();
+        let inh = self.inheritable.halves();
+
+        [
+            ffi::CapUserData {
+                effective: eff.0,
+                permitted: prm.0,
+                inheritable: inh.0,
+            },
+            ffi::CapUserData {
+                effective: eff.1,
+                permitted: prm.1,
+                inheritable: inh.1,
+            },
+        ]
+    }
+
+    /// Update the effective, permitted, and inheritable sets from capability
+    /// data returned by `capget(2)` with `_LINUX_CAPABILITY_VERSION_3`. The
+    /// ambient and bounding sets are left unchanged.
+    pub const fn set_user_data(&mut self, data: &[ffi::CapUserData; 2]) {
+        self.effective = Set::with_halves(data[0].effective, data[1].effective);
+        self.permitted = Set::with_halves(data[0].permitted, data[1].permitted);
+        self.inheritable = Set::with_halves(data[0].inheritable, data[1].inheritable);
+    }
+
+    /// Check whether the capability state satisfies the invariants enforced
+    /// by the kernel. That is, the effective set must be a subset of the
+    /// permitted set, and the ambient set must be a subset of both the
+    /// permitted and inheritable sets.
+    pub const fn is_consistent(&self) -> bool {
+        self.effective.is_subset(self.permitted)
+        && self.ambient.is_subset(self.permitted.intersection(self.inheritable))
+    }
+
+    /// Restrict all capability sets to the capabilities in `keep`.
+    ///
+    /// This is the state a task should transition to when dropping
+    /// privileges. Since capabilities can only be removed, the resulting
+    /// state is consistent, if the original state was consistent.
+    pub const fn restrict(&mut self, keep: Set) {
+        self.effective = self.effective.intersection(keep);
+        self.permitted = self.permitted.intersection(keep);
+        self.inheritable = self.inheritable.intersection(keep);
+        self.ambient = self.ambient.intersection(keep);
+        self.bounding = self.bounding.intersection(keep);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Verify basic set operations.
+    #[test]
+    fn set_basic() {
+        let mut s = Set::new();
+        assert!(s.is_empty());
+        assert!(s.insert(ffi::CAP_SYS_ADMIN));
+        assert!(!s.insert(ffi::CAP_SYS_ADMIN));
+        assert!(s.insert(ffi::CAP_CHECKPOINT_RESTORE));
+        assert!(s.contains(ffi::CAP_SYS_ADMIN));
+        assert!(!s.contains(ffi::CAP_CHOWN));
+        assert!(!s.contains(200));
+        assert_eq!(s.mask(), (1 << 21) | (1 << 40));
+        assert_eq!(s.halves(), (1 << 21, 1 << 8));
+        assert_eq!(Set::with_halves(1 << 21, 1 << 8), s);
+        assert_eq!(s.iter().collect::<alloc::vec::Vec<_>>(), [21, 40]);
+
+        let t: Set = [ffi::CAP_CHOWN, ffi::CAP_SYS_ADMIN].into_iter().collect();
+        assert_eq!(s.union(t).mask(), (1 << 0) | (1 << 21) | (1 << 40));
+        assert_eq!(s.intersection(t).mask(), 1 << 21);
+        assert_eq!(s.difference(t).mask(), 1 << 40);
+        assert!(s.intersection(t).is_subset(s));
+        assert!(!s.is_subset(t));
+
+        assert!(s.remove(ffi::CAP_SYS_ADMIN));
+        assert!(!s.remove(ffi::CAP_SYS_ADMIN));
+        assert!(!s.remove(200));
+        assert_eq!(s.mask(), 1 << 40);
+
+        assert_eq!(Set::full().mask(), 0x1ff_ffff_ffff);
+    }
+
+    // Verify parsing of hexadecimal masks.
+    #[test]
+    fn set_parse() {
+        assert_eq!(Set::parse_hex(b"000001ffffffffff").unwrap(), Set::full());
+        assert_eq!(Set::parse_hex(b"\t0000000000000000").unwrap(), Set::new());
+        assert_eq!(Set::parse_hex(b"A0").unwrap().mask(), 0xa0);
+        assert!(Set::parse_hex(b"").is_none());
+        assert!(Set::parse_hex(b"0x10").is_none());
+        assert!(Set::parse_hex(b"00000000000000000").is_none());
+    }
+
+    // Parse a synthetic status file and verify the state is correctly
+    // extracted and converted.
+    #[test]
+    fn state_status() {
+        let status = b"Name:\tfoo\n\
+            CapInh:\t0000000000000000\n\
+            CapPrm:\t0000000000200400\n\
+            CapEff:\t0000000000000400\n\
+            CapBnd:\t000001ffffffffff\n\
+            CapAmb:\t0000000000000000\n\
+            NoNewPrivs:\t0\n";
+
+        let mut state = State::parse_status(status).unwrap();
+        assert_eq!(state.effective.mask(), 1 << ffi::CAP_NET_BIND_SERVICE);
+        assert_eq!(state.permitted.mask(), 0x200400);
+        assert_eq!(state.inheritable, Set::new());
+        assert_eq!(state.ambient, Set::new());
+        assert_eq!(state.bounding, Set::full());
+        assert!(state.is_consistent());
+
+        let data = state.user_data();
+        assert_eq!(data[0].effective, 0x400);
+        assert_eq!(data[0].permitted, 0x200400);
+        assert_eq!(data[1].permitted, 0);
+        let mut other = State::new();
+        other.set_user_data(&data);
+        assert_eq!(other.permitted, state.permitted);
+
+        state.restrict(Set::with_mask(0x200000));
+        assert!(state.effective.is_empty());
+        assert_eq!(state.permitted.mask(), 0x200000);
+        assert_eq!(state.bounding.mask(), 0x200000);
+        assert!(state.is_consistent());
+
+        state.ambient.insert(ffi::CAP_SYS_ADMIN);
+        assert!(!state.is_consistent());
+
+        assert_eq!(State::parse_status(b"CapInh:\t0\n"), Err(Error::Missing));
+        assert_eq!(
+            State::parse_status(b"CapInh:\t0\nCapInh:\t0\n"),
+            Err(Error::Duplicate),
+        );
+        assert_eq!(State::parse_status(b"CapInh:\tzz\n"), Err(Error::Invalid));
+    }
+
+    // Parse the status file of the running test.
+    #[test]
+    fn state_self() {
+        let status = std::fs::read("/proc/self/status").unwrap();
+        let state = State::parse_status(&status).unwrap();
+        assert!(state.is_consistent());
+    }
+}
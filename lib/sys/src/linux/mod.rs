@@ -4,3 +4,15 @@
 //! kernel and common across all Linux systems.
 
 pub use crate::ffi::linux as ffi;
+
+#[cfg(any(
+    target_arch = "aarch64",
+    all(target_arch = "arm", target_endian = "little"),
+    target_arch = "loongarch64",
+    all(target_arch = "powerpc64", target_endian = "big"),
+    target_arch = "riscv64",
+    target_arch = "s390x",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+pub mod caps;
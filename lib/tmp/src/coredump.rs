@@ -0,0 +1,773 @@
+//! Core-Dump Reader
+//!
+//! Linux writes core-dumps of crashed processes as ELF files of type
+//! `ET_CORE`. The memory of the process is stored in `PT_LOAD` segments,
+//! while information about the process and its threads is stored in a
+//! `PT_NOTE` segment.
+//!
+//! This module provides a reader for such core-dumps on top of
+//! [`elf::ElfFile`], which parses the ELF headers, and [`elf::note`], which
+//! walks the notes. Only the core-specific notes are decoded here. Like the
+//! ELF reader, it does not require the core-dump to originate from the host
+//! platform. The register sets of the supported platforms are exposed via
+//! the foreign-platform definitions of [`sys::ffi::linux`], so a 32-bit x86
+//! core-dump can be inspected on a 64-bit host, and vice versa. Platforms
+//! without such definitions, including byte orders other than the one of
+//! the respective platform module, are reported as [`Arch::Other`].
+//!
+//! The reader lives in `tmp` rather than next to the platform definitions
+//! in `sys`, since `tmp` depends on `sys` and provides the ELF reader and
+//! the I/O abstractions it is built on.
+
+use alloc::vec::Vec;
+use sys::ffi::elf::elf64;
+use sys::ffi::linux;
+
+use crate::{elf, io};
+
+// Core-dump note types are identical on all platforms.
+const NT_PRSTATUS: u32 = linux::x86_64::elfcore::NT_PRSTATUS.to_native();
+const NT_AUXV: u32 = linux::x86_64::elfcore::NT_AUXV.to_native();
+const NT_FILE: u32 = linux::x86_64::elfcore::NT_FILE.to_native();
+const NT_SIGINFO: u32 = linux::x86_64::elfcore::NT_SIGINFO.to_native();
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// The ELF file is malformed.
+    Elf(elf::Error),
+    /// The machine architecture has no register definitions.
+    Unsupported,
+    /// The ELF file is not a core-dump.
+    NotCore,
+    /// The core-dump contains invalid segments or note contents.
+    Malformed,
+    /// The virtual address is not covered by any `PT_LOAD` segment.
+    Unmapped,
+}
+
+/// Machine architecture of a core-dump.
+///
+/// Only architectures with register definitions in [`sys::ffi::linux`] are
+/// decoded. Any other architecture is reported with its ELF machine number.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Arch {
+    X86,
+    X86_64,
+    /// The x32 ABI, i.e., `EM_X86_64` with `ELFCLASS32`.
+    X32,
+    Aarch64,
+    Other(u16),
+}
+
+/// Thread status as stored in `NT_PRSTATUS` notes, using the foreign
+/// definitions of the respective platform.
+#[derive(Clone, Copy, Debug)]
+pub enum Prstatus {
+    X86(linux::x86::elfcore::ElfPrstatus),
+    X86_64(linux::x86_64::elfcore::ElfPrstatus),
+    X32(linux::x32::elfcore::ElfPrstatus),
+    Aarch64(linux::aarch64::elfcore::ElfPrstatus),
+}
+
+/// File mapping as stored in `NT_FILE` notes.
+#[derive(Clone, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Mapping {
+    pub start: u64,
+    pub end: u64,
+    /// File offset in bytes (the note stores it in units of pages).
+    pub offset: u64,
+    pub path: Vec<u8>,
+}
+
+/// Signal information as stored in `NT_SIGINFO` notes.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Siginfo {
+    pub si_signo: i32,
+    pub si_errno: i32,
+    pub si_code: i32,
+}
+
+/// Reader for ELF core-dumps.
+pub struct Core<'read, Read: ?Sized> {
+    file: elf::ElfFile<'read, Read>,
+    arch: Arch,
+}
+
+/// Iterator over all notes of all `PT_NOTE` segments of a core-dump.
+pub struct Notes<'core, 'read, Read: ?Sized> {
+    core: &'core Core<'read, Read>,
+    segment: usize,
+    notes: Option<elf::note::Notes<'read>>,
+}
+
+impl core::convert::From<elf::Error> for Error {
+    fn from(v: elf::Error) -> Self {
+        Self::Elf(v)
+    }
+}
+
+impl Prstatus {
+    /// Return the platform independent part of the thread status. This
+    /// yields `(pid, cursig)`.
+    pub fn pid_cursig(&self) -> (i32, i16) {
+        match self {
+            Self::X86(v) => (v.common.pr_pid.to_native(), v.common.pr_cursig.to_native()),
+            Self::X86_64(v) => (v.common.pr_pid.to_native(), v.common.pr_cursig.to_native()),
+            Self::X32(v) => (v.common.pr_pid.to_native(), v.common.pr_cursig.to_native()),
+            Self::Aarch64(v) => (v.common.pr_pid.to_native(), v.common.pr_cursig.to_native()),
+        }
+    }
+
+    /// Return the thread ID of the thread.
+    pub fn pid(&self) -> i32 {
+        self.pid_cursig().0
+    }
+
+    /// Return the signal that caused the dump, if any.
+    pub fn cursig(&self) -> i16 {
+        self.pid_cursig().1
+    }
+
+    /// Return the instruction pointer of the thread.
+    pub fn ip(&self) -> u64 {
+        match self {
+            Self::X86(v) => v.pr_reg[12].to_native() as u64,
+            Self::X86_64(v) => v.pr_reg[16].to_native(),
+            Self::X32(v) => v.pr_reg[16].to_native(),
+            Self::Aarch64(v) => v.pr_reg[32].to_native(),
+        }
+    }
+
+    /// Return the stack pointer of the thread.
+    pub fn sp(&self) -> u64 {
+        match self {
+            Self::X86(v) => v.pr_reg[15].to_native() as u64,
+            Self::X86_64(v) => v.pr_reg[19].to_native(),
+            Self::X32(v) => v.pr_reg[19].to_native(),
+            Self::Aarch64(v) => v.pr_reg[31].to_native(),
+        }
+    }
+}
+
+// Copy a foreign structure from the start of a note descriptor.
+//
+// Safety: `T` must be valid for any bit pattern.
+unsafe fn read_struct<T: Copy>(desc: &[u8]) -> Result<T, Error> {
+    if desc.len() < size_of::<T>() {
+        return Err(Error::Malformed);
+    }
+
+    // SAFETY: The size was verified above and the caller guarantees that any
+    //     bit pattern is a valid `T`.
+    Ok(unsafe { core::ptr::read_unaligned(desc.as_ptr() as *const T) })
+}
+
+impl<'read, Read: ?Sized + io::map::Read> Core<'read, Read> {
+    /// Parse the ELF header and program headers of a core-dump.
+    pub fn with(read: &'read Read) -> Result<Self, Error> {
+        let file = elf::ElfFile::with(read)?;
+        let encoding = file.encoding();
+        let ehdr = file.header();
+
+        if ehdr.e_type != elf64::ET_CORE {
+            return Err(Error::NotCore);
+        }
+
+        // The platform modules only cover the native byte order of each
+        // platform, so anything else cannot be decoded.
+        let arch = match (ehdr.e_machine, encoding.class, encoding.big_endian) {
+            (elf64::EM_386, elf::Class::Elf32, false) => Arch::X86,
+            (elf64::EM_X86_64, elf::Class::Elf64, false) => Arch::X86_64,
+            (elf64::EM_X86_64, elf::Class::Elf32, false) => Arch::X32,
+            (elf64::EM_AARCH64, elf::Class::Elf64, false) => Arch::Aarch64,
+            (v, _, _) => Arch::Other(v),
+        };
+
+        for phdr in file.program_headers() {
+            if phdr.p_offset.checked_add(phdr.p_filesz).is_none()
+                || phdr.p_vaddr.checked_add(phdr.p_memsz).is_none()
+                || (phdr.p_type == elf64::PT_LOAD && phdr.p_filesz > phdr.p_memsz)
+            {
+                return Err(Error::Malformed);
+            }
+        }
+
+        Ok(Self {
+            file: file,
+            arch: arch,
+        })
+    }
+
+    /// Return the underlying ELF file, which provides the ELF header and
+    /// all program headers.
+    pub fn file(&self) -> &elf::ElfFile<'read, Read> {
+        &self.file
+    }
+
+    /// Return the class and data encoding of the core-dump.
+    pub fn encoding(&self) -> elf::Encoding {
+        self.file.encoding()
+    }
+
+    /// Return the machine architecture of the core-dump.
+    pub fn arch(&self) -> Arch {
+        self.arch
+    }
+
+    /// Iterate all notes of the core-dump.
+    pub fn notes(&self) -> Notes<'_, 'read, Read> {
+        Notes {
+            core: self,
+            segment: 0,
+            notes: None,
+        }
+    }
+
+    /// Collect the status of all threads from the `NT_PRSTATUS` notes. The
+    /// first entry belongs to the thread that caused the dump.
+    ///
+    /// Fails with [`Error::Unsupported`] if the architecture is not known.
+    pub fn threads(&self) -> Result<Vec<Prstatus>, Error> {
+        let mut r = Vec::new();
+        for note in self.notes() {
+            let note = note?;
+            if !note.is(b"CORE", NT_PRSTATUS) {
+                continue;
+            }
+
+            // SAFETY: The register sets are plain integers of the foreign
+            //     platform, which are valid for any bit pattern.
+            r.push(unsafe {
+                match self.arch {
+                    Arch::X86 => Prstatus::X86(read_struct(note.desc())?),
+                    Arch::X86_64 => Prstatus::X86_64(read_struct(note.desc())?),
+                    Arch::X32 => Prstatus::X32(read_struct(note.desc())?),
+                    Arch::Aarch64 => Prstatus::Aarch64(read_struct(note.desc())?),
+                    Arch::Other(_) => return Err(Error::Unsupported),
+                }
+            });
+        }
+        Ok(r)
+    }
+
+    // Find the first note of the given owner and type.
+    fn find(&self, name: &[u8], n_type: u32) -> Result<Option<elf::note::Note<'read>>, Error> {
+        for note in self.notes() {
+            let note = note?;
+            if note.is(name, n_type) {
+                return Ok(Some(note));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Return the auxiliary vector from the `NT_AUXV` note as pairs of type
+    /// and value. The terminating `AT_NULL` entry is not included.
+    pub fn auxv(&self) -> Result<Option<Vec<(u64, u64)>>, Error> {
+        let Some(note) = self.find(b"CORE", NT_AUXV)? else {
+            return Ok(None);
+        };
+
+        let encoding = self.encoding();
+        let n = encoding.word_size();
+        let mut r = Vec::new();
+        for pair in note.desc().chunks_exact(2 * n) {
+            let (k, v) = (encoding.word(pair), encoding.word(&pair[n..]));
+            if k == 0 {
+                break;
+            }
+            r.push((k, v));
+        }
+        Ok(Some(r))
+    }
+
+    /// Return the file mappings from the `NT_FILE` note.
+    pub fn mappings(&self) -> Result<Option<Vec<Mapping>>, Error> {
+        let Some(note) = self.find(b"CORE", NT_FILE)? else {
+            return Ok(None);
+        };
+
+        let encoding = self.encoding();
+        let n = encoding.word_size();
+        let desc = note.desc();
+        if desc.len() < 2 * n {
+            return Err(Error::Malformed);
+        }
+
+        let count = encoding.word(desc);
+        let page_size = encoding.word(&desc[n..]);
+        let table = usize::try_from(count)
+            .ok()
+            .and_then(|v| v.checked_mul(3 * n))
+            .and_then(|v| v.checked_add(2 * n))
+            .filter(|v| *v <= desc.len())
+            .ok_or(Error::Malformed)?;
+        let mut names = desc[table..].split(|v| *v == 0);
+
+        let mut r = Vec::new();
+        for entry in desc[2 * n..table].chunks_exact(3 * n) {
+            let start = encoding.word(entry);
+            let end = encoding.word(&entry[n..]);
+            let offset = encoding.word(&entry[2 * n..])
+                .checked_mul(page_size)
+                .ok_or(Error::Malformed)?;
+            let path = names.next().ok_or(Error::Malformed)?;
+            r.push(Mapping { start, end, offset, path: path.to_vec() });
+        }
+        Ok(Some(r))
+    }
+
+    /// Return the signal information from the `NT_SIGINFO` note.
+    pub fn siginfo(&self) -> Result<Option<Siginfo>, Error> {
+        let Some(note) = self.find(b"CORE", NT_SIGINFO)? else {
+            return Ok(None);
+        };
+
+        let encoding = self.encoding();
+        let desc = note.desc();
+        if desc.len() < 12 {
+            return Err(Error::Malformed);
+        }
+        Ok(Some(Siginfo {
+            si_signo: encoding.u32(&desc[0..]) as i32,
+            si_errno: encoding.u32(&desc[4..]) as i32,
+            si_code: encoding.u32(&desc[8..]) as i32,
+        }))
+    }
+
+    /// Read memory of the dumped process at the given virtual address.
+    ///
+    /// Memory covered by a `PT_LOAD` segment but not stored in the file
+    /// (i.e., beyond `p_filesz`) reads as zero. If any part of the range is
+    /// not covered by a `PT_LOAD` segment, this fails with
+    /// [`Error::Unmapped`].
+    pub fn read_memory(&self, mut vaddr: u64, mut data: &mut [u8]) -> Result<(), Error> {
+        while !data.is_empty() {
+            let phdr = self.file.program_headers()
+                .filter(|v| v.p_type == elf64::PT_LOAD)
+                .find(|v| vaddr >= v.p_vaddr && vaddr - v.p_vaddr < v.p_memsz)
+                .ok_or(Error::Unmapped)?;
+
+            let rel = vaddr - phdr.p_vaddr;
+            let avail = phdr.p_memsz - rel;
+            let n = core::cmp::min(avail, data.len() as u64) as usize;
+            let (now, rest) = data.split_at_mut(n);
+
+            let file = core::cmp::min(
+                phdr.p_filesz.saturating_sub(rel),
+                n as u64,
+            ) as usize;
+            if file > 0 {
+                let off = phdr.p_offset.checked_add(rel).ok_or(Error::Malformed)?;
+                now[..file].copy_from_slice(self.file.map(off, file as u64)?);
+            }
+            now[file..].fill(0);
+
+            vaddr = vaddr.checked_add(n as u64).ok_or(Error::Unmapped)?;
+            data = rest;
+        }
+        Ok(())
+    }
+}
+
+impl<'core, 'read, Read: ?Sized + io::map::Read> Notes<'core, 'read, Read> {
+    fn next_note(&mut self) -> Result<Option<elf::note::Note<'read>>, Error> {
+        let file = &self.core.file;
+
+        loop {
+            if let Some(note) = self.notes.as_mut().and_then(|v| v.next()) {
+                return Ok(Some(note?));
+            }
+
+            let Some(phdr) = file.program_headers().get(self.segment) else {
+                self.notes = None;
+                return Ok(None);
+            };
+            self.segment += 1;
+
+            // Core-dump notes are always 4-byte aligned, regardless of the
+            // ELF class and the alignment of the segment.
+            if phdr.p_type == elf64::PT_NOTE {
+                let data = file.segment_data(&phdr)?;
+                self.notes = Some(elf::note::Notes::with(file.encoding(), data, 4)?);
+            }
+        }
+    }
+}
+
+impl<'core, 'read, Read: ?Sized + io::map::Read> Iterator for Notes<'core, 'read, Read> {
+    type Item = Result<elf::note::Note<'read>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_note() {
+            Ok(v) => v.map(Ok),
+            Err(e) => {
+                // Stop iteration after the first error.
+                self.segment = usize::MAX;
+                self.notes = None;
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::elf::build::{Builder, Contents, Out, Segment};
+
+    // Create a synthetic core-dump with a single thread, auxiliary vector,
+    // file mapping, signal information, and one `PT_LOAD` segment at
+    // `0x10000` with 16 bytes of file content and 32 bytes of memory. The
+    // instruction pointer of the thread is `0x10004`.
+    fn fixture(encoding: elf::Encoding, machine: u16) -> Vec<u8> {
+        let c64 = encoding.class == elf::Class::Elf64;
+        let (prstatus, pid, ip, ipsize) = match machine {
+            elf64::EM_386 => (144, 24, 72 + 12 * 4, 4),
+            elf64::EM_X86_64 if !c64 => (296, 24, 72 + 16 * 8, 8),
+            elf64::EM_X86_64 => (336, 32, 112 + 16 * 8, 8),
+            _ => (392, 32, 112 + 32 * 8, 8),
+        };
+        let put = |desc: &mut Vec<u8>, off: usize, size: usize, v: u64| {
+            let mut out = Out::new(encoding);
+            match size {
+                2 => out.u16(v as u16),
+                4 => out.u32(v as u32),
+                _ => out.u64(v),
+            }
+            desc[off..off + size].copy_from_slice(&out.buf);
+        };
+
+        let mut notes = Out::new(encoding);
+
+        let mut desc = alloc::vec![0u8; prstatus];
+        put(&mut desc, 12, 2, 6);
+        put(&mut desc, pid, 4, 71);
+        put(&mut desc, ip, ipsize, 0x10004);
+        notes.note(4, b"CORE", 1, &desc);
+
+        let mut desc = Out::new(encoding);
+        for v in [6, 4096, 9, 0x10000, 0, 0] {
+            desc.word(v).unwrap();
+        }
+        notes.note(4, b"CORE", 6, &desc.buf);
+
+        let mut desc = Out::new(encoding);
+        for v in [1, 4096, 0x10000, 0x11000, 2] {
+            desc.word(v).unwrap();
+        }
+        desc.buf.extend_from_slice(b"/usr/bin/foo\0");
+        notes.note(4, b"CORE", 0x46494c45, &desc.buf);
+
+        let mut desc = alloc::vec![0u8; 128];
+        put(&mut desc, 0, 4, 6);
+        put(&mut desc, 8, 4, -6i32 as u32 as u64);
+        notes.note(4, b"CORE", 0x53494749, &desc);
+
+        notes.note(4, b"LINUX", 0x202, &[0; 8]);
+
+        let memory: Vec<u8> = (1..=16).collect();
+        let mut b = Builder::new(encoding, elf64::ET_CORE, machine);
+        b.segment(Segment {
+            p_type: elf64::PT_NOTE,
+            p_align: 4,
+            contents: Contents::Data(&notes.buf),
+            ..Default::default()
+        });
+        b.segment(Segment {
+            p_type: elf64::PT_LOAD,
+            p_flags: elf64::PF_R,
+            p_vaddr: 0x10000,
+            p_memsz: 32,
+            contents: Contents::Data(&memory),
+            ..Default::default()
+        });
+
+        let mut buf = Vec::new();
+        b.write(&mut buf).unwrap();
+        buf
+    }
+
+    // Parse synthetic core-dumps of all supported platforms and verify all
+    // supported notes are decoded correctly.
+    #[test]
+    fn synthetic() {
+        for (class, machine, arch) in [
+            (elf::Class::Elf32, elf64::EM_386, Arch::X86),
+            (elf::Class::Elf64, elf64::EM_X86_64, Arch::X86_64),
+            (elf::Class::Elf32, elf64::EM_X86_64, Arch::X32),
+            (elf::Class::Elf64, elf64::EM_AARCH64, Arch::Aarch64),
+        ] {
+            let encoding = elf::Encoding { class: class, big_endian: false };
+            let data = fixture(encoding, machine);
+            let core = Core::with(&data[..]).unwrap();
+
+            assert_eq!(core.encoding(), encoding);
+            assert_eq!(core.arch(), arch);
+            assert_eq!(core.file().program_headers().len(), 2);
+            assert_eq!(core.notes().count(), 5);
+
+            let threads = core.threads().unwrap();
+            assert_eq!(threads.len(), 1);
+            assert_eq!(threads[0].pid(), 71);
+            assert_eq!(threads[0].cursig(), 6);
+            assert_eq!(threads[0].ip(), 0x10004);
+            match arch {
+                Arch::X86 => assert!(matches!(threads[0], Prstatus::X86(_))),
+                Arch::X86_64 => assert!(matches!(threads[0], Prstatus::X86_64(_))),
+                Arch::X32 => assert!(matches!(threads[0], Prstatus::X32(_))),
+                _ => assert!(matches!(threads[0], Prstatus::Aarch64(_))),
+            }
+
+            assert_eq!(core.auxv().unwrap().unwrap(), [(6, 4096), (9, 0x10000)]);
+
+            let maps = core.mappings().unwrap().unwrap();
+            assert_eq!(maps.len(), 1);
+            assert_eq!(maps[0].start, 0x10000);
+            assert_eq!(maps[0].end, 0x11000);
+            assert_eq!(maps[0].offset, 0x2000);
+            assert_eq!(maps[0].path, b"/usr/bin/foo");
+
+            let si = core.siginfo().unwrap().unwrap();
+            assert_eq!(si.si_signo, 6);
+            assert_eq!(si.si_errno, 0);
+            assert_eq!(si.si_code, -6);
+
+            let mut mem = [0xffu8; 8];
+            core.read_memory(0x1000c, &mut mem).unwrap();
+            assert_eq!(mem, [13, 14, 15, 16, 0, 0, 0, 0]);
+            assert_eq!(core.read_memory(0x1001c, &mut mem), Err(Error::Unmapped));
+            assert_eq!(core.read_memory(0x0fff0, &mut mem), Err(Error::Unmapped));
+        }
+    }
+
+    // Parse a big-endian aarch64 core-dump, whose registers cannot be
+    // decoded, but whose other notes can.
+    #[test]
+    fn foreign() {
+        let encoding = elf::Encoding { class: elf::Class::Elf64, big_endian: true };
+        let data = fixture(encoding, elf64::EM_AARCH64);
+        let core = Core::with(&data[..]).unwrap();
+
+        assert_eq!(core.arch(), Arch::Other(elf64::EM_AARCH64));
+        assert_eq!(core.threads().err(), Some(Error::Unsupported));
+        assert_eq!(core.auxv().unwrap().unwrap(), [(6, 4096), (9, 0x10000)]);
+        assert_eq!(core.mappings().unwrap().unwrap()[0].offset, 0x2000);
+        assert_eq!(core.siginfo().unwrap().unwrap().si_code, -6);
+    }
+
+    // Verify that invalid input is rejected.
+    #[test]
+    fn invalid() {
+        let encoding = elf::Encoding { class: elf::Class::Elf64, big_endian: false };
+        let mut data = fixture(encoding, elf64::EM_X86_64);
+        let (off_notes, off_load) = (64 + 2 * 56, 64 + 56);
+
+        assert_eq!(Core::with(&data[..8]).err(), Some(Error::Elf(elf::Error::Bounds)));
+
+        data[16] = elf64::ET_EXEC as u8;
+        assert_eq!(Core::with(&data[..]).err(), Some(Error::NotCore));
+        data[16] = elf64::ET_CORE as u8;
+
+        data[5] = 3;
+        assert_eq!(Core::with(&data[..]).err(), Some(Error::Elf(elf::Error::Data)));
+        data[5] = 1;
+
+        data[0] = 0;
+        assert_eq!(Core::with(&data[..]).err(), Some(Error::Elf(elf::Error::Magic)));
+        data[0] = 0x7f;
+
+        // Truncate the note segment in the middle of a note.
+        let len = data.len() - 16 - 20;
+        let core = Core::with(&data[..len]).unwrap();
+        assert!(core.notes().any(|v| v.is_err()));
+
+        // Announce a note that exceeds its segment.
+        let namesz = data[off_notes..off_notes + 4].to_vec();
+        data[off_notes..off_notes + 4].copy_from_slice(&0xffff_fff0u32.to_le_bytes());
+        let core = Core::with(&data[..]).unwrap();
+        assert!(matches!(core.notes().next(), Some(Err(Error::Elf(elf::Error::Note)))));
+        assert_eq!(core.notes().count(), 1);
+        data[off_notes..off_notes + 4].copy_from_slice(&namesz);
+
+        // Let the note segment exceed the file.
+        data[64 + 32..64 + 40].copy_from_slice(&0xffff_ff00u64.to_le_bytes());
+        let core = Core::with(&data[..]).unwrap();
+        assert!(matches!(core.notes().next(), Some(Err(Error::Elf(elf::Error::Bounds)))));
+
+        // Let the note segment exceed the limit of the address space.
+        data[64 + 8..64 + 16].copy_from_slice(&(u64::MAX - 0x10).to_le_bytes());
+        data[64 + 32..64 + 40].copy_from_slice(&0x20u64.to_le_bytes());
+        assert_eq!(Core::with(&data[..]).err(), Some(Error::Malformed));
+        data[64 + 8..64 + 16].copy_from_slice(&(off_notes as u64).to_le_bytes());
+
+        // Place a `PT_LOAD` segment without file contents at the end of the
+        // file offsets. Its memory must read as zero.
+        data[off_load + 8..off_load + 16].copy_from_slice(&u64::MAX.to_le_bytes());
+        data[off_load + 32..off_load + 40].copy_from_slice(&0u64.to_le_bytes());
+        data[off_load + 40..off_load + 48].copy_from_slice(&0x1000u64.to_le_bytes());
+        let core = Core::with(&data[..]).unwrap();
+        let mut mem = [0xffu8; 8];
+        core.read_memory(0x10001, &mut mem).unwrap();
+        assert_eq!(mem, [0; 8]);
+    }
+
+    // Parse a core-dump with more segments than fit into the ELF header, as
+    // written by Linux for processes with many mappings.
+    #[test]
+    fn extended() {
+        let encoding = elf::Encoding { class: elf::Class::Elf64, big_endian: false };
+        let mut notes = Out::new(encoding);
+        notes.note(4, b"CORE", NT_SIGINFO, &[6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut b = Builder::new(encoding, elf64::ET_CORE, elf64::EM_X86_64);
+        for i in 0..elf64::PN_XNUM as u64 {
+            b.segment(Segment {
+                p_type: elf64::PT_LOAD,
+                p_vaddr: i * 0x1000,
+                p_memsz: 0x1000,
+                ..Default::default()
+            });
+        }
+        b.segment(Segment {
+            p_type: elf64::PT_NOTE,
+            p_align: 4,
+            contents: Contents::Data(&notes.buf),
+            ..Default::default()
+        });
+
+        let mut data = Vec::new();
+        b.write(&mut data).unwrap();
+
+        let core = Core::with(&data[..]).unwrap();
+        assert_eq!(core.file().header().e_phnum, elf64::PN_XNUM);
+        assert_eq!(core.file().program_headers().len(), 0x10000);
+        assert_eq!(core.siginfo().unwrap().unwrap().si_signo, 6);
+
+        let mut mem = [0xffu8; 8];
+        core.read_memory(0xfffe000, &mut mem).unwrap();
+        assert_eq!(mem, [0; 8]);
+        assert_eq!(core.read_memory(0xffff000, &mut mem), Err(Error::Unmapped));
+    }
+
+    // Parse a core-dump written by Linux for a static x86-64 program that
+    // sends itself `SIGABRT`. See `coredump/abort.S` for how it was
+    // generated.
+    #[test]
+    fn kernel() {
+        let data = include_bytes!("coredump/x86_64-abort.core");
+        let core = Core::with(&data[..]).unwrap();
+
+        assert_eq!(core.arch(), Arch::X86_64);
+        assert_eq!(core.file().program_headers().len(), 4);
+
+        let threads = core.threads().unwrap();
+        assert_eq!(threads.len(), 1);
+        assert_eq!(threads[0].pid(), 25298);
+        assert_eq!(threads[0].cursig(), 6);
+        assert_eq!(threads[0].ip(), 0x4000c9);
+        assert_eq!(threads[0].sp(), 0x7ffc42c14dc0);
+
+        let si = core.siginfo().unwrap().unwrap();
+        assert_eq!((si.si_signo, si.si_errno, si.si_code), (6, 0, 0));
+        assert!(core.auxv().unwrap().unwrap().contains(&(6, 4096)));
+        assert_eq!(
+            core.mappings().unwrap().unwrap(),
+            [Mapping {
+                start: 0x400000,
+                end: 0x401000,
+                offset: 0,
+                path: b"/tmp/coredump/abort".to_vec(),
+            }],
+        );
+
+        // The program is dumped, the stack is not.
+        let mut mem = [0xffu8; 16];
+        core.read_memory(0x4000ca, &mut mem).unwrap();
+        assert_eq!(&mem, b"coredump-marker!");
+        core.read_memory(0x7ffc42c14dc0, &mut mem).unwrap();
+        assert_eq!(mem, [0; 16]);
+        assert_eq!(core.read_memory(0x401000, &mut mem), Err(Error::Unmapped));
+    }
+
+    // Fork a child that aborts and parse its core-dump. This is skipped if the
+    // system does not write core-dumps to the working directory.
+    #[cfg(all(
+        feature = "libc",
+        any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"),
+    ))]
+    #[test]
+    fn fork_abort() {
+        let pattern = std::fs::read("/proc/sys/kernel/core_pattern").unwrap_or_default();
+        if pattern.is_empty() || pattern[0] == b'|' || pattern.contains(&b'/') {
+            return;
+        }
+
+        let dir = std::env::temp_dir().join(std::format!("tmp-coredump-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_c = std::ffi::CString::new(dir.as_os_str().as_encoded_bytes()).unwrap();
+
+        let marker: alloc::boxed::Box<[u8; 16]> = alloc::boxed::Box::new(*b"coredump-marker!");
+        let marker_addr = marker.as_ptr() as u64;
+
+        // SAFETY: The child only calls async-signal-safe functions.
+        let pid = unsafe { libc::fork() };
+        assert!(pid >= 0);
+        if pid == 0 {
+            unsafe {
+                let lim = libc::rlimit {
+                    rlim_cur: libc::RLIM_INFINITY,
+                    rlim_max: libc::RLIM_INFINITY,
+                };
+                libc::setrlimit(libc::RLIMIT_CORE, &lim);
+                libc::prctl(libc::PR_SET_DUMPABLE, 1);
+                if libc::chdir(dir_c.as_ptr()) != 0 {
+                    libc::_exit(1);
+                }
+                libc::abort();
+            }
+        }
+
+        let mut status = 0;
+        // SAFETY: `pid` is a child of this process.
+        assert_eq!(unsafe { libc::waitpid(pid, &mut status, 0) }, pid);
+        assert!(libc::WIFSIGNALED(status));
+
+        let path = std::fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|v| v.ok())
+            .map(|v| v.path())
+            .find(|v| v.file_name().is_some_and(|v| v.as_encoded_bytes().starts_with(b"core")));
+        let data = path.map(|v| std::fs::read(v).unwrap());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // The core-dump might have been suppressed by the environment (e.g.,
+        // a read-only or restricted working directory).
+        let Some(data) = data else {
+            return;
+        };
+        assert!(libc::WCOREDUMP(status));
+
+        let core = Core::with(&data[..]).unwrap();
+        osi::cfg::cond! {
+            (target_arch = "aarch64") { assert_eq!(core.arch(), Arch::Aarch64); },
+            (target_arch = "x86") { assert_eq!(core.arch(), Arch::X86); },
+            (target_arch = "x86_64") { assert_eq!(core.arch(), Arch::X86_64); },
+        }
+
+        let threads = core.threads().unwrap();
+        assert_eq!(threads[0].pid(), pid);
+        assert_eq!(threads[0].cursig() as i32, libc::SIGABRT);
+        assert_eq!(core.siginfo().unwrap().unwrap().si_signo, libc::SIGABRT);
+        assert!(core.auxv().unwrap().unwrap().iter().any(|v| v.0 as usize == libc::AT_PAGESZ as usize));
+        assert!(!core.mappings().unwrap().unwrap().is_empty());
+
+        let mut mem = [0u8; 16];
+        core.read_memory(marker_addr, &mut mem).unwrap();
+        assert_eq!(&mem, &*marker);
+    }
+}
@@ -0,0 +1,808 @@
+//! ELF File Builder
+//!
+//! This module writes small ELF files of either class and either data
+//! encoding, as needed for stub executables, core-dump fixtures, or
+//! note-only objects. The caller describes sections, segments, and symbols,
+//! and the builder lays out the file:
+//!
+//! - The ELF header is placed first, followed by the program header table.
+//! - Section contents and segment contents are placed in the order they were
+//!   added, each aligned to its section alignment. The first contents of a
+//!   `PT_LOAD` segment are placed at a file offset congruent to the virtual
+//!   address of the segment, modulo its alignment, so the segment can be
+//!   mapped directly. The first contents of any other segment are aligned
+//!   to the segment alignment.
+//! - The symbol table, its string table, and the section name string table
+//!   are generated and placed last, followed by the section header table.
+//!
+//! Segments either carry their own contents, or span a range of sections.
+//! Segments never span the ELF header or the program header table.
+//!
+//! Section contents are borrowed and written directly into the target, so
+//! larger contents are not copied in between.
+
+use alloc::vec::Vec;
+use core::ops::ControlFlow as Flow;
+use core::ops::Range;
+use sys::ffi::elf::elf64;
+
+use crate::io;
+use super::{Class, Encoding, Error};
+
+/// Section to be written by the builder.
+///
+/// `sh_size` is only used for sections of type `SHT_NOBITS`, which occupy
+/// no space in the file. The size of any other section is the length of
+/// its contents.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Section<'data> {
+    pub name: &'data [u8],
+    pub sh_type: u32,
+    pub sh_flags: u64,
+    /// Virtual address of the section. If `0` and the section is spanned by
+    /// a `PT_LOAD` segment, it is derived from the segment.
+    pub sh_addr: u64,
+    pub sh_size: u64,
+    pub sh_link: u32,
+    pub sh_info: u32,
+    pub sh_addralign: u64,
+    pub sh_entsize: u64,
+    pub data: &'data [u8],
+}
+
+/// Contents of a segment to be written by the builder.
+#[derive(Clone, Debug)]
+pub enum Contents<'data> {
+    /// Contents owned by the segment.
+    Data(&'data [u8]),
+    /// Range of section indices spanned by the segment. The sections must be
+    /// non-empty and added consecutively, without any segment with own
+    /// contents in between.
+    Sections(Range<usize>),
+}
+
+/// Segment to be written by the builder.
+#[derive(Clone, Debug, Default)]
+pub struct Segment<'data> {
+    pub p_type: u32,
+    pub p_flags: u32,
+    pub p_vaddr: u64,
+    pub p_paddr: u64,
+    /// Size of the segment in memory. It is raised to the size of the
+    /// contents, if smaller.
+    pub p_memsz: u64,
+    pub p_align: u64,
+    pub contents: Contents<'data>,
+}
+
+/// Symbol to be written by the builder.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Symbol<'data> {
+    pub name: &'data [u8],
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+    pub st_value: u64,
+    pub st_size: u64,
+}
+
+/// Builder for ELF files.
+#[derive(Clone, Debug)]
+pub struct Builder<'data> {
+    /// Class and data encoding of the file.
+    pub encoding: Encoding,
+    pub osabi: u8,
+    pub e_type: u16,
+    pub e_machine: u16,
+    pub e_entry: u64,
+    pub e_flags: u32,
+    sections: Vec<Section<'data>>,
+    segments: Vec<Segment<'data>>,
+    symbols: Vec<Symbol<'data>>,
+    chunks: Vec<Chunk>,
+}
+
+// Contents placed in the file, in the order they were added.
+#[derive(Clone, Copy, Debug)]
+enum Chunk {
+    Section(usize),
+    Segment(usize),
+}
+
+// Serialize ELF fields with the encoding of the file. This is shared with
+// the unit tests of the ELF modules to encode their fixtures.
+pub(crate) struct Out {
+    pub(crate) buf: Vec<u8>,
+    pub(crate) encoding: Encoding,
+}
+
+// Round `v` up to a multiple of `align`.
+fn align_up(v: usize, align: u64) -> Result<usize, Error> {
+    let align = usize::try_from(align.max(1)).map_err(|_| Error::Range)?;
+    v.checked_next_multiple_of(align).ok_or(Error::Range)
+}
+
+// Raise `v` to the next offset congruent to `vaddr`, modulo `align`.
+fn congruent(v: usize, vaddr: u64, align: u64) -> Result<usize, Error> {
+    let align = align.max(1);
+    let pad = (vaddr % align + align - (v as u64) % align) % align;
+    v.checked_add(pad as usize).ok_or(Error::Range)
+}
+
+impl Default for Contents<'_> {
+    fn default() -> Self {
+        Self::Data(&[])
+    }
+}
+
+impl Out {
+    pub(crate) fn new(encoding: Encoding) -> Self {
+        Self {
+            buf: Vec::new(),
+            encoding: encoding,
+        }
+    }
+
+    pub(crate) fn u8(&mut self, v: u8) {
+        self.buf.push(v);
+    }
+
+    pub(crate) fn u16(&mut self, v: u16) {
+        match self.encoding.big_endian {
+            false => self.buf.extend_from_slice(&v.to_le_bytes()),
+            true => self.buf.extend_from_slice(&v.to_be_bytes()),
+        }
+    }
+
+    pub(crate) fn u32(&mut self, v: u32) {
+        match self.encoding.big_endian {
+            false => self.buf.extend_from_slice(&v.to_le_bytes()),
+            true => self.buf.extend_from_slice(&v.to_be_bytes()),
+        }
+    }
+
+    pub(crate) fn u64(&mut self, v: u64) {
+        match self.encoding.big_endian {
+            false => self.buf.extend_from_slice(&v.to_le_bytes()),
+            true => self.buf.extend_from_slice(&v.to_be_bytes()),
+        }
+    }
+
+    pub(crate) fn word(&mut self, v: u64) -> Result<(), Error> {
+        match self.encoding.class {
+            Class::Elf32 => self.u32(u32::try_from(v).map_err(|_| Error::Range)?),
+            Class::Elf64 => self.u64(v),
+        }
+        Ok(())
+    }
+
+    // Append a note with the given alignment. A terminator is appended to
+    // `name`.
+    #[cfg(test)]
+    pub(crate) fn note(&mut self, align: usize, name: &[u8], n_type: u32, desc: &[u8]) {
+        self.u32(name.len() as u32 + 1);
+        self.u32(desc.len() as u32);
+        self.u32(n_type);
+        self.buf.extend_from_slice(name);
+        self.buf.push(0);
+        self.buf.resize(self.buf.len().next_multiple_of(align), 0);
+        self.buf.extend_from_slice(desc);
+        self.buf.resize(self.buf.len().next_multiple_of(align), 0);
+    }
+
+    pub(crate) fn phdr(&mut self, phdr: &elf64::Phdr) -> Result<(), Error> {
+        self.u32(phdr.p_type);
+        if self.encoding.class == Class::Elf64 {
+            self.u32(phdr.p_flags);
+        }
+        self.word(phdr.p_offset)?;
+        self.word(phdr.p_vaddr)?;
+        self.word(phdr.p_paddr)?;
+        self.word(phdr.p_filesz)?;
+        self.word(phdr.p_memsz)?;
+        if self.encoding.class == Class::Elf32 {
+            self.u32(phdr.p_flags);
+        }
+        self.word(phdr.p_align)
+    }
+
+    pub(crate) fn shdr(&mut self, shdr: &elf64::Shdr) -> Result<(), Error> {
+        self.u32(shdr.sh_name);
+        self.u32(shdr.sh_type);
+        self.word(shdr.sh_flags)?;
+        self.word(shdr.sh_addr)?;
+        self.word(shdr.sh_offset)?;
+        self.word(shdr.sh_size)?;
+        self.u32(shdr.sh_link);
+        self.u32(shdr.sh_info);
+        self.word(shdr.sh_addralign)?;
+        self.word(shdr.sh_entsize)
+    }
+
+    pub(crate) fn sym(&mut self, name: u32, sym: &Symbol) -> Result<(), Error> {
+        self.u32(name);
+        if self.encoding.class == Class::Elf32 {
+            self.word(sym.st_value)?;
+            self.word(sym.st_size)?;
+        }
+        self.u8(sym.st_info);
+        self.u8(sym.st_other);
+        self.u16(sym.st_shndx);
+        if self.encoding.class == Class::Elf64 {
+            self.word(sym.st_value)?;
+            self.word(sym.st_size)?;
+        }
+        Ok(())
+    }
+}
+
+// Append `name` to the string table `strtab` and return its offset. The
+// empty name refers to the leading NUL.
+fn string(strtab: &mut Vec<u8>, name: &[u8]) -> Result<u32, Error> {
+    if name.is_empty() {
+        return Ok(0);
+    }
+
+    let off = u32::try_from(strtab.len()).map_err(|_| Error::Range)?;
+    strtab.extend_from_slice(name);
+    strtab.push(0);
+    Ok(off)
+}
+
+impl<'data> Builder<'data> {
+    /// Create a builder for an ELF file of the given encoding, type, and
+    /// machine, without any sections, segments, or symbols.
+    pub fn new(encoding: Encoding, e_type: u16, e_machine: u16) -> Self {
+        Self {
+            encoding: encoding,
+            osabi: elf64::ELFOSABI_NONE,
+            e_type: e_type,
+            e_machine: e_machine,
+            e_entry: 0,
+            e_flags: 0,
+            sections: Vec::new(),
+            segments: Vec::new(),
+            symbols: Vec::new(),
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Add a section and return its index in the section header table.
+    pub fn section(&mut self, section: Section<'data>) -> usize {
+        self.chunks.push(Chunk::Section(self.sections.len()));
+        self.sections.push(section);
+        self.sections.len()
+    }
+
+    /// Add a segment and return its index in the program header table.
+    pub fn segment(&mut self, segment: Segment<'data>) -> usize {
+        if let Contents::Data(_) = segment.contents {
+            self.chunks.push(Chunk::Segment(self.segments.len()));
+        }
+        self.segments.push(segment);
+        self.segments.len() - 1
+    }
+
+    /// Add a symbol to the symbol table.
+    ///
+    /// Local symbols are placed before all other symbols, as required for
+    /// symbol tables, but the order is preserved otherwise. The symbol table
+    /// is omitted if no symbols are added.
+    pub fn symbol(&mut self, symbol: Symbol<'data>) {
+        self.symbols.push(symbol);
+    }
+
+    /// Write the ELF file into `write`, starting at offset 0, and commit it.
+    /// Returns the size of the file.
+    pub fn write(&self, write: &mut dyn io::map::Write) -> Result<usize, Error> {
+        let encoding = self.encoding;
+        let word = encoding.word_size() as u64;
+        let phnum = self.segments.len();
+
+        // Generate the symbol table and the section name string table, and
+        // collect all sections including the generated ones.
+        let generated = self.sections.len();
+        let mut sections = self.sections.clone();
+        let mut strtab = Vec::from([0]);
+        let mut symtab = Out::new(encoding);
+        if !self.symbols.is_empty() {
+            let mut symbols: Vec<_> = self.symbols.iter().collect();
+            symbols.sort_by_key(|v| v.st_info >> 4 != elf64::STB_LOCAL);
+            let locals = symbols.iter().take_while(|v| v.st_info >> 4 == elf64::STB_LOCAL).count();
+
+            symtab.sym(0, &Symbol::default())?;
+            for sym in symbols {
+                let name = string(&mut strtab, sym.name)?;
+                symtab.sym(name, sym)?;
+            }
+
+            sections.push(Section {
+                name: b".symtab",
+                sh_type: elf64::SHT_SYMTAB,
+                sh_link: u32::try_from(generated + 2).map_err(|_| Error::Range)?,
+                sh_info: u32::try_from(locals + 1).map_err(|_| Error::Range)?,
+                sh_addralign: word,
+                sh_entsize: encoding.sym_size() as u64,
+                data: &symtab.buf,
+                ..Default::default()
+            });
+            sections.push(Section {
+                name: b".strtab",
+                sh_type: elf64::SHT_STRTAB,
+                sh_addralign: 1,
+                data: &strtab,
+                ..Default::default()
+            });
+        }
+
+        let table = !sections.is_empty() || phnum >= elf64::PN_XNUM as usize;
+        let mut shstrtab = Vec::from([0]);
+        let mut names = Vec::new();
+        if table {
+            sections.push(Section {
+                name: b".shstrtab",
+                sh_type: elf64::SHT_STRTAB,
+                sh_addralign: 1,
+                ..Default::default()
+            });
+            for section in &sections {
+                names.push(string(&mut shstrtab, section.name)?);
+            }
+            sections.last_mut().unwrap().data = &shstrtab;
+        }
+
+        let mut chunks = self.chunks.clone();
+        chunks.extend((generated..sections.len()).map(Chunk::Section));
+
+        // Remember the chunk of each section and each segment with own
+        // contents.
+        let mut section_pos = alloc::vec![0; sections.len()];
+        let mut segment_pos = alloc::vec![0; phnum];
+        for (pos, v) in chunks.iter().enumerate() {
+            match *v {
+                Chunk::Section(v) => section_pos[v] = pos,
+                Chunk::Segment(v) => segment_pos[v] = pos,
+            }
+        }
+
+        // Determine the file contents, alignment, and memory size of each
+        // chunk.
+        let infos: Vec<(&[u8], u64, u64)> = chunks.iter().map(|v| match *v {
+            Chunk::Section(v) if sections[v].sh_type == elf64::SHT_NOBITS => {
+                (&[][..], sections[v].sh_addralign, sections[v].sh_size)
+            },
+            Chunk::Section(v) => {
+                let data = sections[v].data;
+                (data, sections[v].sh_addralign, data.len() as u64)
+            },
+            Chunk::Segment(v) => match self.segments[v].contents {
+                Contents::Data(data) => (data, 1, data.len() as u64),
+                Contents::Sections(_) => unreachable!(),
+            },
+        }).collect();
+
+        // Resolve the chunks spanned by each segment.
+        let mut spans = Vec::with_capacity(phnum);
+        for (idx, segment) in self.segments.iter().enumerate() {
+            let span = match segment.contents {
+                Contents::Data(_) => {
+                    segment_pos[idx]..segment_pos[idx] + 1
+                },
+                Contents::Sections(ref r) => {
+                    if r.start == 0 || r.start >= r.end || r.end > generated + 1 {
+                        return Err(Error::SectionIndex);
+                    }
+                    let pos = section_pos[r.start - 1];
+                    let span = pos..pos + r.len();
+                    let consecutive = chunks[span.clone()].iter().zip(r.clone()).all(|v| {
+                        matches!(v, (Chunk::Section(a), b) if *a == b - 1)
+                    });
+                    if !consecutive {
+                        return Err(Error::SectionIndex);
+                    }
+                    span
+                },
+            };
+            spans.push(span);
+        }
+
+        // Place all chunks after the program header table.
+        let ehsize = encoding.ehdr_size();
+        let phoff = if phnum > 0 { ehsize } else { 0 };
+        let mut off = phnum
+            .checked_mul(encoding.phdr_size())
+            .and_then(|v| v.checked_add(ehsize))
+            .ok_or(Error::Range)?;
+
+        // Segments are visited in order of their first chunk, and in table
+        // order among segments starting at the same chunk.
+        let mut starts: Vec<usize> = (0..phnum).collect();
+        starts.sort_by_key(|v| spans[*v].start);
+        let mut starts = starts.into_iter().peekable();
+        let mut offsets = Vec::with_capacity(chunks.len());
+        for (idx, info) in infos.iter().enumerate() {
+            off = align_up(off, info.1)?;
+            while let Some(v) = starts.next_if(|v| spans[*v].start == idx) {
+                let segment = &self.segments[v];
+                off = match segment.p_type {
+                    elf64::PT_LOAD => congruent(off, segment.p_vaddr, segment.p_align)?,
+                    _ => align_up(off, segment.p_align)?,
+                };
+            }
+            offsets.push(off);
+            off = off.checked_add(info.0.len()).ok_or(Error::Range)?;
+        }
+
+        let shnum = sections.len() + table as usize;
+        let shoff = if table { align_up(off, word)? } else { 0 };
+        let end = shnum
+            .checked_mul(encoding.shdr_size())
+            .and_then(|v| v.checked_add(shoff.max(off)))
+            .ok_or(Error::Range)?;
+
+        // Generate the program header table.
+        let mut phdrs = Vec::with_capacity(phnum);
+        for (segment, span) in self.segments.iter().zip(&spans) {
+            let start = offsets[span.start];
+            let mut filesz = 0;
+            let mut memsz = segment.p_memsz;
+            for idx in span.clone() {
+                let (data, _, size) = infos[idx];
+                if !data.is_empty() {
+                    filesz = filesz.max(offsets[idx] - start + data.len());
+                }
+                memsz = memsz.max((offsets[idx] - start) as u64 + size);
+            }
+            phdrs.push(elf64::Phdr {
+                p_type: segment.p_type,
+                p_flags: segment.p_flags,
+                p_offset: start as u64,
+                p_vaddr: segment.p_vaddr,
+                p_paddr: segment.p_paddr,
+                p_filesz: filesz as u64,
+                p_memsz: memsz,
+                p_align: segment.p_align,
+            });
+        }
+
+        // Generate the section header table. The first entry carries the
+        // counts and indices that overflow the ELF header.
+        let shstrndx = shnum.saturating_sub(1);
+        let mut shdrs = Out::new(encoding);
+        if table {
+            shdrs.shdr(&elf64::Shdr {
+                sh_name: 0,
+                sh_type: elf64::SHT_NULL,
+                sh_flags: 0,
+                sh_addr: 0,
+                sh_offset: 0,
+                sh_size: if shnum >= elf64::SHN_LORESERVE as usize { shnum as u64 } else { 0 },
+                sh_link: if shstrndx >= elf64::SHN_LORESERVE as usize { shstrndx as u32 } else { 0 },
+                sh_info: if phnum >= elf64::PN_XNUM as usize { phnum as u32 } else { 0 },
+                sh_addralign: 0,
+                sh_entsize: 0,
+            })?;
+        }
+        for (idx, section) in sections.iter().enumerate() {
+            let pos = section_pos[idx];
+            let (_, _, size) = infos[pos];
+
+            let mut addr = section.sh_addr;
+            if addr == 0 {
+                let load = self.segments.iter().zip(&spans).zip(&phdrs).find(|((s, span), _)| {
+                    s.p_type == elf64::PT_LOAD && span.contains(&pos)
+                });
+                if let Some((_, phdr)) = load {
+                    addr = phdr.p_vaddr + (offsets[pos] as u64 - phdr.p_offset);
+                }
+            }
+
+            shdrs.shdr(&elf64::Shdr {
+                sh_name: names[idx],
+                sh_type: section.sh_type,
+                sh_flags: section.sh_flags,
+                sh_addr: addr,
+                sh_offset: offsets[pos] as u64,
+                sh_size: size,
+                sh_link: section.sh_link,
+                sh_info: section.sh_info,
+                sh_addralign: section.sh_addralign,
+                sh_entsize: section.sh_entsize,
+            })?;
+        }
+
+        // Generate the ELF header and the program header table.
+        let mut out = Out::new(encoding);
+        out.buf.extend_from_slice(&elf64::ELFMAG);
+        out.u8(match encoding.class {
+            Class::Elf32 => elf64::ELFCLASS32,
+            Class::Elf64 => elf64::ELFCLASS64,
+        });
+        out.u8(match encoding.big_endian {
+            false => elf64::ELFDATA2LSB,
+            true => elf64::ELFDATA2MSB,
+        });
+        out.u8(elf64::EV_CURRENT);
+        out.u8(self.osabi);
+        out.buf.extend_from_slice(&[0; 8]);
+        out.u16(self.e_type);
+        out.u16(self.e_machine);
+        out.u32(elf64::EV_CURRENT as u32);
+        out.word(self.e_entry)?;
+        out.word(phoff as u64)?;
+        out.word(shoff as u64)?;
+        out.u32(self.e_flags);
+        out.u16(ehsize as u16);
+        out.u16(if phnum > 0 { encoding.phdr_size() as u16 } else { 0 });
+        out.u16(phnum.min(elf64::PN_XNUM as usize) as u16);
+        out.u16(if table { encoding.shdr_size() as u16 } else { 0 });
+        out.u16(if shnum >= elf64::SHN_LORESERVE as usize { 0 } else { shnum as u16 });
+        out.u16(if shstrndx >= elf64::SHN_LORESERVE as usize { elf64::SHN_XINDEX } else { shstrndx as u16 });
+        for phdr in &phdrs {
+            out.phdr(phdr)?;
+        }
+
+        // Write the file and fill any padding with zeros.
+        let mut idx = 0;
+        let mut put = |at: usize, data: &[u8]| -> Result<(), Error> {
+            let pad = at - idx;
+            if let Flow::Break(v) = write.zero(&mut idx, pad) {
+                return Err(Error::Io(v));
+            }
+            if let Flow::Break(v) = write.write(&mut idx, data) {
+                return Err(Error::Io(v));
+            }
+            Ok(())
+        };
+
+        put(0, &out.buf)?;
+        for (info, off) in infos.iter().zip(&offsets) {
+            put(*off, info.0)?;
+        }
+        put(shoff.max(off), &shdrs.buf)?;
+        assert_eq!(idx, end);
+
+        // SAFETY: All `end` bytes were initialized above.
+        unsafe { write.commit(end) };
+        Ok(end)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::ElfFile;
+    use super::super::note::NAME_GNU;
+
+    // Build an executable with code, data, a build-ID note, and symbols,
+    // and verify it round-trips through the parser for both classes and
+    // both data encodings.
+    #[test]
+    fn roundtrip() {
+        for class in [Class::Elf32, Class::Elf64] {
+            for big_endian in [false, true] {
+                let encoding = Encoding { class: class, big_endian: big_endian };
+                let word = encoding.word_size() as u64;
+
+                let u32 = |v: u32| match big_endian {
+                    false => v.to_le_bytes(),
+                    true => v.to_be_bytes(),
+                };
+                let mut note = Vec::new();
+                note.extend(u32(4));
+                note.extend(u32(4));
+                note.extend(u32(elf64::NT_GNU_BUILD_ID));
+                note.extend(b"GNU\0\x01\x02\x03\x04");
+
+                let mut b = Builder::new(encoding, elf64::ET_EXEC, elf64::EM_X86_64);
+                b.e_entry = 0x401000;
+                let text = b.section(Section {
+                    name: b".text",
+                    sh_type: elf64::SHT_PROGBITS,
+                    sh_flags: (elf64::SHF_ALLOC | elf64::SHF_EXECINSTR) as u64,
+                    sh_addralign: 16,
+                    data: &[0xf4; 5],
+                    ..Default::default()
+                });
+                let data = b.section(Section {
+                    name: b".data",
+                    sh_type: elf64::SHT_PROGBITS,
+                    sh_flags: (elf64::SHF_ALLOC | elf64::SHF_WRITE) as u64,
+                    sh_addralign: 8,
+                    data: &[1, 2, 3],
+                    ..Default::default()
+                });
+                let bss = b.section(Section {
+                    name: b".bss",
+                    sh_type: elf64::SHT_NOBITS,
+                    sh_flags: (elf64::SHF_ALLOC | elf64::SHF_WRITE) as u64,
+                    sh_addralign: 8,
+                    sh_size: 0x100,
+                    ..Default::default()
+                });
+                let notes = b.section(Section {
+                    name: b".note.gnu.build-id",
+                    sh_type: elf64::SHT_NOTE,
+                    sh_flags: elf64::SHF_ALLOC as u64,
+                    sh_addralign: 4,
+                    data: &note,
+                    ..Default::default()
+                });
+                b.segment(Segment {
+                    p_type: elf64::PT_LOAD,
+                    p_flags: elf64::PF_R | elf64::PF_X,
+                    p_vaddr: 0x401000,
+                    p_paddr: 0x401000,
+                    p_align: 0x1000,
+                    contents: Contents::Sections(text..text + 1),
+                    ..Default::default()
+                });
+                b.segment(Segment {
+                    p_type: elf64::PT_LOAD,
+                    p_flags: elf64::PF_R | elf64::PF_W,
+                    p_vaddr: 0x402008,
+                    p_paddr: 0x402008,
+                    p_align: 0x1000,
+                    contents: Contents::Sections(data..bss + 1),
+                    ..Default::default()
+                });
+                b.segment(Segment {
+                    p_type: elf64::PT_NOTE,
+                    p_flags: elf64::PF_R,
+                    p_align: 4,
+                    contents: Contents::Sections(notes..notes + 1),
+                    ..Default::default()
+                });
+                let global = (elf64::STB_GLOBAL << 4) | elf64::STT_FUNC;
+                let local = (elf64::STB_LOCAL << 4) | elf64::STT_OBJECT;
+                b.symbol(Symbol { name: b"_start", st_info: global, st_shndx: text as u16, st_value: 0x401000, ..Default::default() });
+                b.symbol(Symbol { name: b"counter", st_info: local, st_shndx: bss as u16, st_value: 0x402010, st_size: 8, ..Default::default() });
+
+                let mut buf = Vec::new();
+                let len = b.write(&mut buf).unwrap();
+                assert_eq!(len, buf.len());
+
+                let elf = ElfFile::with(&buf[..]).unwrap();
+                assert_eq!(elf.encoding(), encoding);
+                assert_eq!(elf.header().e_type, elf64::ET_EXEC);
+                assert_eq!(elf.header().e_machine, elf64::EM_X86_64);
+                assert_eq!(elf.header().e_entry, 0x401000);
+
+                let phdrs: Vec<_> = elf.program_headers().collect();
+                assert_eq!(phdrs.len(), 3);
+                for phdr in &phdrs[..2] {
+                    assert_eq!(phdr.p_type, elf64::PT_LOAD);
+                    assert_eq!(phdr.p_offset % phdr.p_align, phdr.p_vaddr % phdr.p_align);
+                }
+                assert_eq!(phdrs[0].p_filesz, 5);
+                assert_eq!(phdrs[0].p_memsz, 5);
+                assert_eq!(phdrs[1].p_filesz, 3);
+                assert_eq!(phdrs[1].p_memsz, 0x108);
+                assert_eq!(elf.map_vaddr(0x401000, 5).unwrap(), [0xf4; 5]);
+                assert_eq!(elf.map_vaddr(0x402008, 3).unwrap(), [1, 2, 3]);
+                assert_eq!(elf.segment_data(&phdrs[2]).unwrap(), &note[..]);
+
+                let names: Vec<_> = elf.section_headers()
+                    .map(|v| elf.section_name(&v).unwrap())
+                    .collect();
+                assert_eq!(names, [
+                    &b""[..], b".text", b".data", b".bss", b".note.gnu.build-id",
+                    b".symtab", b".strtab", b".shstrtab",
+                ]);
+
+                let shdr = elf.section_header(bss).unwrap();
+                assert_eq!(shdr.sh_addr, 0x402010);
+                assert_eq!(shdr.sh_size, 0x100);
+                assert_eq!(elf.section_data(&shdr).unwrap(), b"");
+                let shdr = elf.section_header(text).unwrap();
+                assert_eq!(shdr.sh_addr, 0x401000);
+                assert_eq!(shdr.sh_offset % 16, 0);
+                assert_eq!(elf.build_id().unwrap(), Some(&[1, 2, 3, 4][..]));
+                assert_eq!(
+                    elf.find_note(NAME_GNU, elf64::NT_GNU_BUILD_ID).unwrap().unwrap().desc(),
+                    [1, 2, 3, 4],
+                );
+
+                let shdr = elf.section_by_name(b".symtab").unwrap().unwrap();
+                assert_eq!(shdr.sh_info, 2);
+                assert_eq!(shdr.sh_addralign, word);
+                let symtab = elf.symbol_table(&shdr).unwrap();
+                let symbols: Vec<_> = symtab.iter()
+                    .map(|v| (symtab.name(&v).unwrap(), v.st_value, v.st_info))
+                    .collect();
+                assert_eq!(symbols, [
+                    (&b""[..], 0, 0),
+                    (b"counter", 0x402010, local),
+                    (b"_start", 0x401000, global),
+                ]);
+            }
+        }
+    }
+
+    // Build a core-dump fixture with segments only, and verify no section
+    // header table is written.
+    #[test]
+    fn segments() {
+        let encoding = Encoding { class: Class::Elf64, big_endian: false };
+        let mut b = Builder::new(encoding, elf64::ET_CORE, elf64::EM_X86_64);
+        b.segment(Segment {
+            p_type: elf64::PT_NOTE,
+            p_align: 4,
+            contents: Contents::Data(&[0; 12]),
+            ..Default::default()
+        });
+        b.segment(Segment {
+            p_type: elf64::PT_LOAD,
+            p_flags: elf64::PF_R,
+            p_vaddr: 0x7f0000000123,
+            p_memsz: 0x2000,
+            p_align: 0x1000,
+            contents: Contents::Data(b"memory"),
+            ..Default::default()
+        });
+
+        let mut buf = Vec::new();
+        b.write(&mut buf).unwrap();
+
+        let elf = ElfFile::with(&buf[..]).unwrap();
+        assert_eq!(elf.header().e_type, elf64::ET_CORE);
+        assert_eq!(elf.header().e_shoff, 0);
+        assert_eq!(elf.section_headers().len(), 0);
+
+        let phdrs: Vec<_> = elf.program_headers().collect();
+        assert_eq!(phdrs[0].p_offset, 64 + 2 * 56);
+        assert_eq!(phdrs[0].p_filesz, 12);
+        assert_eq!(phdrs[1].p_offset % 0x1000, 0x123);
+        assert_eq!(phdrs[1].p_filesz, 6);
+        assert_eq!(phdrs[1].p_memsz, 0x2000);
+        assert_eq!(elf.map_vaddr(0x7f0000000123, 6).unwrap(), b"memory");
+        assert_eq!(buf.len() as u64, phdrs[1].p_offset + 6);
+    }
+
+    // Build a file with more sections than fit into the ELF header, and
+    // verify extended section numbering is used.
+    #[test]
+    fn extended() {
+        let encoding = Encoding { class: Class::Elf32, big_endian: true };
+        let mut b = Builder::new(encoding, elf64::ET_REL, elf64::EM_NONE);
+        for _ in 0..elf64::SHN_LORESERVE {
+            b.section(Section { sh_type: elf64::SHT_PROGBITS, ..Default::default() });
+        }
+
+        let mut buf = Vec::new();
+        b.write(&mut buf).unwrap();
+
+        let elf = ElfFile::with(&buf[..]).unwrap();
+        assert_eq!(elf.header().e_shnum, 0);
+        assert_eq!(elf.header().e_shstrndx, elf64::SHN_XINDEX);
+        assert_eq!(elf.section_headers().len(), 0xff02);
+        assert_eq!(elf.shstrndx(), 0xff01);
+        assert_eq!(elf.section_names().unwrap().data(), b"\0.shstrtab\0");
+    }
+
+    // Verify that unrepresentable files are rejected.
+    #[test]
+    fn invalid() {
+        let encoding = Encoding { class: Class::Elf32, big_endian: false };
+        let mut buf = Vec::new();
+
+        let mut b = Builder::new(encoding, elf64::ET_EXEC, elf64::EM_386);
+        b.e_entry = 0x100000000;
+        assert_eq!(b.write(&mut buf).err(), Some(Error::Range));
+
+        let mut b = Builder::new(encoding, elf64::ET_EXEC, elf64::EM_386);
+        let a = b.section(Section { name: b"a", ..Default::default() });
+        b.segment(Segment { contents: Contents::Data(b"x"), ..Default::default() });
+        let c = b.section(Section { name: b"c", ..Default::default() });
+        b.segment(Segment { contents: Contents::Sections(a..c + 1), ..Default::default() });
+        assert_eq!(b.write(&mut buf).err(), Some(Error::SectionIndex));
+
+        let mut b = Builder::new(encoding, elf64::ET_EXEC, elf64::EM_386);
+        b.section(Section { name: b"a", ..Default::default() });
+        b.segment(Segment { contents: Contents::Sections(0..1), ..Default::default() });
+        assert_eq!(b.write(&mut buf).err(), Some(Error::SectionIndex));
+        b.segment(Segment { contents: Contents::Sections(1..3), ..Default::default() });
+        assert_eq!(b.write(&mut buf).err(), Some(Error::SectionIndex));
+        assert!(buf.is_empty());
+    }
+}
@@ -0,0 +1,312 @@
+//! Separate Debug Files
+//!
+//! Debug information of an ELF object is often stripped into a separate
+//! file, which is then referenced from the object in one of two ways:
+//!
+//! - The build-ID of the object (`NT_GNU_BUILD_ID`) is shared by the debug
+//!   file, which is installed as `.build-id/xx/yyyy.debug` below a global
+//!   debug directory, where `xx` are the first two hex-digits of the
+//!   build-ID and `yyyy` are the remaining ones.
+//! - The `.gnu_debuglink` section names the debug file and carries the
+//!   CRC-32 checksum of its contents. The file is searched for next to the
+//!   object, in the `.debug` subdirectory next to the object, and at the
+//!   path of the object below the global debug directory.
+//!
+//! Additionally, debug files can share common debug information in a
+//! supplementary file, as named by their `.gnu_debugaltlink` section
+//! together with the build-ID of the supplementary file.
+//!
+//! This module decodes both sections and implements the lookup of debug
+//! files. The file system is accessed via a callback, so the lookup is
+//! independent of the platform.
+
+use alloc::vec::Vec;
+
+use crate::io;
+use super::{ElfFile, Error};
+
+/// Name of the section naming the debug file.
+pub const SECTION_DEBUGLINK: &[u8] = b".gnu_debuglink";
+/// Name of the section naming the supplementary debug file.
+pub const SECTION_DEBUGALTLINK: &[u8] = b".gnu_debugaltlink";
+
+/// Default global debug directory.
+pub const DEBUG_DIR: &[u8] = b"/usr/lib/debug";
+
+/// Debug file as named by a `.gnu_debuglink` section.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct DebugLink<'read> {
+    /// File name of the debug file, without directory.
+    pub file: &'read [u8],
+    /// CRC-32 checksum of the contents of the debug file.
+    pub crc: u32,
+}
+
+/// Supplementary debug file as named by a `.gnu_debugaltlink` section.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct DebugAltLink<'read> {
+    /// Path of the supplementary debug file.
+    pub file: &'read [u8],
+    /// Build-ID of the supplementary debug file.
+    pub build_id: &'read [u8],
+}
+
+// Concatenate path components, separated by a single slash.
+fn join(parts: &[&[u8]]) -> Vec<u8> {
+    let mut r = Vec::new();
+    for part in parts.iter().filter(|v| !v.is_empty()) {
+        if !r.is_empty() && r.last() != Some(&b'/') && part[0] != b'/' {
+            r.push(b'/');
+        } else if r.last() == Some(&b'/') && part[0] == b'/' {
+            r.pop();
+        }
+        r.extend_from_slice(part);
+    }
+    r
+}
+
+/// Return the path of the debug file with the given build-ID below the
+/// debug directory `root`. This is `None` if the build-ID is shorter than
+/// 2 bytes.
+pub fn build_id_path(root: &[u8], build_id: &[u8]) -> Option<Vec<u8>> {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    if build_id.len() < 2 {
+        return None;
+    }
+
+    let hex: Vec<u8> = build_id.iter()
+        .flat_map(|v| [HEX[(v >> 4) as usize], HEX[(v & 0xf) as usize]])
+        .collect();
+    let mut file = hex[2..].to_vec();
+    file.extend_from_slice(b".debug");
+
+    Some(join(&[root, b".build-id", &hex[..2], &file]))
+}
+
+impl<'read> DebugLink<'read> {
+    /// Check whether `data` matches the checksum of the debug link.
+    pub fn matches(&self, data: &[u8]) -> bool {
+        let crc = osi::hash::hash::<osi::hash::crc32::Engine>(data);
+        u32::from_be_bytes(crc.0) == self.crc
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> ElfFile<'read, Read> {
+    /// Decode the `.gnu_debuglink` section, if any.
+    ///
+    /// The section consists of the NUL-terminated file name, padded to a
+    /// multiple of 4 bytes, followed by the 32-bit checksum.
+    pub fn debug_link(&self) -> Result<Option<DebugLink<'read>>, Error> {
+        let Some(shdr) = self.section_by_name(SECTION_DEBUGLINK)? else {
+            return Ok(None);
+        };
+        let data = self.section_data(&shdr)?;
+
+        let len = data.iter().position(|v| *v == 0).ok_or(Error::Unterminated)?;
+        let off = (len + 1).next_multiple_of(4);
+        if len == 0 || data.len() < off + 4 {
+            return Err(Error::DebugLink);
+        }
+
+        Ok(Some(DebugLink {
+            file: &data[..len],
+            crc: self.encoding().u32(&data[off..]),
+        }))
+    }
+
+    /// Decode the `.gnu_debugaltlink` section, if any.
+    ///
+    /// The section consists of the NUL-terminated path, directly followed
+    /// by the build-ID.
+    pub fn debug_alt_link(&self) -> Result<Option<DebugAltLink<'read>>, Error> {
+        let Some(shdr) = self.section_by_name(SECTION_DEBUGALTLINK)? else {
+            return Ok(None);
+        };
+        let data = self.section_data(&shdr)?;
+
+        let len = data.iter().position(|v| *v == 0).ok_or(Error::Unterminated)?;
+        if len == 0 || data.len() == len + 1 {
+            return Err(Error::DebugLink);
+        }
+
+        Ok(Some(DebugAltLink {
+            file: &data[..len],
+            build_id: &data[len + 1..],
+        }))
+    }
+
+    /// Find the separate debug file of the object.
+    ///
+    /// `root` is the global debug directory (usually [`DEBUG_DIR`]), and
+    /// `dir` is the directory the object is located in. `open` is called
+    /// with the candidate paths in lookup order and returns the contents of
+    /// the file, if it exists. The path and contents of the first candidate
+    /// with a matching build-ID or checksum are returned.
+    ///
+    /// The build-ID directory is searched first. Then, the locations named
+    /// by the `.gnu_debuglink` section are searched.
+    pub fn find_debug_file<Data: AsRef<[u8]>>(
+        &self,
+        root: &[u8],
+        dir: &[u8],
+        mut open: impl FnMut(&[u8]) -> Option<Data>,
+    ) -> Result<Option<(Vec<u8>, Data)>, Error> {
+        if let Some(build_id) = self.build_id()? {
+            if let Some(path) = build_id_path(root, build_id) {
+                if let Some(data) = open(&path) {
+                    let matches = ElfFile::with(data.as_ref())
+                        .and_then(|v| v.build_id().map(|v| v == Some(build_id)))
+                        .unwrap_or(false);
+                    if matches {
+                        return Ok(Some((path, data)));
+                    }
+                }
+            }
+        }
+
+        if let Some(link) = self.debug_link()? {
+            for path in [
+                join(&[dir, link.file]),
+                join(&[dir, b".debug", link.file]),
+                join(&[root, dir, link.file]),
+            ] {
+                if let Some(data) = open(&path) {
+                    if link.matches(data.as_ref()) {
+                        return Ok(Some((path, data)));
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::collections::BTreeMap;
+    use sys::ffi::elf::elf64;
+    use super::*;
+    use super::super::build::{Builder, Out, Section};
+    use super::super::{Class, Encoding};
+
+    // Build an object with the given build-ID and extra sections.
+    fn fixture(encoding: Encoding, build_id: &[u8], sections: &[(&[u8], &[u8])]) -> Vec<u8> {
+        let mut note = Out::new(encoding);
+        note.note(4, b"GNU", elf64::NT_GNU_BUILD_ID, build_id);
+
+        let mut b = Builder::new(encoding, elf64::ET_DYN, elf64::EM_X86_64);
+        b.section(Section {
+            name: b".note.gnu.build-id",
+            sh_type: elf64::SHT_NOTE,
+            sh_flags: elf64::SHF_ALLOC as u64,
+            sh_addralign: 4,
+            data: &note.buf,
+            ..Default::default()
+        });
+        for (name, data) in sections {
+            b.section(Section {
+                name: name,
+                sh_type: elf64::SHT_PROGBITS,
+                sh_addralign: 4,
+                data: data,
+                ..Default::default()
+            });
+        }
+
+        let mut buf = Vec::new();
+        b.write(&mut buf).unwrap();
+        buf
+    }
+
+    // Verify path construction.
+    #[test]
+    fn paths() {
+        assert_eq!(join(&[b"/usr/lib/debug/", b"/usr/bin", b"ls.debug"]), b"/usr/lib/debug/usr/bin/ls.debug");
+        assert_eq!(join(&[b"", b".debug", b"ls.debug"]), b".debug/ls.debug");
+        assert_eq!(
+            build_id_path(DEBUG_DIR, &[0xab, 0xcd, 0x01]).unwrap(),
+            b"/usr/lib/debug/.build-id/ab/cd01.debug",
+        );
+        assert!(build_id_path(DEBUG_DIR, &[0xab]).is_none());
+    }
+
+    // Decode both link sections, and find debug files via the build-ID and
+    // via the debug link.
+    #[test]
+    fn synthetic() {
+        for big_endian in [false, true] {
+            let encoding = Encoding { class: Class::Elf64, big_endian: big_endian };
+            let debug = fixture(encoding, &[1, 2, 3, 4], &[]);
+            let other = fixture(encoding, &[5, 6, 7, 8], &[]);
+            let crc = osi::hash::hash::<osi::hash::crc32::Engine>(&debug).0;
+            let crc = match big_endian {
+                false => u32::from_be_bytes(crc).to_le_bytes(),
+                true => crc,
+            };
+
+            let debuglink = [&b"ls.debug\0\0\0\0"[..], &crc].concat();
+            let altlink = b"/usr/lib/debug/.dwz/x.debug\0\x09\x0a";
+            let data = fixture(encoding, &[1, 2, 3, 4], &[
+                (SECTION_DEBUGLINK, &debuglink),
+                (SECTION_DEBUGALTLINK, altlink),
+            ]);
+            let elf = ElfFile::with(&data[..]).unwrap();
+
+            let link = elf.debug_link().unwrap().unwrap();
+            assert_eq!(link.file, b"ls.debug");
+            assert!(link.matches(&debug));
+            assert!(!link.matches(&other));
+            assert_eq!(
+                elf.debug_alt_link().unwrap().unwrap(),
+                DebugAltLink { file: b"/usr/lib/debug/.dwz/x.debug", build_id: &[9, 10] },
+            );
+
+            // Found via the build-ID.
+            let mut files = BTreeMap::<&[u8], &[u8]>::new();
+            files.insert(b"/usr/lib/debug/.build-id/01/020304.debug", &debug);
+            let (path, _) = elf.find_debug_file(DEBUG_DIR, b"/usr/bin", |v| files.get(v).copied()).unwrap().unwrap();
+            assert_eq!(path, b"/usr/lib/debug/.build-id/01/020304.debug");
+
+            // A build-ID mismatch falls back to the debug link, which skips
+            // files with a checksum mismatch.
+            files.insert(b"/usr/lib/debug/.build-id/01/020304.debug", &other);
+            files.insert(b"/usr/bin/ls.debug", &other);
+            files.insert(b"/usr/bin/.debug/ls.debug", &debug);
+            files.insert(b"/usr/lib/debug/usr/bin/ls.debug", &debug);
+            let (path, _) = elf.find_debug_file(DEBUG_DIR, b"/usr/bin", |v| files.get(v).copied()).unwrap().unwrap();
+            assert_eq!(path, b"/usr/bin/.debug/ls.debug");
+
+            files.remove(&b"/usr/bin/.debug/ls.debug"[..]);
+            let (path, _) = elf.find_debug_file(DEBUG_DIR, b"/usr/bin", |v| files.get(v).copied()).unwrap().unwrap();
+            assert_eq!(path, b"/usr/lib/debug/usr/bin/ls.debug");
+
+            files.remove(&b"/usr/lib/debug/usr/bin/ls.debug"[..]);
+            assert!(elf.find_debug_file(DEBUG_DIR, b"/usr/bin", |v| files.get(v).copied()).unwrap().is_none());
+        }
+    }
+
+    // Verify that malformed link sections are rejected.
+    #[test]
+    fn invalid() {
+        let encoding = Encoding { class: Class::Elf64, big_endian: false };
+
+        for (name, data, error) in [
+            (SECTION_DEBUGLINK, &b"ls.debug"[..], Error::Unterminated),
+            (SECTION_DEBUGLINK, b"ls.debug\0\0\0\0\0\0", Error::DebugLink),
+            (SECTION_DEBUGLINK, b"\0\0\0\0\0\0\0\0", Error::DebugLink),
+            (SECTION_DEBUGALTLINK, b"x.debug\0", Error::DebugLink),
+        ] {
+            let data = fixture(encoding, &[1, 2], &[(name, data)]);
+            let elf = ElfFile::with(&data[..]).unwrap();
+            let r = match name == SECTION_DEBUGLINK {
+                true => elf.debug_link().map(|_| ()),
+                false => elf.debug_alt_link().map(|_| ()),
+            };
+            assert_eq!(r, Err(error));
+        }
+    }
+}
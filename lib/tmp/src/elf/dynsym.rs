@@ -0,0 +1,978 @@
+//! Dynamic Symbol Lookup
+//!
+//! Dynamic symbols of an ELF object are looked up by name via one of two
+//! hash tables referenced from the dynamic section: the original SysV hash
+//! table (`DT_HASH`), or the GNU hash table (`DT_GNU_HASH`), which adds a
+//! bloom filter to reject most unsuccessful lookups early. Neither of them
+//! records the number of dynamic symbols directly, so it is derived from the
+//! hash tables.
+//!
+//! Symbols can be versioned via the symbol version table (`DT_VERSYM`),
+//! which assigns a version index to each dynamic symbol. The index refers to
+//! either a version definition (`DT_VERDEF`) or a version requirement
+//! (`DT_VERNEED`) of the object. The same tables are available via the
+//! `.gnu.version`, `.gnu.version_d`, and `.gnu.version_r` sections, which
+//! are also present in files without a dynamic section, like separate debug
+//! files.
+//!
+//! Only hash tables with 32-bit entries are supported, which excludes the
+//! SysV hash tables of 64-bit s390 and Alpha.
+
+use sys::ffi::elf::elf64;
+
+use crate::io;
+use super::{ElfFile, Encoding, Error, StringTable, SymbolTable};
+
+/// Version of a dynamic symbol.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Version<'read> {
+    /// Name of the version.
+    pub name: &'read [u8],
+    /// File the version is required from, or `None` if the version is
+    /// defined by the object itself.
+    pub file: Option<&'read [u8]>,
+    /// Whether the symbol is hidden, i.e., it is not the default version of
+    /// its name.
+    pub hidden: bool,
+}
+
+/// Symbol version table of an ELF file with its version definitions and
+/// requirements, as found via the section headers.
+#[derive(Clone, Copy, Debug)]
+pub struct SymbolVersions<'read> {
+    encoding: Encoding,
+    symtab: usize,
+    versym: &'read [u8],
+    verdef: Option<(u64, u64)>,
+    verneed: Option<(u64, u64)>,
+    data: &'read [u8],
+    strings: StringTable<'read>,
+}
+
+// Version definitions and requirements of an object. Their locations are
+// resolved via `map`, which maps virtual addresses for the dynamic section,
+// or offsets into the mapped version sections.
+struct Tables<'map, 'read> {
+    encoding: Encoding,
+    strings: StringTable<'read>,
+    verdef: Option<(u64, u64)>,
+    verneed: Option<(u64, u64)>,
+    map: &'map dyn Fn(u64, u64) -> Result<&'read [u8], Error>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SysvHash<'read> {
+    buckets: &'read [u8],
+    chains: &'read [u8],
+}
+
+#[derive(Clone, Copy, Debug)]
+struct GnuHash<'read> {
+    symoffset: u32,
+    shift: u32,
+    bloom: &'read [u8],
+    buckets: &'read [u8],
+    chains: &'read [u8],
+}
+
+/// Dynamic symbol table of an ELF object with its hash and version tables.
+pub struct DynamicSymbols<'elf, 'read, Read: ?Sized> {
+    elf: &'elf ElfFile<'read, Read>,
+    symbols: SymbolTable<'read>,
+    sysv: Option<SysvHash<'read>>,
+    gnu: Option<GnuHash<'read>>,
+    versym: &'read [u8],
+    verdef: Option<(u64, u64)>,
+    verneed: Option<(u64, u64)>,
+}
+
+/// Calculate the SysV hash of a symbol name, as used by `DT_HASH`.
+pub const fn sysv_hash(name: &[u8]) -> u32 {
+    let mut h: u32 = 0;
+    let mut i = 0;
+    while i < name.len() {
+        h = (h << 4).wrapping_add(name[i] as u32);
+        let g = h & 0xf0000000;
+        h ^= g >> 24;
+        h &= !g;
+        i += 1;
+    }
+    h
+}
+
+/// Calculate the GNU hash of a symbol name, as used by `DT_GNU_HASH`.
+pub const fn gnu_hash(name: &[u8]) -> u32 {
+    let mut h: u32 = 5381;
+    let mut i = 0;
+    while i < name.len() {
+        h = h.wrapping_mul(33).wrapping_add(name[i] as u32);
+        i += 1;
+    }
+    h
+}
+
+// Calculate `base + idx * size` with overflow checks.
+fn offset(base: u64, idx: u64, size: u64) -> Result<u64, Error> {
+    idx.checked_mul(size)
+        .and_then(|v| v.checked_add(base))
+        .ok_or(Error::Bounds)
+}
+
+impl<'read> SysvHash<'read> {
+    fn with<Read: ?Sized + io::map::Read>(
+        elf: &ElfFile<'read, Read>,
+        addr: u64,
+    ) -> Result<(Self, u64), Error> {
+        let encoding = elf.encoding();
+        let hdr = elf.map_vaddr(addr, 8)?;
+        let nbucket = encoding.u32(&hdr[0..]) as u64;
+        let nchain = encoding.u32(&hdr[4..]) as u64;
+        if nbucket == 0 {
+            return Err(Error::HashTable);
+        }
+
+        let v = Self {
+            buckets: elf.map_vaddr(addr + 8, nbucket * 4)?,
+            chains: elf.map_vaddr(offset(addr + 8, nbucket, 4)?, nchain * 4)?,
+        };
+        Ok((v, nchain))
+    }
+}
+
+impl<'read> GnuHash<'read> {
+    fn with<Read: ?Sized + io::map::Read>(
+        elf: &ElfFile<'read, Read>,
+        addr: u64,
+    ) -> Result<(Self, u64), Error> {
+        let encoding = elf.encoding();
+        let hdr = elf.map_vaddr(addr, 16)?;
+        let nbuckets = encoding.u32(&hdr[0..]) as u64;
+        let symoffset = encoding.u32(&hdr[4..]);
+        let bloom_size = encoding.u32(&hdr[8..]) as u64;
+        let shift = encoding.u32(&hdr[12..]);
+        if nbuckets == 0 || bloom_size == 0 {
+            return Err(Error::HashTable);
+        }
+
+        let addr_bloom = addr + 16;
+        let addr_buckets = offset(addr_bloom, bloom_size, encoding.word_size() as u64)?;
+        let addr_chains = offset(addr_buckets, nbuckets, 4)?;
+        let bloom = elf.map_vaddr(addr_bloom, addr_buckets - addr_bloom)?;
+        let buckets = elf.map_vaddr(addr_buckets, addr_chains - addr_buckets)?;
+
+        // The number of symbols is the end of the last chain, which starts
+        // at the highest bucket and ends with the first odd hash value.
+        let mut nsyms = symoffset as u64;
+        let last = buckets
+            .chunks_exact(4)
+            .map(|v| encoding.u32(v) as u64)
+            .max()
+            .unwrap_or(0);
+        if last >= nsyms {
+            nsyms = last;
+            loop {
+                let v = elf.map_vaddr(offset(addr_chains, nsyms - symoffset as u64, 4)?, 4)?;
+                nsyms += 1;
+                if encoding.u32(v) & 1 == 1 {
+                    break;
+                }
+            }
+        }
+
+        let v = Self {
+            symoffset: symoffset,
+            shift: shift,
+            bloom: bloom,
+            buckets: buckets,
+            chains: elf.map_vaddr(addr_chains, (nsyms - symoffset as u64) * 4)?,
+        };
+        Ok((v, nsyms))
+    }
+}
+
+impl<'elf, 'read, Read: ?Sized + io::map::Read> DynamicSymbols<'elf, 'read, Read> {
+    /// Locate the dynamic symbol table, its string table, and its hash and
+    /// version tables via the dynamic section of an ELF object.
+    pub fn with(elf: &'elf ElfFile<'read, Read>) -> Result<Self, Error> {
+        let encoding = elf.encoding();
+        let mut strtab = None;
+        let mut strsz = 0;
+        let mut symtab = None;
+        let mut syment = encoding.sym_size() as u64;
+        let mut hash = None;
+        let mut gnu_hash = None;
+        let mut versym = None;
+        let mut verdef = None;
+        let mut verdefnum = u64::MAX;
+        let mut verneed = None;
+        let mut verneednum = u64::MAX;
+
+        for v in elf.dynamic()? {
+            let Ok(tag) = u32::try_from(v.d_tag) else {
+                continue;
+            };
+            match tag {
+                elf64::DT_STRTAB => strtab = Some(v.d_val),
+                elf64::DT_STRSZ => strsz = v.d_val,
+                elf64::DT_SYMTAB => symtab = Some(v.d_val),
+                elf64::DT_SYMENT => syment = v.d_val,
+                elf64::DT_HASH => hash = Some(v.d_val),
+                elf64::DT_GNU_HASH => gnu_hash = Some(v.d_val),
+                elf64::DT_VERSYM => versym = Some(v.d_val),
+                elf64::DT_VERDEF => verdef = Some(v.d_val),
+                elf64::DT_VERDEFNUM => verdefnum = v.d_val,
+                elf64::DT_VERNEED => verneed = Some(v.d_val),
+                elf64::DT_VERNEEDNUM => verneednum = v.d_val,
+                _ => {},
+            }
+        }
+
+        let (Some(strtab), Some(symtab)) = (strtab, symtab) else {
+            return Err(Error::Dynamic);
+        };
+        if hash.is_none() && gnu_hash.is_none() {
+            return Err(Error::Dynamic);
+        }
+        if syment < encoding.sym_size() as u64 {
+            return Err(Error::EntrySize);
+        }
+
+        let mut nsyms = 0;
+        let sysv = match hash {
+            None => None,
+            Some(v) => {
+                let (v, n) = SysvHash::with(elf, v)?;
+                nsyms = nsyms.max(n);
+                Some(v)
+            },
+        };
+        let gnu = match gnu_hash {
+            None => None,
+            Some(v) => {
+                let (v, n) = GnuHash::with(elf, v)?;
+                nsyms = nsyms.max(n);
+                Some(v)
+            },
+        };
+
+        let len = nsyms.checked_mul(syment).ok_or(Error::Bounds)?;
+        let symbols = SymbolTable {
+            encoding: encoding,
+            data: elf.map_vaddr(symtab, len)?,
+            entsize: syment as usize,
+            num: nsyms as usize,
+            strings: StringTable::with(elf.map_vaddr(strtab, strsz)?)?,
+        };
+        let versym = match versym {
+            None => &[][..],
+            Some(v) => elf.map_vaddr(v, nsyms * 2)?,
+        };
+
+        Ok(Self {
+            elf: elf,
+            symbols: symbols,
+            sysv: sysv,
+            gnu: gnu,
+            versym: versym,
+            verdef: verdef.map(|v| (v, verdefnum)),
+            verneed: verneed.map(|v| (v, verneednum)),
+        })
+    }
+
+    /// Return the dynamic symbol table.
+    pub fn symbols(&self) -> &SymbolTable<'read> {
+        &self.symbols
+    }
+
+    /// Look up a defined symbol by name, using the GNU hash table if
+    /// available, and the SysV hash table otherwise. This yields the index
+    /// and the symbol.
+    ///
+    /// If `version` is `None`, only the default version of a symbol
+    /// matches. Otherwise, only the given version matches, or an
+    /// unversioned symbol.
+    pub fn lookup(
+        &self,
+        name: &[u8],
+        version: Option<&[u8]>,
+    ) -> Result<Option<(usize, elf64::Sym)>, Error> {
+        if self.gnu.is_some() {
+            self.lookup_gnu(name, version)
+        } else {
+            self.lookup_sysv(name, version)
+        }
+    }
+
+    fn lookup_sysv(
+        &self,
+        name: &[u8],
+        version: Option<&[u8]>,
+    ) -> Result<Option<(usize, elf64::Sym)>, Error> {
+        let Some(table) = self.sysv else {
+            return Ok(None);
+        };
+        let encoding = self.symbols.encoding;
+        let nbucket = table.buckets.len() / 4;
+        let nchain = table.chains.len() / 4;
+
+        let h = sysv_hash(name) as usize;
+        let mut idx = encoding.u32(&table.buckets[(h % nbucket) * 4..]) as usize;
+
+        // Every step visits a distinct index, unless the chains are cyclic.
+        for _ in 0..nchain {
+            if idx == 0 {
+                break;
+            }
+            if idx >= nchain {
+                return Err(Error::HashTable);
+            }
+            if let Some(v) = self.check(idx, name, version)? {
+                return Ok(Some((idx, v)));
+            }
+            idx = encoding.u32(&table.chains[idx * 4..]) as usize;
+        }
+
+        Ok(None)
+    }
+
+    fn lookup_gnu(
+        &self,
+        name: &[u8],
+        version: Option<&[u8]>,
+    ) -> Result<Option<(usize, elf64::Sym)>, Error> {
+        let Some(table) = self.gnu else {
+            return Ok(None);
+        };
+        let encoding = self.symbols.encoding;
+        let bits = encoding.word_size() as u32 * 8;
+        let nbloom = table.bloom.len() / encoding.word_size();
+        let nbuckets = table.buckets.len() / 4;
+        let h = gnu_hash(name);
+
+        let word = ((h / bits) as usize % nbloom) * encoding.word_size();
+        let word = encoding.word(&table.bloom[word..]);
+        let mask = (1u64 << (h % bits)) | (1u64 << ((h >> (table.shift % 32)) % bits));
+        if word & mask != mask {
+            return Ok(None);
+        }
+
+        let mut idx = encoding.u32(&table.buckets[(h as usize % nbuckets) * 4..]) as usize;
+        if idx < table.symoffset as usize {
+            return Ok(None);
+        }
+
+        loop {
+            let Some(v) = table.chains.get((idx - table.symoffset as usize) * 4..) else {
+                return Err(Error::HashTable);
+            };
+            let h2 = encoding.u32(v);
+            if (h | 1) == (h2 | 1) {
+                if let Some(v) = self.check(idx, name, version)? {
+                    return Ok(Some((idx, v)));
+                }
+            }
+            if h2 & 1 == 1 {
+                return Ok(None);
+            }
+            idx += 1;
+        }
+    }
+
+    // Check whether the symbol at `idx` is a definition of `name` in the
+    // requested version.
+    fn check(
+        &self,
+        idx: usize,
+        name: &[u8],
+        version: Option<&[u8]>,
+    ) -> Result<Option<elf64::Sym>, Error> {
+        let sym = self.symbols.get(idx)?;
+        if sym.st_shndx == elf64::SHN_UNDEF || self.symbols.name(&sym)? != name {
+            return Ok(None);
+        }
+
+        let Some(v) = self.versym(idx) else {
+            return Ok(Some(sym));
+        };
+        let ndx = v & elf64::VERSYM_VERSION;
+        let hidden = v & elf64::VERSYM_HIDDEN != 0;
+        let matches = match (ndx, version) {
+            (elf64::VER_NDX_LOCAL, _) => false,
+            (_, None) => !hidden,
+            (elf64::VER_NDX_GLOBAL, Some(_)) => !hidden,
+            (_, Some(version)) => self.definition(ndx)? == Some(version),
+        };
+
+        Ok(matches.then_some(sym))
+    }
+
+    fn versym(&self, idx: usize) -> Option<u16> {
+        let v = self.versym.get(idx * 2..idx * 2 + 2)?;
+        Some(self.symbols.encoding.u16(v))
+    }
+
+    /// Return the version of the symbol at index `idx`. This is `None` for
+    /// unversioned symbols, or if the object carries no version table.
+    pub fn version(&self, idx: usize) -> Result<Option<Version<'read>>, Error> {
+        let Some(v) = self.versym(idx) else {
+            return Ok(None);
+        };
+        let map = |addr, len| self.elf.map_vaddr(addr, len);
+        self.tables(&map).version(v)
+    }
+
+    // Return the name of the version definition with index `ndx`.
+    fn definition(&self, ndx: u16) -> Result<Option<&'read [u8]>, Error> {
+        let map = |addr, len| self.elf.map_vaddr(addr, len);
+        self.tables(&map).definition(ndx)
+    }
+
+    fn tables<'map>(
+        &self,
+        map: &'map dyn Fn(u64, u64) -> Result<&'read [u8], Error>,
+    ) -> Tables<'map, 'read> {
+        Tables {
+            encoding: self.symbols.encoding,
+            strings: self.symbols.strings,
+            verdef: self.verdef,
+            verneed: self.verneed,
+            map: map,
+        }
+    }
+}
+
+impl<'map, 'read> Tables<'map, 'read> {
+    // Resolve the version of a symbol with the version table entry `v`.
+    fn version(&self, v: u16) -> Result<Option<Version<'read>>, Error> {
+        let ndx = v & elf64::VERSYM_VERSION;
+        let hidden = v & elf64::VERSYM_HIDDEN != 0;
+        if ndx == elf64::VER_NDX_LOCAL || ndx == elf64::VER_NDX_GLOBAL {
+            return Ok(None);
+        }
+
+        if let Some(name) = self.definition(ndx)? {
+            return Ok(Some(Version { name: name, file: None, hidden: hidden }));
+        }
+        if let Some(v) = self.requirement(ndx)? {
+            return Ok(Some(Version { hidden: hidden, ..v }));
+        }
+
+        Err(Error::VersionTable)
+    }
+
+    // Return the name of the version definition with index `ndx`.
+    fn definition(&self, ndx: u16) -> Result<Option<&'read [u8]>, Error> {
+        let Some((mut addr, num)) = self.verdef else {
+            return Ok(None);
+        };
+        let encoding = self.encoding;
+        let size = size_of::<elf64::Verdef>() as u64;
+
+        for _ in 0..num {
+            let v = encoding.verdef((self.map)(addr, size)?);
+            if v.vd_version != elf64::VER_DEF_CURRENT {
+                return Err(Error::VersionTable);
+            }
+            if v.vd_ndx == ndx {
+                let aux = addr.checked_add(v.vd_aux as u64).ok_or(Error::Bounds)?;
+                let aux = (self.map)(aux, size_of::<elf64::Verdaux>() as u64)?;
+                let aux = encoding.verdaux(aux);
+                return self.strings.get(aux.vda_name as u64).map(Some);
+            }
+            if v.vd_next == 0 {
+                break;
+            }
+            addr = addr.checked_add(v.vd_next as u64).ok_or(Error::Bounds)?;
+        }
+
+        Ok(None)
+    }
+
+    // Return the version requirement with index `ndx`.
+    fn requirement(&self, ndx: u16) -> Result<Option<Version<'read>>, Error> {
+        let Some((mut addr, num)) = self.verneed else {
+            return Ok(None);
+        };
+        let encoding = self.encoding;
+        let size = size_of::<elf64::Verneed>() as u64;
+        let size_aux = size_of::<elf64::Vernaux>() as u64;
+
+        for _ in 0..num {
+            let v = encoding.verneed((self.map)(addr, size)?);
+            if v.vn_version != elf64::VER_NEED_CURRENT {
+                return Err(Error::VersionTable);
+            }
+
+            let mut aux = addr.checked_add(v.vn_aux as u64).ok_or(Error::Bounds)?;
+            for _ in 0..v.vn_cnt {
+                let a = encoding.vernaux((self.map)(aux, size_aux)?);
+                if a.vna_other == ndx {
+                    return Ok(Some(Version {
+                        name: self.strings.get(a.vna_name as u64)?,
+                        file: Some(self.strings.get(v.vn_file as u64)?),
+                        hidden: false,
+                    }));
+                }
+                aux = aux.checked_add(a.vna_next as u64).ok_or(Error::Bounds)?;
+            }
+
+            if v.vn_next == 0 {
+                break;
+            }
+            addr = addr.checked_add(v.vn_next as u64).ok_or(Error::Bounds)?;
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'read> SymbolVersions<'read> {
+    /// Return the number of entries of the symbol version table.
+    pub fn len(&self) -> usize {
+        self.versym.len() / 2
+    }
+
+    /// Return whether the symbol version table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the index of the symbol table the versions apply to.
+    pub fn symbol_table(&self) -> usize {
+        self.symtab
+    }
+
+    /// Return the version of the symbol at index `idx`. This is `None` for
+    /// unversioned symbols.
+    pub fn version(&self, idx: usize) -> Result<Option<Version<'read>>, Error> {
+        let Some(v) = self.versym.get(idx * 2..idx * 2 + 2) else {
+            return Ok(None);
+        };
+        let map = |off: u64, len: u64| -> Result<&'read [u8], Error> {
+            let end = off.checked_add(len).ok_or(Error::Bounds)?;
+            usize::try_from(off).ok()
+                .zip(usize::try_from(end).ok())
+                .and_then(|(off, end)| self.data.get(off..end))
+                .ok_or(Error::Bounds)
+        };
+        let tables = Tables {
+            encoding: self.encoding,
+            strings: self.strings,
+            verdef: self.verdef,
+            verneed: self.verneed,
+            map: &map,
+        };
+        tables.version(self.encoding.u16(v))
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> ElfFile<'read, Read> {
+    /// Locate the symbol version table (`.gnu.version`) of an ELF file via
+    /// its section headers, together with the version definitions
+    /// (`.gnu.version_d`) and requirements (`.gnu.version_r`). This is
+    /// `None` if the file has no symbol version table.
+    ///
+    /// Unlike [`DynamicSymbols`], this works for files whose dynamic section
+    /// is not available, like separate debug files.
+    pub fn symbol_versions(&self) -> Result<Option<SymbolVersions<'read>>, Error> {
+        let Some(versym) = self.section_headers().find(|v| v.sh_type == elf64::SHT_GNU_VERSYM) else {
+            return Ok(None);
+        };
+        let symtab = versym.sh_link as usize;
+        let strtab = self.section_header(symtab)?.sh_link as usize;
+        let strings = self.string_table(&self.section_header(strtab)?)?;
+
+        // The version tables are mapped as a whole, so offsets within them
+        // can be resolved without further I/O.
+        let mut start = u64::MAX;
+        let mut end = 0;
+        let mut tables = [None, None];
+        for shdr in self.section_headers() {
+            let slot = match shdr.sh_type {
+                elf64::SHT_GNU_VERDEF => 0,
+                elf64::SHT_GNU_VERNEED => 1,
+                _ => continue,
+            };
+            if shdr.sh_link as usize != strtab {
+                return Err(Error::VersionTable);
+            }
+            start = start.min(shdr.sh_offset);
+            end = end.max(shdr.sh_offset.checked_add(shdr.sh_size).ok_or(Error::Bounds)?);
+            tables[slot] = Some((shdr.sh_offset, shdr.sh_info as u64));
+        }
+        let data = match start < end {
+            true => self.map(start, end - start)?,
+            false => &[][..],
+        };
+        let [verdef, verneed] = tables.map(|v| v.map(|(off, num)| (off - start, num)));
+
+        Ok(Some(SymbolVersions {
+            encoding: self.encoding(),
+            symtab: symtab,
+            versym: self.section_data(&versym)?,
+            verdef: verdef,
+            verneed: verneed,
+            data: data,
+            strings: strings,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+    use super::*;
+    use super::super::Class;
+    use super::super::build::{Out, Symbol};
+
+    // Pad the fixture to 8 bytes.
+    fn align(out: &mut Out) {
+        out.buf.resize(out.buf.len().next_multiple_of(8), 0);
+    }
+
+    const BASE: u64 = 0x10000;
+    const STRTAB: &[u8] = b"\0libx.so\0LIBX_1\0LIBX_2\0libc.so.6\0GLIBC_2.0\0foo\0bar\0baz\0qux\0";
+
+    // Return the offset of `name` in the string table of the fixture.
+    fn str_off(name: &[u8]) -> u32 {
+        STRTAB.windows(name.len() + 2)
+            .position(|v| v[0] == 0 && &v[1..=name.len()] == name && v[name.len() + 1] == 0)
+            .unwrap() as u32 + 1
+    }
+
+    // Create a synthetic shared object at address `BASE` with a single
+    // `PT_LOAD` segment covering the file. It defines `foo@LIBX_1`,
+    // `foo@@LIBX_2`, the unversioned `bar`, and the local `qux`, and it
+    // references `baz@GLIBC_2.0` of `libc.so.6`. The hash tables are
+    // included as selected.
+    fn fixture(encoding: Encoding, sysv: bool, gnu: bool) -> Vec<u8> {
+        // (name, defined, versym)
+        let syms: [(&[u8], bool, u16); 6] = [
+            (b"", false, 0),
+            (b"baz", false, 4),
+            (b"foo", true, 2 | elf64::VERSYM_HIDDEN),
+            (b"foo", true, 3),
+            (b"bar", true, 1),
+            (b"qux", true, 0),
+        ];
+        let symoffset = 2;
+
+        let ehsize = encoding.ehdr_size();
+        let phsize = encoding.phdr_size();
+        let mut out = Out::new(encoding);
+        out.buf.resize(ehsize + 2 * phsize, 0);
+        align(&mut out);
+
+        let off_strtab = out.buf.len();
+        out.buf.extend_from_slice(STRTAB);
+        align(&mut out);
+
+        let off_symtab = out.buf.len();
+        for (i, (name, defined, _)) in syms.iter().enumerate() {
+            let name = if name.is_empty() { 0 } else { str_off(name) };
+            let (value, shndx) = if *defined { (BASE + 0x100 * i as u64, 1) } else { (0, 0) };
+            let info = (elf64::STB_GLOBAL << 4) | elf64::STT_FUNC;
+            out.sym(name, &Symbol {
+                st_info: info,
+                st_shndx: shndx,
+                st_value: value,
+                st_size: 4,
+                ..Default::default()
+            }).unwrap();
+        }
+        align(&mut out);
+
+        let off_sysv = out.buf.len();
+        out.u32(1);
+        out.u32(syms.len() as u32);
+        out.u32(5);
+        for v in [0, 0, 1, 2, 3, 4] {
+            out.u32(v);
+        }
+        align(&mut out);
+
+        let off_gnu = out.buf.len();
+        let bits = encoding.word_size() as u32 * 8;
+        let shift = 6;
+        let mut bloom = 0u64;
+        for (name, _, _) in &syms[symoffset..] {
+            let h = gnu_hash(name);
+            bloom |= 1 << (h % bits);
+            bloom |= 1 << ((h >> shift) % bits);
+        }
+        out.u32(1);
+        out.u32(symoffset as u32);
+        out.u32(1);
+        out.u32(shift);
+        out.word(bloom).unwrap();
+        out.u32(symoffset as u32);
+        for (i, (name, _, _)) in syms.iter().enumerate().skip(symoffset) {
+            let last = (i == syms.len() - 1) as u32;
+            out.u32((gnu_hash(name) & !1) | last);
+        }
+        align(&mut out);
+
+        let off_versym = out.buf.len();
+        for (_, _, v) in &syms {
+            out.u16(*v);
+        }
+        align(&mut out);
+
+        let off_verdef = out.buf.len();
+        for (i, names) in [&[&b"libx.so"[..]][..], &[b"LIBX_1"], &[b"LIBX_2", b"LIBX_1"]].iter().enumerate() {
+            let flags = if i == 0 { elf64::VER_FLG_BASE } else { 0 };
+            let next = if i == 2 { 0 } else { 20 + 8 * names.len() as u32 };
+            out.u16(elf64::VER_DEF_CURRENT);
+            out.u16(flags);
+            out.u16(i as u16 + 1);
+            out.u16(names.len() as u16);
+            out.u32(sysv_hash(names[0]));
+            out.u32(20);
+            out.u32(next);
+            for (j, name) in names.iter().enumerate() {
+                out.u32(str_off(name));
+                out.u32(if j + 1 == names.len() { 0 } else { 8 });
+            }
+        }
+        align(&mut out);
+
+        let off_verneed = out.buf.len();
+        out.u16(elf64::VER_NEED_CURRENT);
+        out.u16(1);
+        out.u32(str_off(b"libc.so.6"));
+        out.u32(16);
+        out.u32(0);
+        out.u32(sysv_hash(b"GLIBC_2.0"));
+        out.u16(0);
+        out.u16(4);
+        out.u32(str_off(b"GLIBC_2.0"));
+        out.u32(0);
+        align(&mut out);
+
+        let off_dynamic = out.buf.len();
+        let mut dynamic = alloc::vec![
+            (elf64::DT_STRTAB, BASE + off_strtab as u64),
+            (elf64::DT_STRSZ, STRTAB.len() as u64),
+            (elf64::DT_SYMTAB, BASE + off_symtab as u64),
+            (elf64::DT_SYMENT, encoding.sym_size() as u64),
+            (elf64::DT_VERSYM, BASE + off_versym as u64),
+            (elf64::DT_VERDEF, BASE + off_verdef as u64),
+            (elf64::DT_VERDEFNUM, 3),
+            (elf64::DT_VERNEED, BASE + off_verneed as u64),
+            (elf64::DT_VERNEEDNUM, 1),
+        ];
+        if sysv {
+            dynamic.push((elf64::DT_HASH, BASE + off_sysv as u64));
+        }
+        if gnu {
+            dynamic.push((elf64::DT_GNU_HASH, BASE + off_gnu as u64));
+        }
+        dynamic.push((elf64::DT_NULL, 0));
+        for (tag, val) in dynamic {
+            out.word(tag as u64).unwrap();
+            out.word(val).unwrap();
+        }
+        let end = out.buf.len();
+
+        // Write the headers last, now that all offsets are known.
+        let mut hdr = Out::new(encoding);
+        hdr.buf.extend_from_slice(&elf64::ELFMAG);
+        hdr.buf.push(match encoding.class {
+            Class::Elf32 => elf64::ELFCLASS32,
+            Class::Elf64 => elf64::ELFCLASS64,
+        });
+        hdr.buf.push(match encoding.big_endian {
+            false => elf64::ELFDATA2LSB,
+            true => elf64::ELFDATA2MSB,
+        });
+        hdr.buf.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        hdr.u16(elf64::ET_DYN);
+        hdr.u16(elf64::EM_X86_64);
+        hdr.u32(1);
+        hdr.word(0).unwrap();
+        hdr.word(ehsize as u64).unwrap();
+        hdr.word(0).unwrap();
+        hdr.u32(0);
+        for v in [ehsize, phsize, 2, 0, 0, 0] {
+            hdr.u16(v as u16);
+        }
+        for (p_type, off, size) in [
+            (elf64::PT_LOAD, 0, end),
+            (elf64::PT_DYNAMIC, off_dynamic, end - off_dynamic),
+        ] {
+            hdr.phdr(&elf64::Phdr {
+                p_type: p_type,
+                p_flags: elf64::PF_R,
+                p_offset: off as u64,
+                p_vaddr: BASE + off as u64,
+                p_paddr: BASE + off as u64,
+                p_filesz: size as u64,
+                p_memsz: size as u64,
+                p_align: 8,
+            }).unwrap();
+        }
+        out.buf[..hdr.buf.len()].copy_from_slice(&hdr.buf);
+
+        out.buf
+    }
+
+    // Verify the hash functions against known values.
+    #[test]
+    fn hash() {
+        assert_eq!(sysv_hash(b""), 0);
+        assert_eq!(sysv_hash(b"printf"), 0x077905a6);
+        assert_eq!(sysv_hash(b"__vdso_clock_gettime"), 0x0d35ec75);
+        assert_eq!(gnu_hash(b""), 0x00001505);
+        assert_eq!(gnu_hash(b"printf"), 0x156b2bb8);
+        assert_eq!(gnu_hash(b"__vdso_clock_gettime"), 0x6e43a318);
+    }
+
+    // Look up symbols of synthetic objects via both hash tables, and both
+    // as file and as loaded image.
+    #[test]
+    fn synthetic() {
+        for class in [Class::Elf32, Class::Elf64] {
+            for big_endian in [false, true] {
+                let encoding = Encoding { class: class, big_endian: big_endian };
+                for (sysv, gnu) in [(true, false), (false, true), (true, true)] {
+                    let data = fixture(encoding, sysv, gnu);
+                    for image in [false, true] {
+                        let elf = match image {
+                            false => ElfFile::with(&data[..]).unwrap(),
+                            true => ElfFile::with_image(&data[..]).unwrap(),
+                        };
+                        let dynsym = DynamicSymbols::with(&elf).unwrap();
+                        assert_eq!(dynsym.symbols().len(), 6);
+
+                        let lookup = |name: &[u8], version: Option<&[u8]>| {
+                            let a = dynsym.lookup_sysv(name, version).unwrap();
+                            let b = dynsym.lookup_gnu(name, version).unwrap();
+                            if sysv && gnu {
+                                assert_eq!(a.map(|v| v.0), b.map(|v| v.0));
+                            }
+                            dynsym.lookup(name, version).unwrap().map(|v| v.0)
+                        };
+
+                        assert_eq!(lookup(b"foo", None), Some(3));
+                        assert_eq!(lookup(b"foo", Some(b"LIBX_2")), Some(3));
+                        assert_eq!(lookup(b"foo", Some(b"LIBX_1")), Some(2));
+                        assert_eq!(lookup(b"foo", Some(b"LIBX_3")), None);
+                        assert_eq!(lookup(b"bar", None), Some(4));
+                        assert_eq!(lookup(b"bar", Some(b"LIBX_1")), Some(4));
+                        assert_eq!(lookup(b"baz", None), None);
+                        assert_eq!(lookup(b"qux", None), None);
+                        assert_eq!(lookup(b"quux", None), None);
+
+                        let (_, sym) = dynsym.lookup(b"foo", None).unwrap().unwrap();
+                        assert_eq!(sym.st_value, BASE + 0x300);
+                        assert_eq!(sym.st_size, 4);
+
+                        assert_eq!(
+                            dynsym.version(2).unwrap(),
+                            Some(Version { name: b"LIBX_1", file: None, hidden: true }),
+                        );
+                        assert_eq!(
+                            dynsym.version(3).unwrap(),
+                            Some(Version { name: b"LIBX_2", file: None, hidden: false }),
+                        );
+                        assert_eq!(
+                            dynsym.version(1).unwrap(),
+                            Some(Version { name: b"GLIBC_2.0", file: Some(b"libc.so.6"), hidden: false }),
+                        );
+                        assert_eq!(dynsym.version(4).unwrap(), None);
+                    }
+                }
+            }
+        }
+    }
+
+    // Verify that malformed objects are rejected.
+    #[test]
+    fn invalid() {
+        let encoding = Encoding { class: Class::Elf64, big_endian: false };
+
+        let data = fixture(encoding, false, false);
+        let elf = ElfFile::with(&data[..]).unwrap();
+        assert_eq!(DynamicSymbols::with(&elf).err(), Some(Error::Dynamic));
+
+        // Clear the bucket count of the SysV hash table.
+        let mut data = fixture(encoding, true, false);
+        let elf = ElfFile::with(&data[..]).unwrap();
+        let (_, off) = elf.dynamic().unwrap()
+            .map(|v| (v.d_tag, v.d_val))
+            .find(|v| v.0 == elf64::DT_HASH as u64)
+            .unwrap();
+        let off = (off - BASE) as usize;
+        data[off..off + 4].copy_from_slice(&0u32.to_le_bytes());
+        let elf = ElfFile::with(&data[..]).unwrap();
+        assert_eq!(DynamicSymbols::with(&elf).err(), Some(Error::HashTable));
+
+        // Point the version table of `foo@@LIBX_2` to an unknown version.
+        let mut data = fixture(encoding, true, true);
+        let elf = ElfFile::with(&data[..]).unwrap();
+        let (_, off) = elf.dynamic().unwrap()
+            .map(|v| (v.d_tag, v.d_val))
+            .find(|v| v.0 == elf64::DT_VERSYM as u64)
+            .unwrap();
+        let off = (off - BASE) as usize + 3 * 2;
+        data[off..off + 2].copy_from_slice(&9u16.to_le_bytes());
+        let elf = ElfFile::with(&data[..]).unwrap();
+        let dynsym = DynamicSymbols::with(&elf).unwrap();
+        assert_eq!(dynsym.version(3).err(), Some(Error::VersionTable));
+        assert!(dynsym.lookup(b"foo", Some(b"LIBX_2")).unwrap().is_none());
+    }
+
+    // Verify the section-based version tables of the test executable match
+    // the ones found via its dynamic section.
+    #[test]
+    fn self_exe() {
+        let data = std::fs::read("/proc/self/exe").unwrap();
+        let elf = ElfFile::with(&data[..]).unwrap();
+        let Some(versions) = elf.symbol_versions().unwrap() else {
+            return;
+        };
+        let dynsym = DynamicSymbols::with(&elf).unwrap();
+        let shdr = elf.section_header(versions.symbol_table()).unwrap();
+        assert_eq!(shdr.sh_type, elf64::SHT_DYNSYM);
+        assert_eq!(versions.len(), dynsym.symbols().len());
+
+        let mut n = 0;
+        for idx in 0..versions.len() {
+            let v = versions.version(idx).unwrap();
+            assert_eq!(v, dynsym.version(idx).unwrap());
+            n += v.is_some() as usize;
+        }
+        assert!(n > 0);
+    }
+
+    // Look up `clock_gettime()` in the vDSO of the running process.
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))]
+    #[test]
+    fn vdso() {
+        let maps = std::fs::read_to_string("/proc/self/maps").unwrap();
+        let Some(line) = maps.lines().find(|v| v.ends_with("[vdso]")) else {
+            return;
+        };
+        let range = line.split(' ').next().unwrap();
+        let (start, end) = range.split_once('-').unwrap();
+        let start = usize::from_str_radix(start, 16).unwrap();
+        let end = usize::from_str_radix(end, 16).unwrap();
+
+        // SAFETY: The vDSO is mapped read-only for the lifetime of the
+        //     process.
+        let data = unsafe { core::slice::from_raw_parts(start as *const u8, end - start) };
+        let elf = ElfFile::with_image(data).unwrap();
+        let dynsym = DynamicSymbols::with(&elf).unwrap();
+
+        let (name, version): (&[u8], &[u8]) = if cfg!(target_arch = "aarch64") {
+            (b"__kernel_clock_gettime", b"LINUX_2.6.39")
+        } else {
+            (b"__vdso_clock_gettime", b"LINUX_2.6")
+        };
+        let (idx, sym) = dynsym.lookup(name, Some(version)).unwrap().unwrap();
+        assert_ne!(sym.st_value, 0);
+        assert_eq!(dynsym.version(idx).unwrap().unwrap().name, version);
+        assert_eq!(dynsym.lookup(name, None).unwrap().unwrap().0, idx);
+    }
+}
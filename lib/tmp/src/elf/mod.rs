@@ -0,0 +1,1184 @@
+//! ELF File Reader
+//!
+//! The definitions in [`sys::ffi::elf`] describe the Executable and Linkable
+//! Format, but come without any accompanying implementation. This module
+//! provides a reader for ELF files on top of these definitions. It works on
+//! any [`io::map::Read`] implementation and validates the identification,
+//! the ELF header, and the location of the program and section header tables
+//! before any of it is exposed.
+//!
+//! ELF files of either class and either data encoding are supported,
+//! regardless of the host platform. Structures are read via the matching
+//! [`elf32`] or [`elf64`] definitions and converted from their foreign byte
+//! order via [`osi::ffi`]. All structures are exposed as their [`elf64`]
+//! variants in native byte order, widening 32-bit values where necessary.
+//!
+//! Tables and section contents are mapped directly from the reader, rather
+//! than copied. Hence, the reader must provide any requested range as a
+//! single contiguous mapping.
+//!
+//! Besides ELF files, loaded ELF objects can be read as well. In this case,
+//! the reader provides the memory image of the object and section headers
+//! are ignored, since they are usually not loaded.
+//!
+//! ELF files can be written via the [`build::Builder`].
+
+pub mod build;
+pub mod debuglink;
+pub mod dynsym;
+pub mod note;
+pub mod reloc;
+
+use core::ops::ControlFlow as Flow;
+use osi::ffi::{BigEndian, LittleEndian, NativeEndian};
+use sys::ffi::elf::{elf32, elf64};
+
+use crate::io;
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// The underlying I/O operation failed, or was interrupted if `None`.
+    Io(Option<io::map::Error>),
+    /// The reader cannot provide the requested range as a single contiguous
+    /// mapping.
+    Discontiguous,
+    /// The data does not start with the ELF magic.
+    Magic,
+    /// The ELF class is neither `ELFCLASS32` nor `ELFCLASS64`.
+    Class,
+    /// The data encoding is neither `ELFDATA2LSB` nor `ELFDATA2MSB`.
+    Data,
+    /// The identification or header version is not `EV_CURRENT`.
+    Version,
+    /// The ELF header size is smaller than the header of its class.
+    HeaderSize,
+    /// A table entry size is smaller than the structure of its class.
+    EntrySize,
+    /// A table or section exceeds the end of the file.
+    Bounds,
+    /// The ELF header, program header table, and section header table
+    /// overlap.
+    Overlap,
+    /// A section index is out of range.
+    SectionIndex,
+    /// A section is of a type unsuitable for the requested operation.
+    SectionType,
+    /// A segment is of a type unsuitable for the requested operation.
+    SegmentType,
+    /// A symbol index is out of range.
+    SymbolIndex,
+    /// A string table offset is out of range.
+    StringIndex,
+    /// A string table does not end with a NUL byte.
+    Unterminated,
+    /// The virtual address is not covered by any `PT_LOAD` segment.
+    Unmapped,
+    /// The dynamic section lacks required entries.
+    Dynamic,
+    /// A symbol hash table is malformed.
+    HashTable,
+    /// A symbol version table is malformed or refers to unknown versions.
+    VersionTable,
+    /// A relocation is of a type not supported by the operation.
+    Relocation,
+    /// A note or its descriptor is malformed.
+    Note,
+    /// A debug link section is malformed.
+    DebugLink,
+    /// A value exceeds the range of its field in the ELF class.
+    Range,
+}
+
+/// ELF class of a file.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Class {
+    Elf32,
+    Elf64,
+}
+
+/// Class and data encoding of an ELF file.
+///
+/// This describes how ELF structures are laid out in a file and provides
+/// the decoders to convert them into their native representation.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Encoding {
+    pub class: Class,
+    pub big_endian: bool,
+}
+
+/// Bounds-checked iterator over the entries of an ELF table.
+///
+/// The entries are decoded on access and yielded in native representation.
+#[derive(Clone, Debug)]
+pub struct Entries<'read, T> {
+    encoding: Encoding,
+    data: &'read [u8],
+    entsize: usize,
+    idx: usize,
+    num: usize,
+    decode: fn(&Encoding, &[u8]) -> T,
+}
+
+/// String table of an ELF file with NUL-terminated strings.
+#[derive(Clone, Copy, Debug)]
+pub struct StringTable<'read> {
+    data: &'read [u8],
+}
+
+/// Iterator over all strings of a string table, yielding their offset and
+/// their content without terminating NUL.
+#[derive(Clone, Debug)]
+pub struct Strings<'read> {
+    data: &'read [u8],
+    idx: usize,
+}
+
+/// Symbol table of an ELF file together with its linked string table.
+#[derive(Clone, Copy, Debug)]
+pub struct SymbolTable<'read> {
+    encoding: Encoding,
+    data: &'read [u8],
+    entsize: usize,
+    num: usize,
+    strings: StringTable<'read>,
+}
+
+/// Reader for ELF files.
+pub struct ElfFile<'read, Read: ?Sized> {
+    read: &'read Read,
+    encoding: Encoding,
+    ehdr: elf64::Ehdr,
+    phdrs: &'read [u8],
+    phnum: usize,
+    shdrs: &'read [u8],
+    shnum: usize,
+    shstrndx: usize,
+    image: Option<u64>,
+}
+
+// Copy a structure from the start of `data`.
+//
+// Safety: `T` must be valid for any bit pattern.
+unsafe fn load<T: Copy>(data: &[u8]) -> T {
+    assert!(data.len() >= size_of::<T>());
+
+    // SAFETY: The size was verified above and the caller guarantees that any
+    //     bit pattern is a valid `T`.
+    unsafe { core::ptr::read_unaligned(data.as_ptr() as *const T) }
+}
+
+// Check whether the non-empty ranges `a` and `b` overlap.
+fn overlaps(a: (u64, u64), b: (u64, u64)) -> bool {
+    a.1 > 0 && b.1 > 0 && a.0 < b.0 + b.1 && b.0 < a.0 + a.1
+}
+
+impl core::convert::From<io::map::Error> for Error {
+    fn from(v: io::map::Error) -> Self {
+        Self::Io(Some(v))
+    }
+}
+
+impl Encoding {
+    /// Convert a value of the file from its foreign byte order to the native
+    /// byte order.
+    pub fn native<Raw: Copy>(&self, raw: Raw) -> Raw
+    where
+        BigEndian<Raw>: NativeEndian<Raw>,
+        LittleEndian<Raw>: NativeEndian<Raw>,
+    {
+        if self.big_endian {
+            osi::ffi::to_native(osi::ffi::from_raw::<BigEndian<Raw>, Raw>(raw))
+        } else {
+            osi::ffi::to_native(osi::ffi::from_raw::<LittleEndian<Raw>, Raw>(raw))
+        }
+    }
+
+    /// Return the size of the ELF header.
+    pub fn ehdr_size(&self) -> usize {
+        match self.class {
+            Class::Elf32 => size_of::<elf32::Ehdr>(),
+            Class::Elf64 => size_of::<elf64::Ehdr>(),
+        }
+    }
+
+    /// Return the size of a program header.
+    pub fn phdr_size(&self) -> usize {
+        match self.class {
+            Class::Elf32 => size_of::<elf32::Phdr>(),
+            Class::Elf64 => size_of::<elf64::Phdr>(),
+        }
+    }
+
+    /// Return the size of a section header.
+    pub fn shdr_size(&self) -> usize {
+        match self.class {
+            Class::Elf32 => size_of::<elf32::Shdr>(),
+            Class::Elf64 => size_of::<elf64::Shdr>(),
+        }
+    }
+
+    /// Return the size of a symbol.
+    pub fn sym_size(&self) -> usize {
+        match self.class {
+            Class::Elf32 => size_of::<elf32::Sym>(),
+            Class::Elf64 => size_of::<elf64::Sym>(),
+        }
+    }
+
+    /// Return the size of a dynamic entry.
+    pub fn dyn_size(&self) -> usize {
+        match self.class {
+            Class::Elf32 => size_of::<elf32::Dyn>(),
+            Class::Elf64 => size_of::<elf64::Dyn>(),
+        }
+    }
+
+    /// Return the size of a machine word, which is used for addresses and
+    /// offsets.
+    pub fn word_size(&self) -> usize {
+        match self.class {
+            Class::Elf32 => 4,
+            Class::Elf64 => 8,
+        }
+    }
+
+    /// Decode the 16-bit value at the start of `data`.
+    pub fn u16(&self, data: &[u8]) -> u16 {
+        self.native(u16::from_ne_bytes([data[0], data[1]]))
+    }
+
+    /// Decode the 32-bit value at the start of `data`.
+    pub fn u32(&self, data: &[u8]) -> u32 {
+        self.native(u32::from_ne_bytes([data[0], data[1], data[2], data[3]]))
+    }
+
+    /// Decode the machine word at the start of `data`.
+    pub fn word(&self, data: &[u8]) -> u64 {
+        match self.class {
+            Class::Elf32 => self.u32(data) as u64,
+            Class::Elf64 => {
+                let mut v = [0; 8];
+                v.copy_from_slice(&data[..8]);
+                self.native(u64::from_ne_bytes(v))
+            },
+        }
+    }
+
+    /// Decode the ELF header at the start of `data`.
+    ///
+    /// Panics if `data` is shorter than [`Self::ehdr_size()`].
+    pub fn ehdr(&self, data: &[u8]) -> elf64::Ehdr {
+        match self.class {
+            Class::Elf32 => {
+                // SAFETY: `Ehdr` is valid for any bit pattern.
+                let v: elf32::Ehdr = unsafe { load(data) };
+                elf64::Ehdr {
+                    e_ident: v.e_ident,
+                    e_type: self.native(v.e_type),
+                    e_machine: self.native(v.e_machine),
+                    e_version: self.native(v.e_version),
+                    e_entry: self.native(v.e_entry) as u64,
+                    e_phoff: self.native(v.e_phoff) as u64,
+                    e_shoff: self.native(v.e_shoff) as u64,
+                    e_flags: self.native(v.e_flags),
+                    e_ehsize: self.native(v.e_ehsize),
+                    e_phentsize: self.native(v.e_phentsize),
+                    e_phnum: self.native(v.e_phnum),
+                    e_shentsize: self.native(v.e_shentsize),
+                    e_shnum: self.native(v.e_shnum),
+                    e_shstrndx: self.native(v.e_shstrndx),
+                }
+            },
+            Class::Elf64 => {
+                // SAFETY: `Ehdr` is valid for any bit pattern.
+                let v: elf64::Ehdr = unsafe { load(data) };
+                elf64::Ehdr {
+                    e_ident: v.e_ident,
+                    e_type: self.native(v.e_type),
+                    e_machine: self.native(v.e_machine),
+                    e_version: self.native(v.e_version),
+                    e_entry: self.native(v.e_entry),
+                    e_phoff: self.native(v.e_phoff),
+                    e_shoff: self.native(v.e_shoff),
+                    e_flags: self.native(v.e_flags),
+                    e_ehsize: self.native(v.e_ehsize),
+                    e_phentsize: self.native(v.e_phentsize),
+                    e_phnum: self.native(v.e_phnum),
+                    e_shentsize: self.native(v.e_shentsize),
+                    e_shnum: self.native(v.e_shnum),
+                    e_shstrndx: self.native(v.e_shstrndx),
+                }
+            },
+        }
+    }
+
+    /// Decode the program header at the start of `data`.
+    ///
+    /// Panics if `data` is shorter than [`Self::phdr_size()`].
+    pub fn phdr(&self, data: &[u8]) -> elf64::Phdr {
+        match self.class {
+            Class::Elf32 => {
+                // SAFETY: `Phdr` is valid for any bit pattern.
+                let v: elf32::Phdr = unsafe { load(data) };
+                elf64::Phdr {
+                    p_type: self.native(v.p_type),
+                    p_flags: self.native(v.p_flags),
+                    p_offset: self.native(v.p_offset) as u64,
+                    p_vaddr: self.native(v.p_vaddr) as u64,
+                    p_paddr: self.native(v.p_paddr) as u64,
+                    p_filesz: self.native(v.p_filesz) as u64,
+                    p_memsz: self.native(v.p_memsz) as u64,
+                    p_align: self.native(v.p_align) as u64,
+                }
+            },
+            Class::Elf64 => {
+                // SAFETY: `Phdr` is valid for any bit pattern.
+                let v: elf64::Phdr = unsafe { load(data) };
+                elf64::Phdr {
+                    p_type: self.native(v.p_type),
+                    p_flags: self.native(v.p_flags),
+                    p_offset: self.native(v.p_offset),
+                    p_vaddr: self.native(v.p_vaddr),
+                    p_paddr: self.native(v.p_paddr),
+                    p_filesz: self.native(v.p_filesz),
+                    p_memsz: self.native(v.p_memsz),
+                    p_align: self.native(v.p_align),
+                }
+            },
+        }
+    }
+
+    /// Decode the section header at the start of `data`.
+    ///
+    /// Panics if `data` is shorter than [`Self::shdr_size()`].
+    pub fn shdr(&self, data: &[u8]) -> elf64::Shdr {
+        match self.class {
+            Class::Elf32 => {
+                // SAFETY: `Shdr` is valid for any bit pattern.
+                let v: elf32::Shdr = unsafe { load(data) };
+                elf64::Shdr {
+                    sh_name: self.native(v.sh_name),
+                    sh_type: self.native(v.sh_type),
+                    sh_flags: self.native(v.sh_flags) as u64,
+                    sh_addr: self.native(v.sh_addr) as u64,
+                    sh_offset: self.native(v.sh_offset) as u64,
+                    sh_size: self.native(v.sh_size) as u64,
+                    sh_link: self.native(v.sh_link),
+                    sh_info: self.native(v.sh_info),
+                    sh_addralign: self.native(v.sh_addralign) as u64,
+                    sh_entsize: self.native(v.sh_entsize) as u64,
+                }
+            },
+            Class::Elf64 => {
+                // SAFETY: `Shdr` is valid for any bit pattern.
+                let v: elf64::Shdr = unsafe { load(data) };
+                elf64::Shdr {
+                    sh_name: self.native(v.sh_name),
+                    sh_type: self.native(v.sh_type),
+                    sh_flags: self.native(v.sh_flags),
+                    sh_addr: self.native(v.sh_addr),
+                    sh_offset: self.native(v.sh_offset),
+                    sh_size: self.native(v.sh_size),
+                    sh_link: self.native(v.sh_link),
+                    sh_info: self.native(v.sh_info),
+                    sh_addralign: self.native(v.sh_addralign),
+                    sh_entsize: self.native(v.sh_entsize),
+                }
+            },
+        }
+    }
+
+    /// Decode the symbol at the start of `data`.
+    ///
+    /// Panics if `data` is shorter than [`Self::sym_size()`].
+    pub fn sym(&self, data: &[u8]) -> elf64::Sym {
+        match self.class {
+            Class::Elf32 => {
+                // SAFETY: `Sym` is valid for any bit pattern.
+                let v: elf32::Sym = unsafe { load(data) };
+                elf64::Sym {
+                    st_name: self.native(v.st_name),
+                    st_info: v.st_info,
+                    st_other: v.st_other,
+                    st_shndx: self.native(v.st_shndx),
+                    st_value: self.native(v.st_value) as u64,
+                    st_size: self.native(v.st_size) as u64,
+                }
+            },
+            Class::Elf64 => {
+                // SAFETY: `Sym` is valid for any bit pattern.
+                let v: elf64::Sym = unsafe { load(data) };
+                elf64::Sym {
+                    st_name: self.native(v.st_name),
+                    st_info: v.st_info,
+                    st_other: v.st_other,
+                    st_shndx: self.native(v.st_shndx),
+                    st_value: self.native(v.st_value),
+                    st_size: self.native(v.st_size),
+                }
+            },
+        }
+    }
+
+    /// Decode the dynamic entry at the start of `data`.
+    ///
+    /// Panics if `data` is shorter than [`Self::dyn_size()`].
+    pub fn dynamic(&self, data: &[u8]) -> elf64::Dyn {
+        match self.class {
+            Class::Elf32 => {
+                // SAFETY: `Dyn` is valid for any bit pattern.
+                let v: elf32::Dyn = unsafe { load(data) };
+                elf64::Dyn {
+                    d_tag: self.native(v.d_tag) as u64,
+                    d_val: self.native(v.d_val) as u64,
+                }
+            },
+            Class::Elf64 => {
+                // SAFETY: `Dyn` is valid for any bit pattern.
+                let v: elf64::Dyn = unsafe { load(data) };
+                elf64::Dyn {
+                    d_tag: self.native(v.d_tag),
+                    d_val: self.native(v.d_val),
+                }
+            },
+        }
+    }
+
+    /// Decode the version definition at the start of `data`.
+    ///
+    /// Panics if `data` is shorter than [`elf64::Verdef`].
+    pub fn verdef(&self, data: &[u8]) -> elf64::Verdef {
+        // SAFETY: `Verdef` is valid for any bit pattern.
+        let v: elf64::Verdef = unsafe { load(data) };
+        elf64::Verdef {
+            vd_version: self.native(v.vd_version),
+            vd_flags: self.native(v.vd_flags),
+            vd_ndx: self.native(v.vd_ndx),
+            vd_cnt: self.native(v.vd_cnt),
+            vd_hash: self.native(v.vd_hash),
+            vd_aux: self.native(v.vd_aux),
+            vd_next: self.native(v.vd_next),
+        }
+    }
+
+    /// Decode the version definition auxiliary entry at the start of `data`.
+    ///
+    /// Panics if `data` is shorter than [`elf64::Verdaux`].
+    pub fn verdaux(&self, data: &[u8]) -> elf64::Verdaux {
+        // SAFETY: `Verdaux` is valid for any bit pattern.
+        let v: elf64::Verdaux = unsafe { load(data) };
+        elf64::Verdaux {
+            vda_name: self.native(v.vda_name),
+            vda_next: self.native(v.vda_next),
+        }
+    }
+
+    /// Decode the version requirement at the start of `data`.
+    ///
+    /// Panics if `data` is shorter than [`elf64::Verneed`].
+    pub fn verneed(&self, data: &[u8]) -> elf64::Verneed {
+        // SAFETY: `Verneed` is valid for any bit pattern.
+        let v: elf64::Verneed = unsafe { load(data) };
+        elf64::Verneed {
+            vn_version: self.native(v.vn_version),
+            vn_cnt: self.native(v.vn_cnt),
+            vn_file: self.native(v.vn_file),
+            vn_aux: self.native(v.vn_aux),
+            vn_next: self.native(v.vn_next),
+        }
+    }
+
+    /// Decode the version requirement auxiliary entry at the start of
+    /// `data`.
+    ///
+    /// Panics if `data` is shorter than [`elf64::Vernaux`].
+    pub fn vernaux(&self, data: &[u8]) -> elf64::Vernaux {
+        // SAFETY: `Vernaux` is valid for any bit pattern.
+        let v: elf64::Vernaux = unsafe { load(data) };
+        elf64::Vernaux {
+            vna_hash: self.native(v.vna_hash),
+            vna_flags: self.native(v.vna_flags),
+            vna_other: self.native(v.vna_other),
+            vna_name: self.native(v.vna_name),
+            vna_next: self.native(v.vna_next),
+        }
+    }
+}
+
+impl<'read, T> Entries<'read, T> {
+    fn new(
+        encoding: Encoding,
+        data: &'read [u8],
+        entsize: usize,
+        num: usize,
+        decode: fn(&Encoding, &[u8]) -> T,
+    ) -> Self {
+        Self {
+            encoding: encoding,
+            data: data,
+            entsize: entsize,
+            idx: 0,
+            num: num,
+            decode: decode,
+        }
+    }
+
+    /// Return the entry at index `idx` of the table, regardless of the
+    /// iterator position.
+    pub fn get(&self, idx: usize) -> Option<T> {
+        if idx >= self.num {
+            return None;
+        }
+        Some((self.decode)(&self.encoding, &self.data[idx * self.entsize..]))
+    }
+}
+
+impl<'read, T> Iterator for Entries<'read, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.get(self.idx)?;
+        self.idx += 1;
+        Some(v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.num - self.idx;
+        (n, Some(n))
+    }
+}
+
+impl<'read, T> ExactSizeIterator for Entries<'read, T> {
+}
+
+impl<'read> StringTable<'read> {
+    /// Create a string table from its raw data, which must be empty or end
+    /// with a NUL byte.
+    pub fn with(data: &'read [u8]) -> Result<Self, Error> {
+        if data.last().is_some_and(|v| *v != 0) {
+            return Err(Error::Unterminated);
+        }
+        Ok(Self { data: data })
+    }
+
+    /// Return the raw data of the string table.
+    pub fn data(&self) -> &'read [u8] {
+        self.data
+    }
+
+    /// Return the string at offset `off` without terminating NUL.
+    pub fn get(&self, off: u64) -> Result<&'read [u8], Error> {
+        let off = usize::try_from(off).map_err(|_| Error::StringIndex)?;
+        let Some(v) = self.data.get(off..) else {
+            return Err(Error::StringIndex);
+        };
+        let Some(len) = v.iter().position(|v| *v == 0) else {
+            return Err(Error::StringIndex);
+        };
+        Ok(&v[..len])
+    }
+
+    /// Return an iterator over all strings of the table.
+    pub fn iter(&self) -> Strings<'read> {
+        Strings { data: self.data, idx: 0 }
+    }
+}
+
+impl<'read> Iterator for Strings<'read> {
+    type Item = (usize, &'read [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.data.get(self.idx..).filter(|v| !v.is_empty())?;
+        let len = v.iter().position(|v| *v == 0).unwrap_or(v.len());
+        let idx = self.idx;
+        self.idx += len + 1;
+        Some((idx, &v[..len]))
+    }
+}
+
+impl<'read> SymbolTable<'read> {
+    /// Return the number of symbols in the table, including the reserved
+    /// null symbol.
+    pub fn len(&self) -> usize {
+        self.num
+    }
+
+    /// Check whether the table contains no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.num == 0
+    }
+
+    /// Return the string table linked to the symbol table.
+    pub fn strings(&self) -> StringTable<'read> {
+        self.strings
+    }
+
+    /// Return an iterator over all symbols of the table.
+    pub fn iter(&self) -> Entries<'read, elf64::Sym> {
+        Entries::new(self.encoding, self.data, self.entsize, self.num, Encoding::sym)
+    }
+
+    /// Return the symbol at index `idx`.
+    pub fn get(&self, idx: usize) -> Result<elf64::Sym, Error> {
+        self.iter().get(idx).ok_or(Error::SymbolIndex)
+    }
+
+    /// Return the name of a symbol of this table.
+    pub fn name(&self, sym: &elf64::Sym) -> Result<&'read [u8], Error> {
+        self.strings.get(sym.st_name as u64)
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> ElfFile<'read, Read> {
+    /// Validate the ELF identification, the ELF header, and the program and
+    /// section header tables of an ELF file.
+    pub fn with(read: &'read Read) -> Result<Self, Error> {
+        Self::parse(read, false)
+    }
+
+    /// Validate the ELF identification, the ELF header, and the program
+    /// header table of a loaded ELF object.
+    ///
+    /// `read` must provide the memory image of the object, starting at the
+    /// location of the ELF header, i.e., the start of the first `PT_LOAD`
+    /// segment. Section headers are ignored.
+    pub fn with_image(read: &'read Read) -> Result<Self, Error> {
+        Self::parse(read, true)
+    }
+
+    fn parse(read: &'read Read, image: bool) -> Result<Self, Error> {
+        let ident = Self::map_in(read, 0, size_of::<elf64::Ident>() as u64)?;
+
+        // SAFETY: `Ident` is valid for any bit pattern.
+        let ident: elf64::Ident = unsafe { load(ident) };
+        if ident.i_magic != elf64::ELFMAG {
+            return Err(Error::Magic);
+        }
+        let class = match ident.i_class {
+            elf64::ELFCLASS32 => Class::Elf32,
+            elf64::ELFCLASS64 => Class::Elf64,
+            _ => return Err(Error::Class),
+        };
+        let big_endian = match ident.i_data {
+            elf64::ELFDATA2LSB => false,
+            elf64::ELFDATA2MSB => true,
+            _ => return Err(Error::Data),
+        };
+        if ident.i_version != elf64::EV_CURRENT {
+            return Err(Error::Version);
+        }
+
+        let encoding = Encoding { class: class, big_endian: big_endian };
+        let ehsize = encoding.ehdr_size();
+        let ehdr = encoding.ehdr(Self::map_in(read, 0, ehsize as u64)?);
+        if ehdr.e_version != elf64::EV_CURRENT as u32 {
+            return Err(Error::Version);
+        }
+        if (ehdr.e_ehsize as usize) < ehsize {
+            return Err(Error::HeaderSize);
+        }
+        Self::map_in(read, 0, ehdr.e_ehsize as u64)?;
+
+        let mut this = Self {
+            read: read,
+            encoding: encoding,
+            ehdr: ehdr,
+            phdrs: &[],
+            phnum: 0,
+            shdrs: &[],
+            shnum: 0,
+            shstrndx: 0,
+            image: None,
+        };
+
+        // The section header table is located first, since its first entry
+        // carries the counts and indices that overflow the ELF header.
+        let mut phnum = ehdr.e_phnum as u64;
+        let mut shstrndx = ehdr.e_shstrndx as u64;
+        if ehdr.e_shoff != 0 && !image {
+            let shsize = encoding.shdr_size();
+            if (ehdr.e_shentsize as usize) < shsize {
+                return Err(Error::EntrySize);
+            }
+            let shdr0 = encoding.shdr(this.map(ehdr.e_shoff, shsize as u64)?);
+
+            let shnum = match ehdr.e_shnum {
+                0 => shdr0.sh_size,
+                v => v as u64,
+            };
+            if phnum == elf64::PN_XNUM as u64 {
+                phnum = shdr0.sh_info as u64;
+            }
+            if shstrndx == elf64::SHN_XINDEX as u64 {
+                shstrndx = shdr0.sh_link as u64;
+            }
+            if shstrndx >= shnum && shstrndx != elf64::SHN_UNDEF as u64 {
+                return Err(Error::SectionIndex);
+            }
+
+            let len = shnum
+                .checked_mul(ehdr.e_shentsize as u64)
+                .ok_or(Error::Bounds)?;
+            this.shdrs = this.map(ehdr.e_shoff, len)?;
+            this.shnum = shnum as usize;
+            this.shstrndx = shstrndx as usize;
+        }
+
+        if phnum > 0 {
+            let phsize = encoding.phdr_size();
+            if (ehdr.e_phentsize as usize) < phsize {
+                return Err(Error::EntrySize);
+            }
+
+            let len = phnum
+                .checked_mul(ehdr.e_phentsize as u64)
+                .ok_or(Error::Bounds)?;
+            this.phdrs = this.map(ehdr.e_phoff, len)?;
+            this.phnum = phnum as usize;
+        }
+
+        let ranges = [
+            (0, ehdr.e_ehsize as u64),
+            (ehdr.e_phoff, this.phdrs.len() as u64),
+            (ehdr.e_shoff, this.shdrs.len() as u64),
+        ];
+        if overlaps(ranges[0], ranges[1])
+            || overlaps(ranges[0], ranges[2])
+            || overlaps(ranges[1], ranges[2])
+        {
+            return Err(Error::Overlap);
+        }
+
+        // The ELF header is at the start of the first `PT_LOAD` segment of a
+        // loaded object, which determines the address of the image.
+        if image {
+            let base = this.program_headers()
+                .find(|v| v.p_type == elf64::PT_LOAD)
+                .and_then(|v| v.p_vaddr.checked_sub(v.p_offset))
+                .ok_or(Error::Unmapped)?;
+            this.image = Some(base);
+        }
+
+        Ok(this)
+    }
+
+    fn map_in(
+        read: &'read Read,
+        off: u64,
+        len: u64,
+    ) -> Result<&'read [u8], Error> {
+        if len == 0 {
+            return Ok(&[]);
+        }
+
+        let off = usize::try_from(off).map_err(|_| Error::Bounds)?;
+        let len = usize::try_from(len).map_err(|_| Error::Bounds)?;
+        if off.checked_add(len).is_none() {
+            return Err(Error::Bounds);
+        }
+
+        match read.map(off, len) {
+            Flow::Continue(v) if v.len() < len => Err(Error::Discontiguous),
+            Flow::Continue(v) => Ok(&v[..len]),
+            Flow::Break(Some(io::map::Error::Exceeded)) => Err(Error::Bounds),
+            Flow::Break(v) => Err(Error::Io(v)),
+        }
+    }
+
+    /// Map `len` bytes at offset `off` of the file.
+    pub fn map(&self, off: u64, len: u64) -> Result<&'read [u8], Error> {
+        Self::map_in(self.read, off, len)
+    }
+
+    /// Map `len` bytes at the virtual address `vaddr`.
+    ///
+    /// For files, the address is translated via the `PT_LOAD` segments and
+    /// must be backed by file contents. For loaded objects, the address is
+    /// relative to the image.
+    pub fn map_vaddr(&self, vaddr: u64, len: u64) -> Result<&'read [u8], Error> {
+        if let Some(base) = self.image {
+            let off = vaddr.checked_sub(base).ok_or(Error::Unmapped)?;
+            return self.map(off, len);
+        }
+
+        let end = vaddr.checked_add(len).ok_or(Error::Unmapped)?;
+        for phdr in self.program_headers() {
+            if phdr.p_type == elf64::PT_LOAD
+                && vaddr >= phdr.p_vaddr
+                && end <= phdr.p_vaddr.saturating_add(phdr.p_filesz)
+            {
+                let off = phdr.p_offset
+                    .checked_add(vaddr - phdr.p_vaddr)
+                    .ok_or(Error::Bounds)?;
+                return self.map(off, len);
+            }
+        }
+
+        Err(Error::Unmapped)
+    }
+
+    /// Return the class and data encoding of the file.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Return the ELF header in native representation.
+    ///
+    /// The counts and indices of the header are reported as stored in the
+    /// file. Use [`Self::program_headers()`], [`Self::section_headers()`],
+    /// and [`Self::shstrndx()`] to account for extended numbering.
+    pub fn header(&self) -> &elf64::Ehdr {
+        &self.ehdr
+    }
+
+    /// Return the index of the section name string table, or `SHN_UNDEF` if
+    /// there is none.
+    pub fn shstrndx(&self) -> usize {
+        self.shstrndx
+    }
+
+    /// Return an iterator over all program headers.
+    pub fn program_headers(&self) -> Entries<'read, elf64::Phdr> {
+        Entries::new(
+            self.encoding,
+            self.phdrs,
+            self.ehdr.e_phentsize as usize,
+            self.phnum,
+            Encoding::phdr,
+        )
+    }
+
+    /// Return an iterator over all section headers.
+    pub fn section_headers(&self) -> Entries<'read, elf64::Shdr> {
+        Entries::new(
+            self.encoding,
+            self.shdrs,
+            self.ehdr.e_shentsize as usize,
+            self.shnum,
+            Encoding::shdr,
+        )
+    }
+
+    /// Return the section header at index `idx`.
+    pub fn section_header(&self, idx: usize) -> Result<elf64::Shdr, Error> {
+        self.section_headers().get(idx).ok_or(Error::SectionIndex)
+    }
+
+    /// Return an iterator over the entries of the dynamic section, up to but
+    /// excluding `DT_NULL`. This is empty if there is no `PT_DYNAMIC`
+    /// segment.
+    pub fn dynamic(&self) -> Result<Entries<'read, elf64::Dyn>, Error> {
+        let size = self.encoding.dyn_size();
+        let data = match self.program_headers().find(|v| v.p_type == elf64::PT_DYNAMIC) {
+            None => &[][..],
+            Some(v) if self.image.is_some() => self.map_vaddr(v.p_vaddr, v.p_filesz)?,
+            Some(v) => self.segment_data(&v)?,
+        };
+
+        let mut entries = Entries::new(self.encoding, data, size, data.len() / size, Encoding::dynamic);
+        entries.num = entries.clone()
+            .position(|v| v.d_tag == elf64::DT_NULL as u64)
+            .unwrap_or(entries.num);
+        Ok(entries)
+    }
+
+    /// Return the file contents of a segment.
+    pub fn segment_data(&self, phdr: &elf64::Phdr) -> Result<&'read [u8], Error> {
+        self.map(phdr.p_offset, phdr.p_filesz)
+    }
+
+    /// Return the file contents of a section. Sections of type `SHT_NOBITS`
+    /// occupy no space in the file and yield no data.
+    pub fn section_data(&self, shdr: &elf64::Shdr) -> Result<&'read [u8], Error> {
+        if shdr.sh_type == elf64::SHT_NOBITS {
+            return Ok(&[]);
+        }
+        self.map(shdr.sh_offset, shdr.sh_size)
+    }
+
+    /// Return the string table stored in a section of type `SHT_STRTAB`.
+    pub fn string_table(&self, shdr: &elf64::Shdr) -> Result<StringTable<'read>, Error> {
+        if shdr.sh_type != elf64::SHT_STRTAB {
+            return Err(Error::SectionType);
+        }
+        StringTable::with(self.section_data(shdr)?)
+    }
+
+    /// Return the section name string table. This is empty if the file has
+    /// no such table.
+    pub fn section_names(&self) -> Result<StringTable<'read>, Error> {
+        if self.shstrndx == elf64::SHN_UNDEF as usize {
+            return Ok(StringTable { data: &[] });
+        }
+        self.string_table(&self.section_header(self.shstrndx)?)
+    }
+
+    /// Return the name of a section.
+    pub fn section_name(&self, shdr: &elf64::Shdr) -> Result<&'read [u8], Error> {
+        self.section_names()?.get(shdr.sh_name as u64)
+    }
+
+    /// Return the first section with the given name, if any.
+    pub fn section_by_name(&self, name: &[u8]) -> Result<Option<elf64::Shdr>, Error> {
+        let names = self.section_names()?;
+        for shdr in self.section_headers() {
+            if names.get(shdr.sh_name as u64)? == name {
+                return Ok(Some(shdr));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Return the symbol table stored in a section of type `SHT_SYMTAB` or
+    /// `SHT_DYNSYM`, together with its linked string table.
+    pub fn symbol_table(&self, shdr: &elf64::Shdr) -> Result<SymbolTable<'read>, Error> {
+        if shdr.sh_type != elf64::SHT_SYMTAB && shdr.sh_type != elf64::SHT_DYNSYM {
+            return Err(Error::SectionType);
+        }
+        if shdr.sh_entsize < self.encoding.sym_size() as u64 {
+            return Err(Error::EntrySize);
+        }
+
+        let data = self.section_data(shdr)?;
+        let strtab = self.section_header(shdr.sh_link as usize)?;
+
+        Ok(SymbolTable {
+            encoding: self.encoding,
+            data: data,
+            entsize: shdr.sh_entsize as usize,
+            num: (shdr.sh_size / shdr.sh_entsize) as usize,
+            strings: self.string_table(&strtab)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+    use super::*;
+    use super::build::{Out, Symbol};
+
+    // Create a synthetic relocatable ELF file with a single `PT_LOAD`
+    // segment, a `.text` section with 8 bytes of content, and a symbol table
+    // with the symbols `foo` and `bar`. The section header table is placed
+    // last.
+    fn fixture(encoding: Encoding) -> Vec<u8> {
+        let ehsize = encoding.ehdr_size();
+        let phsize = encoding.phdr_size();
+        let shsize = encoding.shdr_size();
+        let symsize = encoding.sym_size();
+
+        let shstrtab = b"\0.shstrtab\0.strtab\0.symtab\0.text\0";
+        let strtab = b"\0foo\0bar\0";
+
+        let off_shstrtab = ehsize + phsize;
+        let off_strtab = off_shstrtab + shstrtab.len();
+        let off_text = off_strtab + strtab.len();
+        let off_symtab = off_text + 8;
+        let off_shdrs = off_symtab + 3 * symsize;
+
+        let mut out = Out::new(encoding);
+        out.buf.extend_from_slice(&elf64::ELFMAG);
+        out.buf.push(match encoding.class {
+            Class::Elf32 => elf64::ELFCLASS32,
+            Class::Elf64 => elf64::ELFCLASS64,
+        });
+        out.buf.push(match encoding.big_endian {
+            false => elf64::ELFDATA2LSB,
+            true => elf64::ELFDATA2MSB,
+        });
+        out.buf.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        out.u16(elf64::ET_EXEC);
+        out.u16(elf64::EM_X86_64);
+        out.u32(1);
+        out.word(0x1000).unwrap();
+        out.word(ehsize as u64).unwrap();
+        out.word(off_shdrs as u64).unwrap();
+        out.u32(0);
+        for v in [ehsize, phsize, 1, shsize, 5, 1] {
+            out.u16(v as u16);
+        }
+        assert_eq!(out.buf.len(), ehsize);
+
+        out.phdr(&elf64::Phdr {
+            p_type: elf64::PT_LOAD,
+            p_flags: elf64::PF_R | elf64::PF_X,
+            p_offset: 0,
+            p_vaddr: 0x1000,
+            p_paddr: 0x1000,
+            p_filesz: off_shdrs as u64,
+            p_memsz: off_shdrs as u64,
+            p_align: 0x1000,
+        }).unwrap();
+        assert_eq!(out.buf.len(), off_shstrtab);
+
+        out.buf.extend_from_slice(shstrtab);
+        out.buf.extend_from_slice(strtab);
+        out.buf.extend((0..8).map(|v| v as u8 + 1));
+
+        let info = (elf64::STB_GLOBAL << 4) | elf64::STT_FUNC;
+        for (name, st_value) in [(0, 0), (1, 0x1000), (5, 0x1004)] {
+            let symbol = match name {
+                0 => Symbol::default(),
+                _ => Symbol {
+                    st_info: info,
+                    st_shndx: 4,
+                    st_value: st_value,
+                    st_size: 4,
+                    ..Default::default()
+                },
+            };
+            out.sym(name, &symbol).unwrap();
+        }
+        assert_eq!(out.buf.len(), off_shdrs);
+
+        for (sh_name, sh_type, off, size, sh_link, sh_info, entsize) in [
+            (0, elf64::SHT_NULL, 0, 0, 0, 0, 0),
+            (1, elf64::SHT_STRTAB, off_shstrtab, shstrtab.len(), 0, 0, 0),
+            (11, elf64::SHT_STRTAB, off_strtab, strtab.len(), 0, 0, 0),
+            (19, elf64::SHT_SYMTAB, off_symtab, 3 * symsize, 2, 1, symsize),
+            (27, elf64::SHT_PROGBITS, off_text, 8, 0, 0, 0),
+        ] {
+            out.shdr(&elf64::Shdr {
+                sh_name: sh_name,
+                sh_type: sh_type,
+                sh_flags: 0,
+                sh_addr: 0,
+                sh_offset: off as u64,
+                sh_size: size as u64,
+                sh_link: sh_link,
+                sh_info: sh_info,
+                sh_addralign: 1,
+                sh_entsize: entsize as u64,
+            }).unwrap();
+        }
+
+        out.buf
+    }
+
+    // Parse synthetic files of both classes and both data encodings, and
+    // verify all tables are decoded correctly.
+    #[test]
+    fn synthetic() {
+        for class in [Class::Elf32, Class::Elf64] {
+            for big_endian in [false, true] {
+                let encoding = Encoding { class: class, big_endian: big_endian };
+                let data = fixture(encoding);
+                let elf = ElfFile::with(&data[..]).unwrap();
+
+                assert_eq!(elf.encoding(), encoding);
+                assert_eq!(elf.header().e_type, elf64::ET_EXEC);
+                assert_eq!(elf.header().e_machine, elf64::EM_X86_64);
+                assert_eq!(elf.header().e_entry, 0x1000);
+                assert_eq!(elf.shstrndx(), 1);
+
+                let phdrs: Vec<_> = elf.program_headers().collect();
+                assert_eq!(phdrs.len(), 1);
+                assert_eq!(phdrs[0].p_type, elf64::PT_LOAD);
+                assert_eq!(phdrs[0].p_flags, elf64::PF_R | elf64::PF_X);
+                assert_eq!(phdrs[0].p_vaddr, 0x1000);
+                assert_eq!(phdrs[0].p_align, 0x1000);
+                assert_eq!(elf.segment_data(&phdrs[0]).unwrap().len(), phdrs[0].p_filesz as usize);
+
+                let names: Vec<_> = elf.section_headers()
+                    .map(|v| elf.section_name(&v).unwrap())
+                    .collect();
+                assert_eq!(names, [&b""[..], b".shstrtab", b".strtab", b".symtab", b".text"]);
+
+                let text = elf.section_by_name(b".text").unwrap().unwrap();
+                assert_eq!(elf.section_data(&text).unwrap(), [1, 2, 3, 4, 5, 6, 7, 8]);
+                assert!(elf.section_by_name(b".data").unwrap().is_none());
+
+                let strtab = elf.string_table(&elf.section_header(2).unwrap()).unwrap();
+                let strings: Vec<_> = strtab.iter().collect();
+                assert_eq!(strings, [(0, &b""[..]), (1, b"foo"), (5, b"bar")]);
+                assert_eq!(strtab.get(2).unwrap(), b"oo");
+                assert_eq!(strtab.get(9), Err(Error::StringIndex));
+
+                let symtab = elf.symbol_table(&elf.section_header(3).unwrap()).unwrap();
+                assert_eq!(symtab.len(), 3);
+                let sym = symtab.get(2).unwrap();
+                assert_eq!(symtab.name(&sym).unwrap(), b"bar");
+                assert_eq!(sym.st_value, 0x1004);
+                assert_eq!(sym.st_size, 4);
+                assert_eq!(sym.st_shndx, 4);
+                assert_eq!(sym.st_info, (elf64::STB_GLOBAL << 4) | elf64::STT_FUNC);
+                assert_eq!(symtab.get(3).err(), Some(Error::SymbolIndex));
+                assert_eq!(symtab.iter().len(), 3);
+
+                assert_eq!(
+                    elf.symbol_table(&text).err(),
+                    Some(Error::SectionType),
+                );
+                assert_eq!(
+                    elf.section_header(5).err(),
+                    Some(Error::SectionIndex),
+                );
+            }
+        }
+    }
+
+    // Verify that invalid input is rejected with the respective error.
+    #[test]
+    fn invalid() {
+        let encoding = Encoding { class: Class::Elf64, big_endian: false };
+        let data = fixture(encoding);
+        let check = |f: &dyn Fn(&mut Vec<u8>), e: Error| {
+            let mut data = data.clone();
+            f(&mut data);
+            assert_eq!(ElfFile::with(&data[..]).err(), Some(e));
+        };
+
+        check(&|v| v.truncate(8), Error::Bounds);
+        check(&|v| v[0] = 0, Error::Magic);
+        check(&|v| v[4] = 3, Error::Class);
+        check(&|v| v[5] = 3, Error::Data);
+        check(&|v| v[6] = 2, Error::Version);
+        check(&|v| v[20] = 2, Error::Version);
+        check(&|v| v[52] = 32, Error::HeaderSize);
+        check(&|v| v[54] = 32, Error::EntrySize);
+        check(&|v| v[58] = 32, Error::EntrySize);
+        check(&|v| v[60] = 6, Error::Bounds);
+        check(&|v| v[62] = 5, Error::SectionIndex);
+        check(&|v| v[32] = 32, Error::Overlap);
+        check(&|v| v[40] = 64, Error::Overlap);
+        check(&|v| v.truncate(v.len() - 1), Error::Bounds);
+
+        // Sections with out-of-bounds content are only rejected on access.
+        let mut data = data.clone();
+        let off = data.len() - 64 + 24;
+        data[off..off + 8].copy_from_slice(&0x10000u64.to_le_bytes());
+        let elf = ElfFile::with(&data[..]).unwrap();
+        let text = elf.section_header(4).unwrap();
+        assert_eq!(elf.section_data(&text).err(), Some(Error::Bounds));
+
+        assert_eq!(StringTable::with(b"\0foo").err(), Some(Error::Unterminated));
+    }
+
+    // Parse the running test executable and verify its basic properties.
+    #[test]
+    fn self_exe() {
+        let data = std::fs::read("/proc/self/exe").unwrap();
+        let elf = ElfFile::with(&data[..]).unwrap();
+
+        osi::cfg::cond! {
+            (target_pointer_width = "32") { assert_eq!(elf.encoding().class, Class::Elf32); },
+            (target_pointer_width = "64") { assert_eq!(elf.encoding().class, Class::Elf64); },
+        }
+        assert_eq!(elf.encoding().big_endian, cfg!(target_endian = "big"));
+        assert!(elf.program_headers().any(|v| v.p_type == elf64::PT_LOAD));
+        assert!(elf.section_by_name(b".text").unwrap().is_some());
+
+        for shdr in elf.section_headers() {
+            if shdr.sh_type == elf64::SHT_SYMTAB || shdr.sh_type == elf64::SHT_DYNSYM {
+                let symtab = elf.symbol_table(&shdr).unwrap();
+                for sym in symtab.iter() {
+                    symtab.name(&sym).unwrap();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,730 @@
+//! ELF Notes
+//!
+//! Notes carry auxiliary information about an ELF object. Each note is
+//! identified by the name of its owner and a type defined by that owner,
+//! and carries an opaque descriptor. Notes are stored in sections of type
+//! `SHT_NOTE` and segments of type `PT_NOTE`, padded to the alignment of the
+//! respective section or segment.
+//!
+//! This module provides an iterator over the notes of a section or segment,
+//! as well as decoders for the descriptors of common notes:
+//!
+//! - `NT_GNU_BUILD_ID`: Build-ID of the object, usually a hash over its
+//!   contents.
+//! - `NT_GNU_ABI_TAG`: Operating system and minimum kernel version required
+//!   by the object.
+//! - `NT_GNU_PROPERTY_TYPE_0`: Program properties, including the
+//!   control-flow protection features of the object.
+//! - `NT_GO_BUILDID`: Build-ID of Go programs.
+//! - `NT_FDO_PACKAGING_METADATA`: Package metadata as JSON object, as
+//!   stored in `.note.package` following the freedesktop.org ELF package
+//!   metadata specification.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::ControlFlow as Flow;
+use sys::ffi::elf::elf64;
+
+use crate::{fmt::json, io};
+use super::{Class, ElfFile, Encoding, Error};
+
+/// Owner name of GNU notes.
+pub const NAME_GNU: &[u8] = b"GNU";
+/// Owner name of Go notes.
+pub const NAME_GO: &[u8] = b"Go";
+/// Owner name of freedesktop.org notes.
+pub const NAME_FDO: &[u8] = b"FDO";
+
+/// Note of an ELF object with its owner name, type, and descriptor.
+#[derive(Clone, Copy, Debug)]
+pub struct Note<'read> {
+    encoding: Encoding,
+    name: &'read [u8],
+    n_type: u32,
+    desc: &'read [u8],
+}
+
+/// Iterator over the notes of a note section or segment.
+///
+/// Iteration stops after the first malformed note.
+#[derive(Clone, Debug)]
+pub struct Notes<'read> {
+    encoding: Encoding,
+    data: &'read [u8],
+    align: usize,
+    idx: usize,
+}
+
+/// ABI tag as stored in `NT_GNU_ABI_TAG` notes.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct AbiTag {
+    /// Operating system as `ELF_NOTE_OS_*`.
+    pub os: u32,
+    /// Minimum kernel version as major, minor, and patch level.
+    pub version: (u32, u32, u32),
+}
+
+/// Program property as stored in `NT_GNU_PROPERTY_TYPE_0` notes.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Property<'read> {
+    pub pr_type: u32,
+    pub data: &'read [u8],
+}
+
+/// Iterator over the program properties of a `NT_GNU_PROPERTY_TYPE_0`
+/// note.
+///
+/// Iteration stops after the first malformed property.
+#[derive(Clone, Debug)]
+pub struct Properties<'read> {
+    encoding: Encoding,
+    data: &'read [u8],
+    idx: usize,
+}
+
+/// Control-flow protection features of an object, as announced via
+/// `GNU_PROPERTY_X86_FEATURE_1_AND` or `GNU_PROPERTY_AARCH64_FEATURE_1_AND`.
+#[derive(Clone, Copy, Debug, Default, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Features {
+    /// Indirect branch tracking (x86).
+    pub ibt: bool,
+    /// Shadow stack (x86).
+    pub shstk: bool,
+    /// Branch target identification (aarch64).
+    pub bti: bool,
+    /// Pointer authentication (aarch64).
+    pub pac: bool,
+}
+
+/// Package metadata as stored in `NT_FDO_PACKAGING_METADATA` notes.
+///
+/// Only the string fields defined by the specification are extracted. Any
+/// other field is ignored.
+#[derive(Clone, Debug, Default, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Package {
+    /// Package format (`type`), e.g., `rpm` or `deb`.
+    pub kind: Option<String>,
+    pub os: Option<String>,
+    pub os_version: Option<String>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub architecture: Option<String>,
+    pub debug_info_url: Option<String>,
+}
+
+// JSON tokens of package metadata, detached from the tokenizer. Opening
+// tokens carry whether they open an object.
+enum Token {
+    Open(bool),
+    Close,
+    Colon,
+    Comma,
+    String(String),
+    Value,
+}
+
+// Round `v` up to a multiple of the power of two `align`.
+fn align_up(v: usize, align: usize) -> Option<usize> {
+    Some(v.checked_add(align - 1)? & !(align - 1))
+}
+
+impl<'read> Note<'read> {
+    /// Return the owner name of the note, without any terminating NUL.
+    pub fn name(&self) -> &'read [u8] {
+        self.name
+    }
+
+    /// Return the type of the note.
+    pub fn n_type(&self) -> u32 {
+        self.n_type
+    }
+
+    /// Return the descriptor of the note.
+    pub fn desc(&self) -> &'read [u8] {
+        self.desc
+    }
+
+    /// Check whether the note has the given owner name and type.
+    pub fn is(&self, name: &[u8], n_type: u32) -> bool {
+        self.name == name && self.n_type == n_type
+    }
+
+    /// Return the build-ID of a `NT_GNU_BUILD_ID` note.
+    pub fn build_id(&self) -> Option<&'read [u8]> {
+        self.is(NAME_GNU, elf64::NT_GNU_BUILD_ID).then_some(self.desc)
+    }
+
+    /// Return the build-ID of a `NT_GO_BUILDID` note.
+    pub fn go_build_id(&self) -> Option<&'read [u8]> {
+        self.is(NAME_GO, elf64::NT_GO_BUILDID).then_some(self.desc)
+    }
+
+    /// Decode the ABI tag of a `NT_GNU_ABI_TAG` note.
+    pub fn abi_tag(&self) -> Result<Option<AbiTag>, Error> {
+        if !self.is(NAME_GNU, elf64::NT_GNU_ABI_TAG) {
+            return Ok(None);
+        }
+        if self.desc.len() < 16 {
+            return Err(Error::Note);
+        }
+
+        let v = |idx: usize| self.encoding.u32(&self.desc[4 * idx..]);
+        Ok(Some(AbiTag {
+            os: v(0),
+            version: (v(1), v(2), v(3)),
+        }))
+    }
+
+    /// Return an iterator over the program properties of a
+    /// `NT_GNU_PROPERTY_TYPE_0` note.
+    pub fn properties(&self) -> Option<Properties<'read>> {
+        self.is(NAME_GNU, elf64::NT_GNU_PROPERTY_TYPE_0).then_some(Properties {
+            encoding: self.encoding,
+            data: self.desc,
+            idx: 0,
+        })
+    }
+
+    /// Decode the control-flow protection features of a
+    /// `NT_GNU_PROPERTY_TYPE_0` note.
+    ///
+    /// The meaning of processor-specific properties depends on the machine
+    /// of the object, which must be given as `machine`. This yields `None` if
+    /// the machine is not supported or the note lacks the feature property.
+    pub fn features(&self, machine: u16) -> Result<Option<Features>, Error> {
+        let Some(properties) = self.properties() else {
+            return Ok(None);
+        };
+        let pr_type = match machine {
+            elf64::EM_386 | elf64::EM_X86_64 => elf64::GNU_PROPERTY_X86_FEATURE_1_AND,
+            elf64::EM_AARCH64 => elf64::GNU_PROPERTY_AARCH64_FEATURE_1_AND,
+            _ => return Ok(None),
+        };
+
+        for property in properties {
+            let property = property?;
+            if property.pr_type != pr_type {
+                continue;
+            }
+            if property.data.len() != 4 {
+                return Err(Error::Note);
+            }
+
+            let v = self.encoding.u32(property.data);
+            let mut features = Features::default();
+            if pr_type == elf64::GNU_PROPERTY_X86_FEATURE_1_AND {
+                features.ibt = v & elf64::GNU_PROPERTY_X86_FEATURE_1_IBT != 0;
+                features.shstk = v & elf64::GNU_PROPERTY_X86_FEATURE_1_SHSTK != 0;
+            } else {
+                features.bti = v & elf64::GNU_PROPERTY_AARCH64_FEATURE_1_BTI != 0;
+                features.pac = v & elf64::GNU_PROPERTY_AARCH64_FEATURE_1_PAC != 0;
+            }
+            return Ok(Some(features));
+        }
+
+        Ok(None)
+    }
+
+    /// Decode the package metadata of a `NT_FDO_PACKAGING_METADATA` note.
+    ///
+    /// The descriptor must be a JSON object, optionally followed by NUL
+    /// padding. Fields defined by the specification must be strings.
+    pub fn package(&self) -> Result<Option<Package>, Error> {
+        if !self.is(NAME_FDO, elf64::NT_FDO_PACKAGING_METADATA) {
+            return Ok(None);
+        }
+
+        let end = self.desc.iter().rposition(|v| *v != 0).map_or(0, |v| v + 1);
+        let mut tokens = Self::tokenize(&self.desc[..end])?.into_iter();
+        let mut package = Package::default();
+
+        let Some(Token::Open(true)) = tokens.next() else {
+            return Err(Error::Note);
+        };
+        let mut first = true;
+        loop {
+            let key = match tokens.next() {
+                Some(Token::Close) if first => break,
+                Some(Token::String(v)) => v,
+                _ => return Err(Error::Note),
+            };
+            let Some(Token::Colon) = tokens.next() else {
+                return Err(Error::Note);
+            };
+
+            let field = match key.as_str() {
+                "type" => Some(&mut package.kind),
+                "os" => Some(&mut package.os),
+                "osVersion" => Some(&mut package.os_version),
+                "name" => Some(&mut package.name),
+                "version" => Some(&mut package.version),
+                "architecture" => Some(&mut package.architecture),
+                "debugInfoUrl" => Some(&mut package.debug_info_url),
+                _ => None,
+            };
+            match (tokens.next(), field) {
+                (Some(Token::String(v)), Some(field)) => *field = Some(v),
+                (Some(Token::String(_) | Token::Value), None) => {},
+                (Some(Token::Open(_)), None) => {
+                    // Skip nested values of unknown fields.
+                    let mut depth = 1;
+                    while depth > 0 {
+                        match tokens.next() {
+                            Some(Token::Open(_)) => depth += 1,
+                            Some(Token::Close) => depth -= 1,
+                            Some(_) => {},
+                            None => return Err(Error::Note),
+                        }
+                    }
+                },
+                _ => return Err(Error::Note),
+            }
+
+            match tokens.next() {
+                Some(Token::Comma) => first = false,
+                Some(Token::Close) => break,
+                _ => return Err(Error::Note),
+            }
+        }
+
+        if tokens.next().is_some() {
+            return Err(Error::Note);
+        }
+        Ok(Some(package))
+    }
+
+    // Split JSON data into tokens, dropping whitespace and rejecting any
+    // token errors. The data is complete, so the tokenizer is finalized once
+    // it runs out of data. Trailing whitespace is stripped, so at most a
+    // single token is pending at that point.
+    fn tokenize(data: &[u8]) -> Result<Vec<Token>, Error> {
+        let end = data.iter().rposition(|v| !v.is_ascii_whitespace()).map_or(0, |v| v + 1);
+        let mut data = &data[..end];
+        let mut dec = json::token::Dec::with(&mut data);
+        let mut tokens = Vec::new();
+
+        while let Flow::Continue(v) = dec.pop() {
+            tokens.extend(Self::token(v)?);
+        }
+        if let Some(v) = dec.complete() {
+            tokens.extend(Self::token(v)?);
+        }
+
+        Ok(tokens)
+    }
+
+    fn token(v: json::token::Token<'_>) -> Result<Option<Token>, Error> {
+        use json::token::Token as T;
+
+        Ok(Some(match v {
+            T::Error(_) => return Err(Error::Note),
+            T::Whitespace { .. } => return Ok(None),
+            T::Colon => Token::Colon,
+            T::Comma => Token::Comma,
+            T::ArrayOpen => Token::Open(false),
+            T::ObjectOpen => Token::Open(true),
+            T::ArrayClose | T::ObjectClose => Token::Close,
+            T::Null | T::False | T::True | T::Number(_) => Token::Value,
+            T::String { str, .. } => Token::String(str.into()),
+        }))
+    }
+}
+
+impl<'read> Notes<'read> {
+    /// Create an iterator over the notes in `data`, using the alignment
+    /// `align` of the containing section or segment. Alignments of less
+    /// than 4 bytes are treated as 4 bytes. Only 4-byte and 8-byte
+    /// alignments are supported.
+    pub fn with(encoding: Encoding, data: &'read [u8], align: u64) -> Result<Self, Error> {
+        let align = match align {
+            0..=4 => 4,
+            8 => 8,
+            _ => return Err(Error::Note),
+        };
+
+        Ok(Self {
+            encoding: encoding,
+            data: data,
+            align: align,
+            idx: 0,
+        })
+    }
+
+    fn next_note(&mut self) -> Result<Option<Note<'read>>, Error> {
+        let data = &self.data[self.idx..];
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let size = size_of::<elf64::Nhdr>();
+        if data.len() < size {
+            return Err(Error::Note);
+        }
+
+        // SAFETY: `Nhdr` is valid for any bit pattern.
+        let nhdr: elf64::Nhdr = unsafe { super::load(data) };
+        let namesz = self.encoding.native(nhdr.n_namesz) as usize;
+        let descsz = self.encoding.native(nhdr.n_descsz) as usize;
+
+        let off_desc = size.checked_add(namesz)
+            .and_then(|v| align_up(v, self.align))
+            .ok_or(Error::Note)?;
+        let end = off_desc.checked_add(descsz).ok_or(Error::Note)?;
+        if end > data.len() {
+            return Err(Error::Note);
+        }
+
+        // The padding of the last note might be missing.
+        let next = align_up(end, self.align).unwrap_or(end).min(data.len());
+        self.idx += next;
+
+        let name = &data[size..size + namesz];
+        let len = name.iter().rposition(|v| *v != 0).map_or(0, |v| v + 1);
+        Ok(Some(Note {
+            encoding: self.encoding,
+            name: &name[..len],
+            n_type: self.encoding.native(nhdr.n_type),
+            desc: &data[off_desc..end],
+        }))
+    }
+}
+
+impl<'read> Iterator for Notes<'read> {
+    type Item = Result<Note<'read>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_note() {
+            Ok(v) => v.map(Ok),
+            Err(e) => {
+                self.idx = self.data.len();
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+impl<'read> Properties<'read> {
+    fn next_property(&mut self) -> Result<Option<Property<'read>>, Error> {
+        let data = &self.data[self.idx..];
+        if data.is_empty() {
+            return Ok(None);
+        }
+        if data.len() < 8 {
+            return Err(Error::Note);
+        }
+
+        // Properties are padded to the size of a machine word.
+        let pr_type = self.encoding.u32(data);
+        let datasz = self.encoding.u32(&data[4..]) as usize;
+        let end = datasz.checked_add(8).ok_or(Error::Note)?;
+        if end > data.len() {
+            return Err(Error::Note);
+        }
+        let align = match self.encoding.class {
+            Class::Elf32 => 4,
+            Class::Elf64 => 8,
+        };
+        self.idx += align_up(end, align).unwrap_or(end).min(data.len());
+
+        Ok(Some(Property {
+            pr_type: pr_type,
+            data: &data[8..end],
+        }))
+    }
+}
+
+impl<'read> Iterator for Properties<'read> {
+    type Item = Result<Property<'read>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_property() {
+            Ok(v) => v.map(Ok),
+            Err(e) => {
+                self.idx = self.data.len();
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> ElfFile<'read, Read> {
+    /// Return an iterator over the notes of a `PT_NOTE` segment.
+    pub fn segment_notes(&self, phdr: &elf64::Phdr) -> Result<Notes<'read>, Error> {
+        if phdr.p_type != elf64::PT_NOTE {
+            return Err(Error::SegmentType);
+        }
+
+        let data = match self.image {
+            Some(_) => self.map_vaddr(phdr.p_vaddr, phdr.p_filesz)?,
+            None => self.segment_data(phdr)?,
+        };
+        Notes::with(self.encoding, data, phdr.p_align)
+    }
+
+    /// Return an iterator over the notes of a `SHT_NOTE` section.
+    pub fn section_notes(&self, shdr: &elf64::Shdr) -> Result<Notes<'read>, Error> {
+        if shdr.sh_type != elf64::SHT_NOTE {
+            return Err(Error::SectionType);
+        }
+        Notes::with(self.encoding, self.section_data(shdr)?, shdr.sh_addralign)
+    }
+
+    /// Return the first note with the given owner name and type, if any.
+    ///
+    /// All `SHT_NOTE` sections are searched. If there are none, as is the
+    /// case for loaded objects, all `PT_NOTE` segments are searched instead.
+    pub fn find_note(&self, name: &[u8], n_type: u32) -> Result<Option<Note<'read>>, Error> {
+        let mut sections = false;
+        for shdr in self.section_headers() {
+            if shdr.sh_type == elf64::SHT_NOTE {
+                sections = true;
+                for note in self.section_notes(&shdr)? {
+                    let note = note?;
+                    if note.is(name, n_type) {
+                        return Ok(Some(note));
+                    }
+                }
+            }
+        }
+
+        if !sections {
+            for phdr in self.program_headers() {
+                if phdr.p_type == elf64::PT_NOTE {
+                    for note in self.segment_notes(&phdr)? {
+                        let note = note?;
+                        if note.is(name, n_type) {
+                            return Ok(Some(note));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Return the GNU build-ID of the object, if any.
+    pub fn build_id(&self) -> Result<Option<&'read [u8]>, Error> {
+        Ok(self.find_note(NAME_GNU, elf64::NT_GNU_BUILD_ID)?.map(|v| v.desc))
+    }
+
+    /// Return the control-flow protection features of the object, if it
+    /// announces any.
+    pub fn features(&self) -> Result<Option<Features>, Error> {
+        match self.find_note(NAME_GNU, elf64::NT_GNU_PROPERTY_TYPE_0)? {
+            None => Ok(None),
+            Some(v) => v.features(self.ehdr.e_machine),
+        }
+    }
+
+    /// Return the package metadata of the object, if any.
+    pub fn package(&self) -> Result<Option<Package>, Error> {
+        match self.find_note(NAME_FDO, elf64::NT_FDO_PACKAGING_METADATA)? {
+            None => Ok(None),
+            Some(v) => v.package(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::build::Out;
+
+    // Encode 32-bit values with the given encoding.
+    fn words(encoding: Encoding, v: &[u32]) -> Vec<u8> {
+        let mut out = Out::new(encoding);
+        v.iter().for_each(|v| out.u32(*v));
+        out.buf
+    }
+
+    // Decode notes of both classes, both data encodings, and both
+    // alignments.
+    #[test]
+    fn synthetic() {
+        for class in [Class::Elf32, Class::Elf64] {
+            for big_endian in [false, true] {
+                for align in [4, 8] {
+                    let encoding = Encoding { class: class, big_endian: big_endian };
+                    let word = match class {
+                        Class::Elf32 => 4,
+                        Class::Elf64 => 8,
+                    };
+
+                    let abi_tag = words(encoding, &[elf64::ELF_NOTE_OS_LINUX, 3, 2, 0]);
+                    let mut properties = words(encoding, &[elf64::GNU_PROPERTY_STACK_SIZE, 4, 0x10000]);
+                    properties.resize(properties.len().next_multiple_of(word), 0);
+                    properties.extend(words(encoding, &[
+                        elf64::GNU_PROPERTY_X86_FEATURE_1_AND,
+                        4,
+                        elf64::GNU_PROPERTY_X86_FEATURE_1_IBT | elf64::GNU_PROPERTY_X86_FEATURE_1_SHSTK,
+                    ]));
+                    properties.resize(properties.len().next_multiple_of(word), 0);
+                    let package = br#"{"type":"rpm","name":"foo","version":"1.0"}"#;
+
+                    let mut out = Out::new(encoding);
+                    out.note(align, NAME_GNU, elf64::NT_GNU_ABI_TAG, &abi_tag);
+                    out.note(align, NAME_GNU, elf64::NT_GNU_BUILD_ID, b"\x01\x02\x03");
+                    out.note(align, NAME_GNU, elf64::NT_GNU_PROPERTY_TYPE_0, &properties);
+                    out.note(align, b"Go\0", elf64::NT_GO_BUILDID, b"abc/def");
+                    out.note(align, NAME_FDO, elf64::NT_FDO_PACKAGING_METADATA, package);
+                    out.note(align, b"", 7, b"");
+                    let buf = out.buf;
+
+                    let notes: Vec<_> = Notes::with(encoding, &buf, align as u64)
+                        .unwrap()
+                        .map(|v| v.unwrap())
+                        .collect();
+                    assert_eq!(notes.len(), 6);
+
+                    assert_eq!(
+                        notes[0].abi_tag().unwrap(),
+                        Some(AbiTag { os: elf64::ELF_NOTE_OS_LINUX, version: (3, 2, 0) }),
+                    );
+                    assert_eq!(notes[0].build_id(), None);
+
+                    assert_eq!(notes[1].name(), NAME_GNU);
+                    assert_eq!(notes[1].n_type(), elf64::NT_GNU_BUILD_ID);
+                    assert_eq!(notes[1].build_id(), Some(&[1, 2, 3][..]));
+                    assert_eq!(notes[1].abi_tag().unwrap(), None);
+
+                    let props: Vec<_> = notes[2].properties().unwrap().map(|v| v.unwrap()).collect();
+                    assert_eq!(props.len(), 2);
+                    assert_eq!(props[0].pr_type, elf64::GNU_PROPERTY_STACK_SIZE);
+                    assert_eq!(props[0].data.len(), 4);
+                    assert_eq!(
+                        notes[2].features(elf64::EM_X86_64).unwrap(),
+                        Some(Features { ibt: true, shstk: true, ..Default::default() }),
+                    );
+                    assert_eq!(notes[2].features(elf64::EM_AARCH64).unwrap(), None);
+                    assert_eq!(notes[2].features(elf64::EM_RISCV).unwrap(), None);
+
+                    assert_eq!(notes[3].name(), NAME_GO);
+                    assert_eq!(notes[3].go_build_id(), Some(&b"abc/def"[..]));
+                    assert_eq!(notes[3].build_id(), None);
+
+                    let package = notes[4].package().unwrap().unwrap();
+                    assert_eq!(package.kind.as_deref(), Some("rpm"));
+                    assert_eq!(package.name.as_deref(), Some("foo"));
+                    assert_eq!(package.version.as_deref(), Some("1.0"));
+                    assert_eq!(package.os, None);
+                    assert_eq!(notes[3].package().unwrap(), None);
+
+                    assert_eq!(notes[5].name(), b"");
+                    assert_eq!(notes[5].n_type(), 7);
+                    assert_eq!(notes[5].desc(), b"");
+                }
+            }
+        }
+    }
+
+    // Decode package metadata with escapes, unknown fields, and padding,
+    // and verify malformed metadata is rejected.
+    #[test]
+    fn package() {
+        let encoding = Encoding { class: Class::Elf64, big_endian: false };
+        let parse = |desc: &[u8]| {
+            let mut out = Out::new(encoding);
+            out.note(4, NAME_FDO, elf64::NT_FDO_PACKAGING_METADATA, desc);
+            Notes::with(encoding, &out.buf, 4).unwrap().next().unwrap().unwrap().package()
+        };
+
+        let package = parse(&[&br#" {
+            "type": "deb",
+            "os": "debian",
+            "osVersion": "13",
+            "name": "f\"oo\u00e9",
+            "version": "1.0-1",
+            "architecture": "amd64",
+            "debugInfoUrl": "https://debuginfod.debian.net",
+            "extra": { "list": [1, [2], {"x": null}], "flag": true }
+        } "#[..], b"\0\0"].concat()).unwrap().unwrap();
+        assert_eq!(package, Package {
+            kind: Some("deb".into()),
+            os: Some("debian".into()),
+            os_version: Some("13".into()),
+            name: Some("f\"ooé".into()),
+            version: Some("1.0-1".into()),
+            architecture: Some("amd64".into()),
+            debug_info_url: Some("https://debuginfod.debian.net".into()),
+        });
+
+        assert_eq!(parse(b"{}").unwrap(), Some(Package::default()));
+        assert_eq!(parse(b"").err(), Some(Error::Note));
+        assert_eq!(parse(b"[]").err(), Some(Error::Note));
+        assert_eq!(parse(br#"{"name": 1}"#).err(), Some(Error::Note));
+        assert_eq!(parse(br#"{"name": "foo",}"#).err(), Some(Error::Note));
+        assert_eq!(parse(br#"{"name" "foo"}"#).err(), Some(Error::Note));
+        assert_eq!(parse(br#"{"name": "foo"} {}"#).err(), Some(Error::Note));
+        assert_eq!(parse(br#"{"name": "foo""#).err(), Some(Error::Note));
+        assert_eq!(parse(br#"{"x": [}"#).err(), Some(Error::Note));
+        assert_eq!(parse(br#"{"name": fo}"#).err(), Some(Error::Note));
+    }
+
+    // Verify that malformed notes are rejected with the respective error.
+    #[test]
+    fn invalid() {
+        let encoding = Encoding { class: Class::Elf64, big_endian: false };
+        fn first(buf: &[u8]) -> Option<Result<Note<'_>, Error>> {
+            let encoding = Encoding { class: Class::Elf64, big_endian: false };
+            Notes::with(encoding, buf, 4).unwrap().next()
+        }
+
+        let mut out = Out::new(encoding);
+        out.note(4, NAME_GNU, elf64::NT_GNU_BUILD_ID, b"\x01\x02\x03\x04");
+        let buf = out.buf;
+        assert!(first(&buf).unwrap().is_ok());
+        assert_eq!(first(&buf[..8]).unwrap().err(), Some(Error::Note));
+        assert_eq!(first(&buf[..buf.len() - 1]).unwrap().err(), Some(Error::Note));
+        assert!(first(&[]).is_none());
+
+        // Sizes exceeding the note are rejected, and iteration stops.
+        let mut bad = buf.clone();
+        bad[0..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let mut notes = Notes::with(encoding, &bad, 4).unwrap();
+        assert_eq!(notes.next().unwrap().err(), Some(Error::Note));
+        assert!(notes.next().is_none());
+
+        assert_eq!(Notes::with(encoding, &buf, 16).err(), Some(Error::Note));
+
+        let mut out = Out::new(encoding);
+        out.note(4, NAME_GNU, elf64::NT_GNU_ABI_TAG, b"\0\0\0\0");
+        out.note(4, NAME_GNU, elf64::NT_GNU_PROPERTY_TYPE_0, &words(encoding, &[
+            elf64::GNU_PROPERTY_X86_FEATURE_1_AND, 2, 0,
+        ]));
+        let buf = out.buf;
+        let notes: Vec<_> = Notes::with(encoding, &buf, 4).unwrap().map(|v| v.unwrap()).collect();
+        assert_eq!(notes[0].abi_tag().err(), Some(Error::Note));
+        assert_eq!(notes[1].features(elf64::EM_X86_64).err(), Some(Error::Note));
+    }
+
+    // Decode all notes of the running test executable and verify the
+    // build-ID matches its dedicated section.
+    #[test]
+    fn self_exe() {
+        let data = std::fs::read("/proc/self/exe").unwrap();
+        let elf = ElfFile::with(&data[..]).unwrap();
+
+        for shdr in elf.section_headers() {
+            if shdr.sh_type == elf64::SHT_NOTE {
+                for note in elf.section_notes(&shdr).unwrap() {
+                    let note = note.unwrap();
+                    note.abi_tag().unwrap();
+                    note.features(elf.header().e_machine).unwrap();
+                    note.package().unwrap();
+                }
+            }
+        }
+
+        if let Some(shdr) = elf.section_by_name(b".note.gnu.build-id").unwrap() {
+            let note = elf.section_notes(&shdr).unwrap().next().unwrap().unwrap();
+            assert!(!note.desc().is_empty());
+            assert_eq!(elf.build_id().unwrap(), note.build_id());
+        }
+        elf.features().unwrap();
+    }
+}
@@ -0,0 +1,333 @@
+//! Self-Relocation
+//!
+//! Position independent executables without an interpreter (static-PIE), as
+//! well as dynamic loaders themselves, must apply their own relocations
+//! before any global data is accessed. Such images only carry relative
+//! relocations, which add the load bias of the image to the relocated
+//! location.
+//!
+//! This module applies the relative relocations of the native platform, as
+//! described by `DT_RELA`, `DT_REL`, and `DT_RELR` in the dynamic section.
+//! It neither accesses global data nor panics, and thus is suitable to run
+//! before the image is relocated. Any other relocation type is rejected
+//! before the image is modified.
+
+use sys::ffi::elf::elfn;
+
+use super::Error;
+
+/// Relative relocation type of the target platform, or `None` if the
+/// platform is not supported.
+pub const R_RELATIVE: Option<u32> = if cfg!(target_arch = "aarch64") {
+    Some(elfn::R_AARCH64_RELATIVE)
+} else if cfg!(target_arch = "arm") {
+    Some(elfn::R_ARM_RELATIVE)
+} else if cfg!(target_arch = "loongarch64") {
+    Some(elfn::R_LARCH_RELATIVE)
+} else if cfg!(target_arch = "powerpc64") {
+    Some(elfn::R_PPC64_RELATIVE)
+} else if cfg!(target_arch = "riscv64") {
+    Some(elfn::R_RISCV_RELATIVE)
+} else if cfg!(target_arch = "s390x") {
+    Some(elfn::R_390_RELATIVE)
+} else if cfg!(target_arch = "x86") {
+    Some(elfn::R_386_RELATIVE)
+} else if cfg!(target_arch = "x86_64") {
+    Some(elfn::R_X86_64_RELATIVE)
+} else {
+    None
+};
+
+// Relocation table as described by the dynamic section.
+struct Table {
+    addr: usize,
+    size: usize,
+    entsize: usize,
+}
+
+// Extract the relocation type from `r_info`.
+fn r_type(info: usize) -> u32 {
+    if size_of::<usize>() == 8 {
+        (info as u64 & 0xffffffff) as u32
+    } else {
+        (info & 0xff) as u32
+    }
+}
+
+// Check whether a relocation of type `r_type` can be applied.
+fn check(r_type: u32) -> Result<(), Error> {
+    if r_type == 0 || Some(r_type) == R_RELATIVE {
+        Ok(())
+    } else {
+        Err(Error::Relocation)
+    }
+}
+
+// Add `bias` to the word at `addr`.
+//
+// Safety: `addr` must be valid for reads and writes of a word.
+unsafe fn apply(addr: usize, bias: usize) {
+    let p = addr as *mut usize;
+    // SAFETY: Propagated to caller.
+    unsafe { p.write_unaligned(p.read_unaligned().wrapping_add(bias)) };
+}
+
+impl Table {
+    fn new(entsize: usize) -> Self {
+        Self { addr: 0, size: 0, entsize: entsize }
+    }
+
+    fn len(&self) -> usize {
+        if self.addr == 0 { 0 } else { self.size / self.entsize }
+    }
+
+    fn entry<T>(&self, bias: usize, idx: usize) -> *const T {
+        bias.wrapping_add(self.addr).wrapping_add(idx.wrapping_mul(self.entsize)) as *const T
+    }
+}
+
+/// Apply the relative relocations of an image.
+///
+/// `dynamic` points to the dynamic section of the image (usually available
+/// as `_DYNAMIC`), and `bias` is the difference between the load address and
+/// the link address of the image. All addresses in the dynamic section are
+/// link addresses and are adjusted by `bias`.
+///
+/// All relocations are verified to be relative (or `R_*_NONE`) before the
+/// first one is applied. `DT_RELR` tables are only supported on platforms
+/// with a relative relocation type.
+///
+/// ## Safety
+///
+/// `dynamic` must point to a dynamic section terminated by `DT_NULL`. The
+/// relocation tables it refers to, as well as all relocated locations, must
+/// be valid for reads and writes when adjusted by `bias`. The relocations
+/// must not have been applied before.
+pub unsafe fn relocate(dynamic: *const elfn::Dyn, bias: usize) -> Result<(), Error> {
+    let mut rela = Table::new(size_of::<elfn::Rela>());
+    let mut rel = Table::new(size_of::<elfn::Rel>());
+    let mut relr = Table::new(size_of::<usize>());
+
+    let mut i = 0usize;
+    loop {
+        // SAFETY: The caller guarantees `dynamic` is terminated by
+        //     `DT_NULL`, so any entry up to it is valid.
+        let v = unsafe { dynamic.add(i).read() };
+        i = i.wrapping_add(1);
+
+        // `d_tag` is `u32` on 32-bit platforms.
+        #[allow(clippy::unnecessary_cast)]
+        let (tag, val) = (v.d_tag as u64, v.d_val as usize);
+        if tag == elfn::DT_NULL as u64 {
+            break;
+        }
+        let Ok(tag) = u32::try_from(tag) else {
+            continue;
+        };
+        match tag {
+            elfn::DT_RELA => rela.addr = val,
+            elfn::DT_RELASZ => rela.size = val,
+            elfn::DT_RELAENT => rela.entsize = val,
+            elfn::DT_REL => rel.addr = val,
+            elfn::DT_RELSZ => rel.size = val,
+            elfn::DT_RELENT => rel.entsize = val,
+            elfn::DT_RELR => relr.addr = val,
+            elfn::DT_RELRSZ => relr.size = val,
+            elfn::DT_RELRENT => relr.entsize = val,
+            _ => {},
+        }
+    }
+
+    if rela.entsize != size_of::<elfn::Rela>()
+        || rel.entsize != size_of::<elfn::Rel>()
+        || relr.entsize != size_of::<usize>()
+    {
+        return Err(Error::EntrySize);
+    }
+
+    for i in 0..rela.len() {
+        // SAFETY: The caller guarantees the table is valid.
+        let v = unsafe { rela.entry::<elfn::Rela>(bias, i).read_unaligned() };
+        check(r_type(v.r_info as usize))?;
+    }
+    for i in 0..rel.len() {
+        // SAFETY: The caller guarantees the table is valid.
+        let v = unsafe { rel.entry::<elfn::Rel>(bias, i).read_unaligned() };
+        check(r_type(v.r_info as usize))?;
+    }
+    if relr.len() > 0 && R_RELATIVE.is_none() {
+        return Err(Error::Relocation);
+    }
+
+    for i in 0..rela.len() {
+        // SAFETY: The caller guarantees the table is valid.
+        let v = unsafe { rela.entry::<elfn::Rela>(bias, i).read_unaligned() };
+        if r_type(v.r_info as usize) != 0 {
+            let p = bias.wrapping_add(v.r_offset as usize) as *mut usize;
+            // SAFETY: The caller guarantees relocated locations are valid.
+            unsafe { p.write_unaligned(bias.wrapping_add(v.r_addend as usize)) };
+        }
+    }
+
+    for i in 0..rel.len() {
+        // SAFETY: The caller guarantees the table is valid.
+        let v = unsafe { rel.entry::<elfn::Rel>(bias, i).read_unaligned() };
+        if r_type(v.r_info as usize) != 0 {
+            // SAFETY: The caller guarantees relocated locations are valid.
+            unsafe { apply(bias.wrapping_add(v.r_offset as usize), bias) };
+        }
+    }
+
+    // Even entries of `DT_RELR` name the next location to relocate. Odd
+    // entries are bitmaps of the following `usize::BITS - 1` words, which
+    // are relocated if their respective bit is set.
+    let word = size_of::<usize>();
+    let mut next = 0usize;
+    for i in 0..relr.len() {
+        // SAFETY: The caller guarantees the table is valid.
+        let v = unsafe { relr.entry::<usize>(bias, i).read_unaligned() };
+        if v & 1 == 0 {
+            next = bias.wrapping_add(v);
+            // SAFETY: The caller guarantees relocated locations are valid.
+            unsafe { apply(next, bias) };
+            next = next.wrapping_add(word);
+        } else {
+            for j in 1..usize::BITS as usize {
+                if (v >> j) & 1 == 1 {
+                    let p = next.wrapping_add((j - 1) * word);
+                    // SAFETY: The caller guarantees relocated locations are
+                    //     valid.
+                    unsafe { apply(p, bias) };
+                }
+            }
+            next = next.wrapping_add((usize::BITS as usize - 1) * word);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+    use core::mem::offset_of;
+    use super::*;
+
+    #[derive(Clone)]
+    #[repr(C)]
+    struct Image {
+        dynamic: [elfn::Dyn; 10],
+        rela: [elfn::Rela; 3],
+        rel: [elfn::Rel; 1],
+        relr: [usize; 3],
+        data: [usize; 96],
+    }
+
+    // Offset of `data[idx]` in the image.
+    fn data(idx: usize) -> usize {
+        offset_of!(Image, data) + idx * size_of::<usize>()
+    }
+
+    // Create an image linked at address 0 with relocations of all three
+    // tables. Only words 10, 12, 20, 30, 31, 33, and 70 are relocated.
+    fn fixture() -> Image {
+        let relative = R_RELATIVE.unwrap();
+        let dyn_ = |tag: u32, val: usize| elfn::Dyn { d_tag: tag as _, d_val: val as _ };
+
+        let mut image = Image {
+            dynamic: [
+                dyn_(elfn::DT_RELA, offset_of!(Image, rela)),
+                dyn_(elfn::DT_RELASZ, size_of::<[elfn::Rela; 3]>()),
+                dyn_(elfn::DT_RELAENT, size_of::<elfn::Rela>()),
+                dyn_(elfn::DT_REL, offset_of!(Image, rel)),
+                dyn_(elfn::DT_RELSZ, size_of::<[elfn::Rel; 1]>()),
+                dyn_(elfn::DT_RELENT, size_of::<elfn::Rel>()),
+                dyn_(elfn::DT_RELR, offset_of!(Image, relr)),
+                dyn_(elfn::DT_RELRSZ, size_of::<[usize; 3]>()),
+                dyn_(elfn::DT_RELRENT, size_of::<usize>()),
+                dyn_(elfn::DT_NULL, 0),
+            ],
+            rela: [
+                elfn::Rela { r_offset: data(10) as _, r_info: relative as _, r_addend: 0x100 },
+                elfn::Rela { r_offset: data(11) as _, r_info: 0, r_addend: 0x110 },
+                elfn::Rela { r_offset: data(12) as _, r_info: relative as _, r_addend: data(0) as _ },
+            ],
+            rel: [
+                elfn::Rel { r_offset: data(20) as _, r_info: relative as _ },
+            ],
+            relr: [data(30), 0b1011, data(70)],
+            data: [0; 96],
+        };
+        for (i, v) in image.data.iter_mut().enumerate() {
+            if i != 10 && i != 11 && i != 12 {
+                *v = i * 0x10;
+            }
+        }
+        image
+    }
+
+    // Relocate copies of the fixture at different addresses and verify the
+    // relocated values.
+    #[test]
+    fn relative() {
+        if R_RELATIVE.is_none() {
+            return;
+        }
+
+        let original = fixture();
+        let mut images = [Box::new(original.clone()), Box::new(original.clone())];
+        for image in &mut images {
+            let bias = &raw mut **image as usize;
+            unsafe { relocate(image.dynamic.as_ptr(), bias).unwrap() };
+
+            let expected: [(usize, usize); 9] = [
+                (10, bias + 0x100),
+                (11, 0),
+                (12, bias + data(0)),
+                (20, bias + 0x140),
+                (30, bias + 0x1e0),
+                (31, bias + 0x1f0),
+                (32, 0x200),
+                (33, bias + 0x210),
+                (70, bias + 0x460),
+            ];
+            for (idx, v) in expected {
+                assert_eq!(image.data[idx], v, "data[{}]", idx);
+            }
+            for (idx, v) in image.data.iter().enumerate() {
+                if !expected.iter().any(|e| e.0 == idx) {
+                    assert_eq!(*v, original.data[idx]);
+                }
+            }
+        }
+
+        let bias = [&raw const *images[0] as usize, &raw const *images[1] as usize];
+        assert_eq!(
+            images[0].data[10].wrapping_sub(images[1].data[10]),
+            bias[0].wrapping_sub(bias[1]),
+        );
+    }
+
+    // Verify that unsupported relocations are rejected before the image is
+    // modified.
+    #[test]
+    fn invalid() {
+        if R_RELATIVE.is_none() {
+            return;
+        }
+
+        let mut image = Box::new(fixture());
+        image.rela[1].r_info = 0x7f;
+        let bias = &raw mut *image as usize;
+        let r = unsafe { relocate(image.dynamic.as_ptr(), bias) };
+        assert_eq!(r, Err(Error::Relocation));
+        assert_eq!(image.data[10], 0);
+        assert_eq!(image.data[30], 0x1e0);
+
+        let mut image = Box::new(fixture());
+        image.dynamic[2].d_val = 8;
+        let bias = &raw mut *image as usize;
+        let r = unsafe { relocate(image.dynamic.as_ptr(), bias) };
+        assert_eq!(r, Err(Error::EntrySize));
+    }
+}
@@ -0,0 +1,748 @@
+//! ELF to PE/COFF Conversion
+//!
+//! UEFI firmware loads applications in the PE32+ format, yet most toolchains
+//! produce ELF files. This module converts a position independent ELF
+//! executable (`ET_DYN`) into a PE32+ image, similar to what `elf2efi` of
+//! systemd does:
+//!
+//! - The [`msdosmz::STUB_X86`] is placed first, followed by the PE headers,
+//!   the optional header, and the section table.
+//! - Every `PT_LOAD` segment becomes a section. Segments keep their relative
+//!   placement in memory, so position-relative code needs no adjustment.
+//!   All segments are moved by the same amount to leave room for the
+//!   headers, and thus must not share a page.
+//! - The relative relocations of `DT_RELA`, `DT_REL`, `DT_RELR`, and
+//!   `DT_JMPREL` are applied for the selected image base and then emitted
+//!   as PE base relocations in a trailing `.reloc` section. Any other
+//!   relocation type is rejected.
+//!
+//! Only little-endian 64-bit ELF files of machines supported by UEFI are
+//! accepted. Symbols, debug information, and any non-allocated sections
+//! are dropped.
+
+use alloc::vec::Vec;
+use core::ops::ControlFlow as Flow;
+use sys::ffi::elf::elf64;
+
+use crate::{elf, io, msdosmz, pecoff};
+
+type U16Le = osi::ffi::Integer<osi::ffi::LittleEndian<u16>, osi::align::AlignAs<2>>;
+type U32Le = osi::ffi::Integer<osi::ffi::LittleEndian<u32>, osi::align::AlignAs<4>>;
+type U64Le = osi::ffi::Integer<osi::ffi::LittleEndian<u64>, osi::align::AlignAs<8>>;
+
+/// Alignment of sections in memory.
+pub const SECTION_ALIGNMENT: u32 = 0x1000;
+
+/// Alignment of sections in the file.
+pub const FILE_ALIGNMENT: u32 = 0x200;
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// The underlying I/O operation failed, or was interrupted if `None`.
+    Io(Option<io::map::Error>),
+    /// The ELF file is malformed.
+    Elf(elf::Error),
+    /// The ELF file is not a little-endian 64-bit file.
+    Class,
+    /// The ELF file is not of type `ET_DYN`.
+    Type,
+    /// The machine of the ELF file has no PE/COFF equivalent.
+    Machine,
+    /// The ELF file has no `PT_LOAD` segments, or they are unordered or
+    /// share a page.
+    Layout,
+    /// The entry point is not located in an executable segment.
+    Entry,
+    /// A relocation is not relative, or its location is not backed by file
+    /// contents.
+    Relocation,
+    /// The image exceeds the 32-bit range of PE/COFF offsets.
+    Range,
+}
+
+/// Parameters of the generated image.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub struct Options {
+    /// Preferred load address of the image. The relocated words of the
+    /// image are adjusted to it.
+    pub image_base: u64,
+    /// Subsystem of the image, usually [`pecoff::SUBSYSTEM_EFI_APPLICATION`].
+    pub subsystem: u16,
+    pub major_image_version: u16,
+    pub minor_image_version: u16,
+    pub major_subsystem_version: u16,
+    pub minor_subsystem_version: u16,
+    pub dll_characteristics: u16,
+}
+
+// Section of the generated image.
+struct Section {
+    name: [u8; 8],
+    rva: u32,
+    size: u32,
+    characteristics: u32,
+    data: Vec<u8>,
+}
+
+impl core::convert::From<elf::Error> for Error {
+    fn from(v: elf::Error) -> Self {
+        Self::Elf(v)
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            image_base: 0,
+            subsystem: pecoff::SUBSYSTEM_EFI_APPLICATION,
+            major_image_version: 0,
+            minor_image_version: 0,
+            major_subsystem_version: 0,
+            minor_subsystem_version: 0,
+            dll_characteristics: pecoff::DLL_CHARACTERISTIC_HIGH_ENTROPY_VA
+                | pecoff::DLL_CHARACTERISTIC_DYNAMIC_BASE
+                | pecoff::DLL_CHARACTERISTIC_NX_COMPAT,
+        }
+    }
+}
+
+// Return the PE/COFF machine and the relative relocation type of an ELF
+// machine, if supported.
+fn machine(e_machine: u16) -> Option<(u16, u32)> {
+    match e_machine {
+        elf64::EM_X86_64 => Some((pecoff::MACHINE_AMD64, elf64::R_X86_64_RELATIVE)),
+        elf64::EM_AARCH64 => Some((pecoff::MACHINE_ARM64, elf64::R_AARCH64_RELATIVE)),
+        elf64::EM_RISCV => Some((pecoff::MACHINE_RISCV64, elf64::R_RISCV_RELATIVE)),
+        elf64::EM_LOONGARCH => Some((pecoff::MACHINE_LOONGARCH64, elf64::R_LARCH_RELATIVE)),
+        _ => None,
+    }
+}
+
+// Collect the locations of all relative relocations as virtual addresses,
+// together with the link-time value of the relocated word, if it is not
+// stored in the word itself.
+fn relocations<Read: ?Sized + io::map::Read>(
+    file: &elf::ElfFile<'_, Read>,
+    relative: u32,
+) -> Result<Vec<(u64, Option<u64>)>, Error> {
+    let mut rela = (0, 0, 24);
+    let mut rel = (0, 0, 16);
+    let mut relr = (0, 0, 8);
+    let mut jmprel = (0, 0, elf64::DT_RELA as u64);
+
+    for v in file.dynamic()? {
+        let Ok(tag) = u32::try_from(v.d_tag) else {
+            continue;
+        };
+        match tag {
+            elf64::DT_RELA => rela.0 = v.d_val,
+            elf64::DT_RELASZ => rela.1 = v.d_val,
+            elf64::DT_RELAENT => rela.2 = v.d_val,
+            elf64::DT_REL => rel.0 = v.d_val,
+            elf64::DT_RELSZ => rel.1 = v.d_val,
+            elf64::DT_RELENT => rel.2 = v.d_val,
+            elf64::DT_RELR => relr.0 = v.d_val,
+            elf64::DT_RELRSZ => relr.1 = v.d_val,
+            elf64::DT_RELRENT => relr.2 = v.d_val,
+            elf64::DT_JMPREL => jmprel.0 = v.d_val,
+            elf64::DT_PLTRELSZ => jmprel.1 = v.d_val,
+            elf64::DT_PLTREL => jmprel.2 = v.d_val,
+            elf64::DT_TEXTREL => return Err(Error::Relocation),
+            _ => {},
+        }
+    }
+
+    if rela.2 != 24 || rel.2 != 16 || relr.2 != 8 {
+        return Err(Error::Elf(elf::Error::EntrySize));
+    }
+
+    // The PLT relocations of `DT_JMPREL` use the format of either `DT_RELA`
+    // or `DT_REL`, as selected by `DT_PLTREL`.
+    let (jmprela, jmprel) = match u32::try_from(jmprel.2) {
+        Ok(elf64::DT_RELA) => ((jmprel.0, jmprel.1, 24), (0, 0, 16)),
+        Ok(elf64::DT_REL) => ((0, 0, 24), (jmprel.0, jmprel.1, 16)),
+        _ => return Err(Error::Relocation),
+    };
+
+    let table = |(addr, size, _): (u64, u64, u64)| -> Result<&[u8], Error> {
+        if addr == 0 { Ok(&[]) } else { Ok(file.map_vaddr(addr, size)?) }
+    };
+    let encoding = file.encoding();
+    let mut r = Vec::new();
+
+    for t in [rela, jmprela] {
+        for v in table(t)?.chunks_exact(24) {
+            let info = encoding.word(&v[8..]);
+            match (info & 0xffffffff) as u32 {
+                0 => {},
+                t if t == relative => r.push((encoding.word(v), Some(encoding.word(&v[16..])))),
+                _ => return Err(Error::Relocation),
+            }
+        }
+    }
+
+    for t in [rel, jmprel] {
+        for v in table(t)?.chunks_exact(16) {
+            let info = encoding.word(&v[8..]);
+            match (info & 0xffffffff) as u32 {
+                0 => {},
+                t if t == relative => r.push((encoding.word(v), None)),
+                _ => return Err(Error::Relocation),
+            }
+        }
+    }
+
+    // Even entries of `DT_RELR` name the next location to relocate. Odd
+    // entries are bitmaps of the following 63 words, which are relocated if
+    // their respective bit is set.
+    let mut next = 0u64;
+    for v in table(relr)?.chunks_exact(8) {
+        let v = encoding.word(v);
+        if v & 1 == 0 {
+            r.push((v, None));
+            next = v.wrapping_add(8);
+        } else {
+            for j in 1..64 {
+                if (v >> j) & 1 == 1 {
+                    r.push((next.wrapping_add((j - 1) * 8), None));
+                }
+            }
+            next = next.wrapping_add(63 * 8);
+        }
+    }
+
+    Ok(r)
+}
+
+// Return the PE/COFF name of a segment based on its flags.
+fn name(p_flags: u32) -> [u8; 8] {
+    if p_flags & elf64::PF_X != 0 {
+        *b".text\0\0\0"
+    } else if p_flags & elf64::PF_W != 0 {
+        *b".data\0\0\0"
+    } else {
+        *b".rodata\0"
+    }
+}
+
+/// Convert the ELF file of `read` into a PE32+ image.
+///
+/// The image is written into `write`, starting at offset 0, and committed.
+/// Its size is returned.
+pub fn convert<Read: ?Sized + io::map::Read>(
+    read: &Read,
+    options: &Options,
+    write: &mut dyn io::map::Write,
+) -> Result<usize, Error> {
+    let file = elf::ElfFile::with(read)?;
+    let encoding = file.encoding();
+    let ehdr = file.header();
+
+    if encoding.class != elf::Class::Elf64 || encoding.big_endian {
+        return Err(Error::Class);
+    }
+    if ehdr.e_type != elf64::ET_DYN {
+        return Err(Error::Type);
+    }
+    let (pe_machine, relative) = machine(ehdr.e_machine).ok_or(Error::Machine)?;
+
+    let align_up = |v: u64, align: u32| pecoff::align_up(v, align).ok_or(Error::Range);
+    let offset = |v: u64| pecoff::offset(v).ok_or(Error::Range);
+
+    let phdrs: Vec<_> = file.program_headers()
+        .filter(|v| v.p_type == elf64::PT_LOAD && v.p_memsz > 0)
+        .collect();
+    if phdrs.is_empty() {
+        return Err(Error::Layout);
+    }
+
+    let relocs = relocations(&file, relative)?;
+
+    // The headers are followed by the sections, each starting on a new
+    // page. Hence, all segments are moved by the same amount, such that the
+    // page of the first segment directly follows the headers.
+    let nsections = phdrs.len() + if relocs.is_empty() { 0 } else { 1 };
+    let headers = msdosmz::STUB_X86.len()
+        + pecoff::PE_MAGIC.len()
+        + size_of::<pecoff::Header>()
+        + size_of::<pecoff::OptionalHeader32P>()
+        + size_of::<pecoff::OptionalHeaderExt32P>()
+        + size_of::<pecoff::DataDirectory>() * pecoff::DATA_DIRECTORY_NUMBER as usize
+        + size_of::<pecoff::SectionHeader>() * nsections;
+    let size_of_headers = align_up(headers as u64, FILE_ALIGNMENT)?;
+    let base = phdrs[0].p_vaddr & !(SECTION_ALIGNMENT as u64 - 1);
+    let shift = align_up(size_of_headers, SECTION_ALIGNMENT)?;
+    let rva = |vaddr: u64| offset(vaddr - base + shift);
+    let delta = options.image_base.wrapping_add(shift).wrapping_sub(base);
+
+    let mut sections = Vec::new();
+    let mut end = 0u64;
+    for phdr in &phdrs {
+        let start = phdr.p_vaddr & !(SECTION_ALIGNMENT as u64 - 1);
+        if start < end || phdr.p_filesz > phdr.p_memsz {
+            return Err(Error::Layout);
+        }
+        end = phdr.p_vaddr.checked_add(phdr.p_memsz).ok_or(Error::Range)?;
+
+        let pad = (phdr.p_vaddr - start) as usize;
+        let mut data = alloc::vec![0; pad];
+        data.extend_from_slice(file.segment_data(phdr)?);
+
+        let mut characteristics = pecoff::SECTION_CHARACTERISTIC_MEM_READ;
+        if phdr.p_flags & elf64::PF_X != 0 {
+            characteristics |= pecoff::SECTION_CHARACTERISTIC_CNT_CODE
+                | pecoff::SECTION_CHARACTERISTIC_MEM_EXECUTE;
+        } else {
+            characteristics |= pecoff::SECTION_CHARACTERISTIC_CNT_INITIALIZED_DATA;
+        }
+        if phdr.p_flags & elf64::PF_W != 0 {
+            characteristics |= pecoff::SECTION_CHARACTERISTIC_MEM_WRITE;
+        }
+
+        sections.push(Section {
+            name: name(phdr.p_flags),
+            rva: rva(start)?,
+            size: offset(end - start)?,
+            characteristics: characteristics,
+            data: data,
+        });
+    }
+
+    // Sections must be adjacent in memory, so extend each section up to
+    // the start of the following one.
+    for i in 1..sections.len() {
+        sections[i - 1].size = sections[i].rva - sections[i - 1].rva;
+    }
+
+    if !phdrs.iter().any(|v| {
+        v.p_flags & elf64::PF_X != 0
+            && ehdr.e_entry >= v.p_vaddr
+            && ehdr.e_entry - v.p_vaddr < v.p_memsz
+    }) {
+        return Err(Error::Entry);
+    }
+    let entry = rva(ehdr.e_entry)?;
+
+    // Apply all relocations for the preferred image base and collect their
+    // locations for the base relocation table.
+    let mut rvas = Vec::new();
+    for (vaddr, addend) in relocs {
+        let (idx, phdr) = phdrs.iter()
+            .enumerate()
+            .find(|(_, v)| vaddr >= v.p_vaddr && vaddr.saturating_add(8) <= v.p_vaddr + v.p_filesz)
+            .ok_or(Error::Relocation)?;
+        let off = (vaddr - (phdr.p_vaddr & !(SECTION_ALIGNMENT as u64 - 1))) as usize;
+        let word = &mut sections[idx].data[off..off + 8];
+        let value = addend.unwrap_or_else(|| u64::from_le_bytes(word.try_into().unwrap()));
+        word.copy_from_slice(&value.wrapping_add(delta).to_le_bytes());
+        rvas.push(rva(vaddr)?);
+    }
+    rvas.sort_unstable();
+    rvas.dedup();
+
+    let mut reloc_dir = (0, 0);
+    if !rvas.is_empty() {
+        let data = pecoff::reloc::build(&rvas, pecoff::BASE_RELOCATION_DIR64);
+        let last = sections.last().unwrap();
+        reloc_dir = (offset(align_up(last.rva as u64 + last.size as u64, SECTION_ALIGNMENT)?)?, data.len() as u32);
+        sections.push(Section {
+            name: *b".reloc\0\0",
+            rva: reloc_dir.0,
+            size: reloc_dir.1,
+            characteristics: pecoff::SECTION_CHARACTERISTIC_CNT_INITIALIZED_DATA
+                | pecoff::SECTION_CHARACTERISTIC_MEM_READ
+                | pecoff::SECTION_CHARACTERISTIC_MEM_DISCARDABLE,
+            data: data,
+        });
+    }
+
+    // Place the sections in the file, in the order of their addresses.
+    let mut offsets = Vec::with_capacity(sections.len());
+    let mut off = size_of_headers;
+    let mut size_of_code = 0u64;
+    let mut size_of_data = 0u64;
+    for section in &sections {
+        let size = align_up(section.data.len() as u64, FILE_ALIGNMENT)?;
+        offsets.push(off);
+        off = off.checked_add(size).ok_or(Error::Range)?;
+        if section.characteristics & pecoff::SECTION_CHARACTERISTIC_CNT_CODE != 0 {
+            size_of_code += size;
+        } else {
+            size_of_data += size;
+        }
+    }
+    let total = usize::try_from(offset(off)?).map_err(|_| Error::Range)?;
+    let last = sections.last().unwrap();
+    let size_of_image = align_up(last.rva as u64 + last.size as u64, SECTION_ALIGNMENT)?;
+    let base_of_code = sections.iter()
+        .find(|v| v.characteristics & pecoff::SECTION_CHARACTERISTIC_CNT_CODE != 0)
+        .map_or(0, |v| v.rva);
+
+    let header = pecoff::Header {
+        machine: U16Le::from_native(pe_machine),
+        number_of_sections: U16Le::from_native(sections.len() as u16),
+        time_date_stamp: U32Le::from_native(0),
+        pointer_to_symbol_table: U32Le::from_native(0),
+        number_of_symbols: U32Le::from_native(0),
+        size_of_optional_header: U16Le::from_native(
+            (size_of::<pecoff::OptionalHeader32P>()
+                + size_of::<pecoff::OptionalHeaderExt32P>()
+                + size_of::<pecoff::DataDirectory>() * pecoff::DATA_DIRECTORY_NUMBER as usize) as u16,
+        ),
+        characteristics: U16Le::from_native(
+            pecoff::CHARACTERISTIC_EXECUTABLE_IMAGE
+                | pecoff::CHARACTERISTIC_LINE_NUMS_STRIPPED
+                | pecoff::CHARACTERISTIC_LOCAL_SYMS_STRIPPED
+                | pecoff::CHARACTERISTIC_LARGE_ADDRESS_AWARE,
+        ),
+    };
+    let optional = pecoff::OptionalHeader32P {
+        magic: U16Le::from_native(pecoff::PE_MAGIC_OH32P),
+        major_linker_version: osi::ffi::Integer::from_native(0u8),
+        minor_linker_version: osi::ffi::Integer::from_native(0u8),
+        size_of_code: U32Le::from_native(offset(size_of_code)?),
+        size_of_initialized_data: U32Le::from_native(offset(size_of_data)?),
+        size_of_uninitialized_data: U32Le::from_native(0),
+        address_of_entry_point: U32Le::from_native(entry),
+        base_of_code: U32Le::from_native(base_of_code),
+        base_of_data: (),
+    };
+    let optional_ext = pecoff::OptionalHeaderExt32P {
+        image_base: U64Le::from_native(options.image_base),
+        section_alignment: U32Le::from_native(SECTION_ALIGNMENT),
+        file_alignment: U32Le::from_native(FILE_ALIGNMENT),
+        major_operating_system_version: U16Le::from_native(0),
+        minor_operating_system_version: U16Le::from_native(0),
+        major_image_version: U16Le::from_native(options.major_image_version),
+        minor_image_version: U16Le::from_native(options.minor_image_version),
+        major_subsystem_version: U16Le::from_native(options.major_subsystem_version),
+        minor_subsystem_version: U16Le::from_native(options.minor_subsystem_version),
+        win32_version_value: U32Le::from_native(0),
+        size_of_image: U32Le::from_native(offset(size_of_image)?),
+        size_of_headers: U32Le::from_native(size_of_headers as u32),
+        check_sum: U32Le::from_native(0),
+        subsystem: U16Le::from_native(options.subsystem),
+        dll_characteristics: U16Le::from_native(options.dll_characteristics),
+        size_of_stack_reserve: U64Le::from_native(0),
+        size_of_stack_commit: U64Le::from_native(0),
+        size_of_heap_reserve: U64Le::from_native(0),
+        size_of_heap_commit: U64Le::from_native(0),
+        loader_flags: U32Le::from_native(0),
+        number_of_rva_and_sizes: U32Le::from_native(pecoff::DATA_DIRECTORY_NUMBER as u32),
+    };
+
+    let mut out = Vec::with_capacity(size_of_headers as usize);
+    out.extend_from_slice(&msdosmz::STUB_X86);
+    out.extend_from_slice(&pecoff::PE_MAGIC);
+    out.extend_from_slice(header.as_bytes());
+    out.extend_from_slice(optional.as_bytes());
+    out.extend_from_slice(optional_ext.as_bytes());
+    for i in 0..pecoff::DATA_DIRECTORY_NUMBER {
+        let (addr, size) = match i {
+            pecoff::DATA_DIRECTORY_BASE_RELOCATION_TABLE => reloc_dir,
+            _ => (0, 0),
+        };
+        let dir = pecoff::DataDirectory {
+            virtual_address: U32Le::from_native(addr),
+            size: U32Le::from_native(size),
+        };
+        out.extend_from_slice(dir.as_bytes());
+    }
+    for (section, off) in sections.iter().zip(&offsets) {
+        let size = align_up(section.data.len() as u64, FILE_ALIGNMENT)?;
+        let shdr = pecoff::SectionHeader {
+            name: section.name,
+            virtual_size: U32Le::from_native(section.size),
+            virtual_address: U32Le::from_native(section.rva),
+            size_of_raw_data: U32Le::from_native(size as u32),
+            pointer_to_raw_data: U32Le::from_native(if size == 0 { 0 } else { *off as u32 }),
+            pointer_to_relocations: U32Le::from_native(0),
+            pointer_to_linenumbers: U32Le::from_native(0),
+            number_of_relocations: U16Le::from_native(0),
+            number_of_linenumbers: U16Le::from_native(0),
+            characteristics: U32Le::from_native(section.characteristics),
+        };
+        out.extend_from_slice(shdr.as_bytes());
+    }
+    assert_eq!(out.len(), headers);
+
+    // Write the image and fill any padding with zeros.
+    let mut idx = 0;
+    let mut put = |at: usize, data: &[u8]| -> Result<(), Error> {
+        let pad = at - idx;
+        if let Flow::Break(v) = write.zero(&mut idx, pad) {
+            return Err(Error::Io(v));
+        }
+        if let Flow::Break(v) = write.write(&mut idx, data) {
+            return Err(Error::Io(v));
+        }
+        Ok(())
+    };
+
+    put(0, &out)?;
+    for (section, off) in sections.iter().zip(&offsets) {
+        put(*off as usize, &section.data)?;
+    }
+    put(total, &[])?;
+
+    // SAFETY: All `total` bytes were initialized above.
+    unsafe { write.commit(total) };
+    Ok(total)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::elf::build::{Builder, Contents, Section, Segment};
+    use crate::elf::Encoding;
+
+    fn u16(data: &[u8], off: usize) -> u16 {
+        u16::from_le_bytes(data[off..off + 2].try_into().unwrap())
+    }
+
+    fn u32(data: &[u8], off: usize) -> u32 {
+        u32::from_le_bytes(data[off..off + 4].try_into().unwrap())
+    }
+
+    fn u64(data: &[u8], off: usize) -> u64 {
+        u64::from_le_bytes(data[off..off + 8].try_into().unwrap())
+    }
+
+    // Build a position independent executable with a code segment and a
+    // data segment. The data segment holds 4 words, the dynamic section,
+    // a `DT_RELA` table relocating word 0, and a `DT_RELR` table relocating
+    // words 2 and 3. If `plt` is set, the `DT_RELA` table is announced as
+    // `DT_JMPREL` instead.
+    fn fixture(
+        encoding: Encoding,
+        e_type: u16,
+        e_machine: u16,
+        r_type: u32,
+        plt: bool,
+    ) -> Vec<u8> {
+        let mut data = Vec::new();
+        for v in [0u64, 0x5555, 0x1000, 0x2000] {
+            data.extend(v.to_le_bytes());
+        }
+        let rela_tags = match plt {
+            false => [elf64::DT_RELA, elf64::DT_RELASZ, elf64::DT_RELAENT],
+            true => [elf64::DT_JMPREL, elf64::DT_PLTRELSZ, elf64::DT_PLTREL],
+        };
+        let rela_vals = [0x2090u64, 48, if plt { elf64::DT_RELA as u64 } else { 24 }];
+        let mut dynamic = Vec::new();
+        for (tag, val) in rela_tags.into_iter().zip(rela_vals).chain([
+            (elf64::DT_RELR, 0x20c0),
+            (elf64::DT_RELRSZ, 16),
+            (elf64::DT_RELRENT, 8),
+            (elf64::DT_NULL, 0),
+        ]) {
+            dynamic.extend((tag as u64).to_le_bytes());
+            dynamic.extend(val.to_le_bytes());
+        }
+        let mut rela = Vec::new();
+        for (off, info, addend) in [(0x2000u64, r_type, 0x1008u64), (0x2008, 0, 0x1010)] {
+            rela.extend(off.to_le_bytes());
+            rela.extend((info as u64).to_le_bytes());
+            rela.extend(addend.to_le_bytes());
+        }
+        let mut relr = Vec::new();
+        relr.extend(0x2010u64.to_le_bytes());
+        relr.extend(0b11u64.to_le_bytes());
+
+        let section = |name, sh_type, data| Section {
+            name: name,
+            sh_type: sh_type,
+            sh_flags: (elf64::SHF_ALLOC | elf64::SHF_WRITE) as u64,
+            sh_addralign: 8,
+            data: data,
+            ..Default::default()
+        };
+
+        let mut b = Builder::new(encoding, e_type, e_machine);
+        b.e_entry = 0x1004;
+        let text = b.section(Section {
+            name: b".text",
+            sh_type: elf64::SHT_PROGBITS,
+            sh_flags: (elf64::SHF_ALLOC | elf64::SHF_EXECINSTR) as u64,
+            sh_addralign: 16,
+            data: &[0xc3; 16],
+            ..Default::default()
+        });
+        let first = b.section(section(&b".data"[..], elf64::SHT_PROGBITS, &data[..]));
+        let dyn_ = b.section(section(b".dynamic", elf64::SHT_DYNAMIC, &dynamic));
+        b.section(section(b".rela.dyn", elf64::SHT_RELA, &rela));
+        b.section(section(b".relr.dyn", elf64::SHT_RELR, &relr));
+        let bss = b.section(Section {
+            sh_size: 0x100,
+            ..section(b".bss", elf64::SHT_NOBITS, &[])
+        });
+        b.segment(Segment {
+            p_type: elf64::PT_LOAD,
+            p_flags: elf64::PF_R | elf64::PF_X,
+            p_vaddr: 0x1000,
+            p_align: 0x1000,
+            contents: Contents::Sections(text..text + 1),
+            ..Default::default()
+        });
+        b.segment(Segment {
+            p_type: elf64::PT_LOAD,
+            p_flags: elf64::PF_R | elf64::PF_W,
+            p_vaddr: 0x2000,
+            p_align: 0x1000,
+            contents: Contents::Sections(first..bss + 1),
+            ..Default::default()
+        });
+        b.segment(Segment {
+            p_type: elf64::PT_DYNAMIC,
+            p_flags: elf64::PF_R | elf64::PF_W,
+            p_vaddr: 0x2020,
+            p_align: 8,
+            contents: Contents::Sections(dyn_..dyn_ + 1),
+            ..Default::default()
+        });
+
+        let mut buf = Vec::new();
+        b.write(&mut buf).unwrap();
+        buf
+    }
+
+    // Convert a fixture and verify the headers, sections, applied
+    // relocations, and base relocations of the image.
+    #[test]
+    fn convert_image() {
+        let encoding = Encoding { class: elf::Class::Elf64, big_endian: false };
+        let elf = fixture(encoding, elf64::ET_DYN, elf64::EM_X86_64, elf64::R_X86_64_RELATIVE, false);
+        let options = Options { image_base: 0x10000, ..Default::default() };
+
+        let mut pe = Vec::new();
+        let len = convert(&elf[..], &options, &mut pe).unwrap();
+        assert_eq!(len, pe.len());
+        assert_eq!(len, 0x800);
+
+        // The stub is followed by the PE magic at `lfanew`.
+        assert_eq!(&pe[..0x80], &msdosmz::STUB_X86[..]);
+        assert_eq!(u32(&pe, pecoff::PE_OFFSET) as usize, 0x80);
+        assert_eq!(&pe[0x80..0x84], &pecoff::PE_MAGIC);
+
+        // Header
+        assert_eq!(u16(&pe, 0x84), pecoff::MACHINE_AMD64);
+        assert_eq!(u16(&pe, 0x86), 3);
+        assert_eq!(u16(&pe, 0x94), 240);
+
+        // Optional header
+        assert_eq!(u16(&pe, 0x98), pecoff::PE_MAGIC_OH32P);
+        assert_eq!(u32(&pe, 0x9c), 0x200);
+        assert_eq!(u32(&pe, 0xa0), 0x400);
+        assert_eq!(u32(&pe, 0xa8), 0x1004);
+        assert_eq!(u32(&pe, 0xac), 0x1000);
+        assert_eq!(u64(&pe, 0xb0), 0x10000);
+        assert_eq!(u32(&pe, 0xb8), SECTION_ALIGNMENT);
+        assert_eq!(u32(&pe, 0xbc), FILE_ALIGNMENT);
+        assert_eq!(u32(&pe, 0xd0), 0x4000);
+        assert_eq!(u32(&pe, 0xd4), 0x200);
+        assert_eq!(u16(&pe, 0xdc), pecoff::SUBSYSTEM_EFI_APPLICATION);
+        assert_eq!(u32(&pe, 0x104), 16);
+
+        // Only the base relocation directory is set.
+        for i in 0..16 {
+            let dir = (u32(&pe, 0x108 + i * 8), u32(&pe, 0x10c + i * 8));
+            match i as u16 {
+                pecoff::DATA_DIRECTORY_BASE_RELOCATION_TABLE => assert_eq!(dir, (0x3000, 16)),
+                _ => assert_eq!(dir, (0, 0)),
+            }
+        }
+
+        // Section table
+        let sections: Vec<_> = (0..3)
+            .map(|i| {
+                let v = &pe[0x188 + i * 40..];
+                (&v[..8], u32(v, 8), u32(v, 12), u32(v, 16), u32(v, 20))
+            })
+            .collect();
+        assert_eq!(sections, [
+            (&b".text\0\0\0"[..], 0x1000, 0x1000, 0x200, 0x200),
+            (b".data\0\0\0", 0x1d0, 0x2000, 0x200, 0x400),
+            (b".reloc\0\0", 16, 0x3000, 0x200, 0x600),
+        ]);
+        assert_eq!(
+            u32(&pe, 0x188 + 36),
+            pecoff::SECTION_CHARACTERISTIC_CNT_CODE
+                | pecoff::SECTION_CHARACTERISTIC_MEM_EXECUTE
+                | pecoff::SECTION_CHARACTERISTIC_MEM_READ,
+        );
+        assert_eq!(
+            u32(&pe, 0x188 + 40 + 36),
+            pecoff::SECTION_CHARACTERISTIC_CNT_INITIALIZED_DATA
+                | pecoff::SECTION_CHARACTERISTIC_MEM_READ
+                | pecoff::SECTION_CHARACTERISTIC_MEM_WRITE,
+        );
+
+        // Contents, with all relocated words adjusted to the image base.
+        assert_eq!(&pe[0x200..0x210], &[0xc3; 16]);
+        assert_eq!(u64(&pe, 0x400), 0x11008);
+        assert_eq!(u64(&pe, 0x408), 0x5555);
+        assert_eq!(u64(&pe, 0x410), 0x11000);
+        assert_eq!(u64(&pe, 0x418), 0x12000);
+
+        // The image can be read back.
+        let file = pecoff::PeFile::with(&pe[..]).unwrap();
+        assert!(file.is_pe32p());
+        let dir = file.data_directory(pecoff::DATA_DIRECTORY_BASE_RELOCATION_TABLE).unwrap();
+        assert_eq!(file.map_rva(dir.virtual_address.to_native(), 8).unwrap(), &pe[0x600..0x608]);
+        assert_eq!(file.rva_to_offset(0x2018), Ok(0x418));
+
+        // A single block for page 0x2000, padded to 4 bytes.
+        assert_eq!(u32(&pe, 0x600), 0x2000);
+        assert_eq!(u32(&pe, 0x604), 16);
+        assert_eq!(u16(&pe, 0x608), 0xa000);
+        assert_eq!(u16(&pe, 0x60a), 0xa010);
+        assert_eq!(u16(&pe, 0x60c), 0xa018);
+        assert_eq!(u16(&pe, 0x60e), 0);
+        let relocs: Vec<_> = file.base_relocations().unwrap().map(|v| v.unwrap().rva).collect();
+        assert_eq!(relocs, [0x2000, 0x2010, 0x2018, 0x2000]);
+    }
+
+    // Verify relative PLT relocations are applied like their `DT_RELA`
+    // counterparts.
+    #[test]
+    fn convert_plt() {
+        let encoding = Encoding { class: elf::Class::Elf64, big_endian: false };
+        let options = Options { image_base: 0x10000, ..Default::default() };
+        let (mut pe, mut pe_plt) = (Vec::new(), Vec::new());
+
+        let elf = fixture(encoding, elf64::ET_DYN, elf64::EM_X86_64, elf64::R_X86_64_RELATIVE, false);
+        convert(&elf[..], &options, &mut pe).unwrap();
+        let elf = fixture(encoding, elf64::ET_DYN, elf64::EM_X86_64, elf64::R_X86_64_RELATIVE, true);
+        convert(&elf[..], &options, &mut pe_plt).unwrap();
+
+        // Only the tags of the dynamic section differ.
+        let dynamic = 0x400 + 0x20..0x400 + 0x50;
+        assert_eq!(pe[..dynamic.start], pe_plt[..dynamic.start]);
+        assert_eq!(pe[dynamic.end..], pe_plt[dynamic.end..]);
+        assert_eq!(u64(&pe_plt, 0x400), 0x11008);
+    }
+
+    // Verify unsupported files are rejected.
+    #[test]
+    fn invalid() {
+        let le = Encoding { class: elf::Class::Elf64, big_endian: false };
+        let be = Encoding { class: elf::Class::Elf64, big_endian: true };
+        let elf32 = Encoding { class: elf::Class::Elf32, big_endian: false };
+        let options = Options::default();
+        let relative = elf64::R_X86_64_RELATIVE;
+
+        for (elf, error) in [
+            (fixture(be, elf64::ET_DYN, elf64::EM_X86_64, relative, false), Error::Class),
+            (fixture(elf32, elf64::ET_DYN, elf64::EM_X86_64, relative, false), Error::Class),
+            (fixture(le, elf64::ET_EXEC, elf64::EM_X86_64, relative, false), Error::Type),
+            (fixture(le, elf64::ET_DYN, elf64::EM_386, relative, false), Error::Machine),
+            (fixture(le, elf64::ET_DYN, elf64::EM_X86_64, elf64::R_X86_64_64, false), Error::Relocation),
+            (fixture(le, elf64::ET_DYN, elf64::EM_X86_64, elf64::R_X86_64_JUMP_SLOT, true), Error::Relocation),
+            (fixture(le, elf64::ET_DYN, elf64::EM_X86_64, elf64::R_X86_64_GLOB_DAT, true), Error::Relocation),
+        ] {
+            assert_eq!(convert(&elf[..], &options, &mut Vec::new()), Err(error));
+        }
+    }
+}
@@ -516,14 +516,12 @@ impl<'read> Dec<'read> {
                     }
                 },
 
-                b'.' => {
-                    if range == integer {
-                        self.inner.idx = self.inner.idx.strict_add(1);
-                        *fraction = self.inner.idx..self.inner.idx;
-                        None
-                    } else {
-                        Some(self.inner.number_done(&map[..self.inner.idx]))
-                    }
+                // A dot anywhere but after the integer range is handled
+                // by the fallback and starts a new number.
+                b'.' if range == integer => {
+                    self.inner.idx = self.inner.idx.strict_add(1);
+                    *fraction = self.inner.idx..self.inner.idx;
+                    None
                 },
 
                 b'e' | b'E' => {
@@ -11,7 +11,11 @@ extern crate core;
 #[cfg(test)]
 extern crate std;
 
+pub mod coredump;
+pub mod elf;
+pub mod elf2efi;
 pub mod fmt;
 pub mod io;
 pub mod msdosmz;
 pub mod pecoff;
+pub mod uki;
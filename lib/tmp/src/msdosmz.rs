@@ -172,6 +172,32 @@ pub fn sum16(data: &[u8]) -> u16 {
     )
 }
 
+/// Calculate 16-bit One's-Complement Sum
+///
+/// This function splits a byte slice into consecutive 16-bit unsigned integers
+/// and calculates their one's-complement sum. Unlike `sum16()`, any carry of
+/// an addition is added back into the lowest bit. Little endianness is
+/// assumed. If the slice is of an odd length, a missing trailing zero byte is
+/// assumed.
+///
+/// This is the basis of the image checksum of PE files, which extend the DOS
+/// MZ format.
+pub fn csum16(data: &[u8]) -> u16 {
+    data.chunks(2).fold(
+        0u16,
+        |acc, values| {
+            let (v, carry) = acc.overflowing_add(
+                match values.len() {
+                    1 => { values[0] as u16 },
+                    2 => { u16::from_le_bytes(values.try_into().unwrap()) },
+                    _ => { unreachable!(); },
+                }
+            );
+            v + carry as u16
+        },
+    )
+}
+
 /// File Header
 ///
 /// This static structure is located at offset 0 of a DOS MZ executable. It
@@ -486,6 +512,234 @@ impl Relocation {
     }
 }
 
+/// Reader Error
+///
+/// This enumerates the reasons why `MzFile` rejects data as a DOS MZ
+/// executable.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// The data is too small to hold the static header.
+    Truncated,
+    /// The data does not start with `MAGIC`.
+    Magic,
+    /// The program extent as specified by `cp` and `cblp` is invalid, or
+    /// exceeds the data.
+    Extent,
+    /// The header size as specified by `cparhdr` is smaller than the static
+    /// header, or exceeds the program extent.
+    HeaderSize,
+    /// The relocation table as specified by `lfarlc` and `crlc` overlaps the
+    /// static header, or exceeds the header size.
+    Relocations,
+}
+
+/// Payload Kind
+///
+/// This describes the format of the payload an extended header refers to via
+/// `lfanew`, as identified by its signature.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Payload {
+    /// Portable Executable, identified by "PE\0\0".
+    Pe,
+    /// New Executable of 16-bit Windows and OS/2, identified by "NE".
+    Ne,
+    /// Linear Executable of 32-bit Windows VxDs and OS/2, identified by
+    /// "LE".
+    Le,
+    /// Linear Executable of 32-bit OS/2, identified by "LX".
+    Lx,
+}
+
+/// DOS MZ Executable Reader
+///
+/// This wraps a byte slice and provides validated access to the DOS MZ
+/// executable it contains. The static header, the program extent, the header
+/// size, and the relocation table are verified on creation. The checksum is
+/// not, since many linkers leave it unset, but can be checked separately.
+pub struct MzFile<'data> {
+    data: &'data [u8],
+    header: Header,
+    header_ext: Option<HeaderExt>,
+    extent: usize,
+}
+
+/// Relocation Iterator
+///
+/// This iterates over the entries of the relocation table of a DOS MZ
+/// executable, in the order they are stored.
+#[derive(Clone, Debug)]
+pub struct Relocations<'data> {
+    data: &'data [u8],
+}
+
+impl<'data> MzFile<'data> {
+    /// Parse a DOS MZ executable
+    ///
+    /// Verify that `data` contains a valid DOS MZ executable, and return a
+    /// reader for it. Any data beyond the program extent is retained, since
+    /// it is commonly used to store the payload of successor formats.
+    ///
+    /// The extension header is assumed to be present if the relocation table
+    /// is located beyond it, as is common practice.
+    pub fn with(data: &'data [u8]) -> Result<Self, Error> {
+        let header = Header::from_bytes(
+            data.get(..28).ok_or(Error::Truncated)?.try_into().unwrap(),
+        );
+        if header.magic != MAGIC {
+            return Err(Error::Magic);
+        }
+
+        let cp = header.cp.to_native() as usize;
+        let cblp = header.cblp.to_native() as usize;
+        if cp == 0 || cblp >= PAGE_SIZE {
+            return Err(Error::Extent);
+        }
+        let extent = match cblp {
+            0 => { cp * PAGE_SIZE },
+            v => { (cp - 1) * PAGE_SIZE + v },
+        };
+        if extent > data.len() {
+            return Err(Error::Extent);
+        }
+
+        let header_size = header.cparhdr.to_native() as usize * PARAGRAPH_SIZE;
+        if header_size < size_of::<Header>() || header_size > extent {
+            return Err(Error::HeaderSize);
+        }
+
+        let lfarlc = header.lfarlc.to_native() as usize;
+        let crlc = header.crlc.to_native() as usize;
+        if crlc > 0
+            && (lfarlc < size_of::<Header>()
+                || lfarlc + crlc * size_of::<Relocation>() > header_size)
+        {
+            return Err(Error::Relocations);
+        }
+
+        let ext_end = size_of::<Header>() + size_of::<HeaderExt>();
+        let header_ext = match lfarlc >= ext_end && data.len() >= ext_end {
+            true => {
+                Some(HeaderExt::from_bytes(
+                    data[size_of::<Header>()..ext_end].try_into().unwrap(),
+                ))
+            },
+            false => { None },
+        };
+
+        Ok(Self {
+            data: data,
+            header: header,
+            header_ext: header_ext,
+            extent: extent,
+        })
+    }
+
+    /// Return the static header
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Return the extension header, if present
+    pub fn header_ext(&self) -> Option<&HeaderExt> {
+        self.header_ext.as_ref()
+    }
+
+    /// Return the program extent
+    ///
+    /// This is the size of the executable as specified by `cp` and `cblp`,
+    /// including the header. Any data beyond is not part of the executable.
+    pub fn extent(&self) -> usize {
+        self.extent
+    }
+
+    /// Return the header size
+    ///
+    /// This is the size of the header as specified by `cparhdr`, including
+    /// the relocation table and any padding.
+    pub fn header_size(&self) -> usize {
+        self.header.cparhdr.to_native() as usize * PARAGRAPH_SIZE
+    }
+
+    /// Return the program image
+    ///
+    /// This returns the data that is loaded into memory when the program is
+    /// run, which is everything between the header and the program extent.
+    pub fn image(&self) -> &'data [u8] {
+        &self.data[self.header_size()..self.extent]
+    }
+
+    /// Return an iterator over the relocation table
+    pub fn relocations(&self) -> Relocations<'data> {
+        let lfarlc = self.header.lfarlc.to_native() as usize;
+        let crlc = self.header.crlc.to_native() as usize;
+        Relocations {
+            data: match crlc {
+                0 => { &[] },
+                n => { &self.data[lfarlc..lfarlc + n * size_of::<Relocation>()] },
+            },
+        }
+    }
+
+    /// Verify the checksum
+    ///
+    /// Return whether the checksum in `csum` matches the data of the program
+    /// extent. Note that many linkers leave the checksum unset, in which case
+    /// this fails.
+    pub fn verify_checksum(&self) -> bool {
+        !sum16(&self.data[..self.extent]) == 0
+    }
+
+    /// Return the payload offset
+    ///
+    /// Return the offset of the payload of a successor format, as specified
+    /// by `lfanew` of the extension header, if present and non-zero.
+    pub fn lfanew(&self) -> Option<usize> {
+        match self.header_ext.as_ref()?.lfanew.to_native() {
+            0 => { None },
+            v => { Some(v as usize) },
+        }
+    }
+
+    /// Identify the payload
+    ///
+    /// Return the format of the payload at `lfanew`, if its signature is
+    /// recognized.
+    pub fn payload(&self) -> Option<Payload> {
+        let v = self.data.get(self.lfanew()?..)?;
+        if v.starts_with(b"PE\0\0") {
+            Some(Payload::Pe)
+        } else if v.starts_with(b"NE") {
+            Some(Payload::Ne)
+        } else if v.starts_with(b"LE") {
+            Some(Payload::Le)
+        } else if v.starts_with(b"LX") {
+            Some(Payload::Lx)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'data> Iterator for Relocations<'data> {
+    type Item = Relocation;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.data.get(..size_of::<Relocation>())?;
+        self.data = &self.data[size_of::<Relocation>()..];
+        Some(Relocation::from_bytes(v.try_into().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.data.len() / size_of::<Relocation>();
+        (n, Some(n))
+    }
+}
+
+impl<'data> ExactSizeIterator for Relocations<'data> {
+}
+
 /// X86 Stub Program
 ///
 /// This array contains a full MS-DOS EXE program that prints the following
@@ -555,6 +809,8 @@ pub const STUB_X86: [u8; 128] = [
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use super::*;
 
     // Verify alignment and size of our protocol types match the values
@@ -626,6 +882,108 @@ mod tests {
         assert_eq!(sum16(&data), 1);
     }
 
+    // Test the `csum16()` helper and verify carries are folded back.
+    #[test]
+    fn verify_csum16() {
+        // Sum up 0+1+2+3 with a missing trailing byte.
+        let data = [0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03];
+        assert_eq!(csum16(&data), 6);
+
+        // Verify an overflow carries into the lowest bit.
+        let data = [0xff, 0xff, 0x02, 0x00];
+        assert_eq!(csum16(&data), 2);
+        let data = [0xff, 0xff, 0x01, 0x00, 0xff, 0xff];
+        assert_eq!(csum16(&data), 1);
+
+        // Verify shifting a slice by one byte swaps the bytes of its sum.
+        let data = [0x00, 0x34, 0xff, 0xee, 0x56, 0x78];
+        assert_eq!(csum16(&data), csum16(&data[1..]).swap_bytes());
+    }
+
+    // Parse the x86-stub and verify the payload is identified via `lfanew`.
+    #[test]
+    fn verify_mzfile_stub() {
+        let f = MzFile::with(&STUB_X86).unwrap();
+        assert_eq!(f.extent(), 128);
+        assert_eq!(f.header_size(), 64);
+        assert_eq!(f.image(), &STUB_X86[64..]);
+        assert_eq!(f.relocations().len(), 0);
+        assert!(f.verify_checksum());
+        assert_eq!(f.lfanew(), Some(128));
+        assert_eq!(f.payload(), None);
+
+        for (signature, payload) in [
+            (&b"PE\0\0"[..], Some(Payload::Pe)),
+            (b"NE", Some(Payload::Ne)),
+            (b"LE", Some(Payload::Le)),
+            (b"LX", Some(Payload::Lx)),
+            (b"PE", None),
+            (b"ZM", None),
+        ] {
+            let mut data = STUB_X86.to_vec();
+            data.extend(signature);
+            let f = MzFile::with(&data).unwrap();
+            assert_eq!(f.extent(), 128);
+            assert_eq!(f.payload(), payload);
+        }
+    }
+
+    // Parse a program without extension header, but with relocations, an odd
+    // extent, and trailing data.
+    #[test]
+    fn verify_mzfile_relocations() {
+        let mut data = [0u8; 80];
+        data[..28].copy_from_slice(&[
+            0x4d, 0x5a, 0x3b, 0x00, 0x01, 0x00, 0x02, 0x00,
+            0x03, 0x00, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x1c, 0x00, 0x00, 0x00,
+        ]);
+        data[28..36].copy_from_slice(&[0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x01, 0x00]);
+        data[48..55].copy_from_slice(&[0xb8, 0x00, 0x00, 0xb8, 0x01, 0x4c, 0xcd]);
+        data[59..].fill(0xaa);
+
+        let csum = !sum16(&data[..59]);
+        data[18..20].copy_from_slice(&csum.to_le_bytes());
+
+        let f = MzFile::with(&data).unwrap();
+        assert_eq!(f.extent(), 59);
+        assert_eq!(f.image(), &data[48..59]);
+        assert!(f.header_ext().is_none());
+        assert_eq!(f.lfanew(), None);
+        assert_eq!(f.payload(), None);
+        assert!(f.verify_checksum());
+
+        let r: Vec<_> = f.relocations().map(|v| (v.segment.to_native(), v.offset.to_native())).collect();
+        assert_eq!(r, [(0, 1), (1, 5)]);
+
+        data[20] ^= 0x01;
+        assert!(!MzFile::with(&data).unwrap().verify_checksum());
+    }
+
+    // Verify invalid headers are rejected.
+    #[test]
+    fn verify_mzfile_invalid() {
+        let with = |f: &dyn Fn(&mut [u8])| {
+            let mut data = STUB_X86;
+            f(&mut data);
+            MzFile::with(&data).err()
+        };
+
+        assert_eq!(MzFile::with(&STUB_X86[..27]).err(), Some(Error::Truncated));
+        assert_eq!(with(&|v| v[0] = 0), Some(Error::Magic));
+        assert_eq!(with(&|v| v[4] = 0), Some(Error::Extent));
+        assert_eq!(with(&|v| v[4] = 2), Some(Error::Extent));
+        assert_eq!(with(&|v| v[2..4].copy_from_slice(&[0x00, 0x02])), Some(Error::Extent));
+        assert_eq!(with(&|v| v[2] = 0x81), Some(Error::Extent));
+        assert_eq!(with(&|v| v[8] = 1), Some(Error::HeaderSize));
+        assert_eq!(with(&|v| v[8] = 9), Some(Error::HeaderSize));
+        assert_eq!(with(&|v| v[6] = 1), Some(Error::Relocations));
+        assert_eq!(with(&|v| { v[6] = 1; v[24] = 27; }), Some(Error::Relocations));
+        assert_eq!(with(&|v| { v[6] = 10; v[24] = 28; }), Some(Error::Relocations));
+        assert_eq!(with(&|v| { v[6] = 9; v[24] = 28; }), None);
+    }
+
     // Verify the contents of the x86-stub and make sure the decoder produces
     // the expected values.
     #[test]
@@ -0,0 +1,208 @@
+//! Authenticode Image Hash
+//!
+//! Authenticode signatures, as used by Secure Boot, and the measurements of
+//! PE images into TPM PCRs are based on a digest of the image that excludes
+//! all data that is modified when signing it. That is, the `CheckSum` field
+//! of the optional header, the certificate-table data directory, and the
+//! certificate table itself are not covered by the digest.
+//!
+//! The remaining headers are hashed in file order, followed by the file
+//! contents of all sections ordered by their file offset. Any data that
+//! trails the last section, excluding the certificate table, is hashed last
+//! and padded with zeros to a multiple of 8 bytes.
+
+use alloc::vec::Vec;
+
+use crate::io;
+use super::{
+    DataDirectory,
+    Error,
+    PeFile,
+    DATA_DIRECTORY_CERTIFICATE_TABLE,
+};
+
+/// Offset of the `CheckSum` field relative to the start of the optional
+/// header. It is the same for PE32 and PE32+.
+pub const CHECK_SUM_OFFSET: usize = 64;
+
+/// Alignment the hashed trailing data is padded to.
+pub const TRAILER_ALIGNMENT: usize = 8;
+
+impl<'read, Read: ?Sized + io::map::Read> PeFile<'read, Read> {
+    /// Return the file offset of the optional header.
+    pub fn optional_header_offset(&self) -> usize {
+        self.offset + super::PE_MAGIC.len() + size_of::<super::Header>()
+    }
+
+    /// Return the file offset of the `CheckSum` field of the optional
+    /// header.
+    pub fn check_sum_offset(&self) -> usize {
+        self.optional_header_offset() + CHECK_SUM_OFFSET
+    }
+
+    /// Return the file offset of the data directory at index `idx`, or
+    /// `None` if the image has fewer data directories.
+    pub fn data_directory_offset(&self, idx: u16) -> Option<usize> {
+        if idx as usize >= self.data_directories() {
+            return None;
+        }
+        let fixed = match self.is_pe32p() {
+            false => super::OPTIONAL_HEADER_SIZE_OH32,
+            true => super::OPTIONAL_HEADER_SIZE_OH32P,
+        };
+        Some(self.optional_header_offset() + fixed + idx as usize * size_of::<DataDirectory>())
+    }
+
+    /// Compute the Authenticode digest of the image with the given hash
+    /// engine.
+    ///
+    /// `size` is the size of the entire file in bytes. The certificate
+    /// table is excluded from the digest, and it must thus be located at
+    /// the end of the file, if present. The engine is reset before use.
+    pub fn authenticode<Engine: osi::hash::Engine + ?Sized>(
+        &self,
+        size: usize,
+        engine: &mut Engine,
+    ) -> Result<Engine::Hash, Error> {
+        let headers = self.size_of_headers() as usize;
+        let check_sum = self.check_sum_offset();
+        let cert_entry = self.data_directory_offset(DATA_DIRECTORY_CERTIFICATE_TABLE);
+        let cert_size = match self.data_directory(DATA_DIRECTORY_CERTIFICATE_TABLE) {
+            None => 0,
+            Some(v) => {
+                let off = v.virtual_address.to_native() as usize;
+                let len = v.size.to_native() as usize;
+                if off.checked_add(len) != Some(size) {
+                    return Err(Error::Bounds);
+                }
+                len
+            },
+        };
+
+        engine.reset();
+
+        // Hash the headers, skipping the checksum and the certificate-table
+        // data directory entry.
+        let mut skip = [(check_sum, 4), (headers, 0)];
+        if let Some(v) = cert_entry {
+            skip[1] = (v, size_of::<DataDirectory>());
+        }
+        let mut pos = 0;
+        for (off, len) in skip {
+            if off < pos || off + len > headers {
+                return Err(Error::Bounds);
+            }
+            engine.push(self.map(pos, off - pos)?);
+            pos = off + len;
+        }
+        engine.push(self.map(pos, headers - pos)?);
+
+        // Hash the file contents of all sections in file order.
+        let mut sections: Vec<_> = self.section_headers()
+            .filter(|v| v.size_of_raw_data.to_native() > 0)
+            .collect();
+        sections.sort_by_key(|v| v.pointer_to_raw_data.to_native());
+
+        let mut hashed = headers;
+        for section in sections {
+            let off = section.pointer_to_raw_data.to_native() as usize;
+            let len = section.size_of_raw_data.to_native() as usize;
+            engine.push(self.map(off, len)?);
+            hashed += len;
+        }
+
+        // Hash any trailing data up to the certificate table, padded to a
+        // multiple of 8 bytes.
+        let end = size - cert_size;
+        if end > hashed {
+            let len = end - hashed;
+            engine.push(self.map(hashed, len)?);
+            engine.push_zero(len.next_multiple_of(TRAILER_ALIGNMENT) - len);
+        }
+
+        Ok(engine.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use osi::hash::sha256;
+
+    use super::*;
+    use super::super::tests::fixture;
+
+    fn sha256(pe: &PeFile<'_, [u8]>, size: usize) -> alloc::string::String {
+        let mut engine = sha256::Engine::default();
+        pe.authenticode(size, &mut engine).unwrap().to_hex()
+    }
+
+    // Compute the Authenticode digest of an image with trailing data and a
+    // certificate table, and verify that the excluded fields do not affect
+    // it.
+    #[test]
+    fn digest() {
+        let mut data = fixture(true);
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert_eq!(pe.optional_header_offset(), 0x98);
+        assert_eq!(pe.check_sum_offset(), 0xd8);
+        assert_eq!(pe.data_directory_offset(DATA_DIRECTORY_CERTIFICATE_TABLE), Some(0x128));
+        assert_eq!(pe.data_directory_offset(16), None);
+        assert_eq!(
+            sha256(&pe, data.len()),
+            "21a207a76118091fd347e06738cfc86889253a52d99078a9cc1da31f1b0da8d7",
+        );
+
+        // Trailing data is padded to a multiple of 8 bytes.
+        data.extend(b"trail");
+        let pe = PeFile::with(&data[..]).unwrap();
+        let digest = sha256(&pe, data.len());
+        assert_eq!(
+            digest,
+            "2d8da4553bd16d8138afd8360a168ecb0d686aa056274e0cdf78f0b02a70c84b",
+        );
+
+        // Appending a certificate table does not change the digest.
+        data.resize(0x608, 0);
+        data.extend([0xff; 0x10]);
+        data[0x128..0x12c].copy_from_slice(&0x608u32.to_le_bytes());
+        data[0x12c..0x130].copy_from_slice(&0x10u32.to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert_eq!(sha256(&pe, data.len()), digest);
+
+        data[0xd8..0xdc].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+        data[0x610..0x618].copy_from_slice(&[0x00; 8]);
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert_eq!(sha256(&pe, data.len()), digest);
+
+        // The certificate table must be at the end of the file.
+        let pe = PeFile::with(&data[..0x610]).unwrap();
+        let mut engine = sha256::Engine::default();
+        assert_eq!(pe.authenticode(0x610, &mut engine).err(), Some(Error::Bounds));
+    }
+
+    // Verify that sections are hashed in file order, rather than in the
+    // order of the section table.
+    #[test]
+    fn section_order() {
+        let data = fixture(false);
+        assert_eq!(
+            sha256(&PeFile::with(&data[..]).unwrap(), data.len()),
+            "08f1c91ccb51ac81680591e73fddaff4b51f1fb179ccb56f83328572639d29ff",
+        );
+
+        // List `.data` first in the section table. `.text` still precedes it
+        // in the file, and is thus hashed first.
+        let mut swapped = data.clone();
+        let table = 0x98 + 96 + 16 * 8;
+        let (a, b) = swapped[table..table + 80].split_at_mut(40);
+        a.swap_with_slice(b);
+        let pe = PeFile::with(&swapped[..]).unwrap();
+        let names: Vec<_> = pe.section_headers().map(|v| v.name().to_vec()).collect();
+        assert_eq!(names, [b".data", b".text"]);
+        assert_eq!(
+            sha256(&pe, swapped.len()),
+            "013c16df6b976bb70c528a3a58dcbb14b742f7956df1b28b6ce8b58689a309ef",
+        );
+    }
+}
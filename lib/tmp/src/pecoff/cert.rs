@@ -0,0 +1,314 @@
+//! Certificate Table
+//!
+//! Signatures of PE images are stored in the certificate table, which is
+//! referenced by the `DATA_DIRECTORY_CERTIFICATE_TABLE` data directory.
+//! Unlike any other data directory, it refers to the table by file offset,
+//! since the table is not loaded into memory. The table is located at the
+//! end of the file, and is excluded from the Authenticode digest.
+//!
+//! The table is a sequence of `WIN_CERTIFICATE` entries, each starting with
+//! a [`CertificateHeader`] and aligned to 8 bytes. The certificate data is
+//! treated as opaque.
+
+use alloc::vec::Vec;
+
+use crate::io;
+use super::{
+    CertificateHeader,
+    Error,
+    PeFile,
+    DATA_DIRECTORY_CERTIFICATE_TABLE,
+    U16Le,
+    U32Le,
+};
+
+/// Alignment of the certificate table and its entries.
+pub const CERTIFICATE_ALIGNMENT: usize = 8;
+
+/// Entry of the certificate table.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct Certificate<'data> {
+    /// Revision of the entry, as given by the `WIN_CERT_REVISION_*`
+    /// constants.
+    pub revision: u16,
+    /// Type of the certificate data, as given by the `WIN_CERT_TYPE_*`
+    /// constants.
+    pub certificate_type: u16,
+    /// Certificate data, excluding the header and any padding.
+    pub data: &'data [u8],
+}
+
+/// Iterator over the entries of a certificate table.
+///
+/// A malformed entry yields an error and ends the iteration.
+#[derive(Clone, Debug)]
+pub struct Certificates<'data> {
+    data: &'data [u8],
+}
+
+impl<'data> Certificates<'data> {
+    /// Create an iterator over the certificate table in `data`.
+    pub fn new(data: &'data [u8]) -> Self {
+        Self {
+            data: data,
+        }
+    }
+}
+
+impl<'data> Iterator for Certificates<'data> {
+    type Item = Result<Certificate<'data>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let size = size_of::<CertificateHeader>();
+        let header = match self.data.get(..size) {
+            Some(v) => CertificateHeader::from_bytes(v.try_into().unwrap()),
+            None => {
+                self.data = &[];
+                return Some(Err(Error::Certificate));
+            },
+        };
+        let len = header.length.to_native() as usize;
+        if len < size || len > self.data.len() {
+            self.data = &[];
+            return Some(Err(Error::Certificate));
+        }
+
+        let data = &self.data[size..len];
+        let next = len.next_multiple_of(CERTIFICATE_ALIGNMENT).min(self.data.len());
+        self.data = &self.data[next..];
+
+        Some(Ok(Certificate {
+            revision: header.revision.to_native(),
+            certificate_type: header.certificate_type.to_native(),
+            data: data,
+        }))
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> PeFile<'read, Read> {
+    /// Return an iterator over the entries of the certificate table. If the
+    /// image has no certificate table, the iterator is empty.
+    pub fn certificates(&self) -> Result<Certificates<'read>, Error> {
+        match self.data_directory(DATA_DIRECTORY_CERTIFICATE_TABLE) {
+            None => Ok(Certificates::new(&[])),
+            Some(dir) => Ok(Certificates::new(self.map(
+                dir.virtual_address.to_native() as usize,
+                dir.size.to_native() as usize,
+            )?)),
+        }
+    }
+}
+
+// Locate the certificate table of the image `data`, and return the file
+// offset of its data directory entry, and the range of the table. The table
+// must be located at the end of the file.
+fn table(data: &[u8]) -> Result<(usize, usize, usize), Error> {
+    let pe = PeFile::with(data)?;
+    let entry = pe.data_directory_offset(DATA_DIRECTORY_CERTIFICATE_TABLE)
+        .ok_or(Error::Certificate)?;
+
+    match pe.data_directory(DATA_DIRECTORY_CERTIFICATE_TABLE) {
+        None => Ok((entry, data.len(), data.len())),
+        Some(dir) => {
+            let start = dir.virtual_address.to_native() as usize;
+            let end = start.checked_add(dir.size.to_native() as usize);
+            if end != Some(data.len()) || start < pe.size_of_headers() as usize {
+                return Err(Error::Certificate);
+            }
+            Ok((entry, start, data.len()))
+        },
+    }
+}
+
+// Update the certificate-table data directory entry at `entry` of `data`,
+// and recalculate the image checksum.
+fn update(data: &mut [u8], entry: usize, start: usize, size: usize) -> Result<(), Error> {
+    let (start, size) = match size {
+        0 => (0, 0),
+        _ => (
+            u32::try_from(start).map_err(|_| Error::Certificate)?,
+            u32::try_from(size).map_err(|_| Error::Certificate)?,
+        ),
+    };
+    data[entry..entry + 4].copy_from_slice(&start.to_le_bytes());
+    data[entry + 4..entry + 8].copy_from_slice(&size.to_le_bytes());
+
+    let at = PeFile::with(&*data)?.check_sum_offset();
+    let sum = super::check_sum(data, at).ok_or(Error::Bounds)?;
+    data[at..at + 4].copy_from_slice(&sum.to_le_bytes());
+    Ok(())
+}
+
+/// Remove the certificate table from the image `data`.
+///
+/// The table is truncated from the file, and the data directory entry and
+/// the image checksum are updated. Images without a certificate table are
+/// returned unmodified, apart from the checksum.
+pub fn strip(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let (entry, start, _) = table(data)?;
+    let mut r = data[..start].to_vec();
+    update(&mut r, entry, 0, 0)?;
+    Ok(r)
+}
+
+/// Append an entry to the certificate table of the image `data`.
+///
+/// If the image has no certificate table yet, the file is padded with zeros
+/// to 8 bytes, and the table is placed at its end. Otherwise, an existing
+/// table is padded to 8 bytes first, since not all signers pad its last
+/// entry. The entry is padded to 8 bytes, and the data directory entry and
+/// the image checksum are updated.
+pub fn append(data: &[u8], certificate: &Certificate<'_>) -> Result<Vec<u8>, Error> {
+    let (entry, mut start, end) = table(data)?;
+    let mut r = data.to_vec();
+    if start == end {
+        start = end.next_multiple_of(CERTIFICATE_ALIGNMENT);
+        r.resize(start, 0);
+    } else {
+        r.resize(start + (end - start).next_multiple_of(CERTIFICATE_ALIGNMENT), 0);
+    }
+
+    let len = size_of::<CertificateHeader>() + certificate.data.len();
+    let header = CertificateHeader {
+        length: U32Le::from_native(u32::try_from(len).map_err(|_| Error::Certificate)?),
+        revision: U16Le::from_native(certificate.revision),
+        certificate_type: U16Le::from_native(certificate.certificate_type),
+    };
+    r.extend_from_slice(header.as_bytes());
+    r.extend_from_slice(certificate.data);
+    r.resize(r.len().next_multiple_of(CERTIFICATE_ALIGNMENT), 0);
+
+    let size = r.len() - start;
+    update(&mut r, entry, start, size)?;
+    Ok(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use osi::hash::sha256;
+
+    use super::*;
+    use super::super::{
+        tests::fixture,
+        WIN_CERT_REVISION_2_0,
+        WIN_CERT_TYPE_EFI_GUID,
+        WIN_CERT_TYPE_PKCS_SIGNED_DATA,
+    };
+
+    fn digest(data: &[u8]) -> alloc::string::String {
+        let pe = PeFile::with(data).unwrap();
+        pe.authenticode(data.len(), &mut sha256::Engine::default()).unwrap().to_hex()
+    }
+
+    // Append certificates to an image of unaligned size, list them, and
+    // strip them again.
+    #[test]
+    fn append_strip() {
+        let mut data = fixture(true);
+        data.extend(b"trail");
+        let unsigned = digest(&data);
+
+        let pkcs = Certificate {
+            revision: WIN_CERT_REVISION_2_0,
+            certificate_type: WIN_CERT_TYPE_PKCS_SIGNED_DATA,
+            data: &[0x30, 0x82, 0x01],
+        };
+        let guid = Certificate {
+            revision: WIN_CERT_REVISION_2_0,
+            certificate_type: WIN_CERT_TYPE_EFI_GUID,
+            data: &[0xaa; 24],
+        };
+
+        let signed = append(&data, &pkcs).unwrap();
+        assert_eq!(signed.len(), 0x608 + 16);
+        assert_eq!(&signed[0x605..0x608], &[0; 3]);
+        assert_eq!(&signed[0x608..0x610], &[0x0b, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00]);
+        assert_eq!(super::super::verify_check_sum(&signed), Ok(true));
+        assert_eq!(digest(&signed), unsigned);
+
+        let signed = append(&signed, &guid).unwrap();
+        assert_eq!(signed.len(), 0x608 + 16 + 32);
+        assert_eq!(super::super::verify_check_sum(&signed), Ok(true));
+        assert_eq!(digest(&signed), unsigned);
+
+        let pe = PeFile::with(&signed[..]).unwrap();
+        let dir = pe.data_directory(DATA_DIRECTORY_CERTIFICATE_TABLE).unwrap();
+        assert_eq!(dir.virtual_address.to_native(), 0x608);
+        assert_eq!(dir.size.to_native(), 48);
+        let certificates: Vec<_> = pe.certificates().unwrap().map(|v| v.unwrap()).collect();
+        assert_eq!(certificates, [pkcs, guid]);
+
+        let stripped = strip(&signed).unwrap();
+        assert_eq!(stripped.len(), 0x608);
+        assert_eq!(&stripped[..0xd8], &data[..0xd8]);
+        assert_eq!(&stripped[0xdc..0x605], &data[0xdc..]);
+        assert_eq!(super::super::verify_check_sum(&stripped), Ok(true));
+        let pe = PeFile::with(&stripped[..]).unwrap();
+        assert!(pe.data_directory(DATA_DIRECTORY_CERTIFICATE_TABLE).is_none());
+        assert_eq!(pe.certificates().unwrap().count(), 0);
+    }
+
+    // Append a certificate to a table whose last entry is not padded.
+    #[test]
+    fn append_unpadded() {
+        let data = fixture(true);
+        let pkcs = Certificate {
+            revision: WIN_CERT_REVISION_2_0,
+            certificate_type: WIN_CERT_TYPE_PKCS_SIGNED_DATA,
+            data: &[0x30, 0x82, 0x01],
+        };
+        let guid = Certificate {
+            revision: WIN_CERT_REVISION_2_0,
+            certificate_type: WIN_CERT_TYPE_EFI_GUID,
+            data: &[0xaa; 24],
+        };
+
+        let mut signed = append(&data, &pkcs).unwrap();
+        signed.truncate(0x600 + 11);
+        signed[0x12c..0x130].copy_from_slice(&11u32.to_le_bytes());
+
+        let signed = append(&signed, &guid).unwrap();
+        assert_eq!(signed.len(), 0x600 + 16 + 32);
+        assert_eq!(&signed[0x60b..0x610], &[0; 5]);
+        assert_eq!(&signed[0x610..0x614], &[0x20, 0x00, 0x00, 0x00]);
+        assert_eq!(super::super::verify_check_sum(&signed), Ok(true));
+
+        let pe = PeFile::with(&signed[..]).unwrap();
+        let dir = pe.data_directory(DATA_DIRECTORY_CERTIFICATE_TABLE).unwrap();
+        assert_eq!(dir.virtual_address.to_native(), 0x600);
+        assert_eq!(dir.size.to_native(), 48);
+        let certificates: Vec<_> = pe.certificates().unwrap().map(|v| v.unwrap()).collect();
+        assert_eq!(certificates, [pkcs, guid]);
+    }
+
+    // Verify malformed tables are rejected.
+    #[test]
+    fn invalid() {
+        assert_eq!(Certificates::new(&[0x08, 0x00]).collect::<Vec<_>>(), [Err(Error::Certificate)]);
+        assert_eq!(
+            Certificates::new(&[0x07, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00]).collect::<Vec<_>>(),
+            [Err(Error::Certificate)],
+        );
+        assert_eq!(
+            Certificates::new(&[0x09, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00]).collect::<Vec<_>>(),
+            [Err(Error::Certificate)],
+        );
+
+        // The table must be located at the end of the file.
+        let mut data = fixture(true);
+        data.extend([0x08, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00]);
+        data[0x128..0x12c].copy_from_slice(&0x600u32.to_le_bytes());
+        data[0x12c..0x130].copy_from_slice(&0x8u32.to_le_bytes());
+        assert!(strip(&data).is_ok());
+        data.push(0);
+        assert_eq!(strip(&data), Err(Error::Certificate));
+        let certificate = Certificate { revision: 0, certificate_type: 0, data: &[] };
+        assert_eq!(append(&data, &certificate), Err(Error::Certificate));
+    }
+}
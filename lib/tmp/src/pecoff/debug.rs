@@ -0,0 +1,344 @@
+//! Debug Directory
+//!
+//! The debug directory lists the debug information associated with an
+//! image. Each entry is typed and refers to its data both by relative
+//! virtual address and by file offset. This module decodes and encodes the
+//! commonly used entry types:
+//!
+//! - `DEBUG_TYPE_CODEVIEW` entries carry an `RSDS` record, which names the
+//!   PDB file of the image together with a GUID and an age. Symbol servers
+//!   index images by these.
+//! - `DEBUG_TYPE_REPRO` entries mark images of reproducible builds, and
+//!   carry a hash of the build inputs, which replaces the time stamps of
+//!   the image.
+//! - `DEBUG_TYPE_EX_DLLCHARACTERISTICS` entries carry extended DLL
+//!   characteristics, like CET shadow-stack compatibility. NX compatibility
+//!   remains a flag of the optional header.
+
+use alloc::{format, string::String, vec::Vec};
+
+use crate::io;
+use super::{
+    DebugDirectory,
+    Error,
+    PeFile,
+    DATA_DIRECTORY_DEBUG,
+    DEBUG_TYPE_CODEVIEW,
+    DEBUG_TYPE_EX_DLLCHARACTERISTICS,
+    DEBUG_TYPE_REPRO,
+    U16Le,
+    U32Le,
+};
+
+/// Signature of CodeView records in the PDB 7.0 format.
+pub const CODEVIEW_RSDS: [u8; 4] = *b"RSDS";
+
+/// CodeView record in the PDB 7.0 format (`RSDS`).
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct CodeView<'data> {
+    /// GUID of the PDB file, in its on-disk representation.
+    pub guid: [u8; 16],
+    /// Age of the PDB file, incremented on every incremental link.
+    pub age: u32,
+    /// Path of the PDB file, excluding the NUL terminator.
+    pub path: &'data [u8],
+}
+
+/// Data of a reproducible-build entry.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct Repro<'data> {
+    /// Hash of the build inputs. It is empty if the entry carries no data,
+    /// in which case the time stamps of the image hold the hash.
+    pub hash: &'data [u8],
+}
+
+/// Debug directory entry to be encoded by [`build()`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Entry<'data> {
+    pub time_date_stamp: u32,
+    pub major_version: u16,
+    pub minor_version: u16,
+    pub debug_type: u32,
+    pub data: &'data [u8],
+}
+
+impl<'data> CodeView<'data> {
+    /// Decode a CodeView record from the data of a debug directory entry.
+    pub fn parse(data: &'data [u8]) -> Result<Self, Error> {
+        if data.len() < 24 || data[..4] != CODEVIEW_RSDS {
+            return Err(Error::Debug);
+        }
+        let path = &data[24..];
+        let n = path.iter().position(|v| *v == 0).ok_or(Error::Debug)?;
+
+        Ok(Self {
+            guid: data[4..20].try_into().unwrap(),
+            age: u32::from_le_bytes(data[20..24].try_into().unwrap()),
+            path: &path[..n],
+        })
+    }
+
+    /// Encode the record, including the NUL terminator of the path.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut r = Vec::with_capacity(24 + self.path.len() + 1);
+        r.extend_from_slice(&CODEVIEW_RSDS);
+        r.extend_from_slice(&self.guid);
+        r.extend_from_slice(&self.age.to_le_bytes());
+        r.extend_from_slice(self.path);
+        r.push(0);
+        r
+    }
+
+    /// Return the key symbol servers index the PDB file by. It is made of
+    /// the GUID in its textual representation without separators, followed
+    /// by the age, both as upper-case hex-strings.
+    pub fn symbol_key(&self) -> String {
+        let g = &self.guid;
+        let mut r = format!(
+            "{:08X}{:04X}{:04X}",
+            u32::from_le_bytes(g[0..4].try_into().unwrap()),
+            u16::from_le_bytes(g[4..6].try_into().unwrap()),
+            u16::from_le_bytes(g[6..8].try_into().unwrap()),
+        );
+        for v in &g[8..] {
+            r.push_str(&format!("{:02X}", v));
+        }
+        r.push_str(&format!("{:X}", self.age));
+        r
+    }
+}
+
+impl<'data> Repro<'data> {
+    /// Decode the data of a reproducible-build entry. The data is either
+    /// empty, or a 32-bit length followed by the hash.
+    pub fn parse(data: &'data [u8]) -> Result<Self, Error> {
+        if data.is_empty() {
+            return Ok(Self { hash: &[] });
+        }
+        let Some(v) = data.get(..4) else {
+            return Err(Error::Debug);
+        };
+        let len = u32::from_le_bytes(v.try_into().unwrap()) as usize;
+        let hash = data[4..].get(..len).ok_or(Error::Debug)?;
+        Ok(Self { hash: hash })
+    }
+
+    /// Encode the data of the entry.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        if self.hash.is_empty() {
+            return Vec::new();
+        }
+        let mut r = Vec::with_capacity(4 + self.hash.len());
+        r.extend_from_slice(&(self.hash.len() as u32).to_le_bytes());
+        r.extend_from_slice(self.hash);
+        r
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> PeFile<'read, Read> {
+    /// Return an iterator over the entries of the debug directory. If the
+    /// image has no debug directory, the iterator is empty.
+    pub fn debug_directories(&self) -> Result<impl ExactSizeIterator<Item = DebugDirectory> + 'read, Error> {
+        let data = match self.data_directory(DATA_DIRECTORY_DEBUG) {
+            None => &[][..],
+            Some(dir) => {
+                let len = dir.size.to_native() as usize;
+                if !len.is_multiple_of(size_of::<DebugDirectory>()) {
+                    return Err(Error::Debug);
+                }
+                self.map_rva(dir.virtual_address.to_native(), len)?
+            },
+        };
+
+        Ok(data
+            .chunks_exact(size_of::<DebugDirectory>())
+            .map(|v| DebugDirectory::from_bytes(v.try_into().unwrap())))
+    }
+
+    /// Return the data of a debug directory entry.
+    ///
+    /// The data is located via its relative virtual address, if non-zero,
+    /// and via its file offset otherwise.
+    pub fn debug_data(&self, entry: &DebugDirectory) -> Result<&'read [u8], Error> {
+        let len = entry.size_of_data.to_native() as usize;
+        if len == 0 {
+            return Ok(&[]);
+        }
+        match entry.address_of_raw_data.to_native() {
+            0 => self.map(entry.pointer_to_raw_data.to_native() as usize, len),
+            v => self.map_rva(v, len),
+        }
+    }
+
+    // Return the data of the first debug directory entry of the given type.
+    fn debug_data_of(&self, debug_type: u32) -> Result<Option<&'read [u8]>, Error> {
+        let entry = self.debug_directories()?.find(|v| v.debug_type.to_native() == debug_type);
+        entry.map(|v| self.debug_data(&v)).transpose()
+    }
+
+    /// Return the CodeView record of the image, if any.
+    ///
+    /// The first CodeView entry of the debug directory is used, and it must
+    /// carry an `RSDS` record.
+    pub fn codeview(&self) -> Result<Option<CodeView<'read>>, Error> {
+        self.debug_data_of(DEBUG_TYPE_CODEVIEW)?.map(CodeView::parse).transpose()
+    }
+
+    /// Return the reproducible-build entry of the image, if any.
+    pub fn repro(&self) -> Result<Option<Repro<'read>>, Error> {
+        self.debug_data_of(DEBUG_TYPE_REPRO)?.map(Repro::parse).transpose()
+    }
+
+    /// Return the extended DLL characteristics of the image, if any. They
+    /// are given by the `EX_DLL_CHARACTERISTIC_*` constants.
+    pub fn ex_dll_characteristics(&self) -> Result<Option<u32>, Error> {
+        match self.debug_data_of(DEBUG_TYPE_EX_DLLCHARACTERISTICS)? {
+            None => Ok(None),
+            Some(v) if v.len() < 4 => Err(Error::Debug),
+            Some(v) => Ok(Some(u32::from_le_bytes(v[..4].try_into().unwrap()))),
+        }
+    }
+}
+
+/// Encode a debug directory with the given entries, to be placed at the
+/// relative virtual address `rva` and the file offset `offset`.
+///
+/// The directory is followed by the data of all entries, each aligned to 4
+/// bytes. The data directory of the image must refer to the first
+/// `entries.len() * size_of::<DebugDirectory>()` bytes.
+pub fn build(entries: &[Entry<'_>], rva: u32, offset: u32) -> Result<Vec<u8>, Error> {
+    let base = entries.len() * size_of::<DebugDirectory>();
+    let mut dir = Vec::with_capacity(base);
+    let mut data = Vec::new();
+
+    for entry in entries {
+        let at = base + data.len();
+        let size = u32::try_from(entry.data.len()).map_err(|_| Error::Debug)?;
+        let (addr, ptr) = match size {
+            0 => (0, 0),
+            _ => {
+                let at = u32::try_from(at).map_err(|_| Error::Debug)?;
+                (
+                    rva.checked_add(at).ok_or(Error::Debug)?,
+                    offset.checked_add(at).ok_or(Error::Debug)?,
+                )
+            },
+        };
+
+        let v = DebugDirectory {
+            characteristics: U32Le::from_native(0),
+            time_date_stamp: U32Le::from_native(entry.time_date_stamp),
+            major_version: U16Le::from_native(entry.major_version),
+            minor_version: U16Le::from_native(entry.minor_version),
+            debug_type: U32Le::from_native(entry.debug_type),
+            size_of_data: U32Le::from_native(size),
+            address_of_raw_data: U32Le::from_native(addr),
+            pointer_to_raw_data: U32Le::from_native(ptr),
+        };
+        dir.extend_from_slice(v.as_bytes());
+
+        data.extend_from_slice(entry.data);
+        data.resize(data.len().next_multiple_of(4), 0);
+    }
+
+    dir.extend_from_slice(&data);
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use super::super::{
+        tests::fixture,
+        EX_DLL_CHARACTERISTIC_CET_COMPAT,
+        EX_DLL_CHARACTERISTIC_FORWARD_CFI_COMPAT,
+    };
+
+    const GUID: [u8; 16] = [
+        0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66,
+        0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+    ];
+
+    // Encode a debug directory into the `.data` section of the fixture and
+    // read it back.
+    #[test]
+    fn roundtrip() {
+        let cv = CodeView { guid: GUID, age: 0x1a, path: b"test.pdb" };
+        let cv_data = cv.to_bytes();
+        let hash = [0x5a; 32];
+        let repro = Repro { hash: &hash };
+        let repro_data = repro.to_bytes();
+        let ex = (EX_DLL_CHARACTERISTIC_CET_COMPAT | EX_DLL_CHARACTERISTIC_FORWARD_CFI_COMPAT).to_le_bytes();
+
+        let blob = build(&[
+            Entry { debug_type: DEBUG_TYPE_CODEVIEW, data: &cv_data, ..Default::default() },
+            Entry { debug_type: DEBUG_TYPE_REPRO, data: &repro_data, ..Default::default() },
+            Entry { debug_type: DEBUG_TYPE_EX_DLLCHARACTERISTICS, data: &ex, ..Default::default() },
+        ], 0x2100, 0x500).unwrap();
+        assert_eq!(blob.len(), 84 + 36 + 36 + 4);
+
+        let mut data = fixture(true);
+        data[0x500..0x500 + blob.len()].copy_from_slice(&blob);
+        data[0x138..0x13c].copy_from_slice(&0x2100u32.to_le_bytes());
+        data[0x13c..0x140].copy_from_slice(&84u32.to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+
+        let entries: Vec<_> = pe.debug_directories().unwrap().collect();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].address_of_raw_data.to_native(), 0x2154);
+        assert_eq!(entries[0].pointer_to_raw_data.to_native(), 0x554);
+        assert_eq!(entries[1].address_of_raw_data.to_native(), 0x2178);
+        assert_eq!(entries[2].pointer_to_raw_data.to_native(), 0x59c);
+        assert_eq!(pe.debug_data(&entries[1]).unwrap(), &repro_data[..]);
+
+        // Data is found via its file offset if it is not mapped.
+        let mut unmapped = entries[1];
+        unmapped.address_of_raw_data = U32Le::from_native(0);
+        assert_eq!(pe.debug_data(&unmapped).unwrap(), &repro_data[..]);
+
+        assert_eq!(pe.codeview(), Ok(Some(cv)));
+        assert_eq!(pe.repro(), Ok(Some(repro)));
+        assert_eq!(pe.ex_dll_characteristics(), Ok(Some(0x41)));
+    }
+
+    // Decode and encode CodeView records and derive their symbol key.
+    #[test]
+    fn codeview() {
+        let cv = CodeView { guid: GUID, age: 0x1a, path: b"C:\\src\\test.pdb" };
+        let data = cv.to_bytes();
+        assert_eq!(&data[..4], b"RSDS");
+        assert_eq!(data.len(), 24 + 16);
+        assert_eq!(CodeView::parse(&data), Ok(cv));
+        assert_eq!(cv.symbol_key(), "00112233445566778899AABBCCDDEEFF1A");
+
+        assert_eq!(CodeView::parse(&data[..data.len() - 1]), Err(Error::Debug));
+        assert_eq!(CodeView::parse(&data[..23]), Err(Error::Debug));
+        assert_eq!(CodeView::parse(b"NB10\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0"), Err(Error::Debug));
+
+        assert_eq!(Repro::parse(&[]), Ok(Repro { hash: &[] }));
+        assert_eq!(Repro::parse(&[0x02, 0x00, 0x00, 0x00, 0xaa, 0xbb]), Ok(Repro { hash: &[0xaa, 0xbb] }));
+        assert_eq!(Repro::parse(&[0x03, 0x00, 0x00, 0x00, 0xaa, 0xbb]), Err(Error::Debug));
+        assert_eq!(Repro::parse(&[0x03]), Err(Error::Debug));
+        assert!(Repro { hash: &[] }.to_bytes().is_empty());
+    }
+
+    // The fixture carries a debug directory entry of unknown type, and a
+    // directory of invalid size is rejected.
+    #[test]
+    fn fixture_entries() {
+        let mut data = fixture(false);
+        let pe = PeFile::with(&data[..]).unwrap();
+        let entries: Vec<_> = pe.debug_directories().unwrap().collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(pe.codeview(), Ok(None));
+        assert_eq!(pe.ex_dll_characteristics(), Ok(None));
+
+        data[0x12c..0x130].copy_from_slice(&0x1du32.to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert_eq!(pe.debug_directories().err(), Some(Error::Debug));
+    }
+}
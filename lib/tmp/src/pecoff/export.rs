@@ -0,0 +1,214 @@
+//! Export Table
+//!
+//! The export directory table lists the symbols an image exports to other
+//! images. Symbols are identified by their ordinal, and optionally by name.
+//! A symbol either refers to an address in the image, or forwards to a
+//! symbol of another DLL by name. All tables are resolved via the section
+//! table of the image.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::io;
+use super::{
+    Error,
+    ExportDirectory,
+    PeFile,
+    DATA_DIRECTORY_EXPORT_TABLE,
+};
+
+/// Target of an exported symbol.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub enum ExportTarget<'read> {
+    /// Relative virtual address of the symbol in the image.
+    Rva(u32),
+    /// Name of the symbol this symbol forwards to, usually in the form
+    /// `DLL.Symbol` or `DLL.#Ordinal`.
+    Forwarder(&'read [u8]),
+}
+
+/// Symbol exported by an image.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct Export<'read> {
+    /// Ordinal of the symbol, including the ordinal base.
+    pub ordinal: u32,
+    /// Name of the symbol, if exported by name.
+    pub name: Option<&'read [u8]>,
+    pub target: ExportTarget<'read>,
+}
+
+/// Iterator over the symbols of the export directory table, in the order of
+/// their ordinals. Unused entries of the export address table are skipped.
+pub struct Exports<'pe, 'read, Read: ?Sized> {
+    pe: &'pe PeFile<'read, Read>,
+    directory: Option<ExportDirectory>,
+    range: Range<u32>,
+    functions: &'read [u8],
+    // Name pointers with the index into the export address table they
+    // name, sorted by the index.
+    names: Vec<(u16, u32)>,
+    idx: usize,
+}
+
+impl<'pe, 'read, Read: ?Sized + io::map::Read> Exports<'pe, 'read, Read> {
+    /// Return the export directory table, if the image has one.
+    pub fn directory(&self) -> Option<&ExportDirectory> {
+        self.directory.as_ref()
+    }
+
+    /// Return the name of the image, as recorded in the export directory
+    /// table, if any.
+    pub fn name(&self) -> Result<Option<&'read [u8]>, Error> {
+        self.directory.map(|v| self.pe.map_str(v.name.to_native())).transpose()
+    }
+
+    // Return the name of the symbol with the given index into the export
+    // address table, if any.
+    fn name_of(&self, idx: usize) -> Result<Option<&'read [u8]>, Error> {
+        let i = self.names.partition_point(|v| (v.0 as usize) < idx);
+        match self.names.get(i) {
+            Some(v) if v.0 as usize == idx => self.pe.map_str(v.1).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<'pe, 'read, Read: ?Sized + io::map::Read> Iterator for Exports<'pe, 'read, Read> {
+    type Item = Result<Export<'read>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let directory = self.directory?;
+
+        loop {
+            let idx = self.idx;
+            let v = self.functions.get(4 * idx..4 * idx + 4)?;
+            self.idx += 1;
+
+            let rva = u32::from_le_bytes(v.try_into().unwrap());
+            if rva == 0 {
+                continue;
+            }
+
+            let target = if self.range.contains(&rva) {
+                match self.pe.map_str(rva) {
+                    Ok(v) => ExportTarget::Forwarder(v),
+                    Err(e) => return Some(Err(e)),
+                }
+            } else {
+                ExportTarget::Rva(rva)
+            };
+
+            return Some(self.name_of(idx).map(|name| Export {
+                ordinal: directory.base.to_native().wrapping_add(idx as u32),
+                name: name,
+                target: target,
+            }));
+        }
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> PeFile<'read, Read> {
+    /// Return an iterator over the symbols of the export directory table. If
+    /// the image has no export table, the iterator is empty.
+    ///
+    /// The export address table, the name pointer table, and the ordinal
+    /// table are mapped upfront, and the names are indexed by their entry in
+    /// the export address table.
+    pub fn exports(&self) -> Result<Exports<'_, 'read, Read>, Error> {
+        let mut r = Exports {
+            pe: self,
+            directory: None,
+            range: 0..0,
+            functions: &[],
+            names: Vec::new(),
+            idx: 0,
+        };
+
+        let Some(dir) = self.data_directory(DATA_DIRECTORY_EXPORT_TABLE) else {
+            return Ok(r);
+        };
+        let start = dir.virtual_address.to_native();
+        let end = start.checked_add(dir.size.to_native()).ok_or(Error::Unmapped)?;
+
+        let data = self.map_rva(start, size_of::<ExportDirectory>())?;
+        let directory = ExportDirectory::from_bytes(data.try_into().unwrap());
+        let nfunctions = directory.number_of_functions.to_native() as usize;
+        let nnames = directory.number_of_names.to_native() as usize;
+
+        let map = |rva: u32, len: usize| match len {
+            0 => Ok(&[][..]),
+            _ => self.map_rva(rva, len),
+        };
+        r.functions = map(directory.address_of_functions.to_native(), nfunctions.checked_mul(4).ok_or(Error::Unmapped)?)?;
+        let names = map(directory.address_of_names.to_native(), nnames.checked_mul(4).ok_or(Error::Unmapped)?)?;
+        let ordinals = map(directory.address_of_name_ordinals.to_native(), nnames * 2)?;
+
+        // Pair each name with its ordinal. The sort is stable, so the first
+        // name of an entry takes precedence if it has several.
+        r.names = ordinals
+            .chunks_exact(2)
+            .zip(names.chunks_exact(4))
+            .map(|(o, n)| (u16::from_le_bytes([o[0], o[1]]), u32::from_le_bytes(n.try_into().unwrap())))
+            .collect();
+        r.names.sort_by_key(|v| v.0);
+        r.directory = Some(directory);
+        r.range = start..end;
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::tests::{fixture, fixture_symbols};
+
+    // Enumerate the exports of PE32 and PE32+ images.
+    #[test]
+    fn exports() {
+        for pe32p in [false, true] {
+            let data = fixture_symbols(pe32p);
+            let pe = PeFile::with(&data[..]).unwrap();
+            let exports = pe.exports().unwrap();
+            assert_eq!(exports.directory().unwrap().base.to_native(), 3);
+            assert_eq!(exports.name(), Ok(Some(&b"test.efi"[..])));
+
+            let exports: Vec<_> = exports.map(|v| v.unwrap()).collect();
+            assert_eq!(exports, [
+                Export {
+                    ordinal: 3,
+                    name: Some(b"beta"),
+                    target: ExportTarget::Rva(0x1000),
+                },
+                Export {
+                    ordinal: 5,
+                    name: Some(b"alpha"),
+                    target: ExportTarget::Forwarder(b"NTDLL.RtlFoo"),
+                },
+            ]);
+        }
+
+        let data = fixture(true);
+        let pe = PeFile::with(&data[..]).unwrap();
+        let exports = pe.exports().unwrap();
+        assert!(exports.directory().is_none());
+        assert_eq!(exports.name(), Ok(None));
+        assert_eq!(exports.count(), 0);
+    }
+
+    // Verify exports by ordinal only, and out-of-bounds tables.
+    #[test]
+    fn ordinals() {
+        let mut data = fixture_symbols(true);
+        data[0x558..0x55c].copy_from_slice(&0u32.to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        let exports: Vec<_> = pe.exports().unwrap().map(|v| v.unwrap()).collect();
+        assert_eq!(exports[0].name, None);
+        assert_eq!(exports[1].name, None);
+
+        data[0x554..0x558].copy_from_slice(&0x100u32.to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert_eq!(pe.exports().err(), Some(Error::Unmapped));
+    }
+}
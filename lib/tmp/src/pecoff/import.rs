@@ -0,0 +1,237 @@
+//! Import Table
+//!
+//! The import directory table lists the DLLs an image imports symbols from.
+//! For each DLL, the import lookup table names the imported symbols, and
+//! the import address table receives their addresses when the image is
+//! loaded. Both tables are resolved via the section table of the image.
+
+use crate::io;
+use super::{
+    Error,
+    ImportDescriptor,
+    PeFile,
+    DATA_DIRECTORY_IMPORT_TABLE,
+    IMPORT_ORDINAL_FLAG32,
+    IMPORT_ORDINAL_FLAG64,
+};
+
+/// DLL an image imports symbols from.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct Import<'read> {
+    /// Name of the DLL.
+    pub name: &'read [u8],
+    pub descriptor: ImportDescriptor,
+}
+
+/// Symbol reference of an import thunk.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub enum Thunk<'read> {
+    /// The symbol is imported by ordinal.
+    Ordinal(u16),
+    /// The symbol is imported by name, with a hint into the name pointer
+    /// table of the exporting DLL.
+    Name {
+        hint: u16,
+        name: &'read [u8],
+    },
+}
+
+/// Symbol imported from a DLL.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct ImportThunk<'read> {
+    pub thunk: Thunk<'read>,
+    /// Relative virtual address of the entry in the import address table
+    /// that receives the address of the symbol.
+    pub iat: u32,
+}
+
+/// Iterator over the DLLs of the import directory table.
+pub struct Imports<'pe, 'read, Read: ?Sized> {
+    pe: &'pe PeFile<'read, Read>,
+    rva: u32,
+}
+
+/// Iterator over the symbols imported from a DLL.
+pub struct ImportThunks<'pe, 'read, Read: ?Sized> {
+    pe: &'pe PeFile<'read, Read>,
+    lookup: u32,
+    iat: u32,
+}
+
+impl<'pe, 'read, Read: ?Sized + io::map::Read> Iterator for Imports<'pe, 'read, Read> {
+    type Item = Result<Import<'read>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rva == 0 {
+            return None;
+        }
+
+        let data = match self.pe.map_rva(self.rva, size_of::<ImportDescriptor>()) {
+            Ok(v) => v,
+            Err(e) => {
+                self.rva = 0;
+                return Some(Err(e));
+            },
+        };
+        let descriptor = ImportDescriptor::from_bytes(data.try_into().unwrap());
+        if data.iter().all(|v| *v == 0) {
+            self.rva = 0;
+            return None;
+        }
+
+        self.rva = self.rva.wrapping_add(size_of::<ImportDescriptor>() as u32);
+        Some(self.pe.map_str(descriptor.name.to_native()).map(|name| Import {
+            name: name,
+            descriptor: descriptor,
+        }))
+    }
+}
+
+impl<'pe, 'read, Read: ?Sized + io::map::Read> Iterator for ImportThunks<'pe, 'read, Read> {
+    type Item = Result<ImportThunk<'read>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.lookup == 0 {
+            return None;
+        }
+
+        let size = if self.pe.is_pe32p() { 8 } else { 4 };
+        match self.pe.import_thunk(self.lookup) {
+            Ok(Some(thunk)) => {
+                let iat = self.iat;
+                self.lookup = self.lookup.wrapping_add(size);
+                self.iat = self.iat.wrapping_add(size);
+                Some(Ok(ImportThunk {
+                    thunk: thunk,
+                    iat: iat,
+                }))
+            },
+            Ok(None) => {
+                self.lookup = 0;
+                None
+            },
+            Err(e) => {
+                self.lookup = 0;
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> PeFile<'read, Read> {
+    /// Return an iterator over the DLLs of the import directory table. If
+    /// the image has no import table, the iterator is empty.
+    pub fn imports(&self) -> Imports<'_, 'read, Read> {
+        Imports {
+            pe: self,
+            rva: self.data_directory(DATA_DIRECTORY_IMPORT_TABLE)
+                .map_or(0, |v| v.virtual_address.to_native()),
+        }
+    }
+
+    /// Return an iterator over the symbols imported via `descriptor`.
+    ///
+    /// Symbols are taken from the import lookup table, or from the import
+    /// address table if the former is absent, as is the case for some
+    /// older linkers.
+    pub fn import_thunks(&self, descriptor: &ImportDescriptor) -> ImportThunks<'_, 'read, Read> {
+        let iat = descriptor.first_thunk.to_native();
+        ImportThunks {
+            pe: self,
+            lookup: match descriptor.original_first_thunk.to_native() {
+                0 => iat,
+                v => v,
+            },
+            iat: iat,
+        }
+    }
+
+    // Decode the import thunk at `rva`, or return `None` for the terminating
+    // thunk.
+    fn import_thunk(&self, rva: u32) -> Result<Option<Thunk<'read>>, Error> {
+        let (v, ordinal) = if self.is_pe32p() {
+            let v = u64::from_le_bytes(self.map_rva(rva, 8)?.try_into().unwrap());
+            (v, v & IMPORT_ORDINAL_FLAG64 != 0)
+        } else {
+            let v = u32::from_le_bytes(self.map_rva(rva, 4)?.try_into().unwrap());
+            (v as u64, v & IMPORT_ORDINAL_FLAG32 != 0)
+        };
+
+        if v == 0 {
+            Ok(None)
+        } else if ordinal {
+            Ok(Some(Thunk::Ordinal(v as u16)))
+        } else {
+            let rva = v as u32 & !IMPORT_ORDINAL_FLAG32;
+            let hint = u16::from_le_bytes(self.map_rva(rva, 2)?.try_into().unwrap());
+            let name = self.map_str(rva.checked_add(2).ok_or(Error::Unmapped)?)?;
+            Ok(Some(Thunk::Name { hint: hint, name: name }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use super::super::tests::fixture_symbols;
+
+    // Enumerate the imports of PE32 and PE32+ images.
+    #[test]
+    fn imports() {
+        for pe32p in [false, true] {
+            let data = fixture_symbols(pe32p);
+            let pe = PeFile::with(&data[..]).unwrap();
+            let size = if pe32p { 8 } else { 4 };
+
+            let imports: Vec<_> = pe.imports().map(|v| v.unwrap()).collect();
+            assert_eq!(imports.len(), 2);
+            assert_eq!(imports[0].name, b"KERNEL32.dll");
+            assert_eq!(imports[1].name, b"ntoskrnl.exe");
+
+            let thunks: Vec<_> = pe.import_thunks(&imports[0].descriptor).map(|v| v.unwrap()).collect();
+            assert_eq!(thunks, [
+                ImportThunk { thunk: Thunk::Name { hint: 5, name: b"ExitProcess" }, iat: 0x20a0 },
+                ImportThunk { thunk: Thunk::Ordinal(7), iat: 0x20a0 + size },
+            ]);
+
+            let thunks: Vec<_> = pe.import_thunks(&imports[1].descriptor).map(|v| v.unwrap()).collect();
+            assert_eq!(thunks, [
+                ImportThunk { thunk: Thunk::Name { hint: 0x10, name: b"IoCreateDevice" }, iat: 0x20e0 },
+            ]);
+        }
+
+        let data = super::super::tests::fixture(true);
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert_eq!(pe.imports().count(), 0);
+    }
+
+    // Verify that unterminated names and tables are rejected.
+    #[test]
+    fn invalid() {
+        // Let the name and the lookup table of the first descriptor run to
+        // the end of the `.data` section, without any terminator.
+        let mut data = fixture_symbols(false);
+        data[0x5f8..0x600].copy_from_slice(&[0x01, 0x01, 0xff, 0x80, 0x02, 0x02, 0xff, 0x80]);
+        data[0x440..0x444].copy_from_slice(&0x21f8u32.to_le_bytes());
+        data[0x44c..0x450].copy_from_slice(&0x21fcu32.to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+
+        let imports: Vec<_> = pe.imports().collect();
+        assert_eq!(imports.len(), 2);
+        assert_eq!(imports[0], Err(Error::String));
+        assert_eq!(imports[1].unwrap().name, b"ntoskrnl.exe");
+
+        let descriptor = ImportDescriptor::from_bytes(data[0x440..0x454].try_into().unwrap());
+        let thunks: Vec<_> = pe.import_thunks(&descriptor).collect();
+        assert_eq!(thunks, [
+            Ok(ImportThunk { thunk: Thunk::Ordinal(0x0101), iat: 0x20a0 }),
+            Ok(ImportThunk { thunk: Thunk::Ordinal(0x0202), iat: 0x20a4 }),
+            Err(Error::Unmapped),
+        ]);
+    }
+}
@@ -0,0 +1,1431 @@
+//! Portable Executable / Common Object File Format
+//!
+//! The Portable Executable format (PE, PE/COFF, PE32, PE32+) encodes
+//! executable and library code on platforms like Microsoft Windows, UEFI, and
+//! others. The COFF format originates in UNIX but was later adopted and
+//! extended into PE by Microsoft. Apart from the latter, the format has been
+//! widely abandonded and replaced by the Executable and Linker Format (ELF).
+//!
+//! The COFF format is still used for object files and linker input. The PE
+//! format extends COFF slightly to allow MS-DOS stubs to be linked in front
+//! of the COFF executable. The name Portable Executable refers to the fact
+//! that the format is meant to be architecture independent.
+//!
+//! The format was further extended to support offsets larger than 32bit,
+//! paving the way for adoption on 64 bit machines. Files encoded in the
+//! extended format are called PE32+.
+//!
+//! PE images can be read via [`PeFile`], which validates the headers and the
+//! location of the section table before any of it is exposed. Structures
+//! are exposed in their on-disk representation, and their fields are
+//! converted via [`osi::ffi`] on access.
+//!
+//! XXX: This module is still incomplete.
+
+use core::ops::ControlFlow as Flow;
+
+use crate::{io, msdosmz};
+
+pub mod authenticode;
+pub mod cert;
+pub mod debug;
+pub mod export;
+pub mod import;
+pub mod reloc;
+pub mod resource;
+
+type U8Le = osi::ffi::Integer<osi::ffi::LittleEndian<u8>, osi::align::AlignAs<1>>;
+type U16Le = osi::ffi::Integer<osi::ffi::LittleEndian<u16>, osi::align::AlignAs<2>>;
+type U32Le = osi::ffi::Integer<osi::ffi::LittleEndian<u32>, osi::align::AlignAs<4>>;
+type U64Le = osi::ffi::Integer<osi::ffi::LittleEndian<u64>, osi::align::AlignAs<8>>;
+
+pub const INVALID_TIMESTAMPS: [u32; 2] = [0x00000000, 0xffffffff];
+
+pub const PE_MAGIC: [u8; 4] = [0x50, 0x45, 0x00, 0x00];
+pub const PE_MAGIC_OH32: u16 = 0x010b;
+pub const PE_MAGIC_OH32P: u16 = 0x020b;
+
+pub const PE_OFFSET: usize = 0x3c;
+
+/// Size of the optional header of PE32 images, excluding data directories.
+pub const OPTIONAL_HEADER_SIZE_OH32: usize = 96;
+/// Size of the optional header of PE32+ images, excluding data directories.
+pub const OPTIONAL_HEADER_SIZE_OH32P: usize = 112;
+
+pub const CHARACTERISTIC_RELOCS_STRIPPED: u16 = 0x0001;
+pub const CHARACTERISTIC_EXECUTABLE_IMAGE: u16 = 0x0002;
+pub const CHARACTERISTIC_LINE_NUMS_STRIPPED: u16 = 0x0004;
+pub const CHARACTERISTIC_LOCAL_SYMS_STRIPPED: u16 = 0x0008;
+pub const CHARACTERISTIC_AGGRESSIVE_WS_TRIM: u16 = 0x0010;
+pub const CHARACTERISTIC_LARGE_ADDRESS_AWARE: u16 = 0x0020;
+/* reserved: 0x0040 */
+pub const CHARACTERISTIC_BYTES_REVERSED_LO: u16 = 0x0080;
+pub const CHARACTERISTIC_32BIT_MACHINE: u16 = 0x0100;
+pub const CHARACTERISTIC_DEBUG_STRIPPED: u16 = 0x0200;
+pub const CHARACTERISTIC_REMOVABLE_RUN_FROM_SWAP: u16 = 0x0400;
+pub const CHARACTERISTIC_NET_RUN_FROM_SWAP: u16 = 0x0800;
+pub const CHARACTERISTIC_SYSTEM: u16 = 0x1000;
+pub const CHARACTERISTIC_DLL: u16 = 0x2000;
+pub const CHARACTERISTIC_UP_SYSTEM_ONLY: u16 = 0x4000;
+pub const CHARACTERISTIC_BYTES_REVERSED_HI: u16 = 0x8000;
+
+pub const BASE_RELOCATION_ABSOLUTE: u16 = 0;
+pub const BASE_RELOCATION_HIGH: u16 = 1;
+pub const BASE_RELOCATION_LOW: u16 = 2;
+pub const BASE_RELOCATION_HIGHLOW: u16 = 3;
+pub const BASE_RELOCATION_HIGHADJ: u16 = 4;
+pub const BASE_RELOCATION_MIPS_JMPADDR: u16 = 5;
+pub const BASE_RELOCATION_ARM_MOV32: u16 = 5;
+pub const BASE_RELOCATION_RISCV_HIGH20: u16 = 5;
+pub const BASE_RELOCATION_THUMB_MOV32: u16 = 7;
+pub const BASE_RELOCATION_RISCV_LOW12I: u16 = 7;
+pub const BASE_RELOCATION_RISCV_LOW12S: u16 = 8;
+pub const BASE_RELOCATION_LOONGARCH32_MARK_LA: u16 = 8;
+pub const BASE_RELOCATION_LOONGARCH64_MARK_LA: u16 = 8;
+pub const BASE_RELOCATION_MIPS_JMPADDR16: u16 = 9;
+pub const BASE_RELOCATION_DIR64: u16 = 10;
+
+pub const DATA_DIRECTORY_EXPORT_TABLE: u16 = 0;
+pub const DATA_DIRECTORY_IMPORT_TABLE: u16 = 1;
+pub const DATA_DIRECTORY_RESOURCE_TABLE: u16 = 2;
+pub const DATA_DIRECTORY_EXCEPTION_TABLE: u16 = 3;
+pub const DATA_DIRECTORY_CERTIFICATE_TABLE: u16 = 4;
+pub const DATA_DIRECTORY_BASE_RELOCATION_TABLE: u16 = 5;
+pub const DATA_DIRECTORY_DEBUG: u16 = 6;
+pub const DATA_DIRECTORY_ARCHITECTURE: u16 = 7;
+pub const DATA_DIRECTORY_GLOBAL_PTR: u16 = 8;
+pub const DATA_DIRECTORY_TLS_TABLE: u16 = 9;
+pub const DATA_DIRECTORY_LOAD_CONFIG_TABLE: u16 = 10;
+pub const DATA_DIRECTORY_BOUND_IMPORT: u16 = 11;
+pub const DATA_DIRECTORY_IAT: u16 = 12;
+pub const DATA_DIRECTORY_DELAY_IMPORT_DESCRIPTOR: u16 = 13;
+pub const DATA_DIRECTORY_CLR_RUNTIME_HEADER: u16 = 14;
+pub const DATA_DIRECTORY_NUMBER: u16 = 16;
+
+pub const DEBUG_TYPE_UNKNOWN: u32 = 0;
+pub const DEBUG_TYPE_COFF: u32 = 1;
+pub const DEBUG_TYPE_CODEVIEW: u32 = 2;
+pub const DEBUG_TYPE_FPO: u32 = 3;
+pub const DEBUG_TYPE_MISC: u32 = 4;
+pub const DEBUG_TYPE_EXCEPTION: u32 = 5;
+pub const DEBUG_TYPE_FIXUP: u32 = 6;
+pub const DEBUG_TYPE_OMAP_TO_SRC: u32 = 7;
+pub const DEBUG_TYPE_OMAP_FROM_SRC: u32 = 8;
+pub const DEBUG_TYPE_BORLAND: u32 = 9;
+pub const DEBUG_TYPE_RESERVED10: u32 = 10;
+pub const DEBUG_TYPE_CLSID: u32 = 11;
+pub const DEBUG_TYPE_VC_FEATURE: u32 = 12;
+pub const DEBUG_TYPE_POGO: u32 = 13;
+pub const DEBUG_TYPE_ILTCG: u32 = 14;
+pub const DEBUG_TYPE_MPX: u32 = 15;
+pub const DEBUG_TYPE_REPRO: u32 = 16;
+pub const DEBUG_TYPE_EX_DLLCHARACTERISTICS: u32 = 20;
+
+pub const EX_DLL_CHARACTERISTIC_CET_COMPAT: u32 = 0x0001;
+pub const EX_DLL_CHARACTERISTIC_CET_COMPAT_STRICT_MODE: u32 = 0x0002;
+pub const EX_DLL_CHARACTERISTIC_CET_SET_CONTEXT_IP_VALIDATION_RELAXED_MODE: u32 = 0x0004;
+pub const EX_DLL_CHARACTERISTIC_CET_DYNAMIC_APIS_ALLOW_IN_PROC: u32 = 0x0008;
+/* reserved: 0x0010, 0x0020 */
+pub const EX_DLL_CHARACTERISTIC_FORWARD_CFI_COMPAT: u32 = 0x0040;
+pub const EX_DLL_CHARACTERISTIC_HOTPATCH_COMPATIBLE: u32 = 0x0080;
+
+pub const WIN_CERT_REVISION_1_0: u16 = 0x0100;
+pub const WIN_CERT_REVISION_2_0: u16 = 0x0200;
+
+pub const WIN_CERT_TYPE_X509: u16 = 0x0001;
+pub const WIN_CERT_TYPE_PKCS_SIGNED_DATA: u16 = 0x0002;
+pub const WIN_CERT_TYPE_RESERVED_1: u16 = 0x0003;
+pub const WIN_CERT_TYPE_TS_STACK_SIGNED: u16 = 0x0004;
+pub const WIN_CERT_TYPE_EFI_PKCS115: u16 = 0x0ef0;
+pub const WIN_CERT_TYPE_EFI_GUID: u16 = 0x0ef1;
+
+pub const RESOURCE_NAME_IS_STRING: u32 = 0x8000_0000;
+pub const RESOURCE_DATA_IS_DIRECTORY: u32 = 0x8000_0000;
+
+pub const RESOURCE_TYPE_CURSOR: u16 = 1;
+pub const RESOURCE_TYPE_BITMAP: u16 = 2;
+pub const RESOURCE_TYPE_ICON: u16 = 3;
+pub const RESOURCE_TYPE_MENU: u16 = 4;
+pub const RESOURCE_TYPE_DIALOG: u16 = 5;
+pub const RESOURCE_TYPE_STRING: u16 = 6;
+pub const RESOURCE_TYPE_FONTDIR: u16 = 7;
+pub const RESOURCE_TYPE_FONT: u16 = 8;
+pub const RESOURCE_TYPE_ACCELERATOR: u16 = 9;
+pub const RESOURCE_TYPE_RCDATA: u16 = 10;
+pub const RESOURCE_TYPE_MESSAGETABLE: u16 = 11;
+pub const RESOURCE_TYPE_GROUP_CURSOR: u16 = 12;
+pub const RESOURCE_TYPE_GROUP_ICON: u16 = 14;
+pub const RESOURCE_TYPE_VERSION: u16 = 16;
+pub const RESOURCE_TYPE_DLGINCLUDE: u16 = 17;
+pub const RESOURCE_TYPE_PLUGPLAY: u16 = 19;
+pub const RESOURCE_TYPE_VXD: u16 = 20;
+pub const RESOURCE_TYPE_ANICURSOR: u16 = 21;
+pub const RESOURCE_TYPE_ANIICON: u16 = 22;
+pub const RESOURCE_TYPE_HTML: u16 = 23;
+pub const RESOURCE_TYPE_MANIFEST: u16 = 24;
+
+pub const VS_FFI_SIGNATURE: u32 = 0xfeef_04bd;
+
+pub const IMPORT_ORDINAL_FLAG32: u32 = 0x8000_0000;
+pub const IMPORT_ORDINAL_FLAG64: u64 = 0x8000_0000_0000_0000;
+
+/* reserved: 0x0001, 0x0002, 0x0004, 0x0008 */
+pub const DLL_CHARACTERISTIC_HIGH_ENTROPY_VA: u16 = 0x0020;
+pub const DLL_CHARACTERISTIC_DYNAMIC_BASE: u16 = 0x0040;
+pub const DLL_CHARACTERISTIC_FORCE_INTEGRITY: u16 = 0x0080;
+pub const DLL_CHARACTERISTIC_NX_COMPAT: u16 = 0x0100;
+pub const DLL_CHARACTERISTIC_NO_ISOLATION: u16 = 0x0200;
+pub const DLL_CHARACTERISTIC_NO_SEH: u16 = 0x0400;
+pub const DLL_CHARACTERISTIC_NO_BIND: u16 = 0x0800;
+pub const DLL_CHARACTERISTIC_APPCONTAINER: u16 = 0x1000;
+pub const DLL_CHARACTERISTIC_WDM_DRIVER: u16 = 0x2000;
+pub const DLL_CHARACTERISTIC_GUARD_CF: u16 = 0x4000;
+pub const DLL_CHARACTERISTIC_TERMINAL_SERVER_AWARE: u16 = 0x8000;
+
+pub const MACHINE_UNKNOWN: u16 = 0x0000;
+pub const MACHINE_AM33: u16 = 0x01d3;
+pub const MACHINE_AMD64: u16 = 0x8664;
+pub const MACHINE_ARM: u16 = 0x01c0;
+pub const MACHINE_ARM64: u16 = 0xaa64;
+pub const MACHINE_ARMNT: u16 = 0x01c4;
+pub const MACHINE_EBC: u16 = 0x0ebc;
+pub const MACHINE_I386: u16 = 0x014c;
+pub const MACHINE_IA64: u16 = 0x0200;
+pub const MACHINE_LOONGARCH32: u16 = 0x6232;
+pub const MACHINE_LOONGARCH64: u16 = 0x6264;
+pub const MACHINE_M32R: u16 = 0x9041;
+pub const MACHINE_MIPS16: u16 = 0x0266;
+pub const MACHINE_MIPSFPU: u16 = 0x0366;
+pub const MACHINE_MIPSFPU16: u16 = 0x0466;
+pub const MACHINE_POWERPC: u16 = 0x01f0;
+pub const MACHINE_POWERPCFP: u16 = 0x01f1;
+pub const MACHINE_R4000: u16 = 0x0166;
+pub const MACHINE_RISCV32: u16 = 0x5032;
+pub const MACHINE_RISCV64: u16 = 0x5064;
+pub const MACHINE_RISCV128: u16 = 0x5128;
+pub const MACHINE_SH3: u16 = 0x01a2;
+pub const MACHINE_SH3DSP: u16 = 0x01a3;
+pub const MACHINE_SH4: u16 = 0x01a6;
+pub const MACHINE_SH5: u16 = 0x01a8;
+pub const MACHINE_THUMB: u16 = 0x01c2;
+pub const MACHINE_WCEMIPSV2: u16 = 0x0169;
+
+/* reserved: 0x00000001, 0x00000002, 0x00000004 */
+pub const SECTION_CHARACTERISTIC_TYPE_NO_PAD: u32 = 0x00000008;
+/* reserved: 0x00000010 */
+pub const SECTION_CHARACTERISTIC_CNT_CODE: u32 = 0x00000020;
+pub const SECTION_CHARACTERISTIC_CNT_INITIALIZED_DATA: u32 = 0x00000040;
+pub const SECTION_CHARACTERISTIC_CNT_UNINITIALIZED_DATA: u32 = 0x00000080;
+pub const SECTION_CHARACTERISTIC_LNK_OTHER: u32 = 0x00000100;
+pub const SECTION_CHARACTERISTIC_LNK_INFO: u32 = 0x00000200;
+/* reserved: 0x00000400 */
+pub const SECTION_CHARACTERISTIC_LNK_REMOVE: u32 = 0x00000800;
+pub const SECTION_CHARACTERISTIC_LNK_COMDAT: u32 = 0x00001000;
+/* reserved: 0x00002000, 0x00004000 */
+pub const SECTION_CHARACTERISTIC_GPREL: u32 = 0x00008000;
+pub const SECTION_CHARACTERISTIC_MEM_PURGEABLE: u32 = 0x00020000;
+pub const SECTION_CHARACTERISTIC_MEM_16BIT: u32 = 0x00020000;
+pub const SECTION_CHARACTERISTIC_MEM_LOCKED: u32 = 0x00040000;
+pub const SECTION_CHARACTERISTIC_MEM_PRELOAD: u32 = 0x00080000;
+pub const SECTION_CHARACTERISTIC_ALIGN_1BYTES: u32 = 0x00100000;
+pub const SECTION_CHARACTERISTIC_ALIGN_2BYTES: u32 = 0x00200000;
+pub const SECTION_CHARACTERISTIC_ALIGN_4BYTES: u32 = 0x00300000;
+pub const SECTION_CHARACTERISTIC_ALIGN_8BYTES: u32 = 0x00400000;
+pub const SECTION_CHARACTERISTIC_ALIGN_16BYTES: u32 = 0x00500000;
+pub const SECTION_CHARACTERISTIC_ALIGN_32BYTES: u32 = 0x00600000;
+pub const SECTION_CHARACTERISTIC_ALIGN_64BYTES: u32 = 0x00700000;
+pub const SECTION_CHARACTERISTIC_ALIGN_128BYTES: u32 = 0x00800000;
+pub const SECTION_CHARACTERISTIC_ALIGN_256BYTES: u32 = 0x00900000;
+pub const SECTION_CHARACTERISTIC_ALIGN_512BYTES: u32 = 0x00a00000;
+pub const SECTION_CHARACTERISTIC_ALIGN_1024BYTES: u32 = 0x00b00000;
+pub const SECTION_CHARACTERISTIC_ALIGN_2048BYTES: u32 = 0x00c00000;
+pub const SECTION_CHARACTERISTIC_ALIGN_4096BYTES: u32 = 0x00d00000;
+pub const SECTION_CHARACTERISTIC_ALIGN_8192BYTES: u32 = 0x00e00000;
+pub const SECTION_CHARACTERISTIC_LNK_NRELOC_OVFL: u32 = 0x01000000;
+pub const SECTION_CHARACTERISTIC_MEM_DISCARDABLE: u32 = 0x02000000;
+pub const SECTION_CHARACTERISTIC_MEM_NOT_CACHED: u32 = 0x04000000;
+pub const SECTION_CHARACTERISTIC_MEM_NOT_PAGED: u32 = 0x08000000;
+pub const SECTION_CHARACTERISTIC_MEM_SHARED: u32 = 0x10000000;
+pub const SECTION_CHARACTERISTIC_MEM_EXECUTE: u32 = 0x20000000;
+pub const SECTION_CHARACTERISTIC_MEM_READ: u32 = 0x40000000;
+pub const SECTION_CHARACTERISTIC_MEM_WRITE: u32 = 0x80000000;
+
+pub const SUBSYSTEM_UNKNOWN: u16 = 0x0000;
+pub const SUBSYSTEM_NATIVE: u16 = 0x0001;
+pub const SUBSYSTEM_WINDOWS_GUI: u16 = 0x0002;
+pub const SUBSYSTEM_WINDOWS_CUI: u16 = 0x0003;
+pub const SUBSYSTEM_OS2_CUI: u16 = 0x0005;
+pub const SUBSYSTEM_POSIX_CUI: u16 = 0x0007;
+pub const SUBSYSTEM_NATIVE_WINDOWS: u16 = 0x0008;
+pub const SUBSYSTEM_WINDOWS_CE_GUI: u16 = 0x0009;
+pub const SUBSYSTEM_EFI_APPLICATION: u16 = 0x0010;
+pub const SUBSYSTEM_EFI_BOOT_SERVICE_DRIVER: u16 = 0x0011;
+pub const SUBSYSTEM_EFI_RUNTIME_DRIVER: u16 = 0x0012;
+pub const SUBSYSTEM_EFI_ROM: u16 = 0x0013;
+pub const SUBSYSTEM_XBOX: u16 = 0x0014;
+pub const SUBSYSTEM_WINDOWS_BOOT_APPLICATION: u16 = 0x0016;
+
+// aligned on 8-byte boundary
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct Header {
+    pub machine: U16Le,
+    pub number_of_sections: U16Le,
+    pub time_date_stamp: U32Le,
+    pub pointer_to_symbol_table: U32Le,
+    pub number_of_symbols: U32Le,
+    pub size_of_optional_header: U16Le,
+    pub characteristics: U16Le,
+}
+
+#[repr(C)]
+pub struct OptionalHeader<FORMAT: format::Type = format::Pe> {
+    pub magic: U16Le,
+    pub major_linker_version: U8Le,
+    pub minor_linker_version: U8Le,
+    pub size_of_code: U32Le,
+    pub size_of_initialized_data: U32Le,
+    pub size_of_uninitialized_data: U32Le,
+    pub address_of_entry_point: U32Le,
+    pub base_of_code: U32Le,
+    pub base_of_data: FORMAT::BaseOfData,
+}
+
+pub type OptionalHeader32P = OptionalHeader::<format::Pe32P>;
+
+#[repr(C)]
+pub struct OptionalHeaderExt<FORMAT: format::Type = format::Pe> {
+    pub image_base: FORMAT::AddressSpace,
+    pub section_alignment: U32Le,
+    pub file_alignment: U32Le,
+    pub major_operating_system_version: U16Le,
+    pub minor_operating_system_version: U16Le,
+    pub major_image_version: U16Le,
+    pub minor_image_version: U16Le,
+    pub major_subsystem_version: U16Le,
+    pub minor_subsystem_version: U16Le,
+    pub win32_version_value: U32Le,
+    pub size_of_image: U32Le,
+    pub size_of_headers: U32Le,
+    pub check_sum: U32Le,
+    pub subsystem: U16Le,
+    pub dll_characteristics: U16Le,
+    pub size_of_stack_reserve: FORMAT::AddressSpace,
+    pub size_of_stack_commit: FORMAT::AddressSpace,
+    pub size_of_heap_reserve: FORMAT::AddressSpace,
+    pub size_of_heap_commit: FORMAT::AddressSpace,
+    pub loader_flags: U32Le,
+    pub number_of_rva_and_sizes: U32Le,
+}
+
+pub type OptionalHeaderExt32P = OptionalHeaderExt::<format::Pe32P>;
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct DataDirectory {
+    pub virtual_address: U32Le,
+    pub size: U32Le,
+}
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct SectionHeader {
+    pub name: [u8; 8],
+    pub virtual_size: U32Le,
+    pub virtual_address: U32Le,
+    pub size_of_raw_data: U32Le,
+    pub pointer_to_raw_data: U32Le,
+    pub pointer_to_relocations: U32Le,
+    pub pointer_to_linenumbers: U32Le,
+    pub number_of_relocations: U16Le,
+    pub number_of_linenumbers: U16Le,
+    pub characteristics: U32Le,
+}
+
+/// Header of an entry of the certificate table (`WIN_CERTIFICATE`). It is
+/// followed by the certificate data, and `length` includes both. Entries
+/// are padded to 8 bytes, which is not included in `length`.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct CertificateHeader {
+    pub length: U32Le,
+    pub revision: U16Le,
+    pub certificate_type: U16Le,
+}
+
+/// Header of a resource directory table. It is followed by the named
+/// entries, and then by the ID entries of the table.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct ResourceDirectory {
+    pub characteristics: U32Le,
+    pub time_date_stamp: U32Le,
+    pub major_version: U16Le,
+    pub minor_version: U16Le,
+    pub number_of_named_entries: U16Le,
+    pub number_of_id_entries: U16Le,
+}
+
+/// Entry of a resource directory table.
+///
+/// If `RESOURCE_NAME_IS_STRING` is set in `name`, the remaining bits are the
+/// offset of the name string, otherwise `name` is an integer ID. If
+/// `RESOURCE_DATA_IS_DIRECTORY` is set in `offset_to_data`, the remaining
+/// bits are the offset of another resource directory table, otherwise the
+/// offset of a resource data entry. All offsets are relative to the start
+/// of the resource table.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct ResourceDirectoryEntry {
+    pub name: U32Le,
+    pub offset_to_data: U32Le,
+}
+
+/// Leaf of the resource directory tree, describing the location of the
+/// resource data. Unlike the offsets of the tree, `data_rva` is a relative
+/// virtual address.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct ResourceDataEntry {
+    pub data_rva: U32Le,
+    pub size: U32Le,
+    pub codepage: U32Le,
+    pub reserved: U32Le,
+}
+
+/// Fixed part of the version information of an image (`VS_FIXEDFILEINFO`).
+/// Versions are split into their most and least significant 32 bits.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct FixedFileInfo {
+    pub signature: U32Le,
+    pub struc_version: U32Le,
+    pub file_version_ms: U32Le,
+    pub file_version_ls: U32Le,
+    pub product_version_ms: U32Le,
+    pub product_version_ls: U32Le,
+    pub file_flags_mask: U32Le,
+    pub file_flags: U32Le,
+    pub file_os: U32Le,
+    pub file_type: U32Le,
+    pub file_subtype: U32Le,
+    pub file_date_ms: U32Le,
+    pub file_date_ls: U32Le,
+}
+
+/// Entry of the debug directory, describing a single piece of debug
+/// information. The data is located at `address_of_raw_data` in memory, if
+/// non-zero, and at `pointer_to_raw_data` in the file.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct DebugDirectory {
+    pub characteristics: U32Le,
+    pub time_date_stamp: U32Le,
+    pub major_version: U16Le,
+    pub minor_version: U16Le,
+    pub debug_type: U32Le,
+    pub size_of_data: U32Le,
+    pub address_of_raw_data: U32Le,
+    pub pointer_to_raw_data: U32Le,
+}
+
+/// Entry of the import directory table, describing the imports from a
+/// single DLL. The table is terminated by an entry of all zeros.
+///
+/// The import lookup table at `original_first_thunk` and the import address
+/// table at `first_thunk` hold one thunk per imported symbol. Thunks are
+/// 32-bit in PE32 and 64-bit in PE32+ images. If the ordinal flag is set,
+/// the symbol is imported by the ordinal in the lower 16 bits. Otherwise, the
+/// lower 31 bits are the RVA of a 16-bit hint followed by the symbol name.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct ImportDescriptor {
+    pub original_first_thunk: U32Le,
+    pub time_date_stamp: U32Le,
+    pub forwarder_chain: U32Le,
+    pub name: U32Le,
+    pub first_thunk: U32Le,
+}
+
+/// Export directory table, describing the symbols exported by an image.
+///
+/// The export address table at `address_of_functions` holds the RVAs of
+/// `number_of_functions` symbols, indexed by their ordinal minus `base`.
+/// RVAs that point into the export directory itself are forwarders to a
+/// symbol of another DLL. The name pointer table and the ordinal table hold
+/// `number_of_names` entries each, and map symbol names to indices into the
+/// export address table.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct ExportDirectory {
+    pub characteristics: U32Le,
+    pub time_date_stamp: U32Le,
+    pub major_version: U16Le,
+    pub minor_version: U16Le,
+    pub name: U32Le,
+    pub base: U32Le,
+    pub number_of_functions: U32Le,
+    pub number_of_names: U32Le,
+    pub address_of_functions: U32Le,
+    pub address_of_names: U32Le,
+    pub address_of_name_ordinals: U32Le,
+}
+
+/// Header of a block of base relocations. It is followed by 16-bit entries,
+/// each with the relocation type in the upper 4 bits, and the offset into
+/// the page at `virtual_address` in the lower 12 bits.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+#[repr(C)]
+pub struct BaseRelocationBlock {
+    pub virtual_address: U32Le,
+    pub size_of_block: U32Le,
+}
+
+// Implement `from_bytes()` and `as_bytes()` for a protocol type of the
+// given size.
+macro_rules! impl_bytes {
+    ($type:ty, $size:literal, $name:literal) => {
+        impl $type {
+            #[doc = concat!("Import the ", $name, " from a byte slice")]
+            ///
+            /// Create a new structure from a byte slice, copying the data
+            /// over. No byte-order conversions are applied.
+            pub fn from_bytes(data: &[u8; $size]) -> Self {
+                assert!(core::mem::size_of_val(data) == core::mem::size_of::<Self>());
+
+                unsafe {
+                    // Safety: The structure consists of unsigned integers,
+                    //         which have no invalid byte-level
+                    //         representations. Even the wrong endianness is
+                    //         still a valid value.
+                    core::ptr::read_unaligned(data.as_ptr() as *const Self)
+                }
+            }
+
+            #[doc = concat!("Convert the ", $name, " to a byte slice")]
+            ///
+            /// Return a byte-slice reference to the structure. This can be
+            /// used to export it into a file. No byte-order conversions are
+            /// applied.
+            pub fn as_bytes(&self) -> &[u8; $size] {
+                assert!(core::mem::align_of::<[u8; $size]>() <= core::mem::align_of::<Self>());
+                assert!(core::mem::size_of::<[u8; $size]>() == core::mem::size_of::<Self>());
+
+                unsafe {
+                    // Safety: The structure consists of unsigned integers
+                    //         without any padding, so all its bytes are
+                    //         initialized.
+                    core::mem::transmute::<&Self, &[u8; $size]>(self)
+                }
+            }
+        }
+    };
+}
+
+impl_bytes!(Header, 20, "header");
+impl_bytes!(OptionalHeader<format::Pe>, 28, "optional header");
+impl_bytes!(OptionalHeader32P, 24, "optional header");
+impl_bytes!(OptionalHeaderExt<format::Pe>, 68, "optional header extension");
+impl_bytes!(OptionalHeaderExt32P, 88, "optional header extension");
+impl_bytes!(DataDirectory, 8, "data directory");
+impl_bytes!(SectionHeader, 40, "section header");
+impl_bytes!(BaseRelocationBlock, 8, "base relocation block");
+impl_bytes!(CertificateHeader, 8, "certificate header");
+impl_bytes!(ResourceDirectory, 16, "resource directory table");
+impl_bytes!(ResourceDirectoryEntry, 8, "resource directory entry");
+impl_bytes!(ResourceDataEntry, 16, "resource data entry");
+impl_bytes!(FixedFileInfo, 52, "fixed file info");
+impl_bytes!(DebugDirectory, 28, "debug directory entry");
+impl_bytes!(ImportDescriptor, 20, "import descriptor");
+impl_bytes!(ExportDirectory, 40, "export directory table");
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// The underlying I/O operation failed, or was interrupted if `None`.
+    Io(Option<io::map::Error>),
+    /// The reader cannot provide the requested range as a single contiguous
+    /// mapping.
+    Discontiguous,
+    /// The data does not start with the DOS MZ magic.
+    DosMagic,
+    /// The data at the PE offset does not start with the PE magic.
+    PeMagic,
+    /// The optional header is neither of type PE32 nor PE32+.
+    OptionalMagic,
+    /// The optional header is smaller than its fixed part, or than the
+    /// announced data directories.
+    OptionalHeaderSize,
+    /// A header, table, or section exceeds the end of the file.
+    Bounds,
+    /// A section index is out of range.
+    SectionIndex,
+    /// The relative virtual address is not backed by file contents.
+    Unmapped,
+    /// The base relocation table is malformed.
+    Relocation,
+    /// A base relocation is of an unsupported type.
+    RelocationType,
+    /// A string is not NUL-terminated within its section.
+    String,
+    /// A debug directory entry or its data is malformed.
+    Debug,
+    /// The certificate table is malformed, or not located at the end of the
+    /// file.
+    Certificate,
+    /// The resource directory tree or the version information is malformed.
+    Resource,
+}
+
+/// Optional header of either format, together with its data directories.
+///
+/// The variant is selected by the magic of the header, which is
+/// `PE_MAGIC_OH32` for PE32 and `PE_MAGIC_OH32P` for PE32+ images.
+pub enum OptionalHeaderAny<'read> {
+    /// Optional header of a PE32 image.
+    Pe {
+        header: OptionalHeader<format::Pe>,
+        ext: OptionalHeaderExt<format::Pe>,
+        directories: &'read [u8],
+    },
+    /// Optional header of a PE32+ image.
+    Pe32P {
+        header: OptionalHeader32P,
+        ext: OptionalHeaderExt32P,
+        directories: &'read [u8],
+    },
+}
+
+/// Reader for PE images.
+pub struct PeFile<'read, Read: ?Sized> {
+    read: &'read Read,
+    offset: usize,
+    header: Header,
+    optional: OptionalHeaderAny<'read>,
+    sections: &'read [u8],
+}
+
+impl core::convert::From<io::map::Error> for Error {
+    fn from(v: io::map::Error) -> Self {
+        Self::Io(Some(v))
+    }
+}
+
+impl SectionHeader {
+    /// Return the name of the section without trailing NUL bytes.
+    ///
+    /// Names longer than 8 bytes are stored in the COFF string table of
+    /// object files and are returned as their `/<offset>` reference.
+    pub fn name(&self) -> &[u8] {
+        let len = self.name.iter().position(|v| *v == 0).unwrap_or(self.name.len());
+        &self.name[..len]
+    }
+
+    /// Check whether the section contains the relative virtual address
+    /// `rva` in memory.
+    pub fn contains(&self, rva: u32) -> bool {
+        let start = self.virtual_address.to_native();
+        let size = self.virtual_size.to_native().max(self.size_of_raw_data.to_native());
+        rva >= start && ((rva - start) as u64) < size as u64
+    }
+}
+
+// Access a field of the optional header of either format.
+macro_rules! field {
+    ($self:ident . $part:ident . $field:ident) => {
+        match $self {
+            OptionalHeaderAny::Pe { $part, .. } => $part.$field.to_native(),
+            OptionalHeaderAny::Pe32P { $part, .. } => $part.$field.to_native(),
+        }
+    };
+}
+
+impl<'read> OptionalHeaderAny<'read> {
+    /// Decode an optional header, including its data directories, from the
+    /// raw data of size `SizeOfOptionalHeader`.
+    ///
+    /// The format is taken from the magic of the header. All data
+    /// directories announced by the header must fit into `data`.
+    pub fn with(data: &'read [u8]) -> Result<Self, Error> {
+        if data.len() < 2 {
+            return Err(Error::OptionalHeaderSize);
+        }
+
+        let this = match u16::from_le_bytes([data[0], data[1]]) {
+            PE_MAGIC_OH32 => {
+                let Some(v) = data.get(..OPTIONAL_HEADER_SIZE_OH32) else {
+                    return Err(Error::OptionalHeaderSize);
+                };
+                Self::Pe {
+                    header: OptionalHeader::<format::Pe>::from_bytes(v[..28].try_into().unwrap()),
+                    ext: OptionalHeaderExt::<format::Pe>::from_bytes(v[28..].try_into().unwrap()),
+                    directories: &data[OPTIONAL_HEADER_SIZE_OH32..],
+                }
+            },
+            PE_MAGIC_OH32P => {
+                let Some(v) = data.get(..OPTIONAL_HEADER_SIZE_OH32P) else {
+                    return Err(Error::OptionalHeaderSize);
+                };
+                Self::Pe32P {
+                    header: OptionalHeader32P::from_bytes(v[..24].try_into().unwrap()),
+                    ext: OptionalHeaderExt32P::from_bytes(v[24..].try_into().unwrap()),
+                    directories: &data[OPTIONAL_HEADER_SIZE_OH32P..],
+                }
+            },
+            _ => return Err(Error::OptionalMagic),
+        };
+
+        let n = this.number_of_rva_and_sizes() as usize;
+        if n > this.directories().len() / size_of::<DataDirectory>() {
+            return Err(Error::OptionalHeaderSize);
+        }
+
+        Ok(this)
+    }
+
+    fn directories(&self) -> &'read [u8] {
+        match self {
+            Self::Pe { directories, .. } => directories,
+            Self::Pe32P { directories, .. } => directories,
+        }
+    }
+
+    /// Return whether the header is in the PE32+ format, rather than PE32.
+    pub fn is_pe32p(&self) -> bool {
+        matches!(self, Self::Pe32P { .. })
+    }
+
+    /// Return the magic of the optional header, identifying its format.
+    pub fn magic(&self) -> u16 {
+        field!(self.header.magic)
+    }
+
+    /// Return the combined size of all code sections.
+    pub fn size_of_code(&self) -> u32 {
+        field!(self.header.size_of_code)
+    }
+
+    /// Return the RVA of the entry point.
+    pub fn address_of_entry_point(&self) -> u32 {
+        field!(self.header.address_of_entry_point)
+    }
+
+    /// Return the RVA of the start of the code section.
+    pub fn base_of_code(&self) -> u32 {
+        field!(self.header.base_of_code)
+    }
+
+    /// Return the preferred load address, widened to 64 bits for PE32.
+    pub fn image_base(&self) -> u64 {
+        match self {
+            Self::Pe { ext, .. } => ext.image_base.to_native::<u32>() as u64,
+            Self::Pe32P { ext, .. } => ext.image_base.to_native(),
+        }
+    }
+
+    /// Return the alignment of sections in memory.
+    pub fn section_alignment(&self) -> u32 {
+        field!(self.ext.section_alignment)
+    }
+
+    /// Return the alignment of section data in the file.
+    pub fn file_alignment(&self) -> u32 {
+        field!(self.ext.file_alignment)
+    }
+
+    /// Return the size of the image in memory, including all headers.
+    pub fn size_of_image(&self) -> u32 {
+        field!(self.ext.size_of_image)
+    }
+
+    /// Return the size of all headers, rounded up to the file alignment.
+    pub fn size_of_headers(&self) -> u32 {
+        field!(self.ext.size_of_headers)
+    }
+
+    /// Return the image checksum.
+    pub fn check_sum(&self) -> u32 {
+        field!(self.ext.check_sum)
+    }
+
+    /// Return the subsystem required to run the image.
+    pub fn subsystem(&self) -> u16 {
+        field!(self.ext.subsystem)
+    }
+
+    /// Return the DLL characteristics flags.
+    pub fn dll_characteristics(&self) -> u16 {
+        field!(self.ext.dll_characteristics)
+    }
+
+    /// Return the number of data directories as recorded in the header.
+    pub fn number_of_rva_and_sizes(&self) -> u32 {
+        field!(self.ext.number_of_rva_and_sizes)
+    }
+
+    /// Return the number of data directories.
+    pub fn data_directories(&self) -> usize {
+        self.number_of_rva_and_sizes() as usize
+    }
+
+    /// Return the data directory at index `idx`, or `None` if the header has
+    /// fewer data directories, or the directory is empty.
+    pub fn data_directory(&self, idx: u16) -> Option<DataDirectory> {
+        if idx as usize >= self.data_directories() {
+            return None;
+        }
+        let off = idx as usize * size_of::<DataDirectory>();
+        let dir = DataDirectory::from_bytes(self.directories()[off..off + 8].try_into().unwrap());
+        (dir.virtual_address.to_native() != 0 || dir.size.to_native() != 0).then_some(dir)
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> PeFile<'read, Read> {
+    /// Validate the DOS header, the PE headers, and the section table of a
+    /// PE image.
+    ///
+    /// The DOS header is followed to the PE headers via its `lfanew` field
+    /// at [`PE_OFFSET`]. The format of the image is taken from the magic of
+    /// the optional header.
+    pub fn with(read: &'read Read) -> Result<Self, Error> {
+        let dos = Self::map_in(read, 0, 64)?;
+        if dos[..2] != msdosmz::MAGIC {
+            return Err(Error::DosMagic);
+        }
+        let ext = msdosmz::HeaderExt::from_bytes(dos[28..64].try_into().unwrap());
+        let offset = ext.lfanew.to_native() as usize;
+
+        let pe = Self::map_in(read, offset, PE_MAGIC.len() + size_of::<Header>())?;
+        if pe[..4] != PE_MAGIC {
+            return Err(Error::PeMagic);
+        }
+        let header = Header::from_bytes(pe[4..].try_into().unwrap());
+
+        let off_optional = offset + pe.len();
+        let size = header.size_of_optional_header.to_native() as usize;
+        let optional = OptionalHeaderAny::with(Self::map_in(read, off_optional, size)?)?;
+
+        let nsections = header.number_of_sections.to_native() as usize;
+        let sections = Self::map_in(read, off_optional + size, nsections * size_of::<SectionHeader>())?;
+
+        Ok(Self {
+            read: read,
+            offset: offset,
+            header: header,
+            optional: optional,
+            sections: sections,
+        })
+    }
+
+    fn map_in(
+        read: &'read Read,
+        off: usize,
+        len: usize,
+    ) -> Result<&'read [u8], Error> {
+        if len == 0 {
+            return Ok(&[]);
+        }
+        if off.checked_add(len).is_none() {
+            return Err(Error::Bounds);
+        }
+
+        match read.map(off, len) {
+            Flow::Continue(v) if v.len() < len => Err(Error::Discontiguous),
+            Flow::Continue(v) => Ok(&v[..len]),
+            Flow::Break(Some(io::map::Error::Exceeded)) => Err(Error::Bounds),
+            Flow::Break(v) => Err(Error::Io(v)),
+        }
+    }
+
+    /// Map `len` bytes at offset `off` of the file.
+    pub fn map(&self, off: usize, len: usize) -> Result<&'read [u8], Error> {
+        Self::map_in(self.read, off, len)
+    }
+
+    /// Return the file offset of the PE magic, as stored in the DOS header.
+    pub fn pe_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Return the file header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Return whether the image is in the PE32+ format, rather than PE32.
+    pub fn is_pe32p(&self) -> bool {
+        self.optional.is_pe32p()
+    }
+
+    /// Return the optional header.
+    pub fn optional_header(&self) -> &OptionalHeaderAny<'read> {
+        &self.optional
+    }
+
+    /// Return the size of all headers, including the section table, as
+    /// recorded in the optional header.
+    pub fn size_of_headers(&self) -> u32 {
+        self.optional.size_of_headers()
+    }
+
+    /// Return the number of data directories.
+    pub fn data_directories(&self) -> usize {
+        self.optional.data_directories()
+    }
+
+    /// Return the data directory at index `idx`, or `None` if the image has
+    /// fewer data directories, or the directory is empty.
+    pub fn data_directory(&self, idx: u16) -> Option<DataDirectory> {
+        self.optional.data_directory(idx)
+    }
+
+    /// Return an iterator over the section headers.
+    pub fn section_headers(&self) -> impl ExactSizeIterator<Item = SectionHeader> + 'read {
+        self.sections
+            .chunks_exact(size_of::<SectionHeader>())
+            .map(|v| SectionHeader::from_bytes(v.try_into().unwrap()))
+    }
+
+    /// Return the section header at index `idx`, starting at 0.
+    pub fn section_header(&self, idx: usize) -> Result<SectionHeader, Error> {
+        self.section_headers().nth(idx).ok_or(Error::SectionIndex)
+    }
+
+    /// Return the first section with the given name, if any.
+    pub fn section_by_name(&self, name: &[u8]) -> Option<SectionHeader> {
+        self.section_headers().find(|v| v.name() == name)
+    }
+
+    /// Return the section containing the relative virtual address `rva` in
+    /// memory, if any.
+    pub fn section_by_rva(&self, rva: u32) -> Option<SectionHeader> {
+        self.section_headers().find(|v| v.contains(rva))
+    }
+
+    /// Return the file contents of a section. This is limited to the size of
+    /// the section in memory, unless the section is not loaded.
+    pub fn section_data(&self, section: &SectionHeader) -> Result<&'read [u8], Error> {
+        let mut len = section.size_of_raw_data.to_native();
+        let vsize = section.virtual_size.to_native();
+        if vsize > 0 && vsize < len {
+            len = vsize;
+        }
+        let off = section.pointer_to_raw_data.to_native();
+        if off == 0 {
+            return Ok(&[]);
+        }
+        self.map(off as usize, len as usize)
+    }
+
+    /// Translate the relative virtual address `rva` into a file offset.
+    ///
+    /// Addresses within the headers are mapped to themselves. Any other
+    /// address must be backed by the file contents of a section.
+    pub fn rva_to_offset(&self, rva: u32) -> Result<usize, Error> {
+        if rva < self.size_of_headers() {
+            return Ok(rva as usize);
+        }
+
+        let section = self.section_by_rva(rva).ok_or(Error::Unmapped)?;
+        let delta = rva - section.virtual_address.to_native();
+        if delta >= section.size_of_raw_data.to_native() || section.pointer_to_raw_data.to_native() == 0 {
+            return Err(Error::Unmapped);
+        }
+        Ok(section.pointer_to_raw_data.to_native() as usize + delta as usize)
+    }
+
+    /// Map `len` bytes at the relative virtual address `rva`.
+    ///
+    /// The range must be backed by the file contents of a single section,
+    /// or lie within the headers.
+    pub fn map_rva(&self, rva: u32, len: usize) -> Result<&'read [u8], Error> {
+        let end = (rva as u64).checked_add(len as u64).ok_or(Error::Unmapped)?;
+        if end <= self.size_of_headers() as u64 {
+            return self.map(rva as usize, len);
+        }
+
+        let section = self.section_by_rva(rva).ok_or(Error::Unmapped)?;
+        let start = section.virtual_address.to_native() as u64;
+        if end - start > section.size_of_raw_data.to_native() as u64 {
+            return Err(Error::Unmapped);
+        }
+        self.map(self.rva_to_offset(rva)?, len)
+    }
+
+    /// Map the NUL-terminated string at the relative virtual address `rva`,
+    /// excluding the terminator.
+    ///
+    /// The string must be backed by the file contents of a single section,
+    /// or lie within the headers.
+    pub fn map_str(&self, rva: u32) -> Result<&'read [u8], Error> {
+        let len = if rva < self.size_of_headers() {
+            self.size_of_headers() - rva
+        } else {
+            let section = self.section_by_rva(rva).ok_or(Error::Unmapped)?;
+            let delta = rva - section.virtual_address.to_native();
+            section.size_of_raw_data.to_native().saturating_sub(delta)
+        };
+        if len == 0 {
+            return Err(Error::Unmapped);
+        }
+
+        let data = self.map_rva(rva, len as usize)?;
+        let n = data.iter().position(|v| *v == 0).ok_or(Error::String)?;
+        Ok(&data[..n])
+    }
+}
+
+// Round `v` up to a multiple of `align`, or return `None` on overflow.
+pub(crate) fn align_up(v: u64, align: u32) -> Option<u64> {
+    v.checked_next_multiple_of(align as u64)
+}
+
+// Convert `v` to a 32-bit PE/COFF offset, or return `None` if it exceeds
+// the 32-bit range.
+pub(crate) fn offset(v: u64) -> Option<u32> {
+    u32::try_from(v).ok()
+}
+
+/// Calculate the image checksum of the PE file `data`.
+///
+/// The checksum is the 16-bit one's-complement sum of the file, with the
+/// `CheckSum` field at file offset `offset` treated as zero, plus the size
+/// of the file. See [`PeFile::check_sum_offset()`] for the location of the
+/// field.
+///
+/// Return `None` if the 4-byte field at `offset` is not fully contained in
+/// `data`.
+pub fn check_sum(data: &[u8], offset: usize) -> Option<u32> {
+    let end = offset.checked_add(4).filter(|v| *v <= data.len())?;
+    let head = msdosmz::csum16(&data[..offset]);
+    let mut tail = msdosmz::csum16(&data[end..]);
+    if offset % 2 == 1 {
+        // The tail starts at an odd offset, so its bytes are swapped with
+        // respect to the 16-bit words of the file.
+        tail = tail.swap_bytes();
+    }
+
+    let (sum, carry) = head.overflowing_add(tail);
+    Some(((sum + carry as u16) as u32).wrapping_add(data.len() as u32))
+}
+
+/// Verify the image checksum of the PE file `data`.
+///
+/// Return whether the `CheckSum` field of the optional header matches the
+/// checksum of the file. Note that a zero checksum commonly denotes that
+/// no checksum was calculated, but is not treated specially.
+pub fn verify_check_sum(data: &[u8]) -> Result<bool, Error> {
+    let pe = PeFile::with(data)?;
+    let offset = pe.check_sum_offset();
+    let sum = check_sum(data, offset).ok_or(Error::Bounds)?;
+    Ok(pe.optional_header().check_sum() == sum)
+}
+
+/// Format Parameter Customization
+///
+/// The PE format comes in multiple types. This module provides a trait named
+/// `format::Type` which describes all the possible customizations for the
+/// PE format. The PE types are parameterized with this trait if they allow
+/// customizations.
+///
+/// The `Pe` and `Pe32P` types are predefined instances for the Pe and Pe32+
+/// types of the format.
+pub mod format {
+    pub trait Type {
+        type AddressSpace;
+        type BaseOfData;
+    }
+
+    pub struct Pe {}
+
+    impl Type for Pe {
+        type AddressSpace = super::U32Le;
+        type BaseOfData = super::U32Le;
+    }
+
+    pub struct Pe32P {}
+
+    impl Type for Pe32P {
+        type AddressSpace = super::U64Le;
+        type BaseOfData = ();
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloc::vec::Vec;
+    use core::mem::{
+        align_of,
+        size_of,
+    };
+    use super::*;
+
+    // Create a PE32 or PE32+ image with 16 data directories and two
+    // sections. `.text` is located at 0x1000 with 0x10 bytes of `0xc3`, and
+    // `.data` is located at 0x2000 with 0x300 bytes in memory but only 0x200
+    // bytes in the file, filled with their offset. The debug directory
+    // points to 0x2010.
+    pub(crate) fn fixture(pe32p: bool) -> Vec<u8> {
+        let mut v = Vec::new();
+        let u16 = |v: &mut Vec<u8>, x: u16| v.extend(x.to_le_bytes());
+        let u32 = |v: &mut Vec<u8>, x: u32| v.extend(x.to_le_bytes());
+        let fixed = if pe32p { OPTIONAL_HEADER_SIZE_OH32P } else { OPTIONAL_HEADER_SIZE_OH32 };
+
+        v.extend(msdosmz::STUB_X86);
+        v.extend(PE_MAGIC);
+        u16(&mut v, if pe32p { MACHINE_AMD64 } else { MACHINE_I386 });
+        u16(&mut v, 2);
+        u32(&mut v, 0);
+        u32(&mut v, 0);
+        u32(&mut v, 0);
+        u16(&mut v, (fixed + 16 * 8) as u16);
+        u16(&mut v, CHARACTERISTIC_EXECUTABLE_IMAGE);
+
+        u16(&mut v, if pe32p { PE_MAGIC_OH32P } else { PE_MAGIC_OH32 });
+        v.extend([14, 0]);
+        u32(&mut v, 0x200);
+        u32(&mut v, 0x200);
+        u32(&mut v, 0);
+        u32(&mut v, 0x1000);
+        u32(&mut v, 0x1000);
+        if pe32p {
+            v.extend(0x140000000u64.to_le_bytes());
+        } else {
+            u32(&mut v, 0x2000);
+            u32(&mut v, 0x400000);
+        }
+        u32(&mut v, 0x1000);
+        u32(&mut v, 0x200);
+        for x in [6, 0, 1, 2, 10, 0] {
+            u16(&mut v, x);
+        }
+        u32(&mut v, 0);
+        u32(&mut v, 0x3000);
+        u32(&mut v, 0x200);
+        u32(&mut v, 0);
+        u16(&mut v, SUBSYSTEM_EFI_APPLICATION);
+        u16(&mut v, DLL_CHARACTERISTIC_NX_COMPAT);
+        for x in [0x100000, 0x1000, 0x100000, 0x1000] {
+            match pe32p {
+                false => u32(&mut v, x),
+                true => v.extend((x as u64).to_le_bytes()),
+            }
+        }
+        u32(&mut v, 0);
+        u32(&mut v, 16);
+        for i in 0..16 {
+            let (rva, size) = if i == DATA_DIRECTORY_DEBUG { (0x2010, 0x1c) } else { (0, 0) };
+            u32(&mut v, rva);
+            u32(&mut v, size);
+        }
+
+        for (name, vsize, rva, raw, off, flags) in [
+            (b".text\0\0\0", 0x10, 0x1000, 0x200, 0x200, SECTION_CHARACTERISTIC_CNT_CODE),
+            (b".data\0\0\0", 0x300, 0x2000, 0x200, 0x400, SECTION_CHARACTERISTIC_CNT_INITIALIZED_DATA),
+        ] {
+            v.extend(name);
+            for x in [vsize, rva, raw, off, 0, 0] {
+                u32(&mut v, x);
+            }
+            u32(&mut v, 0);
+            u32(&mut v, flags | SECTION_CHARACTERISTIC_MEM_READ);
+        }
+
+        v.resize(0x200, 0);
+        v.extend([0xc3; 0x10]);
+        v.resize(0x400, 0);
+        v.extend((0..0x200).map(|v| v as u8));
+        v
+    }
+
+    // Create a fixture with an import table and an export table in the
+    // `.data` section.
+    pub(crate) fn fixture_symbols(pe32p: bool) -> Vec<u8> {
+        let mut v = fixture(pe32p);
+        let thunk = |v: &mut Vec<u8>, at: usize, x: u64| match pe32p {
+            false => v[at..at + 4].copy_from_slice(&(x as u32).to_le_bytes()),
+            true => v[at..at + 8].copy_from_slice(&x.to_le_bytes()),
+        };
+        let put = |v: &mut Vec<u8>, at: usize, data: &[u8]| {
+            v[at..at + data.len()].copy_from_slice(data);
+        };
+        let u32s = |v: &mut Vec<u8>, at: usize, data: &[u32]| {
+            for (i, x) in data.iter().enumerate() {
+                v[at + 4 * i..at + 4 * i + 4].copy_from_slice(&x.to_le_bytes());
+            }
+        };
+        let ordinal = if pe32p { IMPORT_ORDINAL_FLAG64 } else { IMPORT_ORDINAL_FLAG32 as u64 };
+
+        // The `.data` section is mapped at 0x2000 from file offset 0x400.
+        v[0x440..0x600].fill(0);
+        let dirs = if pe32p { 0x108 } else { 0xf8 };
+        u32s(&mut v, dirs, &[0x2140, 0x70, 0x2040, 60]);
+
+        // Import directory table with 2 descriptors.
+        u32s(&mut v, 0x440, &[0x2080, 0, 0, 0x20c0, 0x20a0]);
+        u32s(&mut v, 0x454, &[0, 0, 0, 0x20d0, 0x20e0]);
+        for at in [0x480, 0x4a0] {
+            thunk(&mut v, at, 0x2100);
+            thunk(&mut v, at + if pe32p { 8 } else { 4 }, ordinal | 7);
+        }
+        thunk(&mut v, 0x4e0, 0x2110);
+        put(&mut v, 0x4c0, b"KERNEL32.dll");
+        put(&mut v, 0x4d0, b"ntoskrnl.exe");
+        put(&mut v, 0x500, b"\x05\x00ExitProcess");
+        put(&mut v, 0x510, b"\x10\x00IoCreateDevice");
+
+        // Export directory table with 3 functions, of which 1 is unused and
+        // 1 is a forwarder.
+        u32s(&mut v, 0x540, &[0, 0, 0, 0x2170, 3, 3, 2, 0x2180, 0x2190, 0x2198]);
+        put(&mut v, 0x570, b"test.efi");
+        u32s(&mut v, 0x580, &[0x1000, 0, 0x21a0]);
+        u32s(&mut v, 0x590, &[0x21c0, 0x21c8]);
+        put(&mut v, 0x598, &[0x02, 0x00, 0x00, 0x00]);
+        put(&mut v, 0x5a0, b"NTDLL.RtlFoo");
+        put(&mut v, 0x5c0, b"alpha");
+        put(&mut v, 0x5c8, b"beta");
+        v
+    }
+
+    #[test]
+    fn verify_types() {
+        assert_eq!(size_of::<Header>(), 20);
+        assert_eq!(align_of::<Header>(), 4);
+
+        assert_eq!(size_of::<OptionalHeader>(), 28);
+        assert_eq!(align_of::<OptionalHeader>(), 4);
+        assert_eq!(size_of::<OptionalHeader32P>(), 24);
+        assert_eq!(align_of::<OptionalHeader32P>(), 4);
+
+        assert_eq!(size_of::<OptionalHeaderExt>(), 68);
+        assert_eq!(align_of::<OptionalHeaderExt>(), 4);
+        assert_eq!(size_of::<OptionalHeaderExt32P>(), 88);
+        assert_eq!(align_of::<OptionalHeaderExt32P>(), 8);
+
+        assert_eq!(size_of::<DataDirectory>(), 8);
+        assert_eq!(size_of::<SectionHeader>(), 40);
+        assert_eq!(size_of::<BaseRelocationBlock>(), 8);
+        assert_eq!(size_of::<CertificateHeader>(), 8);
+        assert_eq!(size_of::<ResourceDirectory>(), 16);
+        assert_eq!(size_of::<ResourceDirectoryEntry>(), 8);
+        assert_eq!(size_of::<ResourceDataEntry>(), 16);
+        assert_eq!(size_of::<FixedFileInfo>(), 52);
+        assert_eq!(size_of::<DebugDirectory>(), 28);
+        assert_eq!(size_of::<ImportDescriptor>(), 20);
+        assert_eq!(size_of::<ExportDirectory>(), 40);
+    }
+
+    // Parse PE32 and PE32+ fixtures and verify section lookups and address
+    // translation.
+    #[test]
+    fn read() {
+        for pe32p in [false, true] {
+            let data = fixture(pe32p);
+            let pe = PeFile::with(&data[..]).unwrap();
+
+            assert_eq!(pe.pe_offset(), 0x80);
+            assert_eq!(pe.is_pe32p(), pe32p);
+            assert_eq!(pe.header().number_of_sections.to_native(), 2);
+            assert_eq!(pe.size_of_headers(), 0x200);
+            assert_eq!(pe.data_directories(), 16);
+
+            let dir = pe.data_directory(DATA_DIRECTORY_DEBUG).unwrap();
+            assert_eq!(dir.virtual_address.to_native(), 0x2010);
+            assert_eq!(dir.size.to_native(), 0x1c);
+            assert!(pe.data_directory(DATA_DIRECTORY_EXPORT_TABLE).is_none());
+            assert!(pe.data_directory(16).is_none());
+
+            let names: Vec<_> = pe.section_headers().map(|v| v.name().to_vec()).collect();
+            assert_eq!(names, [b".text", b".data"]);
+            let text = pe.section_by_name(b".text").unwrap();
+            assert_eq!(pe.section_data(&text).unwrap(), [0xc3; 0x10]);
+            let data_ = pe.section_by_name(b".data").unwrap();
+            assert_eq!(pe.section_data(&data_).unwrap().len(), 0x200);
+            assert!(pe.section_by_name(b".bss").is_none());
+            assert_eq!(pe.section_header(1).unwrap(), data_);
+            assert_eq!(pe.section_header(2), Err(Error::SectionIndex));
+
+            assert_eq!(pe.section_by_rva(0x1004).unwrap(), text);
+            assert_eq!(pe.section_by_rva(0x22ff).unwrap(), data_);
+            assert!(pe.section_by_rva(0x2300).is_none());
+
+            assert_eq!(pe.rva_to_offset(0x40), Ok(0x40));
+            assert_eq!(pe.rva_to_offset(0x1004), Ok(0x204));
+            assert_eq!(pe.rva_to_offset(0x2010), Ok(0x410));
+            assert_eq!(pe.rva_to_offset(0x2250), Err(Error::Unmapped));
+            assert_eq!(pe.rva_to_offset(0x5000), Err(Error::Unmapped));
+
+            assert_eq!(pe.map_rva(0x2010, 4).unwrap(), [0x10, 0x11, 0x12, 0x13]);
+            assert_eq!(pe.map_rva(0x21fc, 8), Err(Error::Unmapped));
+            assert_eq!(pe.map_rva(0x80, 4).unwrap(), PE_MAGIC);
+        }
+    }
+
+    // Decode the optional headers of real PE32 and PE32+ images and verify
+    // that both formats are told apart by their magic.
+    #[test]
+    fn optional_header() {
+        let w32: &[u8] = &[
+            0x0b, 0x01, 0x0a, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0xa2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x49, 0x3e, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00,
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xb0, 0x01, 0x00, 0x00, 0x04, 0x00, 0x00,
+            0x69, 0x20, 0x02, 0x00, 0x02, 0x00, 0x40, 0x81, 0x00, 0x00, 0x10, 0x00, 0x00, 0x10, 0x00, 0x00,
+            0x00, 0x00, 0x10, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9c, 0xf4, 0x00, 0x00, 0x50, 0x00, 0x00, 0x00,
+            0x00, 0x40, 0x01, 0x00, 0xf4, 0x53, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa0, 0x01, 0x00, 0x6c, 0x09, 0x00, 0x00,
+            0xc0, 0xd1, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0xf0, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0xd0, 0x00, 0x00, 0x80, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let w64: &[u8] = &[
+            0x0b, 0x02, 0x0a, 0x00, 0x00, 0xd8, 0x00, 0x00, 0x00, 0xb2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x0c, 0x46, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x01, 0x00, 0x00, 0x00,
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x04, 0x00, 0x00,
+            0xa2, 0xd1, 0x01, 0x00, 0x02, 0x00, 0x40, 0x81, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x1f, 0x01, 0x00, 0x50, 0x00, 0x00, 0x00,
+            0x00, 0x90, 0x01, 0x00, 0xf4, 0x53, 0x00, 0x00, 0x00, 0x80, 0x01, 0x00, 0x04, 0x0b, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x01, 0x00, 0x64, 0x01, 0x00, 0x00,
+            0x80, 0xf3, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0xf0, 0x00, 0x00, 0x08, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let oh = OptionalHeaderAny::with(w32).unwrap();
+        assert!(!oh.is_pe32p());
+        assert_eq!(oh.magic(), PE_MAGIC_OH32);
+        assert_eq!(oh.address_of_entry_point(), 0x3e49);
+        assert_eq!(oh.image_base(), 0x400000);
+        assert_eq!(oh.section_alignment(), 0x1000);
+        assert_eq!(oh.file_alignment(), 0x200);
+        assert_eq!(oh.size_of_image(), 0x1b000);
+        assert_eq!(oh.size_of_headers(), 0x400);
+        assert_eq!(oh.check_sum(), 0x22069);
+        assert_eq!(oh.subsystem(), 2);
+        assert_eq!(oh.dll_characteristics(), 0x8140);
+        assert_eq!(oh.data_directories(), 16);
+        for (idx, rva, size) in [
+            (DATA_DIRECTORY_IMPORT_TABLE, 0xf49c, 0x50),
+            (DATA_DIRECTORY_RESOURCE_TABLE, 0x14000, 0x53f4),
+            (DATA_DIRECTORY_BASE_RELOCATION_TABLE, 0x1a000, 0x96c),
+            (DATA_DIRECTORY_DEBUG, 0xd1c0, 0x1c),
+            (DATA_DIRECTORY_LOAD_CONFIG_TABLE, 0xf000, 0x40),
+            (DATA_DIRECTORY_IAT, 0xd000, 0x180),
+        ] {
+            let dir = oh.data_directory(idx).unwrap();
+            assert_eq!(dir.virtual_address.to_native(), rva);
+            assert_eq!(dir.size.to_native(), size);
+        }
+        assert!(oh.data_directory(DATA_DIRECTORY_EXCEPTION_TABLE).is_none());
+
+        let oh = OptionalHeaderAny::with(w64).unwrap();
+        assert!(oh.is_pe32p());
+        assert_eq!(oh.magic(), PE_MAGIC_OH32P);
+        assert_eq!(oh.address_of_entry_point(), 0x460c);
+        assert_eq!(oh.image_base(), 0x140000000);
+        assert_eq!(oh.section_alignment(), 0x1000);
+        assert_eq!(oh.file_alignment(), 0x200);
+        assert_eq!(oh.size_of_image(), 0x20000);
+        assert_eq!(oh.size_of_headers(), 0x400);
+        assert_eq!(oh.check_sum(), 0x1d1a2);
+        assert_eq!(oh.subsystem(), 2);
+        assert_eq!(oh.dll_characteristics(), 0x8140);
+        assert_eq!(oh.data_directories(), 16);
+        for (idx, rva, size) in [
+            (DATA_DIRECTORY_IMPORT_TABLE, 0x11f38, 0x50),
+            (DATA_DIRECTORY_RESOURCE_TABLE, 0x19000, 0x53f4),
+            (DATA_DIRECTORY_EXCEPTION_TABLE, 0x18000, 0xb04),
+            (DATA_DIRECTORY_BASE_RELOCATION_TABLE, 0x1f000, 0x164),
+            (DATA_DIRECTORY_DEBUG, 0xf380, 0x1c),
+            (DATA_DIRECTORY_IAT, 0xf000, 0x308),
+        ] {
+            let dir = oh.data_directory(idx).unwrap();
+            assert_eq!(dir.virtual_address.to_native(), rva);
+            assert_eq!(dir.size.to_native(), size);
+        }
+        assert!(oh.data_directory(DATA_DIRECTORY_LOAD_CONFIG_TABLE).is_none());
+
+        // With a PE32+ magic, the directories of a PE32 header no longer fit.
+        let mut v = w32.to_vec();
+        v[..2].copy_from_slice(&PE_MAGIC_OH32P.to_le_bytes());
+        assert_eq!(OptionalHeaderAny::with(&v).err(), Some(Error::OptionalHeaderSize));
+        let mut v = w64.to_vec();
+        v[..2].copy_from_slice(&0x0107u16.to_le_bytes());
+        assert_eq!(OptionalHeaderAny::with(&v).err(), Some(Error::OptionalMagic));
+        assert_eq!(OptionalHeaderAny::with(&w64[..0x70]).err(), Some(Error::OptionalHeaderSize));
+        assert_eq!(OptionalHeaderAny::with(&w64[..1]).err(), Some(Error::OptionalHeaderSize));
+    }
+
+    // Calculate and verify image checksums, including files of odd length
+    // and checksum fields at odd offsets.
+    #[test]
+    fn check_sum() {
+        let mut data = fixture(true);
+        assert_eq!(super::check_sum(&data, 0xd8), Some(0xdf55));
+        assert_eq!(verify_check_sum(&data), Ok(false));
+        data[0xd8..0xdc].copy_from_slice(&0xdf55u32.to_le_bytes());
+        assert_eq!(super::check_sum(&data, 0xd8), Some(0xdf55));
+        assert_eq!(verify_check_sum(&data), Ok(true));
+        data[0x300] ^= 0x01;
+        assert_eq!(verify_check_sum(&data), Ok(false));
+
+        let data = fixture(false);
+        assert_eq!(super::check_sum(&data, 0xd8), Some(0x396c));
+
+        let mut data = fixture(true);
+        data.push(b'x');
+        assert_eq!(super::check_sum(&data, 0xd8), Some(0xdfce));
+
+        let mut data = alloc::vec![0x01, 0x02, 0x03];
+        data.extend(fixture(true));
+        assert_eq!(super::check_sum(&data, 0xdb), Some(0x5de0));
+
+        assert!(super::check_sum(&data, data.len() - 4).is_some());
+        assert_eq!(super::check_sum(&data, data.len() - 3), None);
+        assert_eq!(super::check_sum(&data, usize::MAX), None);
+
+        assert_eq!(verify_check_sum(&[]), Err(Error::Bounds));
+    }
+
+    // Verify that malformed images are rejected.
+    #[test]
+    fn invalid() {
+        let soh = 0x80 + 4 + 16;
+        let magic = 0x80 + 4 + 20;
+        for (off, value, error) in [
+            (0, &[0x00, 0x00][..], Error::DosMagic),
+            (0x3c, &[0x00, 0x10, 0x00, 0x00], Error::Bounds),
+            (0x80, b"NE\0\0", Error::PeMagic),
+            (magic, &[0x07, 0x01], Error::OptionalMagic),
+            (soh, &[0x40, 0x00], Error::OptionalHeaderSize),
+            (soh, &[0x78, 0x00], Error::OptionalHeaderSize),
+        ] {
+            let mut data = fixture(true);
+            data[off..off + value.len()].copy_from_slice(value);
+            assert_eq!(PeFile::with(&data[..]).err(), Some(error));
+        }
+
+        let data = fixture(false);
+        assert_eq!(PeFile::with(&data[..0x180]).err(), Some(Error::Bounds));
+    }
+}
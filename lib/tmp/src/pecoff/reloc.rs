@@ -0,0 +1,313 @@
+//! Base Relocations
+//!
+//! PE images are linked for the preferred load address in `ImageBase`. If
+//! an image is loaded at a different address, all absolute addresses in the
+//! image must be adjusted by the difference. The locations of these
+//! addresses are recorded in the base relocation table, which is referenced
+//! by the `DATA_DIRECTORY_BASE_RELOCATION_TABLE` data directory.
+//!
+//! The table consists of blocks, each covering a single 4KiB page. A block
+//! starts with a [`BaseRelocationBlock`] header, followed by 16-bit entries
+//! that carry the relocation type and the offset into the page. Blocks are
+//! padded to 4 bytes with entries of type `BASE_RELOCATION_ABSOLUTE`.
+//!
+//! Some relocation types share their value and are interpreted based on the
+//! machine type of the image. ARM64 images use `BASE_RELOCATION_DIR64`
+//! exclusively.
+
+use alloc::vec::Vec;
+
+use crate::io;
+use super::{
+    BaseRelocationBlock,
+    Error,
+    PeFile,
+    BASE_RELOCATION_ABSOLUTE,
+    BASE_RELOCATION_DIR64,
+    BASE_RELOCATION_HIGH,
+    BASE_RELOCATION_HIGHLOW,
+    BASE_RELOCATION_LOW,
+    DATA_DIRECTORY_BASE_RELOCATION_TABLE,
+};
+
+/// Size of the page covered by a single block of base relocations.
+pub const PAGE_SIZE: u32 = 0x1000;
+
+/// Single base relocation, decoded from a block entry.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct BaseRelocation {
+    /// Relocation type, as given by the `BASE_RELOCATION_*` constants.
+    pub kind: u16,
+    /// Relative virtual address of the location to relocate.
+    pub rva: u32,
+}
+
+/// Iterator over the entries of a base relocation table.
+///
+/// Entries are yielded in table order, including padding entries of type
+/// `BASE_RELOCATION_ABSOLUTE`. A malformed block yields an error and ends
+/// the iteration.
+#[derive(Clone, Debug)]
+pub struct BaseRelocations<'data> {
+    data: &'data [u8],
+    page: u32,
+    entries: &'data [u8],
+}
+
+impl<'data> BaseRelocations<'data> {
+    /// Create an iterator over the base relocation table in `data`.
+    pub fn new(data: &'data [u8]) -> Self {
+        Self {
+            data: data,
+            page: 0,
+            entries: &[],
+        }
+    }
+}
+
+impl<'data> Iterator for BaseRelocations<'data> {
+    type Item = Result<BaseRelocation, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.entries.is_empty() {
+            if self.data.is_empty() {
+                return None;
+            }
+
+            let size = size_of::<BaseRelocationBlock>();
+            let block = match self.data.get(..size) {
+                Some(v) => BaseRelocationBlock::from_bytes(v.try_into().unwrap()),
+                None => {
+                    self.data = &[];
+                    return Some(Err(Error::Relocation));
+                },
+            };
+
+            let len = block.size_of_block.to_native() as usize;
+            if len < size || !len.is_multiple_of(2) || len > self.data.len() {
+                self.data = &[];
+                return Some(Err(Error::Relocation));
+            }
+
+            self.page = block.virtual_address.to_native();
+            self.entries = &self.data[size..len];
+            self.data = &self.data[len..];
+        }
+
+        let v = u16::from_le_bytes([self.entries[0], self.entries[1]]);
+        self.entries = &self.entries[2..];
+        Some(Ok(BaseRelocation {
+            kind: v >> 12,
+            rva: self.page.wrapping_add((v & 0xfff) as u32),
+        }))
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> PeFile<'read, Read> {
+    /// Return an iterator over the base relocations of the image. If the
+    /// image has no base relocation table, the iterator is empty.
+    pub fn base_relocations(&self) -> Result<BaseRelocations<'read>, Error> {
+        match self.data_directory(DATA_DIRECTORY_BASE_RELOCATION_TABLE) {
+            None => Ok(BaseRelocations::new(&[])),
+            Some(dir) => Ok(BaseRelocations::new(self.map_rva(
+                dir.virtual_address.to_native(),
+                dir.size.to_native() as usize,
+            )?)),
+        }
+    }
+}
+
+/// Build a base relocation table with an entry of type `kind` for each of
+/// the relative virtual addresses in `rvas`.
+///
+/// The addresses must be sorted. Consecutive addresses in the same page are
+/// grouped into a single block.
+pub fn build(rvas: &[u32], kind: u16) -> Vec<u8> {
+    debug_assert!(rvas.is_sorted());
+
+    let mut r = Vec::new();
+    let mut rvas = rvas;
+
+    while let Some(first) = rvas.first() {
+        let page = first & !(PAGE_SIZE - 1);
+        let n = rvas.iter().take_while(|v| *v & !(PAGE_SIZE - 1) == page).count();
+        let size = (size_of::<BaseRelocationBlock>() + 2 * n).next_multiple_of(4);
+
+        r.extend(page.to_le_bytes());
+        r.extend((size as u32).to_le_bytes());
+        for rva in &rvas[..n] {
+            let v = (kind << 12) | (*rva & 0xfff) as u16;
+            r.extend(v.to_le_bytes());
+        }
+        if n % 2 == 1 {
+            r.extend(BASE_RELOCATION_ABSOLUTE.to_le_bytes());
+        }
+
+        rvas = &rvas[n..];
+    }
+
+    r
+}
+
+/// Apply the base relocation table `table` to the loaded image `image`,
+/// which is indexed by relative virtual address.
+///
+/// `delta` is the difference between the actual and the preferred load
+/// address, modulo 2^64. Relocations of type `BASE_RELOCATION_ABSOLUTE`,
+/// `BASE_RELOCATION_HIGH`, `BASE_RELOCATION_LOW`,
+/// `BASE_RELOCATION_HIGHLOW`, and `BASE_RELOCATION_DIR64` are supported.
+/// Any other type fails the operation, possibly after the preceding
+/// relocations were already applied.
+pub fn apply(image: &mut [u8], table: &[u8], delta: u64) -> Result<(), Error> {
+    for r in BaseRelocations::new(table) {
+        let r = r?;
+        let len = match r.kind {
+            BASE_RELOCATION_ABSOLUTE => continue,
+            BASE_RELOCATION_HIGH | BASE_RELOCATION_LOW => 2,
+            BASE_RELOCATION_HIGHLOW => 4,
+            BASE_RELOCATION_DIR64 => 8,
+            _ => return Err(Error::RelocationType),
+        };
+
+        let off = r.rva as usize;
+        let Some(word) = image.get_mut(off..off + len) else {
+            return Err(Error::Bounds);
+        };
+
+        match r.kind {
+            BASE_RELOCATION_HIGH => {
+                let v = u16::from_le_bytes(word.try_into().unwrap());
+                word.copy_from_slice(&v.wrapping_add((delta >> 16) as u16).to_le_bytes());
+            },
+            BASE_RELOCATION_LOW => {
+                let v = u16::from_le_bytes(word.try_into().unwrap());
+                word.copy_from_slice(&v.wrapping_add(delta as u16).to_le_bytes());
+            },
+            BASE_RELOCATION_HIGHLOW => {
+                let v = u32::from_le_bytes(word.try_into().unwrap());
+                word.copy_from_slice(&v.wrapping_add(delta as u32).to_le_bytes());
+            },
+            _ => {
+                let v = u64::from_le_bytes(word.try_into().unwrap());
+                word.copy_from_slice(&v.wrapping_add(delta).to_le_bytes());
+            },
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use super::super::{
+        tests::fixture,
+        BASE_RELOCATION_RISCV_HIGH20,
+        BASE_RELOCATION_RISCV_LOW12I,
+        BASE_RELOCATION_RISCV_LOW12S,
+    };
+
+    fn parse(table: &[u8]) -> Result<Vec<(u16, u32)>, Error> {
+        BaseRelocations::new(table).map(|v| v.map(|v| (v.kind, v.rva))).collect()
+    }
+
+    // Verify base relocations are grouped by page, and blocks are padded.
+    #[test]
+    fn build_blocks() {
+        let r = build(&[0x1008, 0x1ff8, 0x3000], BASE_RELOCATION_DIR64);
+        assert_eq!(r, [
+            0x00, 0x10, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+            0x08, 0xa0, 0xf8, 0xaf,
+            0x00, 0x30, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+            0x00, 0xa0, 0x00, 0x00,
+        ]);
+        assert_eq!(parse(&r).unwrap(), [
+            (BASE_RELOCATION_DIR64, 0x1008),
+            (BASE_RELOCATION_DIR64, 0x1ff8),
+            (BASE_RELOCATION_DIR64, 0x3000),
+            (BASE_RELOCATION_ABSOLUTE, 0x3000),
+        ]);
+
+        let r = build(&[0x2004, 0x2008], BASE_RELOCATION_HIGHLOW);
+        assert_eq!(r.len(), 12);
+        assert_eq!(parse(&r).unwrap(), [
+            (BASE_RELOCATION_HIGHLOW, 0x2004),
+            (BASE_RELOCATION_HIGHLOW, 0x2008),
+        ]);
+
+        assert!(build(&[], BASE_RELOCATION_DIR64).is_empty());
+    }
+
+    // Parse RISC-V relocations and empty blocks, and verify that malformed
+    // tables are rejected.
+    #[test]
+    fn parse_blocks() {
+        let r = [
+            0x00, 0x40, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+            0x00, 0x50, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+            0x10, 0x50, 0x14, 0x70, 0x20, 0x80, 0x00, 0x00,
+        ];
+        assert_eq!(parse(&r).unwrap(), [
+            (BASE_RELOCATION_RISCV_HIGH20, 0x5010),
+            (BASE_RELOCATION_RISCV_LOW12I, 0x5014),
+            (BASE_RELOCATION_RISCV_LOW12S, 0x5020),
+            (BASE_RELOCATION_ABSOLUTE, 0x5000),
+        ]);
+
+        assert_eq!(parse(&r[..4]), Err(Error::Relocation));
+        assert_eq!(parse(&r[..20]), Err(Error::Relocation));
+
+        let mut v = r;
+        v[12] = 0x06;
+        assert_eq!(parse(&v), Err(Error::Relocation));
+        v[12] = 0x0b;
+        assert_eq!(parse(&v), Err(Error::Relocation));
+
+        let mut it = BaseRelocations::new(&r[..4]);
+        assert_eq!(it.next(), Some(Err(Error::Relocation)));
+        assert_eq!(it.next(), None);
+    }
+
+    // Apply relocations to an image and verify unsupported types and
+    // out-of-bounds locations are rejected.
+    #[test]
+    fn apply_delta() {
+        let mut image = [0u8; 0x2000];
+        image[0x1000..0x1008].copy_from_slice(&0x140001000u64.to_le_bytes());
+        image[0x1008..0x100c].copy_from_slice(&0x00401000u32.to_le_bytes());
+        image[0x1010..0x1012].copy_from_slice(&0x0040u16.to_le_bytes());
+        image[0x1012..0x1014].copy_from_slice(&0x1000u16.to_le_bytes());
+
+        let mut r = build(&[0x1000], BASE_RELOCATION_DIR64);
+        r.extend(build(&[0x1008], BASE_RELOCATION_HIGHLOW));
+        r.extend(build(&[0x1010], BASE_RELOCATION_HIGH));
+        r.extend(build(&[0x1012], BASE_RELOCATION_LOW));
+        apply(&mut image, &r, 0x12340).unwrap();
+
+        assert_eq!(&image[0x1000..0x1008], &0x140013340u64.to_le_bytes());
+        assert_eq!(&image[0x1008..0x100c], &0x00413340u32.to_le_bytes());
+        assert_eq!(&image[0x1010..0x1012], &0x0041u16.to_le_bytes());
+        assert_eq!(&image[0x1012..0x1014], &0x3340u16.to_le_bytes());
+
+        // A negative delta restores the original values.
+        apply(&mut image, &r, 0x12340u64.wrapping_neg()).unwrap();
+        assert_eq!(&image[0x1000..0x1008], &0x140001000u64.to_le_bytes());
+        assert_eq!(&image[0x1008..0x100c], &0x00401000u32.to_le_bytes());
+
+        let r = build(&[0x1ffc], BASE_RELOCATION_DIR64);
+        assert_eq!(apply(&mut image, &r, 1), Err(Error::Bounds));
+        let r = build(&[0x1000], BASE_RELOCATION_RISCV_HIGH20);
+        assert_eq!(apply(&mut image, &r, 1), Err(Error::RelocationType));
+    }
+
+    // Images without a base relocation table yield no relocations.
+    #[test]
+    fn image() {
+        let data = fixture(true);
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert_eq!(pe.base_relocations().unwrap().count(), 0);
+    }
+}
@@ -0,0 +1,639 @@
+//! Resource Table
+//!
+//! Resources of an image are organized as a tree of resource directory
+//! tables, referenced by the `DATA_DIRECTORY_RESOURCE_TABLE` data directory.
+//! By convention, the levels of the tree are the resource type, the
+//! resource name, and the language. Entries are identified by an integer ID
+//! or by a UTF-16 name, and the leaves describe the location of the
+//! resource data.
+//!
+//! The version resource (`VS_VERSIONINFO`) of an image is decoded by
+//! [`VersionInfo`].
+
+use alloc::{collections::BTreeSet, format, string::String, vec::Vec};
+
+use crate::io;
+use super::{
+    Error,
+    FixedFileInfo,
+    PeFile,
+    ResourceDataEntry,
+    ResourceDirectory,
+    ResourceDirectoryEntry,
+    DATA_DIRECTORY_RESOURCE_TABLE,
+    RESOURCE_DATA_IS_DIRECTORY,
+    RESOURCE_NAME_IS_STRING,
+    RESOURCE_TYPE_VERSION,
+    VS_FFI_SIGNATURE,
+};
+
+/// Maximum depth of the resource directory tree that is walked.
+pub const DEPTH_MAX: usize = 8;
+
+/// Name of an entry of a resource directory table.
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub enum Name<'read> {
+    /// The entry is identified by an integer ID.
+    Id(u16),
+    /// The entry is identified by a name, given as UTF-16LE code units
+    /// without terminator.
+    Name(&'read [u8]),
+}
+
+/// Target of an entry of a resource directory table.
+#[derive(Clone, Copy, Debug)]
+pub enum Target<'read> {
+    /// The entry refers to another resource directory table.
+    Directory(Directory<'read>),
+    /// The entry refers to resource data.
+    Data(ResourceDataEntry),
+}
+
+/// Entry of a resource directory table.
+#[derive(Clone, Copy, Debug)]
+pub struct Entry<'read> {
+    pub name: Name<'read>,
+    pub target: Target<'read>,
+}
+
+/// Resource table of an image.
+#[derive(Clone, Copy, Debug)]
+pub struct Resources<'read> {
+    data: &'read [u8],
+}
+
+/// Resource directory table, as part of a resource table.
+#[derive(Clone, Copy, Debug)]
+pub struct Directory<'read> {
+    resources: Resources<'read>,
+    offset: u32,
+    directory: ResourceDirectory,
+    entries: &'read [u8],
+}
+
+/// Iterator over the entries of a resource directory table. Named entries
+/// are yielded before ID entries.
+#[derive(Clone, Debug)]
+pub struct Entries<'read> {
+    resources: Resources<'read>,
+    entries: &'read [u8],
+}
+
+/// String of the version information.
+#[derive(Clone, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct VersionString {
+    /// Key of the string table, formed by the language and code page as 8
+    /// hexadecimal digits.
+    pub table: String,
+    pub key: String,
+    pub value: String,
+}
+
+/// Version information of an image (`VS_VERSIONINFO`).
+#[derive(Clone, Debug, Hash)]
+#[derive(Eq, PartialEq)]
+pub struct VersionInfo {
+    /// Fixed part of the version information, if present.
+    pub fixed: Option<FixedFileInfo>,
+    /// Strings of all string tables, in order.
+    pub strings: Vec<VersionString>,
+    /// Pairs of language and code page the image supports.
+    pub translations: Vec<(u16, u16)>,
+}
+
+// Node of the version information. All nodes share a common header,
+// followed by a UTF-16 key, a value, and child nodes.
+struct Node<'data> {
+    key: &'data [u8],
+    text: bool,
+    value: &'data [u8],
+    children: &'data [u8],
+}
+
+// Decode UTF-16LE code units, replacing invalid ones.
+fn utf16(data: &[u8]) -> String {
+    char::decode_utf16(data.chunks_exact(2).map(|v| u16::from_le_bytes([v[0], v[1]])))
+        .map(|v| v.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+impl<'read> Name<'read> {
+    /// Return the name as string. IDs are formatted as `#<id>`, following
+    /// the convention of resource compilers.
+    pub fn to_string_lossy(&self) -> String {
+        match self {
+            Self::Id(v) => format!("#{}", v),
+            Self::Name(v) => utf16(v),
+        }
+    }
+}
+
+impl<'read> Resources<'read> {
+    /// Create a resource table from its raw data.
+    pub fn new(data: &'read [u8]) -> Self {
+        Self {
+            data: data,
+        }
+    }
+
+    // Map `len` bytes at `offset` of the resource table.
+    fn map(&self, offset: u32, len: usize) -> Result<&'read [u8], Error> {
+        let offset = offset as usize;
+        offset.checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or(Error::Resource)
+    }
+
+    /// Return the resource directory table at `offset`.
+    pub fn directory(&self, offset: u32) -> Result<Directory<'read>, Error> {
+        let size = size_of::<ResourceDirectory>();
+        let data = self.map(offset, size)?;
+        let directory = ResourceDirectory::from_bytes(data.try_into().unwrap());
+        let n = directory.number_of_named_entries.to_native() as usize
+            + directory.number_of_id_entries.to_native() as usize;
+        let entries = self.map(
+            offset.checked_add(size as u32).ok_or(Error::Resource)?,
+            n * size_of::<ResourceDirectoryEntry>(),
+        )?;
+
+        Ok(Directory {
+            resources: *self,
+            offset: offset,
+            directory: directory,
+            entries: entries,
+        })
+    }
+
+    /// Return the root resource directory table.
+    pub fn root(&self) -> Result<Directory<'read>, Error> {
+        self.directory(0)
+    }
+
+    /// Walk the resource directory tree in depth-first order, and invoke
+    /// `f` for every resource data entry, together with the names of the
+    /// entries leading to it.
+    ///
+    /// Trees deeper than `DEPTH_MAX` are rejected, as are directories that
+    /// are referenced more than once. The latter bounds the work to the
+    /// number of directories in the table, even for cyclic tables.
+    pub fn walk(
+        &self,
+        f: &mut dyn FnMut(&[Name<'read>], &ResourceDataEntry),
+    ) -> Result<(), Error> {
+        let mut path = Vec::new();
+        let mut visited = BTreeSet::new();
+        self.walk_in(&self.root()?, &mut path, &mut visited, f)
+    }
+
+    fn walk_in(
+        &self,
+        directory: &Directory<'read>,
+        path: &mut Vec<Name<'read>>,
+        visited: &mut BTreeSet<u32>,
+        f: &mut dyn FnMut(&[Name<'read>], &ResourceDataEntry),
+    ) -> Result<(), Error> {
+        if path.len() >= DEPTH_MAX || !visited.insert(directory.offset) {
+            return Err(Error::Resource);
+        }
+
+        for entry in directory.entries() {
+            let entry = entry?;
+            path.push(entry.name);
+            match entry.target {
+                Target::Directory(v) => self.walk_in(&v, path, visited, f)?,
+                Target::Data(v) => f(path, &v),
+            }
+            path.pop();
+        }
+
+        Ok(())
+    }
+}
+
+impl<'read> Directory<'read> {
+    /// Return the header of the resource directory table.
+    pub fn directory(&self) -> &ResourceDirectory {
+        &self.directory
+    }
+
+    /// Return an iterator over the entries of the table.
+    pub fn entries(&self) -> Entries<'read> {
+        Entries {
+            resources: self.resources,
+            entries: self.entries,
+        }
+    }
+
+    /// Return the first entry with the given name, if any.
+    pub fn find(&self, name: &Name<'_>) -> Result<Option<Entry<'read>>, Error> {
+        for entry in self.entries() {
+            let entry = entry?;
+            if entry.name == *name {
+                return Ok(Some(entry));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'read> Entries<'read> {
+    fn entry(&self, entry: &ResourceDirectoryEntry) -> Result<Entry<'read>, Error> {
+        let name = entry.name.to_native();
+        let name = if name & RESOURCE_NAME_IS_STRING != 0 {
+            let offset = name & !RESOURCE_NAME_IS_STRING;
+            let len = self.resources.map(offset, 2)?;
+            let len = u16::from_le_bytes([len[0], len[1]]) as usize;
+            Name::Name(self.resources.map(offset + 2, 2 * len)?)
+        } else {
+            Name::Id(name as u16)
+        };
+
+        let offset = entry.offset_to_data.to_native();
+        let target = if offset & RESOURCE_DATA_IS_DIRECTORY != 0 {
+            Target::Directory(self.resources.directory(offset & !RESOURCE_DATA_IS_DIRECTORY)?)
+        } else {
+            let data = self.resources.map(offset, size_of::<ResourceDataEntry>())?;
+            Target::Data(ResourceDataEntry::from_bytes(data.try_into().unwrap()))
+        };
+
+        Ok(Entry {
+            name: name,
+            target: target,
+        })
+    }
+}
+
+impl<'read> Iterator for Entries<'read> {
+    type Item = Result<Entry<'read>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = size_of::<ResourceDirectoryEntry>();
+        let data = self.entries.get(..size)?;
+        self.entries = &self.entries[size..];
+        Some(self.entry(&ResourceDirectoryEntry::from_bytes(data.try_into().unwrap())))
+    }
+}
+
+impl<'data> Node<'data> {
+    // Parse the node at the start of `data`, and return it together with
+    // the data following it.
+    fn parse(data: &'data [u8]) -> Result<(Self, &'data [u8]), Error> {
+        let header = data.get(..6).ok_or(Error::Resource)?;
+        let len = u16::from_le_bytes([header[0], header[1]]) as usize;
+        let value_len = u16::from_le_bytes([header[2], header[3]]) as usize;
+        let text = u16::from_le_bytes([header[4], header[5]]) == 1;
+        if len < 6 || len > data.len() {
+            return Err(Error::Resource);
+        }
+        let v = &data[..len];
+
+        let key = v[6..]
+            .chunks_exact(2)
+            .position(|v| v == [0, 0])
+            .ok_or(Error::Resource)?;
+        let at = (6 + 2 * key + 2).next_multiple_of(4).min(len);
+
+        // Text values are sized in UTF-16 code units, but not all writers
+        // follow this. Clip them to the node.
+        let value_len = if text { 2 * value_len } else { value_len };
+        let end = at.checked_add(value_len).filter(|v| *v <= len);
+        let end = match (end, text) {
+            (Some(v), _) => v,
+            (None, true) => len,
+            (None, false) => return Err(Error::Resource),
+        };
+
+        let node = Self {
+            key: &v[6..6 + 2 * key],
+            text: text,
+            value: &v[at..end],
+            children: &v[end.next_multiple_of(4).min(len)..],
+        };
+        Ok((node, &data[len.next_multiple_of(4).min(data.len())..]))
+    }
+
+    // Parse all child nodes.
+    fn children(&self) -> Result<Vec<Self>, Error> {
+        let mut r = Vec::new();
+        let mut data = self.children;
+        while !data.is_empty() {
+            let (node, rest) = Self::parse(data)?;
+            r.push(node);
+            data = rest;
+        }
+        Ok(r)
+    }
+
+    // Return the value as string, without any terminator.
+    fn text(&self) -> String {
+        let mut v = self.value;
+        while v.ends_with(&[0, 0]) {
+            v = &v[..v.len() - 2];
+        }
+        utf16(v)
+    }
+}
+
+impl FixedFileInfo {
+    /// Return the file version, most significant part first.
+    pub fn file_version(&self) -> [u16; 4] {
+        let (ms, ls) = (self.file_version_ms.to_native(), self.file_version_ls.to_native());
+        [(ms >> 16) as u16, ms as u16, (ls >> 16) as u16, ls as u16]
+    }
+
+    /// Return the product version, most significant part first.
+    pub fn product_version(&self) -> [u16; 4] {
+        let (ms, ls) = (self.product_version_ms.to_native(), self.product_version_ls.to_native());
+        [(ms >> 16) as u16, ms as u16, (ls >> 16) as u16, ls as u16]
+    }
+}
+
+impl VersionInfo {
+    /// Parse the data of a version resource.
+    pub fn parse(data: &[u8]) -> Result<Self, Error> {
+        let (root, _) = Node::parse(data)?;
+        if utf16(root.key) != "VS_VERSION_INFO" {
+            return Err(Error::Resource);
+        }
+
+        let fixed = match root.value.len() {
+            0 => None,
+            n if n < size_of::<FixedFileInfo>() => return Err(Error::Resource),
+            _ => {
+                let v = &root.value[..size_of::<FixedFileInfo>()];
+                let v = FixedFileInfo::from_bytes(v.try_into().unwrap());
+                if v.signature.to_native() != VS_FFI_SIGNATURE {
+                    return Err(Error::Resource);
+                }
+                Some(v)
+            },
+        };
+
+        let mut r = Self {
+            fixed: fixed,
+            strings: Vec::new(),
+            translations: Vec::new(),
+        };
+
+        for node in root.children()? {
+            match utf16(node.key).as_str() {
+                "StringFileInfo" => {
+                    for table in node.children()? {
+                        for string in table.children()? {
+                            r.strings.push(VersionString {
+                                table: utf16(table.key),
+                                key: utf16(string.key),
+                                value: string.text(),
+                            });
+                        }
+                    }
+                },
+                "VarFileInfo" => {
+                    for var in node.children()? {
+                        if utf16(var.key) != "Translation" || var.text {
+                            continue;
+                        }
+                        for v in var.value.chunks_exact(4) {
+                            r.translations.push((
+                                u16::from_le_bytes([v[0], v[1]]),
+                                u16::from_le_bytes([v[2], v[3]]),
+                            ));
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        Ok(r)
+    }
+
+    /// Return the value of the first string with the given key, if any.
+    pub fn string(&self, key: &str) -> Option<&str> {
+        self.strings.iter().find(|v| v.key == key).map(|v| v.value.as_str())
+    }
+}
+
+impl<'read, Read: ?Sized + io::map::Read> PeFile<'read, Read> {
+    /// Return the resource table of the image, if any.
+    pub fn resources(&self) -> Result<Option<Resources<'read>>, Error> {
+        match self.data_directory(DATA_DIRECTORY_RESOURCE_TABLE) {
+            None => Ok(None),
+            Some(dir) => Ok(Some(Resources::new(self.map_rva(
+                dir.virtual_address.to_native(),
+                dir.size.to_native() as usize,
+            )?))),
+        }
+    }
+
+    /// Map the data of a resource.
+    pub fn resource_data(&self, entry: &ResourceDataEntry) -> Result<&'read [u8], Error> {
+        self.map_rva(entry.data_rva.to_native(), entry.size.to_native() as usize)
+    }
+
+    /// Return the version information of the image, if any.
+    ///
+    /// If there are multiple version resources, or multiple languages of
+    /// it, the first one is used. Trees deeper than `DEPTH_MAX` are rejected.
+    pub fn version_info(&self) -> Result<Option<VersionInfo>, Error> {
+        let Some(resources) = self.resources()? else {
+            return Ok(None);
+        };
+
+        let mut target = match resources.root()?.find(&Name::Id(RESOURCE_TYPE_VERSION))? {
+            None => return Ok(None),
+            Some(v) => v.target,
+        };
+        let mut depth = 1;
+        let data = loop {
+            match target {
+                Target::Data(v) => break v,
+                Target::Directory(_) if depth >= DEPTH_MAX => return Err(Error::Resource),
+                Target::Directory(v) => match v.entries().next() {
+                    None => return Ok(None),
+                    Some(v) => target = v?.target,
+                },
+            }
+            depth += 1;
+        };
+
+        VersionInfo::parse(self.resource_data(&data)?).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use super::super::tests::fixture;
+
+    // Encode a node of the version information.
+    fn node(key: &str, text: bool, value: &[u8], children: &[Vec<u8>]) -> Vec<u8> {
+        let mut v = vec![0; 6];
+        v[2..4].copy_from_slice(&(if text { value.len() / 2 } else { value.len() } as u16).to_le_bytes());
+        v[4..6].copy_from_slice(&(text as u16).to_le_bytes());
+        v.extend(key.encode_utf16().chain([0]).flat_map(u16::to_le_bytes));
+        v.resize(v.len().next_multiple_of(4), 0);
+        v.extend(value);
+        for c in children {
+            v.resize(v.len().next_multiple_of(4), 0);
+            v.extend(c);
+        }
+        let len = v.len() as u16;
+        v[..2].copy_from_slice(&len.to_le_bytes());
+        v
+    }
+
+    fn text(v: &str) -> Vec<u8> {
+        v.encode_utf16().chain([0]).flat_map(u16::to_le_bytes).collect()
+    }
+
+    fn version() -> Vec<u8> {
+        let mut fixed = vec![0; 52];
+        fixed[0..4].copy_from_slice(&VS_FFI_SIGNATURE.to_le_bytes());
+        fixed[8..12].copy_from_slice(&0x0001_0002u32.to_le_bytes());
+        fixed[12..16].copy_from_slice(&0x0003_0004u32.to_le_bytes());
+
+        node("VS_VERSION_INFO", false, &fixed, &[
+            node("StringFileInfo", true, &[], &[
+                node("040904b0", true, &[], &[
+                    node("ProductName", true, &text("Test"), &[]),
+                    node("FileVersion", true, &text("1.2"), &[]),
+                ]),
+            ]),
+            node("VarFileInfo", true, &[], &[
+                node("Translation", false, &[0x09, 0x04, 0xb0, 0x04], &[]),
+            ]),
+        ])
+    }
+
+    // Create a PE32+ image with a resource table at 0x2000, which contains a
+    // named `MUI` resource and a version resource.
+    fn image() -> Vec<u8> {
+        let mut v = fixture(true);
+        let u32s = |v: &mut Vec<u8>, at: usize, data: &[u32]| {
+            for (i, x) in data.iter().enumerate() {
+                v[at + 4 * i..at + 4 * i + 4].copy_from_slice(&x.to_le_bytes());
+            }
+        };
+        let dir = |v: &mut Vec<u8>, at: usize, named: u16, ids: u16| {
+            v[at + 12..at + 14].copy_from_slice(&named.to_le_bytes());
+            v[at + 14..at + 16].copy_from_slice(&ids.to_le_bytes());
+        };
+
+        // Replace the debug directory with the resource table.
+        v[0x400..0x600].fill(0);
+        u32s(&mut v, 0x138, &[0, 0]);
+        u32s(&mut v, 0x118, &[0x2000, 0x200]);
+
+        let t = 0x400;
+        dir(&mut v, t, 1, 1);
+        u32s(&mut v, t + 0x10, &[RESOURCE_NAME_IS_STRING | 0xa0, RESOURCE_DATA_IS_DIRECTORY | 0x20]);
+        u32s(&mut v, t + 0x18, &[RESOURCE_TYPE_VERSION as u32, RESOURCE_DATA_IS_DIRECTORY | 0x38]);
+        dir(&mut v, t + 0x20, 0, 1);
+        u32s(&mut v, t + 0x30, &[1, RESOURCE_DATA_IS_DIRECTORY | 0x50]);
+        dir(&mut v, t + 0x38, 0, 1);
+        u32s(&mut v, t + 0x48, &[1, RESOURCE_DATA_IS_DIRECTORY | 0x68]);
+        dir(&mut v, t + 0x50, 0, 1);
+        u32s(&mut v, t + 0x60, &[0x409, 0x80]);
+        dir(&mut v, t + 0x68, 0, 1);
+        u32s(&mut v, t + 0x78, &[0x409, 0x90]);
+        u32s(&mut v, t + 0x80, &[0x20b0, 4]);
+        u32s(&mut v, t + 0x90, &[0x20b8, version().len() as u32]);
+        v[t + 0xa0..t + 0xa8].copy_from_slice(&[3, 0, b'M', 0, b'U', 0, b'I', 0]);
+        v[t + 0xb0..t + 0xb4].copy_from_slice(b"abcd");
+        let version = version();
+        v[t + 0xb8..t + 0xb8 + version.len()].copy_from_slice(&version);
+        v
+    }
+
+    // Walk the resource tree and extract the version information.
+    #[test]
+    fn resources() {
+        let data = image();
+        let pe = PeFile::with(&data[..]).unwrap();
+        let resources = pe.resources().unwrap().unwrap();
+        assert_eq!(resources.root().unwrap().directory().number_of_named_entries.to_native(), 1);
+
+        let mut leaves = Vec::new();
+        resources.walk(&mut |path, entry| {
+            let path: Vec<_> = path.iter().map(|v| v.to_string_lossy()).collect();
+            leaves.push((path, pe.resource_data(entry).unwrap()));
+        }).unwrap();
+        assert_eq!(leaves.len(), 2);
+        assert_eq!(leaves[0].0, ["MUI", "#1", "#1033"]);
+        assert_eq!(leaves[0].1, b"abcd");
+        assert_eq!(leaves[1].0, ["#16", "#1", "#1033"]);
+        assert_eq!(leaves[1].1, version());
+
+        let root = resources.root().unwrap();
+        let entry = root.find(&Name::Name(&[b'M', 0, b'U', 0, b'I', 0])).unwrap().unwrap();
+        assert!(matches!(entry.target, Target::Directory(_)));
+        assert!(root.find(&Name::Id(3)).unwrap().is_none());
+
+        let info = pe.version_info().unwrap().unwrap();
+        let fixed = info.fixed.unwrap();
+        assert_eq!(fixed.file_version(), [1, 2, 3, 4]);
+        assert_eq!(fixed.product_version(), [0, 0, 0, 0]);
+        assert_eq!(info.string("ProductName"), Some("Test"));
+        assert_eq!(info.string("FileVersion"), Some("1.2"));
+        assert_eq!(info.string("CompanyName"), None);
+        assert_eq!(info.strings[0].table, "040904b0");
+        assert_eq!(info.translations, [(0x0409, 0x04b0)]);
+
+        let data = fixture(true);
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert!(pe.resources().unwrap().is_none());
+        assert_eq!(pe.version_info(), Ok(None));
+    }
+
+    // Verify malformed trees and version information are rejected.
+    #[test]
+    fn invalid() {
+        // Let the version directory refer to the root, forming a cycle.
+        let mut data = image();
+        data[0x41c..0x420].copy_from_slice(&RESOURCE_DATA_IS_DIRECTORY.to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        let resources = pe.resources().unwrap().unwrap();
+        assert_eq!(resources.walk(&mut |_, _| {}), Err(Error::Resource));
+
+        // Let both root entries refer to the root, which must be rejected
+        // without walking every path up to the depth limit.
+        data[0x414..0x418].copy_from_slice(&RESOURCE_DATA_IS_DIRECTORY.to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        let resources = pe.resources().unwrap().unwrap();
+        assert_eq!(resources.walk(&mut |_, _| {}), Err(Error::Resource));
+
+        // Let both root entries share the version directory.
+        let mut data = image();
+        data[0x414..0x418].copy_from_slice(&(RESOURCE_DATA_IS_DIRECTORY | 0x38).to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        let resources = pe.resources().unwrap().unwrap();
+        assert_eq!(resources.walk(&mut |_, _| {}), Err(Error::Resource));
+
+        // Let the version directory refer to itself.
+        let mut data = image();
+        data[0x44c..0x450].copy_from_slice(&(RESOURCE_DATA_IS_DIRECTORY | 0x38).to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        assert_eq!(pe.version_info(), Err(Error::Resource));
+
+        // Let the named entry exceed the resource table.
+        let mut data = image();
+        data[0x410..0x414].copy_from_slice(&(RESOURCE_NAME_IS_STRING | 0x1ff).to_le_bytes());
+        let pe = PeFile::with(&data[..]).unwrap();
+        let resources = pe.resources().unwrap().unwrap();
+        let entries: Vec<_> = resources.root().unwrap().entries().map(|v| v.is_ok()).collect();
+        assert_eq!(entries, [false, true]);
+
+        let mut version = version();
+        assert!(VersionInfo::parse(&version).is_ok());
+        version[0x28] ^= 0xff;
+        assert_eq!(VersionInfo::parse(&version), Err(Error::Resource));
+        assert_eq!(VersionInfo::parse(&node("VS_VERSIONINFO", false, &[], &[])), Err(Error::Resource));
+        assert_eq!(VersionInfo::parse(&[0x04, 0x00, 0x00, 0x00, 0x00, 0x00]), Err(Error::Resource));
+    }
+}
@@ -0,0 +1,364 @@
+//! Unified Kernel Image Assembler
+//!
+//! A Unified Kernel Image (UKI) is an EFI stub with the kernel, the initrd,
+//! the kernel command line, and further resources appended as PE sections,
+//! as specified by the UAPI Group. The stub locates these sections by name
+//! at runtime. This module appends such sections to a stub image, similar to
+//! what `ukify` of systemd does via `objcopy`:
+//!
+//! - The stub is copied unmodified, apart from the file header and the
+//!   optional header, which are updated for the new sections.
+//! - The new section headers are placed after the existing section table,
+//!   which must have room for them within `SizeOfHeaders`.
+//! - The new sections are placed after all existing sections in memory,
+//!   aligned to the section alignment, unless an address is requested
+//!   explicitly. Their contents are appended to the file, aligned to the
+//!   file alignment.
+//! - `SizeOfImage`, `SizeOfInitializedData`, and `CheckSum` are updated.
+//!
+//! Signed stubs are rejected, since their certificate table must remain the
+//! last part of the file, and their signature would be invalidated anyway.
+
+use alloc::vec::Vec;
+use core::ops::ControlFlow as Flow;
+
+use crate::{io, pecoff};
+
+type U16Le = osi::ffi::Integer<osi::ffi::LittleEndian<u16>, osi::align::AlignAs<2>>;
+type U32Le = osi::ffi::Integer<osi::ffi::LittleEndian<u32>, osi::align::AlignAs<4>>;
+
+pub const SECTION_LINUX: &[u8] = b".linux";
+pub const SECTION_INITRD: &[u8] = b".initrd";
+pub const SECTION_CMDLINE: &[u8] = b".cmdline";
+pub const SECTION_OSREL: &[u8] = b".osrel";
+pub const SECTION_UNAME: &[u8] = b".uname";
+pub const SECTION_SPLASH: &[u8] = b".splash";
+pub const SECTION_DTB: &[u8] = b".dtb";
+pub const SECTION_PCRSIG: &[u8] = b".pcrsig";
+pub const SECTION_PCRPKEY: &[u8] = b".pcrpkey";
+pub const SECTION_SBAT: &[u8] = b".sbat";
+
+/// Characteristics of all appended sections.
+pub const SECTION_CHARACTERISTICS: u32 =
+    pecoff::SECTION_CHARACTERISTIC_CNT_INITIALIZED_DATA
+    | pecoff::SECTION_CHARACTERISTIC_MEM_READ;
+
+// Offsets of the fields of the optional header that are updated.
+const OFFSET_SIZE_OF_INITIALIZED_DATA: usize = 8;
+const OFFSET_SIZE_OF_IMAGE: usize = 56;
+
+#[derive(Clone, Copy, Debug, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// The underlying I/O operation failed, or was interrupted if `None`.
+    Io(Option<io::map::Error>),
+    /// The stub is not a valid PE image.
+    Pe(pecoff::Error),
+    /// The stub carries a certificate table.
+    Signed,
+    /// The section or file alignment of the stub is not a power of 2.
+    Alignment,
+    /// The section table of the stub has no room for the new sections.
+    Space,
+    /// A section name is empty or longer than 8 bytes.
+    Name,
+    /// A section name is already used by another section.
+    Duplicate,
+    /// A requested section address is not aligned to the section alignment.
+    Address,
+    /// A section overlaps another section in memory.
+    Overlap,
+    /// The image exceeds the 32-bit range of PE/COFF offsets.
+    Range,
+}
+
+/// Section to be appended to the stub.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Section<'data> {
+    pub name: &'data [u8],
+    /// Relative virtual address of the section. If `0`, the section is
+    /// placed after all preceding sections.
+    pub rva: u32,
+    pub data: &'data [u8],
+}
+
+/// Builder for Unified Kernel Images.
+#[derive(Clone, Debug)]
+pub struct Builder<'data> {
+    stub: &'data [u8],
+    sections: Vec<Section<'data>>,
+}
+
+impl core::convert::From<pecoff::Error> for Error {
+    fn from(v: pecoff::Error) -> Self {
+        Self::Pe(v)
+    }
+}
+
+impl<'data> Builder<'data> {
+    /// Create a builder for the EFI stub image `stub`, without any sections
+    /// to append.
+    pub fn new(stub: &'data [u8]) -> Self {
+        Self {
+            stub: stub,
+            sections: Vec::new(),
+        }
+    }
+
+    /// Add a section to append to the stub.
+    pub fn section(&mut self, section: Section<'data>) {
+        self.sections.push(section);
+    }
+
+    /// Write the image into `write`, starting at offset 0, and commit it.
+    /// Returns the size of the image.
+    pub fn write(&self, write: &mut dyn io::map::Write) -> Result<usize, Error> {
+        let out = self.build()?;
+
+        let mut idx = 0;
+        if let Flow::Break(v) = write.write(&mut idx, &out) {
+            return Err(Error::Io(v));
+        }
+
+        // SAFETY: All bytes were initialized above.
+        unsafe { write.commit(out.len()) };
+        Ok(out.len())
+    }
+
+    // Assemble the image in memory.
+    fn build(&self) -> Result<Vec<u8>, Error> {
+        let pe = pecoff::PeFile::with(self.stub)?;
+        if pe.data_directory(pecoff::DATA_DIRECTORY_CERTIFICATE_TABLE).is_some() {
+            return Err(Error::Signed);
+        }
+
+        let optional = pe.optional_header();
+        let section_alignment = optional.section_alignment();
+        let file_alignment = optional.file_alignment();
+        if !section_alignment.is_power_of_two() || !file_alignment.is_power_of_two() {
+            return Err(Error::Alignment);
+        }
+
+        let align_up = |v: u64, align: u32| pecoff::align_up(v, align).ok_or(Error::Range);
+        let offset = |v: u64| pecoff::offset(v).ok_or(Error::Range);
+
+        // Verify the section table has room for the new section headers,
+        // both within the headers and before the first section contents.
+        let header = pe.header();
+        let nsections = header.number_of_sections.to_native() as usize;
+        let table = pe.optional_header_offset() + header.size_of_optional_header.to_native() as usize;
+        let table_end = table + (nsections + self.sections.len()) * size_of::<pecoff::SectionHeader>();
+        let first = pe.section_headers()
+            .filter(|v| v.size_of_raw_data.to_native() > 0 && v.pointer_to_raw_data.to_native() > 0)
+            .map(|v| v.pointer_to_raw_data.to_native() as usize)
+            .min()
+            .unwrap_or(usize::MAX);
+        if nsections + self.sections.len() > u16::MAX as usize
+            || table_end > pe.size_of_headers() as usize
+            || table_end > first
+        {
+            return Err(Error::Space);
+        }
+
+        // Collect the memory ranges of all existing sections.
+        let mut names: Vec<[u8; 8]> = Vec::new();
+        let mut next = align_up(pe.size_of_headers() as u64, section_alignment)?;
+        let mut ranges: Vec<(u64, u64)> = Vec::from([(0, next)]);
+        for v in pe.section_headers() {
+            let start = v.virtual_address.to_native() as u64;
+            let size = match v.virtual_size.to_native() {
+                0 => v.size_of_raw_data.to_native(),
+                n => n,
+            };
+            let end = align_up(start + size as u64, section_alignment)?;
+            names.push(v.name);
+            ranges.push((start, end));
+            next = next.max(end);
+        }
+        next = next.max(align_up(optional.size_of_image() as u64, section_alignment)?);
+
+        // Place the new sections in memory and in the file.
+        let mut out = self.stub.to_vec();
+        let mut off = align_up(out.len() as u64, file_alignment)?;
+        let mut initialized = 0u64;
+        for (i, section) in self.sections.iter().enumerate() {
+            if section.name.is_empty() || section.name.len() > 8 {
+                return Err(Error::Name);
+            }
+            let mut name = [0u8; 8];
+            name[..section.name.len()].copy_from_slice(section.name);
+            if names.contains(&name) {
+                return Err(Error::Duplicate);
+            }
+
+            let start = match section.rva {
+                0 => next,
+                v if v % section_alignment != 0 => return Err(Error::Address),
+                v => v as u64,
+            };
+            let end = align_up(start + section.data.len() as u64, section_alignment)?;
+            if ranges.iter().any(|v| start < v.1.max(v.0 + 1) && v.0 < end.max(start + 1)) {
+                return Err(Error::Overlap);
+            }
+
+            let size = align_up(section.data.len() as u64, file_alignment)?;
+            let shdr = pecoff::SectionHeader {
+                name: name,
+                virtual_size: U32Le::from_native(offset(section.data.len() as u64)?),
+                virtual_address: U32Le::from_native(offset(start)?),
+                size_of_raw_data: U32Le::from_native(offset(size)?),
+                pointer_to_raw_data: U32Le::from_native(if size == 0 { 0 } else { offset(off)? }),
+                pointer_to_relocations: U32Le::from_native(0),
+                pointer_to_linenumbers: U32Le::from_native(0),
+                number_of_relocations: U16Le::from_native(0),
+                number_of_linenumbers: U16Le::from_native(0),
+                characteristics: U32Le::from_native(SECTION_CHARACTERISTICS),
+            };
+            let at = table + (nsections + i) * size_of::<pecoff::SectionHeader>();
+            out[at..at + size_of::<pecoff::SectionHeader>()].copy_from_slice(shdr.as_bytes());
+
+            if size > 0 {
+                out.resize(off as usize, 0);
+                out.extend_from_slice(section.data);
+                off += size;
+                out.resize(off as usize, 0);
+            }
+
+            names.push(name);
+            ranges.push((start, end));
+            next = next.max(end);
+            initialized += size;
+        }
+        offset(off)?;
+
+        // Update the headers and the checksum last.
+        let at = pe.pe_offset() + pecoff::PE_MAGIC.len() + 2;
+        out[at..at + 2].copy_from_slice(&((nsections + self.sections.len()) as u16).to_le_bytes());
+
+        let at = pe.optional_header_offset() + OFFSET_SIZE_OF_INITIALIZED_DATA;
+        let v = u32::from_le_bytes(out[at..at + 4].try_into().unwrap());
+        out[at..at + 4].copy_from_slice(&v.wrapping_add(offset(initialized)?).to_le_bytes());
+
+        let at = pe.optional_header_offset() + OFFSET_SIZE_OF_IMAGE;
+        out[at..at + 4].copy_from_slice(&offset(next)?.to_le_bytes());
+
+        let at = pe.check_sum_offset();
+        let sum = pecoff::check_sum(&out, at).ok_or(Error::Pe(pecoff::Error::Bounds))?;
+        out[at..at + 4].copy_from_slice(&sum.to_le_bytes());
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Create a stub from the PE fixture, with the headers enlarged to make room
+    // for 13 more section headers.
+    fn stub() -> Vec<u8> {
+        let mut stub = pecoff::tests::fixture(true);
+        stub[0xd4..0xd8].copy_from_slice(&0x400u32.to_le_bytes());
+        for at in [0x188 + 20, 0x1b0 + 20] {
+            let v = u32::from_le_bytes(stub[at..at + 4].try_into().unwrap());
+            stub[at..at + 4].copy_from_slice(&(v + 0x200).to_le_bytes());
+        }
+        stub.splice(0x200..0x200, [0; 0x200]);
+        stub
+    }
+
+    // Append sections to a stub and read the image back.
+    #[test]
+    fn build() {
+        let stub = stub();
+        let mut uki = Builder::new(&stub);
+        uki.section(Section { name: SECTION_OSREL, rva: 0, data: b"ID=test\n" });
+        uki.section(Section { name: SECTION_CMDLINE, rva: 0, data: b"quiet" });
+        uki.section(Section { name: SECTION_LINUX, rva: 0x10000, data: &[0xaa; 0x1234] });
+        uki.section(Section { name: SECTION_INITRD, rva: 0, data: &[] });
+
+        let mut out = Vec::new();
+        let n = uki.write(&mut out).unwrap();
+        assert_eq!(n, out.len());
+        assert_eq!(n, 0x2000);
+        assert_eq!(&out[0x400..0x800], &stub[0x400..0x800]);
+        assert_eq!(pecoff::verify_check_sum(&out), Ok(true));
+
+        let pe = pecoff::PeFile::with(&out[..]).unwrap();
+        assert_eq!(pe.header().number_of_sections.to_native(), 6);
+        assert_eq!(pe.optional_header().size_of_image(), 0x12000);
+
+        let names: Vec<_> = pe.section_headers().map(|v| v.name().to_vec()).collect();
+        assert_eq!(names, [
+            &b".text"[..], b".data", b".osrel", b".cmdline", b".linux", b".initrd",
+        ]);
+
+        for (name, rva, vsize, raw, off) in [
+            (SECTION_OSREL, 0x3000, 8, 0x200, 0x800),
+            (SECTION_CMDLINE, 0x4000, 5, 0x200, 0xa00),
+            (SECTION_LINUX, 0x10000, 0x1234, 0x1400, 0xc00),
+            (SECTION_INITRD, 0x12000, 0, 0, 0),
+        ] {
+            let v = pe.section_by_name(name).unwrap();
+            assert_eq!(v.virtual_address.to_native(), rva);
+            assert_eq!(v.virtual_size.to_native(), vsize);
+            assert_eq!(v.size_of_raw_data.to_native(), raw);
+            assert_eq!(v.pointer_to_raw_data.to_native(), off);
+            assert_eq!(v.characteristics.to_native(), SECTION_CHARACTERISTICS);
+        }
+
+        let v = pe.section_by_name(SECTION_CMDLINE).unwrap();
+        assert_eq!(pe.section_data(&v).unwrap(), b"quiet");
+        let v = pe.section_by_name(SECTION_LINUX).unwrap();
+        assert_eq!(pe.section_data(&v).unwrap(), &[0xaa; 0x1234]);
+        assert_eq!(pe.map_rva(0x3000, 8).unwrap(), b"ID=test\n");
+    }
+
+    // Verify invalid sections and stubs are rejected.
+    #[test]
+    fn invalid() {
+        let stub = stub();
+        for (section, error) in [
+            (Section { name: b"", rva: 0, data: b"x" }, Error::Name),
+            (Section { name: b".initramfs", rva: 0, data: b"x" }, Error::Name),
+            (Section { name: b".data", rva: 0, data: b"x" }, Error::Duplicate),
+            (Section { name: SECTION_DTB, rva: 0x3800, data: b"x" }, Error::Address),
+            (Section { name: SECTION_DTB, rva: 0x2000, data: b"x" }, Error::Overlap),
+            (Section { name: SECTION_DTB, rva: 0x1000, data: b"" }, Error::Overlap),
+        ] {
+            let mut uki = Builder::new(&stub);
+            uki.section(section);
+            assert_eq!(uki.write(&mut Vec::new()), Err(error));
+        }
+
+        // Sections must not overlap each other, or repeat.
+        let mut uki = Builder::new(&stub);
+        uki.section(Section { name: SECTION_SPLASH, rva: 0x5000, data: &[0; 0x1001] });
+        uki.section(Section { name: SECTION_DTB, rva: 0x6000, data: b"x" });
+        assert_eq!(uki.write(&mut Vec::new()), Err(Error::Overlap));
+        let mut uki = Builder::new(&stub);
+        uki.section(Section { name: SECTION_UNAME, rva: 0, data: b"6.1" });
+        uki.section(Section { name: SECTION_UNAME, rva: 0, data: b"6.2" });
+        assert_eq!(uki.write(&mut Vec::new()), Err(Error::Duplicate));
+
+        // The section table has no room for 14 more sections.
+        let names: Vec<_> = (0..14).map(|v| [b'.', b's', b'a' + v]).collect();
+        let mut uki = Builder::new(&stub);
+        for name in &names {
+            uki.section(Section { name: name, rva: 0, data: b"x" });
+        }
+        assert_eq!(uki.write(&mut Vec::new()), Err(Error::Space));
+
+        // Signed stubs are rejected.
+        let mut signed = stub.clone();
+        signed[0x128..0x12c].copy_from_slice(&0x800u32.to_le_bytes());
+        signed[0x12c..0x130].copy_from_slice(&0x8u32.to_le_bytes());
+        signed.extend([0; 8]);
+        assert_eq!(Builder::new(&signed).write(&mut Vec::new()), Err(Error::Signed));
+
+        assert_eq!(
+            Builder::new(&stub[..0x40]).write(&mut Vec::new()),
+            Err(Error::Pe(pecoff::Error::Bounds)),
+        );
+    }
+}